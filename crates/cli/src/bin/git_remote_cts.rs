@@ -0,0 +1,356 @@
+// =============================================================================
+// git-remote-cts - Git 원격 헬퍼
+// =============================================================================
+//
+// Git 이 "cts::{주소}" 형식의 원격을 만나면 이 프로그램을 실행 (PATH 에 있어야 함)
+//   git clone cts::http://server/api/repositories/{id} repo
+//   git remote add origin cts::/path/to/repo && git push origin main
+//
+// 원격 헬퍼 프로토콜 (표준 입력으로 명령, 표준 출력으로 응답, 빈 줄로 끝):
+//   capabilities        → import, export, option, import-marks {파일}, refspec
+//   list [for-push]     → "? {참조}" (값은 CTS 해시라 Git 에 알려주지 않음), "@{브랜치} HEAD"
+//   import {참조}       → CTS 원격에서 받아 fast-import 스트림으로 출력 (feature done ... done)
+//   export              → Git 의 fast-export 스트림을 CTS 객체로 가져와 원격에 push
+//   option force true   → fast-forward 가 아닌 push 허용
+//
+// 상태 디렉토리: $GIT_DIR/cts/{원격 이름}/
+// - .cts/          원격의 CTS 객체를 담는 미러 저장소 (참조는 마지막으로 본 원격 값)
+// - .cts/git-map   Git SHA-1 ↔ CTS 해시 매핑
+// - git-marks      capabilities 에 답할 때 매핑의 커밋으로 다시 기록 (":{번호} {SHA-1}")
+//                  fast-export 는 이 커밋들을 보내지 않고 ":{번호}" 로 부모를 참조
+//                  (mark 가 없으면 from 을 생략해서 새 브랜치의 첫 커밋이 루트가 됨)
+// Git 쪽 추적 참조: refs/cts/{원격 이름}/heads/*, refs/cts/{원격 이름}/tags/*
+// 주석 태그는 커밋으로 벗겨서 전달 (fast-import 의 tag 명령은 refs/tags 에 바로 기록해 clone 과 충돌)
+// fetch 는 추적 참조가 가리키는 커밋(Git 이 이미 가진 히스토리)을 다시 보내지 않음
+//
+// 파일 위치: crates/cli/src/bin/git_remote_cts.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+
+use anyhow::{bail, Context};
+use core::fast_import::{export_stream, import_stream_with_marks, GitMap};
+use core::refs::{Expected, Identity, HEADS_PREFIX, TAGS_PREFIX};
+use core::repo::{Repository, CTS_DIR_NAME};
+use core::revwalk::ancestors;
+use core::transport::{self, receive_pack, FetchRequest, PushRequest, PushUpdate, Transport, TOKEN_ENV};
+use shared::error::AppError;
+
+/// Git 쪽 추적 참조 접두사 (뒤에 "{원격 이름}/")
+const PRIVATE_PREFIX: &str = "refs/cts/";
+
+/// fast-export 용 mark 파일 (상태 디렉토리 안)
+const MARKS_FILE: &str = "git-marks";
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    let (remote, url) = match args.as_slice() {
+        [_, remote] => (remote.as_str(), remote.as_str()),
+        [_, remote, url] => (remote.as_str(), url.as_str()),
+        _ => bail!("usage: git-remote-cts <remote> [<url>]"),
+    };
+    let git_dir = PathBuf::from(std::env::var("GIT_DIR").context("GIT_DIR is not set (git-remote-cts is run by git)")?);
+    let mut helper = Helper::new(&git_dir, remote, url)?;
+
+    let mut input = io::stdin().lock();
+    let mut out = io::stdout().lock();
+    loop {
+        let Some(line) = read_line(&mut input)? else { break };
+        let (command, argument) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+        match command {
+            "capabilities" => {
+                // push 때 fast-export 가 바로 읽으므로 export 명령보다 먼저 준비
+                helper.write_marks()?;
+                writeln!(out, "import\nexport\noption")?;
+                writeln!(out, "import-marks {}", helper.marks_path.display())?;
+                writeln!(out, "refspec refs/heads/*:{}heads/*", helper.private_prefix)?;
+                writeln!(out, "refspec refs/tags/*:{}tags/*", helper.private_prefix)?;
+                writeln!(out)?;
+            }
+            "option" => writeln!(out, "{}", helper.option(argument))?,
+            "list" => {
+                for line in helper.list()? {
+                    writeln!(out, "{}", line)?;
+                }
+                writeln!(out)?;
+            }
+            "import" => {
+                // import 명령은 빈 줄까지 한 묶음
+                let mut names = vec![argument.to_string()];
+                while let Some(line) = read_line(&mut input)? {
+                    match line.strip_prefix("import ") {
+                        Some(name) => names.push(name.to_string()),
+                        None if line.is_empty() => break,
+                        None => bail!("unexpected command in import batch: {}", line),
+                    }
+                }
+                helper.import(&names, &mut out)?;
+            }
+            "export" => {
+                for status in helper.export(&mut input)? {
+                    writeln!(out, "{}", status)?;
+                }
+                writeln!(out)?;
+            }
+            // 빈 줄: Git 이 연결을 끝냄
+            "" => break,
+            _ => bail!("unsupported command: {}", line),
+        }
+        out.flush()?;
+    }
+    Ok(())
+}
+
+fn read_line(input: &mut impl BufRead) -> anyhow::Result<Option<String>> {
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim_end_matches('\n').to_string()))
+}
+
+struct Helper {
+    remote: Arc<dyn Transport>,
+    /// 원격 객체를 담는 미러 저장소
+    mirror: Repository,
+    map_path: PathBuf,
+    map: GitMap,
+    marks_path: PathBuf,
+    /// git-marks 에 기록한 mark → Git SHA-1
+    marks: BTreeMap<u64, String>,
+    /// "refs/cts/{원격 이름}/"
+    private_prefix: String,
+    force: bool,
+    identity: Identity,
+}
+
+impl Helper {
+    fn new(git_dir: &Path, remote: &str, url: &str) -> anyhow::Result<Self> {
+        // 명령줄에서 주소를 바로 쓰면 원격 이름도 주소 → 참조 이름에 쓸 수 있게 바꿈
+        let name: String = remote
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        // fast-export 에 넘길 mark 파일 경로는 작업 디렉토리와 상관없도록 절대 경로로
+        let state = fs::canonicalize(git_dir)?.join("cts").join(&name);
+        let mirror = if state.join(CTS_DIR_NAME).is_dir() {
+            Repository::open(state.join(CTS_DIR_NAME))?
+        } else {
+            Repository::init(&state)?
+        };
+        let map_path = GitMap::path(&mirror);
+        let identity = mirror.identity()?;
+        Ok(Self {
+            remote: transport::connect(&transport::normalize_url(url))?,
+            map: GitMap::load(&map_path)?,
+            map_path,
+            marks_path: state.join(MARKS_FILE),
+            marks: BTreeMap::new(),
+            mirror,
+            private_prefix: format!("{}{}/", PRIVATE_PREFIX, name),
+            force: false,
+            identity,
+        })
+    }
+
+    fn option(&mut self, argument: &str) -> &'static str {
+        match argument.split_once(' ') {
+            Some(("force", value)) => {
+                self.force = value == "true";
+                "ok"
+            }
+            // 진행 표시 관련은 출력이 없으므로 받기만 함
+            Some(("verbosity" | "progress", _)) => "ok",
+            _ => "unsupported",
+        }
+    }
+
+    /// 원격의 브랜치와 태그, HEAD 가 가리키는 브랜치
+    fn list(&self) -> anyhow::Result<Vec<String>> {
+        let refs = self.remote.list_refs()?;
+        let mut lines: Vec<String> = refs
+            .iter()
+            .filter(|(name, _)| name.starts_with(HEADS_PREFIX) || name.starts_with(TAGS_PREFIX))
+            .map(|(name, _)| format!("? {}", name))
+            .collect();
+
+        // 원격은 HEAD 의 해시만 알려줌 → 같은 커밋의 브랜치 (main, master 우선)
+        if let Some((_, head)) = refs.iter().find(|(name, _)| name == "HEAD") {
+            let branches: Vec<&str> = refs
+                .iter()
+                .filter(|(name, hash)| name.starts_with(HEADS_PREFIX) && hash == head)
+                .map(|(name, _)| name.as_str())
+                .collect();
+            let preferred = branches
+                .iter()
+                .find(|name| **name == "refs/heads/main" || **name == "refs/heads/master")
+                .or(branches.first());
+            if let Some(branch) = preferred {
+                lines.push(format!("@{} HEAD", branch));
+            }
+        }
+        Ok(lines)
+    }
+
+    /// 원격 참조를 미러로 받은 뒤 추적 참조 이름으로 fast-import 스트림 출력
+    fn import(&mut self, names: &[String], out: &mut impl Write) -> anyhow::Result<()> {
+        let remote_refs = self.remote.list_refs()?;
+        let wanted: Vec<(String, String)> = remote_refs
+            .into_iter()
+            .filter(|(name, _)| names.contains(name))
+            .collect();
+
+        let store = self.mirror.objects();
+        let missing: Vec<String> = wanted
+            .iter()
+            .map(|(_, hash)| hash.clone())
+            .filter(|hash| !store.contains(hash))
+            .collect();
+        if !missing.is_empty() {
+            let haves = self.mirror.list_refs("refs/")?.into_iter().map(|(_, hash)| hash).collect();
+            let request = FetchRequest { wants: missing, haves, ..Default::default() };
+            receive_pack(&self.mirror, &self.remote.fetch(&request)?)?;
+        }
+        let mut transaction = self.mirror.refs().transaction();
+        for (name, hash) in &wanted {
+            transaction.update(name, hash, Expected::Any);
+        }
+        if !transaction.is_empty() {
+            transaction.commit(&self.identity, "git-remote-cts: fetch")?;
+        }
+
+        let exclude = self.git_known_commits()?;
+        let refs = wanted
+            .iter()
+            .map(|(name, hash)| Ok((self.private_ref(name), store.peel_to_commit(hash)?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        // 원격이 강제로 바뀌었어도 추적 참조는 원격 값을 따름 (force 가 없으면 fast-import 가 거부)
+        writeln!(out, "feature done\nfeature force")?;
+        export_stream(&self.mirror, &refs, &exclude, &mut self.map, &mut *out)?;
+        writeln!(out, "done")?;
+        self.map.save(&self.map_path)?;
+        Ok(())
+    }
+
+    /// Git 의 fast-export 스트림을 미러로 가져와 원격에 push, 참조마다 "ok" / "error" 상태
+    fn export(&mut self, input: &mut impl BufRead) -> anyhow::Result<Vec<String>> {
+        let summary = import_stream_with_marks(&self.mirror, &mut *input, &mut self.map, &self.identity, &self.marks)?;
+        self.map.save(&self.map_path)?;
+
+        let remote_refs: HashMap<String, String> = self.remote.list_refs()?.into_iter().collect();
+        let mut statuses = Vec::new();
+        let mut updates = Vec::new();
+        for (name, new) in summary.refs {
+            let old = remote_refs.get(&name).cloned();
+            if old.as_ref() == Some(&new) {
+                statuses.push(format!("ok {}", name));
+                continue;
+            }
+            if let (Some(old), false) = (&old, self.force) {
+                if name.starts_with(TAGS_PREFIX) {
+                    statuses.push(format!("error {} already exists", name));
+                    continue;
+                }
+                if !self.is_ancestor(old, &new)? {
+                    // 원격이 모르는 커밋으로 앞서 있으면 먼저 받아야 함
+                    let reason = if self.mirror.objects().contains(old) { "non-fast-forward" } else { "fetch first" };
+                    statuses.push(format!("error {} {}", name, reason));
+                    continue;
+                }
+            }
+            updates.push(PushUpdate { name, old, new });
+        }
+        if updates.is_empty() {
+            return Ok(statuses);
+        }
+
+        let haves: Vec<String> = remote_refs.into_values().collect();
+        let request = PushRequest::new(self.mirror.objects(), updates, &haves)?;
+        let failure = match self.remote.push(&request) {
+            Ok(()) => None,
+            Err(AppError::Conflict(_)) => Some("fetch first".to_string()),
+            Err(AppError::Unauthorized) => Some(format!("authentication required (set {})", TOKEN_ENV)),
+            Err(err) => Some(err.to_string().replace('\n', " ")),
+        };
+        for update in &request.updates {
+            statuses.push(match &failure {
+                None => format!("ok {}", update.name),
+                Some(reason) => format!("error {} {}", update.name, reason),
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// 매핑의 Git SHA-1 중 이 Git 저장소에 있는 커밋을 mark 파일로 기록
+    /// (fast-export 는 파일에 없는 객체가 있으면 실패, 커밋이 아닌 객체는 무시)
+    fn write_marks(&mut self) -> anyhow::Result<()> {
+        let mut child = Command::new("git")
+            .args(["cat-file", "--batch-check=%(objectname) %(objecttype)"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .context("Cannot run git cat-file")?;
+        let mut stdin = child.stdin.take().expect("piped stdin");
+        let shas: Vec<String> = self.map.iter().map(|(git, _)| git.to_string()).collect();
+        // 출력을 읽는 동안 입력이 막히지 않도록 따로 기록
+        let writer = std::thread::spawn(move || -> io::Result<()> {
+            for sha in shas {
+                writeln!(stdin, "{}", sha)?;
+            }
+            Ok(())
+        });
+        let output = child.wait_with_output()?;
+        writer.join().expect("cat-file writer panicked")?;
+        if !output.status.success() {
+            bail!("git cat-file failed");
+        }
+
+        self.marks = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.strip_suffix(" commit"))
+            .zip(1..)
+            .map(|(sha, mark)| (mark, sha.to_string()))
+            .collect();
+        let mut text = String::new();
+        for (mark, sha) in &self.marks {
+            text.push_str(&format!(":{} {}\n", mark, sha));
+        }
+        fs::write(&self.marks_path, text)?;
+        Ok(())
+    }
+
+    fn private_ref(&self, name: &str) -> String {
+        format!("{}{}", self.private_prefix, name.strip_prefix("refs/").unwrap_or(name))
+    }
+
+    /// Git 이 이미 가진 커밋: 이 원격의 추적 참조가 가리키는 커밋 (매핑으로 CTS 해시로)
+    fn git_known_commits(&self) -> anyhow::Result<Vec<String>> {
+        let output = Command::new("git")
+            .args(["for-each-ref", "--format=%(objectname)", &self.private_prefix])
+            .output()
+            .context("Cannot run git for-each-ref")?;
+        if !output.status.success() {
+            bail!("git for-each-ref failed: {}", String::from_utf8_lossy(&output.stderr));
+        }
+        let store = self.mirror.objects();
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|sha1| self.map.cts(sha1))
+            .filter_map(|hash| store.peel_to_commit(hash).ok())
+            .collect())
+    }
+
+    fn is_ancestor(&self, old: &str, new: &str) -> anyhow::Result<bool> {
+        let store = self.mirror.objects();
+        if !store.contains(old) {
+            return Ok(false);
+        }
+        let old = store.peel_to_commit(old)?;
+        Ok(ancestors(store, &[store.peel_to_commit(new)?])?.contains(&old))
+    }
+}
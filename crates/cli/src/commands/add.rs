@@ -0,0 +1,140 @@
+// =============================================================================
+// cts add / cts rm / cts mv
+// =============================================================================
+//
+// 사용법:
+//   cts add README.md src/            # 파일과 디렉토리 (지운 파일은 인덱스에서도 제거)
+//   cts add '*.rs'                    # glob pathspec
+//   cts add -A                        # 작업 디렉토리 전체
+//   cts add -u                        # 추적하는 파일의 수정/삭제만
+//   cts add -n .                      # 무엇을 추가할지 보기만
+//   cts rm old.txt                    # 인덱스와 작업 디렉토리에서 제거
+//   cts rm -r --cached build/         # 인덱스에서만 제거 (파일은 남김)
+//   cts mv old.txt new.txt            # 이름 변경
+//   cts mv a.txt b.txt docs/          # 디렉토리 안으로
+//
+// 경로는 현재 디렉토리 기준
+
+use std::path::Path;
+
+use clap::Args;
+use core::repo::Repository;
+use core::staging::{self, AddOptions, MoveOptions, RemoveOptions, StagedPath};
+
+#[derive(Args)]
+pub struct AddArgs {
+    /// Files, directories or glob patterns to add
+    pathspecs: Vec<String>,
+    /// Stage every change in the working tree, including new and deleted files
+    #[arg(short = 'A', long, conflicts_with = "update")]
+    all: bool,
+    /// Stage changes to tracked files only
+    #[arg(short, long)]
+    update: bool,
+    /// Show what would be staged without staging it
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+    /// Print each staged path
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Args)]
+pub struct RemoveArgs {
+    /// Files, directories or glob patterns to remove
+    #[arg(required = true)]
+    pathspecs: Vec<String>,
+    /// Remove from the index only and keep the working tree files
+    #[arg(long)]
+    cached: bool,
+    /// Remove even if the files have uncommitted changes
+    #[arg(short, long)]
+    force: bool,
+    /// Allow removing directories
+    #[arg(short = 'r')]
+    recursive: bool,
+    /// Show what would be removed without removing it
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+    /// Do not print removed paths
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(Args)]
+pub struct MoveArgs {
+    /// Files or directories to move, followed by the destination
+    #[arg(required = true, num_args = 2..)]
+    paths: Vec<String>,
+    /// Overwrite an existing destination file
+    #[arg(short, long)]
+    force: bool,
+    /// Show what would be moved without moving it
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+    /// Print each moved path
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+pub fn run(args: AddArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let options = AddOptions {
+        all: args.all,
+        update: args.update,
+        dry_run: args.dry_run,
+    };
+    let staged = staging::add(&repo, &pathspecs(&repo, &args.pathspecs)?, &options)?;
+    if args.dry_run || args.verbose {
+        for path in staged {
+            match path {
+                StagedPath::Added(path) => println!("add '{}'", path),
+                StagedPath::Removed(path) => println!("remove '{}'", path),
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn run_rm(args: RemoveArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let options = RemoveOptions {
+        cached: args.cached,
+        force: args.force,
+        recursive: args.recursive,
+        dry_run: args.dry_run,
+    };
+    let removed = staging::remove(&repo, &pathspecs(&repo, &args.pathspecs)?, &options)?;
+    if !args.quiet {
+        for path in removed {
+            println!("rm '{}'", path);
+        }
+    }
+    Ok(())
+}
+
+pub fn run_mv(args: MoveArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let mut paths = pathspecs(&repo, &args.paths)?;
+    let destination = paths.pop().unwrap_or_default();
+    let options = MoveOptions {
+        force: args.force,
+        dry_run: args.dry_run,
+    };
+    let renamed = staging::rename(&repo, &paths, &destination, &options)?;
+    if args.dry_run || args.verbose {
+        for (from, to) in renamed {
+            println!("Renaming {} to {}", from, to);
+        }
+    }
+    Ok(())
+}
+
+/// 현재 디렉토리 기준 경로 → 작업 디렉토리 기준 pathspec
+fn pathspecs(repo: &Repository, paths: &[String]) -> anyhow::Result<Vec<String>> {
+    let cwd = std::env::current_dir()?;
+    paths
+        .iter()
+        .map(|path| Ok(repo.relative_path(&cwd, Path::new(path))?))
+        .collect()
+}
@@ -0,0 +1,122 @@
+// =============================================================================
+// cts am
+// =============================================================================
+//
+// 사용법:
+//   cts am 0001-fix.patch 0002-docs.patch
+//   cts am --3way series.mbox
+//   cts am -S 0001-fix.patch          # 만든 커밋에 서명
+//   cts am -s 0001-fix.patch          # Signed-off-by 트레일러 추가
+//
+// 패치 시리즈를 순서대로 적용하며 패치마다 커밋 생성
+// 작성자/날짜/메시지는 패치에 기록된 값을 그대로 사용
+//
+// 그대로 적용되지 않는 패치는 --3way 로 원본 Blob 기준 3-way 병합
+// 충돌이 나면 충돌 마커를 작업 디렉토리에 남기고 중단
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::Args;
+use core::checkout::update_worktree;
+use core::index::Index;
+use core::object::{Actor, Commit, Object};
+use core::patch::{apply_patch, split_series, Patch};
+use core::refs::Expected;
+use core::repo::Repository;
+use core::trailer::{add_trailers, Trailer};
+
+use super::signing_key::load_signing_key;
+
+#[derive(Args)]
+pub struct AmArgs {
+    /// Patch files (or mailboxes containing several patches) in order
+    #[arg(required = true)]
+    patches: Vec<PathBuf>,
+    /// Fall back to a three-way merge when a patch does not apply cleanly
+    #[arg(short = '3', long = "3way")]
+    three_way: bool,
+    /// Sign the created commits with the local signing key
+    #[arg(short = 'S', long)]
+    sign: bool,
+    /// Add a Signed-off-by trailer for the committer to each message
+    #[arg(short = 's', long)]
+    signoff: bool,
+}
+
+pub fn run(args: AmArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let work_dir = repo
+        .work_dir()
+        .context("cts am needs a working tree")?
+        .to_path_buf();
+
+    let mut series = Vec::new();
+    for path in &args.patches {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        for part in split_series(&text) {
+            let patch = Patch::parse(part).with_context(|| format!("In {}", path.display()))?;
+            series.push(patch);
+        }
+    }
+
+    let key = if args.sign { Some(load_signing_key()?) } else { None };
+    let identity = repo.committer_identity()?;
+    let mut head = repo.head()?;
+    let mut tree = match &head {
+        Some(hash) => repo.objects().read_commit(hash)?.tree_hash,
+        None => repo.objects().write_flat_tree(&BTreeMap::new())?,
+    };
+
+    for (i, patch) in series.iter().enumerate() {
+        println!("Applying: {}", patch.subject());
+        let outcome = apply_patch(repo.objects(), &tree, patch, args.three_way)
+            .with_context(|| format!("Patch failed at {}/{}: {}", i + 1, series.len(), patch.subject()))?;
+
+        update_worktree(repo.objects(), Some(&tree), &outcome.tree, &work_dir)?;
+        if !outcome.conflicts.is_empty() {
+            for path in &outcome.conflicts {
+                println!("CONFLICT (content): merge conflict in {}", path);
+            }
+            bail!(
+                "Patch failed at {}/{}: {}\nResolve the conflicts, commit the result, \
+                 then re-run cts am with the remaining {} patch(es)",
+                i + 1,
+                series.len(),
+                patch.subject(),
+                series.len() - i - 1
+            );
+        }
+
+        let message = if args.signoff {
+            add_trailers(&patch.message, &[Trailer::signed_off_by(&identity.name, &identity.email)])
+        } else {
+            patch.message.clone()
+        };
+        let mut commit = Commit::new(
+            outcome.tree.clone(),
+            head.clone(),
+            message,
+            patch.author.clone(),
+            Actor::now(identity.name.clone(), identity.email.clone()),
+        );
+        if let Some(key) = &key {
+            key.sign_commit(&mut commit);
+        }
+        let hash = repo.objects().write(&Object::from(commit))?;
+        // HEAD 가 브랜치를 가리키면 브랜치가 갱신됨
+        let expected = match &head {
+            Some(old) => Expected::Value(old.clone()),
+            None => Expected::Missing,
+        };
+        repo.refs()
+            .update("HEAD", &hash, expected, &identity, &format!("am: {}", patch.subject()))?;
+        repo.write_index(&Index::from_tree(repo.objects(), &outcome.tree)?)?;
+        head = Some(hash);
+        tree = outcome.tree;
+    }
+    Ok(())
+}
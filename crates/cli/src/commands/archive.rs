@@ -0,0 +1,63 @@
+// =============================================================================
+// cts archive
+// =============================================================================
+//
+// 사용법:
+//   cts archive v1.0 > release.tar
+//   cts archive --format=zip -o release.zip v1.0
+//   cts archive -o app-1.0.tar.gz --prefix=app-1.0/ v1.0 src docs
+//
+// 형식을 지정하지 않으면 -o 파일 이름의 확장자, 그것도 없으면 tar
+// 같은 커밋이면 항상 같은 바이트 (수정 시각은 커밋 시각, 권한은 트리 모드)
+// .ctsattributes 에서 export-ignore 로 표시한 경로는 제외
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use clap::Args;
+use core::archive::{write_archive, ArchiveFormat, ArchiveOptions};
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct ArchiveArgs {
+    /// Archive format (tar, tar.gz or zip)
+    #[arg(long)]
+    format: Option<ArchiveFormat>,
+    /// Prepend this to every path in the archive (e.g. "project-1.0/")
+    #[arg(long, default_value = "")]
+    prefix: String,
+    /// Write the archive to this file instead of standard output
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Branch, tag or commit to archive
+    rev: String,
+    /// Only include these files or directories
+    paths: Vec<String>,
+}
+
+pub fn run(args: ArchiveArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let commit = repo.resolve_rev(&args.rev)?;
+    let format = args
+        .format
+        .or_else(|| {
+            args.output
+                .as_ref()
+                .and_then(|path| ArchiveFormat::from_file_name(&path.to_string_lossy()))
+        })
+        .unwrap_or(ArchiveFormat::Tar);
+    let options = ArchiveOptions {
+        prefix: args.prefix,
+        paths: args.paths,
+    };
+
+    let out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(std::io::stdout().lock()),
+    };
+    let mut out = BufWriter::new(out);
+    write_archive(repo.objects(), &commit, format, &options, &mut out)?;
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,174 @@
+// =============================================================================
+// cts bisect
+// =============================================================================
+//
+// 사용법:
+//   cts bisect start [<bad> [<good>...]]
+//   cts bisect good [<rev>]            # 지정하지 않으면 HEAD (현재 시험 중인 커밋)
+//   cts bisect bad [<rev>]
+//   cts bisect skip [<rev>]            # 빌드가 안 되는 등 시험할 수 없는 커밋
+//   cts bisect log
+//   cts bisect reset                   # 시작 전 브랜치로 복귀
+//   cts bisect run ./test.sh arg...    # 스크립트로 자동 판정
+//
+// run 종료 코드: 0 → good, 125 → skip, 1~127 (125 제외) → bad, 그 밖 → 중단
+// 탐색 범위: CTS 커밋은 부모가 하나 → bad 에서 good 까지 부모 사슬 한 줄만 이분 탐색
+//   (병합 커밋이 없으므로 갈라졌다 합쳐진 히스토리를 따로 다루지 않음)
+// 시험할 커밋으로 HEAD 가 옮겨질 때마다 post-checkout 훅 실행
+
+use std::process::Command;
+
+use anyhow::{bail, Context};
+use clap::{Args, Subcommand};
+use core::bisect::{Bisect, BisectStep, Mark};
+use core::repo::Repository;
+
+use crate::hooks;
+
+#[derive(Args)]
+pub struct BisectArgs {
+    #[command(subcommand)]
+    command: BisectCommand,
+}
+
+#[derive(Subcommand)]
+enum BisectCommand {
+    /// Start a bisect session, optionally with the bad and good commits
+    Start {
+        bad: Option<String>,
+        good: Vec<String>,
+    },
+    /// Mark a commit as good (default: HEAD)
+    Good { rev: Option<String> },
+    /// Mark a commit as bad (default: HEAD)
+    Bad { rev: Option<String> },
+    /// Mark a commit as untestable (default: HEAD)
+    Skip { rev: Option<String> },
+    /// Finish the session and return to the original HEAD
+    Reset,
+    /// Show the marks recorded so far
+    Log,
+    /// Mark commits automatically with the exit code of a command
+    Run {
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
+}
+
+pub fn run(args: BisectArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let bisect = Bisect::new(&repo, repo.identity()?);
+    let mut head = repo.head()?;
+
+    let step = match args.command {
+        BisectCommand::Start { bad, good } => bisect.start(bad.as_deref(), &good)?,
+        BisectCommand::Good { rev } => bisect.mark(Mark::Good, rev.as_deref().unwrap_or("HEAD"))?,
+        BisectCommand::Bad { rev } => bisect.mark(Mark::Bad, rev.as_deref().unwrap_or("HEAD"))?,
+        BisectCommand::Skip { rev } => bisect.mark(Mark::Skip, rev.as_deref().unwrap_or("HEAD"))?,
+        BisectCommand::Reset => {
+            let start = bisect.reset()?;
+            println!("Bisect reset; HEAD is back at {}", start.strip_prefix("refs/heads/").unwrap_or(&start));
+            return notify_checkout(&repo, &mut head);
+        }
+        BisectCommand::Log => {
+            print!("{}", bisect.log()?);
+            return Ok(());
+        }
+        BisectCommand::Run { command } => return run_command(&repo, &bisect, &command, &mut head),
+    };
+    notify_checkout(&repo, &mut head)?;
+    report(&repo, &step)?;
+    Ok(())
+}
+
+/// HEAD 가 다른 커밋으로 옮겨졌으면 post-checkout 훅 실행
+fn notify_checkout(repo: &Repository, head: &mut Option<String>) -> anyhow::Result<()> {
+    let new = repo.head()?;
+    if let Some(commit) = new.as_deref().filter(|commit| Some(*commit) != head.as_deref()) {
+        hooks::post_checkout(repo, head.as_deref(), commit)?;
+    }
+    *head = new;
+    Ok(())
+}
+
+/// 명령 종료 코드로 판정을 반복
+fn run_command(
+    repo: &Repository,
+    bisect: &Bisect<'_>,
+    command: &[String],
+    head: &mut Option<String>,
+) -> anyhow::Result<()> {
+    if !bisect.is_active() {
+        bail!("Not bisecting; use 'cts bisect start' first");
+    }
+    let work_dir = repo.work_dir().context("Bisect requires a work tree")?;
+    let mut step = bisect.next()?;
+    loop {
+        notify_checkout(repo, head)?;
+        if let BisectStep::NeedMore { .. } = step {
+            report(repo, &step)?;
+            bail!("'bisect run' needs both a good and a bad commit");
+        }
+        if report(repo, &step)? {
+            return Ok(());
+        }
+        println!("running {}", command.join(" "));
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(format!("{} \"$@\"", command[0]))
+            .arg(&command[0])
+            .args(&command[1..])
+            .current_dir(work_dir)
+            .status()
+            .with_context(|| format!("Failed to run '{}'", command[0]))?;
+        let mark = Mark::from_exit_code(status.code())?;
+        step = bisect.mark(mark, "HEAD")?;
+    }
+}
+
+/// 단계 출력
+///
+/// # Returns
+/// 탐색이 끝났는지 (범인을 찾았거나 skip 때문에 더 좁힐 수 없음)
+fn report(repo: &Repository, step: &BisectStep) -> anyhow::Result<bool> {
+    match step {
+        BisectStep::NeedMore { bad, good } => {
+            match (bad, good) {
+                (false, false) => println!("Waiting for both good and bad commits"),
+                (false, true) => println!("Waiting for a bad commit"),
+                _ => println!("Waiting for a good commit"),
+            }
+            Ok(false)
+        }
+        BisectStep::Testing { commit, remaining, steps } => {
+            let message = repo.objects().read_commit(commit)?.message;
+            println!(
+                "Bisecting: {} revisions left to test after this (roughly {} steps)",
+                remaining, steps
+            );
+            println!("[{}] {}", commit, message.lines().next().unwrap_or(""));
+            Ok(false)
+        }
+        BisectStep::Found(commit) => {
+            let found = repo.objects().read_commit(commit)?;
+            println!("{} is the first bad commit", commit);
+            println!("commit {}", commit);
+            println!("Author: {} <{}>", found.author.name, found.author.email);
+            println!("Date:   {}", found.author.local_time().to_rfc2822());
+            println!();
+            for line in found.message.lines() {
+                println!("    {}", line);
+            }
+            Ok(true)
+        }
+        BisectStep::OnlySkipped(commits) => {
+            println!("There are only 'skip'ped commits left to test.");
+            println!("The first bad commit could be any of:");
+            for commit in commits {
+                println!("{}", commit);
+            }
+            println!("We cannot bisect more!");
+            Ok(true)
+        }
+    }
+}
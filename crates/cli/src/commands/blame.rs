@@ -0,0 +1,90 @@
+// =============================================================================
+// cts blame
+// =============================================================================
+//
+// 사용법:
+//   cts blame src/main.rs
+//   cts blame --rev v1.0..HEAD src/main.rs
+//   cts blame --ignore-rev <hash> --ignore-revs-file .cts-blame-ignore-revs src/main.rs
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::Args;
+use core::blame::{blame, BlameOptions};
+use core::repo::Repository;
+
+/// 출력 시 표시할 해시 길이
+const SHORT_HASH_LENGTH: usize = 8;
+
+#[derive(Args)]
+pub struct BlameArgs {
+    /// File to annotate
+    path: PathBuf,
+    /// Revision or range to annotate (e.g. HEAD, v1.0..HEAD)
+    #[arg(short, long, default_value = "HEAD")]
+    rev: String,
+    /// Ignore changes made by the given revision (repeatable)
+    #[arg(long = "ignore-rev", value_name = "REV")]
+    ignore_revs: Vec<String>,
+    /// Ignore revisions listed in the file (one per line, '#' comments)
+    #[arg(long, value_name = "FILE")]
+    ignore_revs_file: Option<PathBuf>,
+    /// Show author email instead of name
+    #[arg(short = 'e', long)]
+    show_email: bool,
+}
+
+pub fn run(args: BlameArgs) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let repo = Repository::from_env()?;
+    let path = repo.relative_path(&cwd, &args.path)?;
+
+    let (exclude, start) = repo.resolve_range(&args.rev)?;
+
+    let mut ignore_specs = args.ignore_revs;
+    if let Some(file) = &args.ignore_revs_file {
+        let content = fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        ignore_specs.extend(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string),
+        );
+    }
+    let ignore_revs = ignore_specs
+        .iter()
+        .map(|spec| repo.resolve_rev(spec))
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    let options = BlameOptions { exclude, ignore_revs };
+    let lines = blame(repo.objects(), &start, &path, &options)?;
+
+    let who = |line: &core::blame::BlameLine| {
+        if args.show_email {
+            format!("<{}>", line.author.email)
+        } else {
+            line.author.name.clone()
+        }
+    };
+    let who_width = lines.iter().map(|l| who(l).chars().count()).max().unwrap_or(0);
+    let number_width = lines.len().to_string().len();
+
+    for line in &lines {
+        let marker = if line.boundary { "^" } else { "" };
+        println!(
+            "{}{} ({:<who_width$} {} {:>number_width$}) {}",
+            marker,
+            &line.commit[..SHORT_HASH_LENGTH],
+            who(line),
+            line.author.local_time().format("%Y-%m-%d %H:%M:%S %z"),
+            line.line_number,
+            line.content,
+        );
+    }
+    Ok(())
+}
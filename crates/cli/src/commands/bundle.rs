@@ -0,0 +1,138 @@
+// =============================================================================
+// cts bundle
+// =============================================================================
+//
+// 사용법:
+//   cts bundle create repo.bundle main
+//   cts bundle create update.bundle v1.0..main
+//   cts bundle create --notes repo.bundle main     # refs/notes/* 포함
+//   cts bundle verify repo.bundle
+//   cts bundle list-heads repo.bundle
+//
+// 만든 번들은 cts clone / cts fetch 에 파일 경로로 넘겨 적용
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{Args, Subcommand};
+use core::bundle::Bundle;
+use core::refs::NOTES_PREFIX;
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct BundleArgs {
+    #[command(subcommand)]
+    command: BundleCommand,
+}
+
+#[derive(Subcommand)]
+enum BundleCommand {
+    /// Write the history of the given refs into a bundle file
+    Create {
+        /// Bundle file to write
+        file: PathBuf,
+        /// Refs or ranges to include (e.g. main, v1.0..main)
+        #[arg(required_unless_present = "notes")]
+        revs: Vec<String>,
+        /// Also include every notes ref (refs/notes/*)
+        #[arg(long)]
+        notes: bool,
+    },
+    /// Check that a bundle is intact and applicable to this repository
+    Verify {
+        /// Bundle file to check
+        file: PathBuf,
+    },
+    /// List the refs contained in a bundle
+    ListHeads {
+        /// Bundle file to read
+        file: PathBuf,
+    },
+}
+
+pub fn run(args: BundleArgs) -> anyhow::Result<()> {
+    match args.command {
+        BundleCommand::Create { file, revs, notes } => create(file, revs, notes),
+        BundleCommand::Verify { file } => verify(file),
+        BundleCommand::ListHeads { file } => list_heads(file),
+    }
+}
+
+fn create(file: PathBuf, revs: Vec<String>, notes: bool) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+
+    let mut refs = Vec::new();
+    let mut exclude = Vec::new();
+    for spec in &revs {
+        let (from, hash) = repo.resolve_range(spec)?;
+        // 범위의 끝은 번들 안에서 참조 이름으로 기록되어야 함
+        let tip = spec.split_once("..").map_or(spec.as_str(), |(_, to)| to);
+        let tip = if tip.is_empty() { "HEAD" } else { tip };
+        let name = repo
+            .full_ref_name(tip)?
+            .with_context(|| format!("Not a ref: {} (bundles need named refs)", tip))?;
+        if !refs.iter().any(|(n, _): &(String, String)| n == &name) {
+            // 주석 태그는 태그 객체 그대로 (resolve_range 는 커밋으로 해석함)
+            let value = repo.read_ref(&name)?.unwrap_or(hash);
+            refs.push((name, value));
+        }
+        exclude.extend(from);
+    }
+    if notes {
+        // 노트 히스토리는 범위 없이 통째로 (노트 커밋은 작아서 부담이 적음)
+        for (name, hash) in repo.list_refs(NOTES_PREFIX)? {
+            if !name.starts_with(&format!("{}remotes/", NOTES_PREFIX)) && !refs.iter().any(|(n, _)| n == &name) {
+                refs.push((name, hash));
+            }
+        }
+    }
+
+    let bundle = Bundle::create(repo.objects(), refs, &exclude)?;
+    bundle.write_to(&file)?;
+
+    let verification = bundle.verify(repo.objects())?;
+    println!(
+        "Created {} ({} objects, {} refs)",
+        file.display(),
+        verification.object_count,
+        bundle.refs.len()
+    );
+    Ok(())
+}
+
+fn verify(file: PathBuf) -> anyhow::Result<()> {
+    let bundle = Bundle::read_from(&file)?;
+    let repo = Repository::from_env()?;
+    let verification = bundle.verify(repo.objects())?;
+
+    println!("The bundle contains {} ref(s):", bundle.refs.len());
+    for (name, hash) in &bundle.refs {
+        println!("{} {}", hash, name);
+    }
+    if bundle.prerequisites.is_empty() {
+        println!("The bundle records a complete history.");
+    } else {
+        println!("The bundle requires {} commit(s):", bundle.prerequisites.len());
+        for (hash, summary) in &bundle.prerequisites {
+            println!("{} {}", hash, summary);
+        }
+    }
+
+    if !verification.is_ok() {
+        bail!(
+            "{} is not applicable: missing prerequisite commit(s) {}",
+            file.display(),
+            verification.missing_prerequisites.join(", ")
+        );
+    }
+    println!("{} is okay ({} objects)", file.display(), verification.object_count);
+    Ok(())
+}
+
+fn list_heads(file: PathBuf) -> anyhow::Result<()> {
+    let bundle = Bundle::read_from(&file)?;
+    for (name, hash) in &bundle.refs {
+        println!("{} {}", hash, name);
+    }
+    Ok(())
+}
@@ -0,0 +1,166 @@
+// =============================================================================
+// cts cherry-pick / cts revert
+// =============================================================================
+//
+// 사용법:
+//   cts cherry-pick <commit>...        # 커밋들을 현재 HEAD 위에 다시 적용
+//   cts cherry-pick main~3..main       # 범위 (오래된 커밋부터)
+//   cts revert <commit>...             # 변경을 되돌리는 커밋 생성
+//   cts cherry-pick --continue | --skip | --abort
+//
+// 충돌이 나면 파일에 충돌 마커를 남기고 멈춤
+// 해결한 뒤 --continue (해결한 파일은 자동으로 스테이징), 건너뛰려면 --skip,
+// 처음 상태로 돌아가려면 --abort
+
+use anyhow::bail;
+use clap::Args;
+use core::repo::Repository;
+use core::revwalk::walk;
+use core::sequencer::{Operation, Sequencer, SequencerOutcome};
+use shared::error::AppError;
+
+use crate::editor::commit_message_editor;
+
+#[derive(Args)]
+pub struct CherryPickArgs {
+    /// Commits or ranges (A..B) to apply, oldest first
+    #[arg(required_unless_present_any = ["resume", "skip", "abort"])]
+    commits: Vec<String>,
+    #[command(flatten)]
+    control: SequencerControl,
+}
+
+#[derive(Args)]
+pub struct RevertArgs {
+    /// Commits or ranges (A..B) to revert, in the given order
+    #[arg(required_unless_present_any = ["resume", "skip", "abort"])]
+    commits: Vec<String>,
+    #[command(flatten)]
+    control: SequencerControl,
+}
+
+/// 멈춘 작업 제어 (cherry-pick / revert / rebase 공통)
+#[derive(Args)]
+pub struct SequencerControl {
+    /// Continue after resolving conflicts
+    #[arg(long = "continue", conflicts_with_all = ["skip", "abort"])]
+    pub resume: bool,
+    /// Skip the commit that stopped and continue with the rest
+    #[arg(long, conflicts_with = "abort")]
+    pub skip: bool,
+    /// Cancel and return to the state before the operation started
+    #[arg(long)]
+    pub abort: bool,
+}
+
+impl SequencerControl {
+    pub fn is_set(&self) -> bool {
+        self.resume || self.skip || self.abort
+    }
+}
+
+pub fn run(args: CherryPickArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let sequencer = Sequencer::new(&repo, repo.committer_identity()?);
+    let mut edit = commit_message_editor(&repo);
+    let outcome = if args.control.is_set() {
+        match control(&sequencer, Operation::CherryPick, &args.control, &mut edit)? {
+            Some(outcome) => outcome,
+            None => return Ok(()),
+        }
+    } else {
+        let commits = expand_commits(&repo, &args.commits, true)?;
+        sequencer.cherry_pick(&commits, &mut edit)?
+    };
+    report(Operation::CherryPick, outcome)
+}
+
+pub fn run_revert(args: RevertArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let sequencer = Sequencer::new(&repo, repo.committer_identity()?);
+    let mut edit = commit_message_editor(&repo);
+    let outcome = if args.control.is_set() {
+        match control(&sequencer, Operation::Revert, &args.control, &mut edit)? {
+            Some(outcome) => outcome,
+            None => return Ok(()),
+        }
+    } else {
+        // 되돌리기는 최신 커밋부터
+        let commits = expand_commits(&repo, &args.commits, false)?;
+        sequencer.revert(&commits, &mut edit)?
+    };
+    report(Operation::Revert, outcome)
+}
+
+/// --continue / --skip / --abort 처리
+///
+/// # Returns
+/// 이어서 진행한 결과 (abort 면 None)
+pub fn control(
+    sequencer: &Sequencer<'_>,
+    operation: Operation,
+    control: &SequencerControl,
+    edit: &mut dyn FnMut(&str) -> Result<String, AppError>,
+) -> anyhow::Result<Option<SequencerOutcome>> {
+    if sequencer.operation()? != Some(operation) {
+        bail!("No {} in progress", operation);
+    }
+    if control.abort {
+        let head = sequencer.abort()?;
+        println!("Aborted {}; HEAD is back at {}", operation, &head[..8]);
+        return Ok(None);
+    }
+    let outcome = if control.skip {
+        sequencer.skip(edit)?
+    } else {
+        sequencer.resume(edit)?
+    };
+    Ok(Some(outcome))
+}
+
+/// 결과 출력 (멈췄으면 안내와 함께 실패)
+pub fn report(operation: Operation, outcome: SequencerOutcome) -> anyhow::Result<()> {
+    match outcome {
+        SequencerOutcome::Done { head, empty } => {
+            for item in empty {
+                println!("Skipped {} (the change is already present)", item.summary());
+            }
+            println!("HEAD is now at {}", &head[..8]);
+            Ok(())
+        }
+        SequencerOutcome::Stopped { item, conflicts } => {
+            for path in &conflicts {
+                println!("CONFLICT (content): Merge conflict in {}", path);
+            }
+            bail!(
+                "could not apply {}\nResolve the conflicts, then run 'cts {} --continue' \
+                 (or --skip to drop this commit, --abort to cancel)",
+                item.summary(),
+                operation
+            )
+        }
+    }
+}
+
+/// 리비전/범위 인자를 커밋 목록으로
+///
+/// * `oldest_first` - 범위 안의 커밋을 오래된 것부터 (false 면 최신부터)
+fn expand_commits(repo: &Repository, specs: &[String], oldest_first: bool) -> anyhow::Result<Vec<String>> {
+    let mut commits = Vec::new();
+    for spec in specs {
+        if spec.contains("..") {
+            let (exclude, include) = repo.resolve_range(spec)?;
+            let mut range = walk(repo.objects(), &[include], &exclude)?;
+            if oldest_first {
+                range.reverse();
+            }
+            commits.extend(range);
+        } else {
+            commits.push(repo.resolve_rev(spec)?);
+        }
+    }
+    if commits.is_empty() {
+        bail!("Empty commit range");
+    }
+    Ok(commits)
+}
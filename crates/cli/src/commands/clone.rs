@@ -0,0 +1,183 @@
+// =============================================================================
+// cts clone
+// =============================================================================
+//
+// 사용법:
+//   cts clone repo.bundle
+//   cts clone repo.bundle my-project
+//   cts clone http://server:3000/api/repositories/{id} my-project
+//   cts clone ../other-checkout
+//   cts clone --filter=blob:none http://...          # partial clone: 커밋과 트리만
+//   cts clone --filter=blob:limit=1m http://...      # 1MiB 이상인 Blob 만 생략
+//   cts clone --depth 1 http://...                   # shallow clone: 최신 커밋만
+//   cts clone --shallow-since 2024-01-01 http://...  # 이 날짜 이후의 커밋만
+//   cts clone --recurse-submodules http://...        # 서브모듈까지 받아서 체크아웃
+//
+// partial clone 은 원격을 .cts/config 에 promisor 로 기록하고,
+// 빠진 Blob 은 checkout / diff / blame 이 처음 필요로 할 때 한 번에 받아옴
+// shallow clone 은 부모를 받지 않은 경계 커밋을 .cts/shallow 에 기록
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use clap::Args;
+use core::bundle::Bundle;
+use core::checkout::checkout_tree;
+use core::index::Index;
+use core::refs::{Expected, HEADS_PREFIX, NOTES_PREFIX, REMOTES_PREFIX, TAGS_PREFIX};
+use core::repo::Repository;
+use core::shallow::DepthLimit;
+use core::transport::{self, FetchRequest, ObjectFilter};
+use shared::types::parse_date;
+
+use crate::hooks;
+
+/// 복제 원본에 붙이는 원격 이름
+pub const DEFAULT_REMOTE: &str = "origin";
+
+#[derive(Args)]
+pub struct CloneArgs {
+    /// Repository to clone (bundle file, repository path or server URL)
+    source: String,
+    /// Directory to clone into (defaults to the source name)
+    directory: Option<PathBuf>,
+    /// Omit objects from the download (blob:none or blob:limit=<size>)
+    #[arg(long, value_name = "FILTER")]
+    filter: Option<String>,
+    /// Fetch only the latest N commits of history
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    depth: Option<usize>,
+    /// Fetch only commits newer than this date
+    #[arg(long, value_name = "DATE", conflicts_with = "depth")]
+    shallow_since: Option<String>,
+    /// Initialize and update all submodules after checkout
+    #[arg(long)]
+    recurse_submodules: bool,
+}
+
+pub fn run(args: CloneArgs) -> anyhow::Result<()> {
+    let source = Path::new(args.source.trim_end_matches('/'));
+    let is_bundle = Bundle::is_bundle_file(source);
+    let filter = args.filter.as_deref().map(str::parse::<ObjectFilter>).transpose()?;
+    let limit = match (args.depth, &args.shallow_since) {
+        (Some(depth), _) => Some(DepthLimit::Depth(depth)),
+        (_, Some(date)) => Some(DepthLimit::Since(parse_date(date)?.timestamp())),
+        _ => None,
+    };
+    if is_bundle && (filter.is_some() || limit.is_some()) {
+        bail!("--filter, --depth and --shallow-since are not supported when cloning from a bundle");
+    }
+
+    let directory = match args.directory {
+        Some(dir) => dir,
+        None => PathBuf::from(
+            source
+                .file_stem()
+                .with_context(|| format!("Cannot derive directory name from {}", args.source))?,
+        ),
+    };
+    if directory.exists() && directory.read_dir()?.next().is_some() {
+        bail!("Destination {} already exists and is not empty", directory.display());
+    }
+
+    let (repo, refs) = if is_bundle {
+        let bundle = Bundle::read_from(source)?;
+        if !bundle.prerequisites.is_empty() {
+            bail!("{} is an incremental bundle and cannot be cloned from", args.source);
+        }
+        let repo = Repository::init(&directory)?;
+        let refs = bundle.unbundle(repo.objects())?;
+        (repo, refs)
+    } else {
+        clone_remote(&args.source, &directory, filter, limit)?
+    };
+
+    let identity = repo.identity()?;
+    let reason = format!("clone: from {}", args.source);
+    let mut transaction = repo.refs().transaction();
+    for (name, hash) in &refs {
+        if let Some(branch) = name.strip_prefix(HEADS_PREFIX) {
+            transaction.update(name, hash, Expected::Missing);
+            transaction.update(
+                &format!("{}{}/{}", REMOTES_PREFIX, DEFAULT_REMOTE, branch),
+                hash,
+                Expected::Missing,
+            );
+        } else if name.starts_with(TAGS_PREFIX) || name.starts_with(NOTES_PREFIX) {
+            transaction.update(name, hash, Expected::Missing);
+        }
+    }
+    transaction.commit(&identity, &reason)?;
+
+    // HEAD: 번들의 HEAD 와 같은 커밋의 브랜치 → 기본 브랜치 → 첫 브랜치
+    let head_hash = refs.iter().find(|(name, _)| name == "HEAD").map(|(_, hash)| hash);
+    let branches: Vec<&(String, String)> =
+        refs.iter().filter(|(name, _)| name.starts_with(HEADS_PREFIX)).collect();
+    let head = branches
+        .iter()
+        .find(|(_, hash)| Some(hash) == head_hash)
+        .or_else(|| branches.iter().find(|(name, _)| name == &format!("{}{}", HEADS_PREFIX, core::repo::DEFAULT_BRANCH)))
+        .or_else(|| branches.first());
+
+    match head {
+        Some((name, hash)) => {
+            repo.refs().set_symbolic("HEAD", name, &identity, &reason)?;
+            let branch = &name[HEADS_PREFIX.len()..];
+            repo.refs().set_symbolic(
+                &format!("{}{}/HEAD", REMOTES_PREFIX, DEFAULT_REMOTE),
+                &format!("{}{}/{}", REMOTES_PREFIX, DEFAULT_REMOTE, branch),
+                &identity,
+                &reason,
+            )?;
+            let tree = repo.objects().read_commit(hash)?.tree_hash;
+            checkout_tree(repo.objects(), &tree, &directory)?;
+            repo.write_index(&Index::from_tree(repo.objects(), &tree)?)?;
+            if args.recurse_submodules {
+                super::submodule::update(&repo, &[], true, true)?;
+            }
+            hooks::post_checkout(&repo, None, hash)?;
+        }
+        None => println!("warning: source has no branches; nothing checked out"),
+    }
+
+    println!("Cloned into {}", directory.display());
+    Ok(())
+}
+
+/// 원격 저장소에서 참조 목록과 객체 받기
+///
+/// 원격 주소(와 partial clone 필터)를 설정에 기록한 뒤 다시 열어서
+/// 이후 checkout 이 빠진 Blob 을 원격에서 받아오고 shallow 경계에서 탐색을 멈추도록 함
+fn clone_remote(
+    url: &str,
+    directory: &Path,
+    filter: Option<ObjectFilter>,
+    limit: Option<DepthLimit>,
+) -> anyhow::Result<(Repository, Vec<(String, String)>)> {
+    let url = transport::normalize_url(url);
+    let remote = transport::connect(&url)?;
+    let refs = remote.list_refs()?;
+
+    let repo = Repository::init(directory)?;
+    let mut config = repo.config()?;
+    config.set(&format!("remote.{}.url", DEFAULT_REMOTE), &url)?;
+    if let Some(filter) = filter {
+        config.set(&format!("remote.{}.promisor", DEFAULT_REMOTE), "true")?;
+        config.set(&format!("remote.{}.partialclonefilter", DEFAULT_REMOTE), &filter.to_string())?;
+    }
+    config.save(&repo.config_path())?;
+
+    let mut wants: Vec<String> = refs.iter().map(|(_, hash)| hash.clone()).collect();
+    wants.sort();
+    wants.dedup();
+    if !wants.is_empty() {
+        let request = FetchRequest {
+            wants,
+            filter,
+            limit,
+            ..Default::default()
+        };
+        transport::receive_pack(&repo, &remote.fetch(&request)?)?;
+    }
+    Ok((Repository::open(repo.cts_dir())?, refs))
+}
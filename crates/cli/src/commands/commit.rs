@@ -0,0 +1,176 @@
+// =============================================================================
+// cts commit
+// =============================================================================
+//
+// 사용법:
+//   cts commit -m "message"                    # 인덱스 내용으로 커밋
+//   cts commit                                 # 편집기로 메시지 작성 ($CTS_EDITOR → $EDITOR → vi)
+//   cts commit -a -m "message"                 # 추적하는 파일의 수정/삭제를 먼저 스테이징
+//   cts commit --amend                         # 마지막 커밋 고치기 (편집기에 이전 메시지)
+//   cts commit --amend --no-edit               # 메시지는 그대로, 트리만 다시
+//   cts commit --author "Jane <jane@example.com>" -m "message"
+//   cts commit --allow-empty -m "trigger CI"
+//   cts commit -S -m "message"                 # 로컬 서명 키로 서명 (cts verify-commit 으로 확인)
+//   cts commit -s --trailer "Fixes: #42" -m "message"  # Signed-off-by 와 트레일러를 메시지 끝에 추가
+//
+// 순서: (-a 스테이징) → pre-commit 훅 → 메시지 → 트레일러 → commit-msg 훅 → 커밋 → 브랜치 갱신
+// 작성자는 --author (없으면 amend 는 원래 작성자), 커미터는 설정의 user.name / user.email
+// user.name / user.email 이 설정되지 않았으면 커밋하지 않음 (USER 기반 기본값을 쓰지 않음)
+// 브랜치는 읽어 둔 HEAD 값에서만 바꿈 (그새 다른 곳에서 바뀌었으면 실패)
+
+use anyhow::{anyhow, bail};
+use clap::Args;
+use core::object::{Actor, Commit, Object};
+use core::refs::{Expected, HEADS_PREFIX};
+use core::repo::Repository;
+use core::sequencer::cleanup_message;
+use core::staging::{self, AddOptions};
+use core::trailer::{add_trailers, Trailer};
+
+use super::signing_key::load_signing_key;
+use crate::editor::commit_message_editor;
+use crate::hooks;
+
+#[derive(Args)]
+pub struct CommitArgs {
+    /// Commit message (repeat for more paragraphs; opens the editor when omitted)
+    #[arg(short, long)]
+    message: Vec<String>,
+    /// Stage modified and deleted tracked files first
+    #[arg(short, long)]
+    all: bool,
+    /// Replace the last commit instead of adding a new one
+    #[arg(long)]
+    amend: bool,
+    /// Keep the previous message when amending
+    #[arg(long, requires = "amend")]
+    no_edit: bool,
+    /// Override the author ("Name <email>")
+    #[arg(long, value_name = "AUTHOR")]
+    author: Option<String>,
+    /// Allow a commit that changes nothing
+    #[arg(long)]
+    allow_empty: bool,
+    /// Sign the commit with the local signing key
+    #[arg(short = 'S', long)]
+    sign: bool,
+    /// Add a Signed-off-by trailer for the committer
+    #[arg(short = 's', long)]
+    signoff: bool,
+    /// Add a trailer ("Key: value" or "Key=value"), may be repeated
+    #[arg(long = "trailer", value_name = "TRAILER")]
+    trailers: Vec<Trailer>,
+    /// Skip the pre-commit and commit-msg hooks
+    #[arg(short = 'n', long)]
+    no_verify: bool,
+}
+
+pub fn run(mut args: CommitArgs) -> anyhow::Result<()> {
+    // 서명 키와 작성자는 커밋을 만들기 전에 확인
+    let key = if args.sign { Some(load_signing_key()?) } else { None };
+    let author = args.author.as_deref().map(parse_author).transpose()?;
+    let repo = Repository::from_env()?;
+    let store = repo.objects();
+    let identity = repo.committer_identity()?;
+    let head = repo.head()?;
+    let previous = match &head {
+        Some(head) if args.amend => Some(store.read_commit(head)?),
+        None if args.amend => bail!("Nothing to amend; the current branch has no commits yet"),
+        _ => None,
+    };
+
+    if args.all {
+        let update = AddOptions {
+            update: true,
+            ..Default::default()
+        };
+        staging::add(&repo, &[], &update)?;
+    }
+    if !args.no_verify {
+        hooks::run_blocking(&repo, hooks::PRE_COMMIT, &[], None)?;
+    }
+
+    // 트리는 pre-commit 훅이 스테이징한 변경까지 포함
+    let index = repo.read_index()?;
+    let tree = index.write_tree(store)?;
+    let parent = match &previous {
+        Some(commit) => commit.parent_hash.clone(),
+        None => head.clone(),
+    };
+    let unchanged = match &parent {
+        Some(parent) => store.read_commit(parent)?.tree_hash == tree,
+        None => index.is_empty(),
+    };
+    if unchanged && !args.allow_empty && !args.amend {
+        bail!("Nothing to commit (stage changes with cts add, or use --allow-empty)");
+    }
+
+    let message = match &previous {
+        _ if !args.message.is_empty() => format!("{}\n", args.message.join("\n\n").trim_end()),
+        Some(commit) if args.no_edit => commit.message.clone(),
+        previous => {
+            let template = previous.as_ref().map_or("", |commit| commit.message.as_str());
+            cleanup_message(&commit_message_editor(&repo)(template)?)?
+        }
+    };
+    if args.signoff {
+        args.trailers.push(Trailer::signed_off_by(&identity.name, &identity.email));
+    }
+    let mut message = add_trailers(&message, &args.trailers);
+    if !args.no_verify {
+        message = hooks::commit_msg(&repo, &message)?;
+    }
+
+    let author = match (author, &previous) {
+        (Some(author), _) => author,
+        (None, Some(commit)) => commit.author.clone(),
+        (None, None) => Actor::now(identity.name.clone(), identity.email.clone()),
+    };
+    let reason = match (&previous, &parent) {
+        (Some(_), _) => "commit (amend)",
+        (None, None) => "commit (initial)",
+        (None, Some(_)) => "commit",
+    };
+    let root = parent.is_none();
+    let mut commit = Commit::new(
+        tree,
+        parent,
+        message,
+        author,
+        Actor::now(identity.name.clone(), identity.email.clone()),
+    );
+    if let Some(key) = &key {
+        key.sign_commit(&mut commit);
+    }
+    let subject = commit.message.lines().next().unwrap_or("").to_string();
+    let hash = store.write(&Object::from(commit))?;
+
+    // HEAD 가 브랜치를 가리키면 브랜치가 갱신됨
+    let expected = match &head {
+        Some(old) => Expected::Value(old.clone()),
+        None => Expected::Missing,
+    };
+    repo.refs()
+        .update("HEAD", &hash, expected, &identity, &format!("{}: {}", reason, subject))?;
+    repo.write_index(&index)?;
+
+    let branch = match repo.full_ref_name("HEAD")? {
+        Some(name) => name.strip_prefix(HEADS_PREFIX).unwrap_or(&name).to_string(),
+        None => "detached HEAD".to_string(),
+    };
+    let root = if root { " (root-commit)" } else { "" };
+    println!("[{}{} {}] {}", branch, root, &hash[..8], subject);
+    Ok(())
+}
+
+/// --author "Name <email>" → 현재 시각의 작성자
+fn parse_author(value: &str) -> anyhow::Result<Actor> {
+    let (name, email) = value
+        .trim()
+        .strip_suffix('>')
+        .and_then(|rest| rest.rsplit_once('<'))
+        .map(|(name, email)| (name.trim(), email.trim()))
+        .filter(|(name, email)| !name.is_empty() && !email.is_empty())
+        .ok_or_else(|| anyhow!("--author must look like \"Name <email>\": {}", value))?;
+    Ok(Actor::now(name.to_string(), email.to_string()))
+}
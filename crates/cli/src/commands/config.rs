@@ -0,0 +1,183 @@
+// =============================================================================
+// cts config
+// =============================================================================
+//
+// 사용법:
+//   cts config get user.name
+//   cts config get --type int core.compression
+//   cts config get --all --show-origin include.path
+//   cts config set user.email jane@example.com        # 저장소 (.cts/config)
+//   cts config set --global user.name "Jane Doe"      # ~/.ctsconfig
+//   cts config unset --system core.compression
+//   cts config list --show-origin
+//
+// 읽기는 모든 계층을 합친 값 (system → global → local → 환경 변수, 뒤쪽 우선)
+// --system / --global / --local 을 주면 그 파일만 (include 한 파일 포함)
+// 쓰기는 기본으로 저장소 설정, 저장소 밖에서는 --global 이나 --system 필요
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{Args, Subcommand};
+use core::config::{
+    expand_path, global_config_path, parse_bool, parse_int, system_config_path, Config, ConfigEntry, ConfigScope,
+    LayeredConfig, SYSTEM_CONFIG_PATH,
+};
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    command: ConfigCommand,
+    #[command(flatten)]
+    scope: ScopeArgs,
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+struct ScopeArgs {
+    /// Use the system-wide config file
+    #[arg(long, global = true)]
+    system: bool,
+    /// Use the per-user config file (~/.ctsconfig)
+    #[arg(long, global = true)]
+    global: bool,
+    /// Use the repository config file (.cts/config)
+    #[arg(long, global = true)]
+    local: bool,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print the value of a key
+    Get {
+        key: String,
+        /// Print every value instead of the one that wins
+        #[arg(long)]
+        all: bool,
+        /// Check and normalize the value as this type
+        #[arg(long = "type", value_parser = ["bool", "int", "path"])]
+        value_type: Option<String>,
+        /// Show the file each value comes from
+        #[arg(long)]
+        show_origin: bool,
+    },
+    /// Set a key in one config file
+    Set { key: String, value: String },
+    /// Remove a key from one config file
+    Unset { key: String },
+    /// Print every key and value
+    List {
+        /// Show the file each value comes from
+        #[arg(long)]
+        show_origin: bool,
+    },
+}
+
+impl ScopeArgs {
+    fn selected(&self) -> Option<ConfigScope> {
+        if self.system {
+            Some(ConfigScope::System)
+        } else if self.global {
+            Some(ConfigScope::Global)
+        } else if self.local {
+            Some(ConfigScope::Local)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn run(args: ConfigArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env().ok();
+    let scope = args.scope.selected();
+    match args.command {
+        ConfigCommand::Get {
+            key,
+            all,
+            value_type,
+            show_origin,
+        } => {
+            let config = read(repo.as_ref(), scope)?;
+            let entries = match (all, config.get_entry(&key)) {
+                (true, _) => config.get_all(&key),
+                (false, Some(entry)) => vec![entry],
+                (false, None) => Vec::new(),
+            };
+            if entries.is_empty() {
+                bail!("Config key {} is not set", key);
+            }
+            for entry in entries {
+                let value = typed_value(&key, &entry.value, value_type.as_deref())?;
+                print_entry(None, &value, entry, show_origin);
+            }
+        }
+        ConfigCommand::Set { key, value } => {
+            let path = file_for_write(repo.as_ref(), scope)?;
+            let mut config = Config::load(&path)?;
+            config.set(&key, &value)?;
+            config.save(&path).with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        ConfigCommand::Unset { key } => {
+            let path = file_for_write(repo.as_ref(), scope)?;
+            let mut config = Config::load(&path)?;
+            if !config.unset(&key) {
+                bail!("Config key {} is not set in {}", key, path.display());
+            }
+            config.save(&path).with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        ConfigCommand::List { show_origin } => {
+            for entry in read(repo.as_ref(), scope)?.entries() {
+                print_entry(Some(&entry.key), &entry.value, entry, show_origin);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 읽을 설정: 계층을 지정하면 그 파일만, 아니면 전부
+fn read(repo: Option<&Repository>, scope: Option<ConfigScope>) -> anyhow::Result<LayeredConfig> {
+    match scope {
+        None => Ok(match repo {
+            Some(repo) => repo.layered_config()?,
+            None => LayeredConfig::load(None)?,
+        }),
+        Some(scope) => Ok(LayeredConfig::from_files(&[(scope, file_for_write(repo, Some(scope))?)])?),
+    }
+}
+
+/// 계층의 설정 파일 (기본은 저장소)
+fn file_for_write(repo: Option<&Repository>, scope: Option<ConfigScope>) -> anyhow::Result<PathBuf> {
+    match scope {
+        Some(ConfigScope::System) => Ok(system_config_path().unwrap_or_else(|| PathBuf::from(SYSTEM_CONFIG_PATH))),
+        Some(ConfigScope::Global) => global_config_path().context("Cannot find the home directory for --global"),
+        _ => match repo {
+            Some(repo) => Ok(repo.config_path()),
+            None => bail!("Not in a CTS repository; use --global or --system"),
+        },
+    }
+}
+
+/// --type 으로 값 검사 및 정규화
+fn typed_value(key: &str, value: &str, value_type: Option<&str>) -> anyhow::Result<String> {
+    Ok(match value_type {
+        Some("bool") => parse_bool(key, value)?.to_string(),
+        Some("int") => parse_int(key, value)?.to_string(),
+        Some("path") => expand_path(value).display().to_string(),
+        _ => value.to_string(),
+    })
+}
+
+/// "[{출처}\t]{키}={값}" (get 은 키 없이 값만)
+fn print_entry(key: Option<&str>, value: &str, entry: &ConfigEntry, show_origin: bool) {
+    if show_origin {
+        match &entry.file {
+            Some(file) => print!("file:{}\t", file.display()),
+            None => print!("{}\t", entry.scope),
+        }
+    }
+    match key {
+        Some(key) => println!("{}={}", key, value),
+        None => println!("{}", value),
+    }
+}
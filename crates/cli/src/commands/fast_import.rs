@@ -0,0 +1,123 @@
+// =============================================================================
+// cts fast-import / cts fast-export
+// =============================================================================
+//
+// 사용법:
+//   git -C ../old-repo fast-export --all --show-original-ids > repo.stream
+//   cts fast-import repo.stream              # 또는 표준 입력: cts fast-import < repo.stream
+//   cts fast-import --map ../shared.map sub.stream
+//
+//   cts fast-export --all > repo.stream
+//   cts fast-export main v1.0 | git -C other fast-import
+//
+// Git SHA-1 ↔ CTS 해시 매핑은 기본으로 .cts/git-map 에 누적 (내보낸 객체도 기록)
+// 가져온 뒤 작업 디렉토리는 그대로 (cts checkout 으로 풀기)
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::Args;
+use core::fast_import::{export_stream, import_stream, GitMap};
+use core::refs::{HEADS_PREFIX, TAGS_PREFIX};
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct FastImportArgs {
+    /// Mapping file from Git SHA-1 to CTS hashes (default: .cts/git-map)
+    #[arg(long)]
+    map: Option<PathBuf>,
+    /// Stream produced by `git fast-export` (default: standard input)
+    file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct FastExportArgs {
+    /// Export every branch and tag
+    #[arg(long)]
+    all: bool,
+    /// Mapping file from Git SHA-1 to CTS hashes, updated with the exported objects (default: .cts/git-map)
+    #[arg(long)]
+    map: Option<PathBuf>,
+    /// Branches or tags to export
+    #[arg(required_unless_present = "all")]
+    refs: Vec<String>,
+}
+
+pub fn run(args: FastImportArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let map_path = args.map.unwrap_or_else(|| GitMap::path(&repo));
+    let mut map = GitMap::load(&map_path)?;
+    let identity = repo.identity()?;
+
+    let summary = match &args.file {
+        Some(path) => {
+            let file = File::open(path).with_context(|| format!("Cannot open {}", path.display()))?;
+            import_stream(&repo, BufReader::new(file), &mut map, &identity)?
+        }
+        None => import_stream(&repo, std::io::stdin().lock(), &mut map, &identity)?,
+    };
+    map.save(&map_path)?;
+
+    // 빈 저장소의 HEAD 가 없는 브랜치를 가리키면 가져온 브랜치로 (Git 저장소는 보통 master)
+    if repo.head()?.is_none() {
+        let branches: Vec<&str> = summary
+            .refs
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| name.starts_with(HEADS_PREFIX))
+            .collect();
+        let master = format!("{}master", HEADS_PREFIX);
+        if let Some(branch) = branches.iter().find(|name| **name == master).or(branches.first()) {
+            repo.refs().set_symbolic("HEAD", branch, &identity, "fast-import")?;
+        }
+    }
+
+    println!(
+        "Imported {} blobs, {} commits, {} tags; updated {} refs",
+        summary.blobs,
+        summary.commits,
+        summary.tags,
+        summary.refs.len()
+    );
+    if summary.unmapped > 0 {
+        println!("warning: {} objects have no Git SHA-1 in {}", summary.unmapped, map_path.display());
+    }
+    Ok(())
+}
+
+pub fn run_export(args: FastExportArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let map_path = args.map.unwrap_or_else(|| GitMap::path(&repo));
+    let mut map = GitMap::load(&map_path)?;
+
+    let mut names = Vec::new();
+    if args.all {
+        for prefix in [HEADS_PREFIX, TAGS_PREFIX] {
+            names.extend(repo.list_refs(prefix)?.into_iter().map(|(name, _)| name));
+        }
+    }
+    for spec in &args.refs {
+        match repo.full_ref_name(spec)? {
+            Some(name) => names.push(name),
+            None => bail!("Not a branch or tag: {}", spec),
+        }
+    }
+    names.dedup();
+
+    let mut refs = Vec::with_capacity(names.len());
+    for name in names {
+        // 주석 태그는 태그 객체 그대로 (tag 명령으로 기록)
+        let value = repo.read_ref(&name)?.with_context(|| format!("Ref has no value: {}", name))?;
+        refs.push((name, value));
+    }
+    let out = BufWriter::new(std::io::stdout().lock());
+    let summary = export_stream(&repo, &refs, &[], &mut map, out)?;
+    map.save(&map_path)?;
+    eprintln!(
+        "Exported {} blobs, {} commits, {} tags",
+        summary.blobs, summary.commits, summary.tags
+    );
+    Ok(())
+}
@@ -0,0 +1,187 @@
+// =============================================================================
+// cts fetch
+// =============================================================================
+//
+// 사용법:
+//   cts fetch                      # origin (clone 한 원격)
+//   cts fetch upstream             # .cts/config 의 remote.upstream.url
+//   cts fetch http://server/...    # 주소 직접 지정 (origin 으로 추적)
+//   cts fetch update.bundle
+//   cts fetch --depth 1                     # shallow: 원격 브랜치 끝에서 1 개만
+//   cts fetch --shallow-since 2024-01-01
+//   cts fetch --deepen 10                   # 지금 경계에서 10 개 더
+//   cts fetch --unshallow                   # 나머지 히스토리 전부
+//
+// 원격(또는 번들)의 객체를 저장소에 추가하고 원격 추적 브랜치를 갱신
+// 가져온 참조는 .cts/FETCH_HEAD 에도 기록 ("{hash}\t{ref}")
+// partial clone 원격이면 clone 때의 필터(remote.{이름}.partialclonefilter)를 그대로 사용
+//
+// 노트 참조(refs/notes/*)는 refs/notes/remotes/origin/* 에 두고,
+// 로컬 노트가 없거나 빨리 감기로 따라갈 수 있으면 로컬 노트도 갱신
+// 갈라졌으면 cts notes merge 로 합치도록 안내
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::bail;
+use clap::Args;
+use core::bundle::Bundle;
+use core::refs::{Expected, HEADS_PREFIX, NOTES_PREFIX, REMOTES_PREFIX, TAGS_PREFIX};
+use core::repo::Repository;
+use core::revwalk::ancestors;
+use core::shallow::{self, DepthLimit};
+use core::transport::{self, FetchRequest, ObjectFilter};
+use shared::types::parse_date;
+
+use super::clone::DEFAULT_REMOTE;
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Where to fetch from (remote name, URL or bundle file)
+    #[arg(default_value = DEFAULT_REMOTE)]
+    source: String,
+    /// Limit history to the latest N commits of each branch
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), group = "shallow")]
+    depth: Option<usize>,
+    /// Limit history to commits newer than this date
+    #[arg(long, value_name = "DATE", group = "shallow")]
+    shallow_since: Option<String>,
+    /// Fetch N more commits beyond the current shallow boundary
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), group = "shallow")]
+    deepen: Option<usize>,
+    /// Fetch the complete history of a shallow repository
+    #[arg(long, group = "shallow")]
+    unshallow: bool,
+}
+
+pub fn run(args: FetchArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let limit = match (&args.depth, &args.shallow_since, &args.deepen) {
+        (Some(depth), _, _) => Some(DepthLimit::Depth(*depth)),
+        (_, Some(date), _) => Some(DepthLimit::Since(parse_date(date)?.timestamp())),
+        (_, _, Some(depth)) => Some(DepthLimit::Deepen(*depth)),
+        _ if args.unshallow => Some(DepthLimit::Unshallow),
+        _ => None,
+    };
+    if matches!(limit, Some(DepthLimit::Deepen(_) | DepthLimit::Unshallow))
+        && shallow::load(repo.common_dir())?.is_empty()
+    {
+        bail!("--deepen and --unshallow only make sense in a shallow repository");
+    }
+
+    let (remote_name, refs) = if Bundle::is_bundle_file(Path::new(&args.source)) {
+        if limit.is_some() {
+            bail!("Shallow options are not supported when fetching from a bundle");
+        }
+        let bundle = Bundle::read_from(&args.source)?;
+        (DEFAULT_REMOTE.to_string(), bundle.unbundle(repo.objects())?)
+    } else {
+        fetch_remote(&repo, &args.source, limit)?
+    };
+    // 경계 커밋이 바뀌었을 수 있으므로 다시 열어서 탐색에 반영
+    let repo = Repository::open(repo.cts_dir())?;
+    let remote_name = remote_name.as_str();
+
+    // 원격 추적 브랜치와 새 태그를 한 트랜잭션으로 갱신
+    let mut transaction = repo.refs().transaction();
+    let mut fetch_head = String::new();
+    for (name, hash) in &refs {
+        if let Some(branch) = name.strip_prefix(HEADS_PREFIX) {
+            let tracking = format!("{}{}/{}", REMOTES_PREFIX, remote_name, branch);
+            match repo.read_ref(&tracking)? {
+                Some(old) if &old == hash => {}
+                Some(old) => {
+                    println!("  {}..{}  {} -> {}", &old[..8], &hash[..8], branch, tracking);
+                    transaction.update(&tracking, hash, Expected::Value(old));
+                }
+                None => {
+                    println!("  * [new branch]  {} -> {}", branch, tracking);
+                    transaction.update(&tracking, hash, Expected::Missing);
+                }
+            }
+        } else if let Some(namespace) = name.strip_prefix(NOTES_PREFIX) {
+            if namespace.starts_with("remotes/") {
+                continue;
+            }
+            let tracking = format!("{}remotes/{}/{}", NOTES_PREFIX, remote_name, namespace);
+            match repo.read_ref(&tracking)? {
+                Some(old) if &old == hash => {}
+                Some(old) => {
+                    transaction.update(&tracking, hash, Expected::Value(old));
+                }
+                None => {
+                    transaction.update(&tracking, hash, Expected::Missing);
+                }
+            }
+            match repo.read_ref(name)? {
+                Some(old) if &old == hash => {}
+                Some(old) if ancestors(repo.objects(), std::slice::from_ref(hash))?.contains(&old) => {
+                    println!("  {}..{}  {} (notes)", &old[..8], &hash[..8], name);
+                    transaction.update(name, hash, Expected::Value(old));
+                }
+                Some(_) => println!(
+                    "  ! [diverged]  {} -> {} (run `cts notes --ref {} merge {}`)",
+                    name, tracking, namespace, tracking
+                ),
+                None => {
+                    println!("  * [new notes]  {}", name);
+                    transaction.update(name, hash, Expected::Missing);
+                }
+            }
+        } else if name.starts_with(TAGS_PREFIX) && repo.read_ref(name)?.is_none() {
+            println!("  * [new tag]  {}", name);
+            transaction.update(name, hash, Expected::Missing);
+        }
+        fetch_head.push_str(&format!("{}\t{}\n", hash, name));
+    }
+    transaction.commit(&repo.identity()?, &format!("fetch: from {}", args.source))?;
+    fs::write(repo.cts_dir().join("FETCH_HEAD"), fetch_head)?;
+    Ok(())
+}
+
+/// 원격 저장소에서 새 객체 받기
+///
+/// # Returns
+/// (원격 추적 브랜치에 쓸 원격 이름, 원격의 참조 목록)
+fn fetch_remote(
+    repo: &Repository,
+    source: &str,
+    limit: Option<DepthLimit>,
+) -> anyhow::Result<(String, Vec<(String, String)>)> {
+    let config = repo.layered_config()?;
+    let (name, url, filter) = match config.get(&format!("remote.{}.url", source)) {
+        Some(url) => {
+            let filter = config
+                .get(&format!("remote.{}.partialclonefilter", source))
+                .map(str::parse::<ObjectFilter>)
+                .transpose()?;
+            (source.to_string(), url.to_string(), filter)
+        }
+        None if source == DEFAULT_REMOTE => bail!("No remote '{}' configured", source),
+        None => (DEFAULT_REMOTE.to_string(), transport::normalize_url(source), None),
+    };
+    let remote = transport::connect(&url)?;
+    let refs = remote.list_refs()?;
+
+    // 이미 가진 커밋은 빼고 받음 (깊이를 바꿀 때는 가진 커밋도 기준으로 보냄)
+    let mut wants: Vec<String> = refs
+        .iter()
+        .map(|(_, hash)| hash.clone())
+        .filter(|hash| limit.is_some() || !repo.objects().contains(hash))
+        .collect();
+    wants.sort();
+    wants.dedup();
+    if !wants.is_empty() {
+        let haves = repo.list_refs("refs/")?.into_iter().map(|(_, hash)| hash).collect();
+        let request = FetchRequest {
+            wants,
+            haves,
+            filter,
+            shallow: shallow::load(repo.common_dir())?.into_iter().collect(),
+            limit,
+            ..Default::default()
+        };
+        transport::receive_pack(repo, &remote.fetch(&request)?)?;
+    }
+    Ok((name, refs))
+}
@@ -0,0 +1,88 @@
+// =============================================================================
+// cts format-patch
+// =============================================================================
+//
+// 사용법:
+//   cts format-patch main..feature           # 0001-....patch 파일들 생성
+//   cts format-patch origin/main -o outgoing/ # origin/main..HEAD
+//   cts format-patch main..feature --stdout > series.mbox
+//
+// 만든 패치는 cts am 으로 다시 커밋으로 적용
+
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+use core::patch::format_patch;
+use core::repo::Repository;
+use core::revwalk::walk;
+
+/// 파일 이름에 쓰는 제목 최대 길이
+const MAX_SLUG_LENGTH: usize = 52;
+
+#[derive(Args)]
+pub struct FormatPatchArgs {
+    /// Commits to export: A..B, or A for A..HEAD
+    range: String,
+    /// Directory to write patch files into
+    #[arg(short, long, default_value = ".")]
+    output_directory: PathBuf,
+    /// Print all patches to standard output instead of files
+    #[arg(long)]
+    stdout: bool,
+}
+
+pub fn run(args: FormatPatchArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+
+    // Git 과 같이 "A" 하나만 주면 A..HEAD
+    let spec = if args.range.contains("..") {
+        args.range.clone()
+    } else {
+        format!("{}..HEAD", args.range)
+    };
+    let (exclude, tip) = repo.resolve_range(&spec)?;
+
+    let mut commits = walk(repo.objects(), &[tip], &exclude)?;
+    commits.reverse();
+    let total = commits.len();
+
+    if !args.stdout {
+        fs::create_dir_all(&args.output_directory)?;
+    }
+    for (i, hash) in commits.iter().enumerate() {
+        let text = format_patch(repo.objects(), hash, Some((i + 1, total)))?;
+        if args.stdout {
+            print!("{}", text);
+            continue;
+        }
+        let subject = repo.objects().read_commit(hash)?.message.lines().next().unwrap_or("").to_string();
+        let path = args
+            .output_directory
+            .join(format!("{:04}-{}.patch", i + 1, slug(&subject)));
+        fs::write(&path, text)?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+/// 제목을 파일 이름용으로 변환 ("Fix: the bug!" → "Fix-the-bug")
+fn slug(subject: &str) -> String {
+    let mut slug = String::new();
+    for c in subject.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '.' {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_LENGTH {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches(['-', '.']);
+    if slug.is_empty() {
+        "patch".to_string()
+    } else {
+        slug.to_string()
+    }
+}
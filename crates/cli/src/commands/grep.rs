@@ -0,0 +1,150 @@
+// =============================================================================
+// cts grep
+// =============================================================================
+//
+// 사용법:
+//   cts grep -n "fn main"                     # 작업 디렉토리의 추적 중인 파일
+//   cts grep -i todo v1.0 main~3              # 리비전들 (체크아웃 없이 객체에서 직접)
+//   cts grep --all-branches -w parse_args     # 모든 브랜치 끝 커밋
+//   cts grep -C 2 "unsafe" -- src "*.rs"      # 문맥 2줄, 경로 제한 (저장소 루트 기준)
+//
+// 출력: [{리비전}:]{경로}:[{라인}:]{내용}
+// 문맥 라인은 ':' 대신 '-', 떨어진 구간 사이에는 "--"
+
+use anyhow::{bail, Context};
+use clap::Args;
+use core::grep::{Grep, GrepHits, GrepOptions};
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct GrepArgs {
+    /// Pattern to search for (a regular expression unless -F)
+    pattern: String,
+    /// Revisions to search instead of the working tree
+    revs: Vec<String>,
+    /// Limit the search to these paths or globs
+    #[arg(last = true)]
+    paths: Vec<String>,
+    /// Search the tip of every local branch
+    #[arg(long, conflicts_with = "revs")]
+    all_branches: bool,
+    /// Ignore case
+    #[arg(short = 'i', long)]
+    ignore_case: bool,
+    /// Treat the pattern as a literal string
+    #[arg(short = 'F', long)]
+    fixed_strings: bool,
+    /// Match whole words only
+    #[arg(short = 'w', long)]
+    word_regexp: bool,
+    /// Select non-matching lines
+    #[arg(short = 'v', long)]
+    invert_match: bool,
+    /// Prefix lines with their line number
+    #[arg(short = 'n', long)]
+    line_number: bool,
+    /// Show only the names of matching files
+    #[arg(short = 'l', long)]
+    files_with_matches: bool,
+    /// Show the number of matching lines per file
+    #[arg(short = 'c', long, conflicts_with = "files_with_matches")]
+    count: bool,
+    /// Lines of context after each match
+    #[arg(short = 'A', long, value_name = "N")]
+    after_context: Option<usize>,
+    /// Lines of context before each match
+    #[arg(short = 'B', long, value_name = "N")]
+    before_context: Option<usize>,
+    /// Lines of context before and after each match
+    #[arg(short = 'C', long, value_name = "N")]
+    context: Option<usize>,
+    /// Number of search threads (default: number of CPUs)
+    #[arg(short = 'j', long, default_value_t = 0)]
+    threads: usize,
+}
+
+pub fn run(args: GrepArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let options = GrepOptions {
+        ignore_case: args.ignore_case,
+        fixed_strings: args.fixed_strings,
+        word_regexp: args.word_regexp,
+        invert: args.invert_match,
+        before_context: args.before_context.or(args.context).unwrap_or(0),
+        after_context: args.after_context.or(args.context).unwrap_or(0),
+        pathspecs: args.paths,
+        threads: args.threads,
+    };
+    let grep = Grep::new(&args.pattern, &options)?;
+
+    let results = if args.all_branches || !args.revs.is_empty() {
+        let mut trees = Vec::new();
+        if args.all_branches {
+            for (name, hash) in repo.list_refs("refs/heads/")? {
+                let label = name.trim_start_matches("refs/heads/").to_string();
+                trees.push((label, repo.objects().read_commit(&hash)?.tree_hash));
+            }
+        }
+        for rev in &args.revs {
+            let commit = repo.resolve_rev(rev)?;
+            trees.push((rev.clone(), repo.objects().read_commit(&commit)?.tree_hash));
+        }
+        grep.search_trees(repo.objects(), &trees)?
+    } else {
+        let work_dir = repo.work_dir().context("No work tree; specify a revision to search")?;
+        let index = repo.read_index()?;
+        let paths: Vec<String> = index.entries().keys().cloned().collect();
+        grep.search_worktree(work_dir, &paths)?
+    };
+
+    if results.is_empty() {
+        // grep 과 같이 찾은 게 없으면 실패로 종료
+        bail!("No matches");
+    }
+
+    let show_context = options.before_context > 0 || options.after_context > 0;
+    let mut first_group = true;
+    for result in results {
+        let name = match &result.label {
+            Some(label) => format!("{}:{}", label, result.path),
+            None => result.path.clone(),
+        };
+        let lines = match result.hits {
+            GrepHits::Binary => {
+                if args.files_with_matches {
+                    println!("{}", name);
+                } else if !args.count {
+                    println!("Binary file {} matches", name);
+                }
+                continue;
+            }
+            GrepHits::Lines(lines) => lines,
+        };
+        if args.files_with_matches {
+            println!("{}", name);
+            continue;
+        }
+        if args.count {
+            println!("{}:{}", name, lines.iter().filter(|line| line.matched).count());
+            continue;
+        }
+
+        let mut previous: Option<usize> = None;
+        for line in lines {
+            if show_context && previous.is_none_or(|previous| line.number > previous + 1) {
+                if !first_group {
+                    println!("--");
+                }
+                first_group = false;
+            }
+            previous = Some(line.number);
+            let separator = if line.matched { ':' } else { '-' };
+            if args.line_number {
+                println!("{}{}{}{}{}", name, separator, line.number, separator, line.text);
+            } else {
+                println!("{}{}{}", name, separator, line.text);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// =============================================================================
+// cts init
+// =============================================================================
+//
+// 사용법:
+//   cts init                          # 현재 디렉토리에 .cts 생성
+//   cts init my-project               # 디렉토리를 만들고 그 안에
+//   cts init -b trunk                 # 첫 브랜치 이름 (기본: 설정 init.defaultbranch → main)
+//   cts init --bare repositories/42   # 작업 디렉토리 없는 저장소 (서버 보관용)
+//
+// .cts/config 에 형식 버전과 해시 알고리즘(sha256)을 기록
+// 이미 저장소가 있으면 건드리지 않고 실패
+
+use std::path::PathBuf;
+
+use clap::Args;
+use core::repo::{InitOptions, Repository};
+
+#[derive(Args)]
+pub struct InitArgs {
+    /// Directory to create the repository in (default: current directory)
+    directory: Option<PathBuf>,
+    /// Create a repository without a working directory
+    #[arg(long)]
+    bare: bool,
+    /// Name of the first branch
+    #[arg(short = 'b', long, value_name = "NAME")]
+    initial_branch: Option<String>,
+    /// Print nothing on success
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+pub fn run(args: InitArgs) -> anyhow::Result<()> {
+    let directory = std::env::current_dir()?.join(args.directory.unwrap_or_default());
+    let options = InitOptions {
+        bare: args.bare,
+        initial_branch: args.initial_branch,
+    };
+    let repo = Repository::init_with(&directory, &options)?;
+    if !args.quiet {
+        let kind = if args.bare { "bare " } else { "" };
+        println!("Initialized empty {}CTS repository in {}", kind, repo.cts_dir().display());
+    }
+    Ok(())
+}
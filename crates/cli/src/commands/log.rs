@@ -0,0 +1,106 @@
+// =============================================================================
+// cts log
+// =============================================================================
+//
+// 사용법:
+//   cts log                            # HEAD 부터 히스토리
+//   cts log --oneline -n 10 main
+//   cts log --all
+//   cts log -S parse_config            # 등장 횟수가 바뀐 커밋 (추가/삭제)
+//   cts log -S "fn \w+_v2" --pickaxe-regex
+//   cts log -G "TODO|FIXME"            # 추가/삭제된 라인이 패턴과 맞는 커밋
+
+use anyhow::bail;
+use clap::Args;
+use core::grep::Pickaxe;
+use core::repo::Repository;
+use core::revwalk::walk;
+
+/// --oneline 에서 표시할 해시 길이
+const SHORT_HASH_LENGTH: usize = 8;
+
+#[derive(Args)]
+pub struct LogArgs {
+    /// Revisions to start from (default: HEAD)
+    revs: Vec<String>,
+    /// Start from every local branch and tag
+    #[arg(long)]
+    all: bool,
+    /// Show at most this many commits
+    #[arg(short = 'n', long = "max-count", value_name = "N")]
+    max_count: Option<usize>,
+    /// One line per commit
+    #[arg(long)]
+    oneline: bool,
+    /// Commits that change the number of occurrences of a string
+    #[arg(short = 'S', value_name = "STRING", conflicts_with = "changed_lines")]
+    occurrences: Option<String>,
+    /// Treat the -S argument as a regular expression
+    #[arg(long, requires = "occurrences")]
+    pickaxe_regex: bool,
+    /// Commits whose added or removed lines match a regular expression
+    #[arg(short = 'G', value_name = "REGEX")]
+    changed_lines: Option<String>,
+}
+
+pub fn run(args: LogArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let store = repo.objects();
+
+    let mut starts = Vec::new();
+    if args.all {
+        for prefix in ["refs/heads/", "refs/tags/"] {
+            // 커밋이 아닌 객체를 가리키는 태그는 건너뜀
+            for (_, hash) in repo.list_refs(prefix)? {
+                if let Ok(commit) = store.peel_to_commit(&hash) {
+                    starts.push(commit);
+                }
+            }
+        }
+    }
+    for rev in &args.revs {
+        starts.push(repo.resolve_rev(rev)?);
+    }
+    if starts.is_empty() && !args.all {
+        match repo.head()? {
+            Some(head) => starts.push(head),
+            None => bail!("Your current branch does not have any commits yet"),
+        }
+    }
+
+    let pickaxe = match (&args.occurrences, &args.changed_lines) {
+        (Some(string), _) => Some(Pickaxe::occurrences(string, args.pickaxe_regex)?),
+        (_, Some(regex)) => Some(Pickaxe::changed_lines(regex)?),
+        _ => None,
+    };
+
+    let mut shown = 0;
+    for hash in walk(store, &starts, &[])? {
+        if args.max_count.is_some_and(|max| shown >= max) {
+            break;
+        }
+        let commit = store.read_commit(&hash)?;
+        if let Some(pickaxe) = &pickaxe {
+            if !pickaxe.matches_commit(store, &hash, &commit)? {
+                continue;
+            }
+        }
+        shown += 1;
+
+        if args.oneline {
+            println!("{} {}", &hash[..SHORT_HASH_LENGTH], commit.message.lines().next().unwrap_or(""));
+            continue;
+        }
+        if shown > 1 {
+            println!();
+        }
+        println!("commit {}", hash);
+        println!("Author: {} <{}>", commit.author.name, commit.author.email);
+        println!("Date:   {}", commit.author.local_time().to_rfc2822());
+        println!();
+        for line in commit.message.trim_end().lines() {
+            println!("    {}", line);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// =============================================================================
+// CLI 서브커맨드 구현
+// =============================================================================
+//
+// 각 서브커맨드는 별도 파일에 인자(Args)와 run() 함수로 구현
+// 실제 로직은 core 크레이트에 두고, 여기서는 입출력만 담당
+
+pub mod add;
+pub mod am;
+pub mod archive;
+pub mod bisect;
+pub mod blame;
+pub mod bundle;
+pub mod cherry_pick;
+pub mod clone;
+pub mod commit;
+pub mod config;
+pub mod fetch;
+pub mod format_patch;
+pub mod fast_import;
+pub mod grep;
+pub mod init;
+pub mod log;
+pub mod notes;
+pub mod pack_refs;
+pub mod push;
+pub mod rebase;
+pub mod reflog;
+pub mod show_ref;
+pub mod signing_key;
+pub mod sparse_checkout;
+pub mod stash;
+pub mod status;
+pub mod submodule;
+pub mod tag;
+pub mod update_ref;
+pub mod verify_commit;
+pub mod worktree;
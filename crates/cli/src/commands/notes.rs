@@ -0,0 +1,185 @@
+// =============================================================================
+// cts notes
+// =============================================================================
+//
+// 사용법:
+//   cts notes add -m "build: passed" HEAD
+//   cts notes append -F result.txt v1.0
+//   cts notes show main~1
+//   cts notes list
+//   cts notes remove HEAD
+//   cts notes --ref review add -m "Approved-by: Jane" HEAD
+//   cts notes merge -s union remotes/origin/commits
+//
+// 이름공간은 --ref (기본값: $CTS_NOTES_REF 또는 refs/notes/commits)
+// 노트는 커밋 해시를 바꾸지 않음 → 빌드 결과/리뷰 승인을 나중에 덧붙일 때 사용
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{Args, Subcommand};
+use core::notes::{notes_ref_name, Notes, NotesMergeOutcome, NotesMergeStrategy, DEFAULT_NOTES_REF};
+use core::repo::Repository;
+
+/// 기본 이름공간을 바꾸는 환경 변수
+const NOTES_REF_ENV: &str = "CTS_NOTES_REF";
+
+#[derive(Args)]
+pub struct NotesArgs {
+    #[command(subcommand)]
+    command: NotesCommand,
+    /// Notes namespace (e.g. review or refs/notes/review)
+    #[arg(long = "ref", global = true)]
+    namespace: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum NotesCommand {
+    /// Attach a note to a commit
+    Add {
+        #[command(flatten)]
+        content: NoteContent,
+        /// Replace an existing note
+        #[arg(short, long)]
+        force: bool,
+        /// Commit to annotate
+        #[arg(default_value = "HEAD")]
+        rev: String,
+    },
+    /// Append to the note of a commit (creating it if needed)
+    Append {
+        #[command(flatten)]
+        content: NoteContent,
+        /// Commit to annotate
+        #[arg(default_value = "HEAD")]
+        rev: String,
+    },
+    /// Print the note of a commit
+    Show {
+        #[arg(default_value = "HEAD")]
+        rev: String,
+    },
+    /// List notes as "<note blob> <commit>" pairs
+    List {
+        /// Only show the note blob of this commit
+        rev: Option<String>,
+    },
+    /// Remove the note of a commit
+    Remove {
+        #[arg(default_value = "HEAD")]
+        rev: String,
+    },
+    /// Merge another notes ref (e.g. fetched notes) into this namespace
+    Merge {
+        /// How to resolve notes changed on both sides
+        /// (manual, ours, theirs, union, cat_sort_uniq)
+        #[arg(short, long, default_value = "manual")]
+        strategy: NotesMergeStrategy,
+        /// Notes ref or notes commit to merge
+        source: String,
+    },
+}
+
+#[derive(Args)]
+struct NoteContent {
+    /// Note text (paragraphs when given more than once)
+    #[arg(short, long = "message")]
+    messages: Vec<String>,
+    /// Read the note from a file
+    #[arg(short = 'F', long, conflicts_with = "messages")]
+    file: Option<PathBuf>,
+}
+
+impl NoteContent {
+    fn read(self) -> anyhow::Result<Vec<u8>> {
+        if let Some(path) = self.file {
+            return fs::read(&path).with_context(|| format!("Failed to read {}", path.display()));
+        }
+        if self.messages.is_empty() {
+            bail!("Provide the note with -m <text> or -F <file>");
+        }
+        let text = self
+            .messages
+            .iter()
+            .map(|message| message.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        Ok(format!("{}\n", text).into_bytes())
+    }
+}
+
+pub fn run(args: NotesArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let namespace = match args.namespace {
+        Some(name) => name,
+        None => std::env::var(NOTES_REF_ENV).unwrap_or_else(|_| DEFAULT_NOTES_REF.to_string()),
+    };
+    let notes = Notes::open(&repo, &namespace)?;
+
+    match args.command {
+        NotesCommand::Add { content, force, rev } => {
+            let commit = repo.resolve_rev(&rev)?;
+            notes.add(&commit, &content.read()?, force, &repo.committer_identity()?)?;
+        }
+        NotesCommand::Append { content, rev } => {
+            let commit = repo.resolve_rev(&rev)?;
+            notes.append(&commit, &content.read()?, &repo.committer_identity()?)?;
+        }
+        NotesCommand::Show { rev } => {
+            let commit = repo.resolve_rev(&rev)?;
+            let note = notes
+                .get(&commit)?
+                .with_context(|| format!("No note found for {} in {}", commit, notes.name()))?;
+            std::io::stdout().write_all(&note)?;
+        }
+        NotesCommand::List { rev: Some(rev) } => {
+            let commit = repo.resolve_rev(&rev)?;
+            let list = notes.list()?;
+            let blob = list
+                .get(&commit)
+                .with_context(|| format!("No note found for {} in {}", commit, notes.name()))?;
+            println!("{}", blob);
+        }
+        NotesCommand::List { rev: None } => {
+            for (commit, blob) in notes.list()? {
+                println!("{} {}", blob, commit);
+            }
+        }
+        NotesCommand::Remove { rev } => {
+            let commit = repo.resolve_rev(&rev)?;
+            notes.remove(&commit, &repo.committer_identity()?)?;
+            println!("Removing note for {}", commit);
+        }
+        NotesCommand::Merge { strategy, source } => {
+            let theirs = resolve_notes(&repo, &source)?;
+            match notes.merge(&theirs, strategy, &repo.committer_identity()?)? {
+                NotesMergeOutcome::UpToDate => println!("Already up to date."),
+                NotesMergeOutcome::FastForward(tip) => println!("Fast-forward {} to {}", notes.name(), &tip[..8]),
+                NotesMergeOutcome::Merged(tip) => {
+                    println!("Merged {} into {} ({}, strategy {})", source, notes.name(), &tip[..8], strategy)
+                }
+                NotesMergeOutcome::Conflicts(commits) => {
+                    for commit in &commits {
+                        println!("CONFLICT (notes): both sides changed the note for {}", commit);
+                    }
+                    bail!(
+                        "Automatic notes merge failed; re-run with -s ours, theirs, union or cat_sort_uniq"
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 병합 대상: 노트 참조 이름이 우선, 없으면 리비전으로 해석
+fn resolve_notes(repo: &Repository, source: &str) -> anyhow::Result<String> {
+    if let Ok(name) = notes_ref_name(source) {
+        if let Some(hash) = repo.read_ref(&name)? {
+            return Ok(hash);
+        }
+    }
+    Ok(repo.resolve_rev(source)?)
+}
@@ -0,0 +1,22 @@
+// =============================================================================
+// cts pack-refs
+// =============================================================================
+//
+// 사용법:
+//   cts pack-refs
+//
+// 개별 참조 파일들을 .cts/packed-refs 한 파일로 모음
+// 태그가 수천 개인 저장소에서 참조 목록 조회가 빨라짐
+
+use clap::Args;
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct PackRefsArgs {}
+
+pub fn run(_args: PackRefsArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let count = repo.refs().pack_refs()?;
+    println!("Packed {} ref(s)", count);
+    Ok(())
+}
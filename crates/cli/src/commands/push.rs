@@ -0,0 +1,207 @@
+// =============================================================================
+// cts push
+// =============================================================================
+//
+// 사용법:
+//   cts push                              # 현재 브랜치를 origin 의 같은 이름으로
+//   cts push upstream main topic:review   # "{로컬}[:{원격}]" (원격 이름은 로컬과 같은 종류)
+//   cts push --tags                       # 모든 태그도 함께
+//   cts push -f origin main               # fast-forward 가 아니어도 덮어쓰기
+//   cts push --no-verify                  # pre-push 훅 건너뛰기
+//
+// 원격 브랜치는 fast-forward 로만 바꾸고, 원격에 이미 있는 태그는 바꾸지 않음 (-f 제외)
+// 원격이 알려준 값에서 그새 바뀌었으면 서버가 거부 → fetch 후 다시
+// 노트(refs/notes/*)는 브랜치와 함께 보냄
+// 이름 있는 원격이면 보낸 뒤 원격 추적 참조도 갱신
+
+use std::collections::HashMap;
+
+use anyhow::bail;
+use clap::Args;
+use core::refs::{Expected, HEADS_PREFIX, NOTES_PREFIX, REMOTES_PREFIX, TAGS_PREFIX};
+use core::repo::Repository;
+use core::revwalk::ancestors;
+use core::transport::{self, PushRequest, PushUpdate, TOKEN_ENV};
+use shared::error::AppError;
+
+use super::clone::DEFAULT_REMOTE;
+use crate::hooks;
+
+#[derive(Args)]
+pub struct PushArgs {
+    /// Where to push (remote name or URL)
+    #[arg(default_value = DEFAULT_REMOTE)]
+    remote: String,
+    /// Refs to push as "<local>[:<remote>]" (default: the current branch)
+    refspecs: Vec<String>,
+    /// Also push every tag
+    #[arg(long)]
+    tags: bool,
+    /// Overwrite remote refs even when it is not a fast-forward
+    #[arg(short, long)]
+    force: bool,
+    /// Skip the pre-push hook
+    #[arg(long)]
+    no_verify: bool,
+}
+
+pub fn run(args: PushArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let config = repo.layered_config()?;
+    let (tracking, url) = match config.get(&format!("remote.{}.url", args.remote)) {
+        Some(url) => (Some(args.remote.as_str()), url.to_string()),
+        None if args.remote == DEFAULT_REMOTE => bail!("No remote '{}' configured", args.remote),
+        None => (None, transport::normalize_url(&args.remote)),
+    };
+
+    // (로컬 참조, 보낼 값, 원격 참조)
+    let mut targets = Vec::new();
+    if args.refspecs.is_empty() {
+        let Some(branch) = repo.full_ref_name("HEAD")? else {
+            bail!("HEAD is detached; say which branch to push");
+        };
+        targets.push(push_target(&repo, &branch)?);
+    }
+    for refspec in &args.refspecs {
+        targets.push(push_target(&repo, refspec)?);
+    }
+    // 노트 원격 추적 참조(refs/notes/remotes/*)는 제외
+    let mut extra: Vec<(String, String)> = repo
+        .list_refs(NOTES_PREFIX)?
+        .into_iter()
+        .filter(|(name, _)| !name[NOTES_PREFIX.len()..].starts_with("remotes/"))
+        .collect();
+    if args.tags {
+        extra.extend(repo.list_refs(TAGS_PREFIX)?);
+    }
+    for (name, hash) in extra {
+        if !targets.iter().any(|(_, _, remote)| remote == &name) {
+            targets.push((name.clone(), hash, name));
+        }
+    }
+
+    let remote = transport::connect(&url)?;
+    let remote_refs: HashMap<String, String> = remote.list_refs()?.into_iter().collect();
+    let mut updates = Vec::new();
+    let mut rejected = 0;
+    for (local, new, name) in targets {
+        let old = remote_refs.get(&name).cloned();
+        let (from, to) = (short_name(&local), short_name(&name));
+        match &old {
+            Some(old) if old == &new => continue,
+            None => {
+                let kind = if name.starts_with(TAGS_PREFIX) { "new tag" } else if name.starts_with(HEADS_PREFIX) { "new branch" } else { "new ref" };
+                println!("  * [{}]  {} -> {}", kind, from, to);
+            }
+            Some(old) => match rejection(&repo, &name, old, &new)? {
+                None => println!("  {}..{}  {} -> {}", &old[..8], &new[..8], from, to),
+                Some(_) if args.force => println!("+ {}...{}  {} -> {} (forced update)", &old[..8], &new[..8], from, to),
+                Some(reason) => {
+                    println!("  ! [rejected]  {} -> {} ({})", from, to, reason);
+                    rejected += 1;
+                    continue;
+                }
+            },
+        }
+        updates.push((local, PushUpdate { name, old, new }));
+    }
+
+    if updates.is_empty() {
+        if rejected == 0 {
+            println!("Everything up-to-date");
+            return Ok(());
+        }
+    } else {
+        if !args.no_verify {
+            let zero = hooks::null_hash();
+            let input: String = updates
+                .iter()
+                .map(|(local, update)| {
+                    let old = update.old.as_deref().unwrap_or(&zero);
+                    format!("{} {} {} {}\n", local, update.new, update.name, old)
+                })
+                .collect();
+            hooks::run_blocking(&repo, hooks::PRE_PUSH, &[&args.remote, &url], Some(input.as_bytes()))?;
+        }
+
+        let haves: Vec<String> = remote_refs.into_values().collect();
+        let updates: Vec<PushUpdate> = updates.into_iter().map(|(_, update)| update).collect();
+        let request = PushRequest::new(repo.objects(), updates, &haves)?;
+        match remote.push(&request) {
+            Ok(()) => {}
+            Err(AppError::Conflict(message)) => bail!("{}; fetch and try again", message),
+            Err(AppError::Unauthorized) => bail!("{} requires authentication; set {} to an access token", url, TOKEN_ENV),
+            Err(err) => return Err(err.into()),
+        }
+        if let Some(remote_name) = tracking {
+            update_tracking(&repo, remote_name, &request.updates)?;
+        }
+    }
+    if rejected > 0 {
+        bail!("Failed to push {} ref(s) to {}; fetch first or use --force", rejected, url);
+    }
+    Ok(())
+}
+
+/// "{로컬}[:{원격}]" → (로컬 참조, 값, 원격 참조)
+fn push_target(repo: &Repository, refspec: &str) -> anyhow::Result<(String, String, String)> {
+    let (source, destination) = refspec.split_once(':').unwrap_or((refspec, refspec));
+    let local = repo.full_ref_name(source)?;
+    // 참조는 태그 객체도 그대로, 그 밖(커밋 해시 등)은 커밋으로 해석
+    let value = match &local {
+        Some(name) => repo.read_ref(name)?.ok_or_else(|| AppError::NotFound(format!("Ref {}", name)))?,
+        None => repo.resolve_rev(source)?,
+    };
+    let remote = if destination.starts_with("refs/") {
+        destination.to_string()
+    } else {
+        let prefix = match &local {
+            Some(name) if name.starts_with(TAGS_PREFIX) => TAGS_PREFIX,
+            _ => HEADS_PREFIX,
+        };
+        format!("{}{}", prefix, destination)
+    };
+    Ok((local.unwrap_or_else(|| source.to_string()), value, remote))
+}
+
+/// -f 없이 바꿀 수 없는 이유
+fn rejection(repo: &Repository, name: &str, old: &str, new: &str) -> anyhow::Result<Option<&'static str>> {
+    if name.starts_with(TAGS_PREFIX) {
+        return Ok(Some("already exists"));
+    }
+    let store = repo.objects();
+    if !store.contains(old) {
+        return Ok(Some("fetch first"));
+    }
+    let new = store.peel_to_commit(new)?;
+    if ancestors(store, &[new])?.contains(&store.peel_to_commit(old)?) {
+        Ok(None)
+    } else {
+        Ok(Some("non-fast-forward"))
+    }
+}
+
+/// 보낸 브랜치와 노트의 원격 추적 참조 갱신 (fetch 와 같은 위치)
+fn update_tracking(repo: &Repository, remote: &str, updates: &[PushUpdate]) -> anyhow::Result<()> {
+    let mut transaction = repo.refs().transaction();
+    for update in updates {
+        let tracking = if let Some(branch) = update.name.strip_prefix(HEADS_PREFIX) {
+            format!("{}{}/{}", REMOTES_PREFIX, remote, branch)
+        } else if let Some(namespace) = update.name.strip_prefix(NOTES_PREFIX) {
+            format!("{}remotes/{}/{}", NOTES_PREFIX, remote, namespace)
+        } else {
+            continue;
+        };
+        transaction.update(&tracking, &update.new, Expected::Any);
+    }
+    if !transaction.is_empty() {
+        transaction.commit(&repo.identity()?, &format!("push: to {}", remote))?;
+    }
+    Ok(())
+}
+
+fn short_name(name: &str) -> &str {
+    name.strip_prefix(HEADS_PREFIX)
+        .or_else(|| name.strip_prefix(TAGS_PREFIX))
+        .unwrap_or(name)
+}
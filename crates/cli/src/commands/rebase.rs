@@ -0,0 +1,90 @@
+// =============================================================================
+// cts rebase
+// =============================================================================
+//
+// 사용법:
+//   cts rebase main                    # main 에 없는 커밋을 main 위로 옮김
+//   cts rebase --onto release main     # main..HEAD 커밋을 release 위로
+//   cts rebase -i main                 # 할 일 목록을 편집기로 수정 후 실행
+//   cts rebase --continue | --skip | --abort
+//
+// 할 일 목록 명령: pick, reword, squash, fixup, drop (p, r, s, f, d)
+// 스크립트로 쓸 때는 CTS_SEQUENCE_EDITOR 로 목록 편집기를 바꿈
+//   예) CTS_SEQUENCE_EDITOR="cp plan.txt" cts rebase -i main
+
+use anyhow::bail;
+use clap::Args;
+use core::refs::RefValue;
+use core::repo::Repository;
+use core::sequencer::{format_todo, parse_todo, Operation, Sequencer, SequencerOutcome};
+
+use super::cherry_pick::{control, report, SequencerControl};
+use crate::editor::{commit_message_editor, edit_file, sequence_editor};
+
+/// 편집 중인 할 일 목록 파일 (.cts 기준)
+const REBASE_TODO: &str = "rebase-todo";
+
+const TODO_HELP: &str = "\n# Commands:\n\
+                         # p, pick <commit> = use commit\n\
+                         # r, reword <commit> = use commit, but edit the commit message\n\
+                         # s, squash <commit> = meld into previous commit and edit the message\n\
+                         # f, fixup <commit> = meld into previous commit, discard this message\n\
+                         # d, drop <commit> = remove commit\n\
+                         #\n\
+                         # Lines can be reordered; an empty list aborts the rebase.\n";
+
+#[derive(Args)]
+pub struct RebaseArgs {
+    /// Branch or commit to rebase onto
+    #[arg(required_unless_present_any = ["resume", "skip", "abort"])]
+    upstream: Option<String>,
+    /// Start the rebased commits at this commit instead of <upstream>
+    #[arg(long)]
+    onto: Option<String>,
+    /// Edit the todo list before starting
+    #[arg(short, long)]
+    interactive: bool,
+    #[command(flatten)]
+    control: SequencerControl,
+}
+
+pub fn run(args: RebaseArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let sequencer = Sequencer::new(&repo, repo.committer_identity()?);
+    let mut edit = commit_message_editor(&repo);
+
+    let outcome = if args.control.is_set() {
+        match control(&sequencer, Operation::Rebase, &args.control, &mut edit)? {
+            Some(outcome) => outcome,
+            None => return Ok(()),
+        }
+    } else {
+        let Some(upstream) = args.upstream else {
+            bail!("Specify the upstream to rebase onto");
+        };
+        let upstream = repo.resolve_rev(&upstream)?;
+        let onto = match &args.onto {
+            Some(onto) => repo.resolve_rev(onto)?,
+            None => upstream.clone(),
+        };
+        let mut todo = sequencer.rebase_todo(&upstream)?;
+        if args.interactive {
+            let path = repo.cts_dir().join(REBASE_TODO);
+            let text = format!("{}{}", format_todo(&todo, true), TODO_HELP);
+            todo = parse_todo(&repo, &edit_file(&sequence_editor(), &path, &text)?)?;
+            if todo.is_empty() {
+                bail!("Nothing to do");
+            }
+        }
+        sequencer.rebase(&onto, todo, &mut edit)?
+    };
+
+    if let SequencerOutcome::Done { .. } = &outcome {
+        let target = match repo.refs().read("HEAD")? {
+            Some(RefValue::Symbolic(target)) => target,
+            _ => "HEAD".to_string(),
+        };
+        println!("Successfully rebased and updated {}.", target);
+    }
+    report(Operation::Rebase, outcome)
+}
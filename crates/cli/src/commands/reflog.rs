@@ -0,0 +1,46 @@
+// =============================================================================
+// cts reflog
+// =============================================================================
+//
+// 사용법:
+//   cts reflog              # HEAD 의 이동 기록
+//   cts reflog main         # 브랜치의 갱신 기록
+//
+// 출력: {해시} {참조}@{n}: {사유} (최신 기록이 n = 0)
+// 예전 값은 "main@{2}" 처럼 리비전으로 사용 가능
+
+use anyhow::Context;
+use clap::Args;
+use core::repo::Repository;
+
+/// 출력 시 표시할 해시 길이
+const SHORT_HASH_LENGTH: usize = 8;
+
+#[derive(Args)]
+pub struct ReflogArgs {
+    /// Ref whose log to show
+    #[arg(default_value = "HEAD")]
+    name: String,
+}
+
+pub fn run(args: ReflogArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let full = if args.name == "HEAD" {
+        "HEAD".to_string()
+    } else {
+        repo.full_ref_name(&args.name)?
+            .with_context(|| format!("Unknown ref: {}", args.name))?
+    };
+
+    for (n, entry) in repo.refs().reflog(&full)?.iter().rev().enumerate() {
+        let hash = entry.new.as_deref().unwrap_or("(deleted)");
+        println!(
+            "{} {}@{{{}}}: {}",
+            &hash[..SHORT_HASH_LENGTH.min(hash.len())],
+            args.name,
+            n,
+            entry.reason
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// =============================================================================
+// cts show-ref
+// =============================================================================
+//
+// 사용법:
+//   cts show-ref                # 모든 참조
+//   cts show-ref --heads        # 브랜치만
+//   cts show-ref --tags         # 태그만
+
+use clap::Args;
+use core::refs::{HEADS_PREFIX, TAGS_PREFIX};
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct ShowRefArgs {
+    /// Show only branches (refs/heads/)
+    #[arg(long)]
+    heads: bool,
+    /// Show only tags (refs/tags/)
+    #[arg(long)]
+    tags: bool,
+    /// Also show HEAD
+    #[arg(long)]
+    head: bool,
+}
+
+pub fn run(args: ShowRefArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+
+    if args.head {
+        if let Some(hash) = repo.head()? {
+            println!("{} HEAD", hash);
+        }
+    }
+    let prefixes: Vec<&str> = match (args.heads, args.tags) {
+        (false, false) => vec!["refs/"],
+        (heads, tags) => [(heads, HEADS_PREFIX), (tags, TAGS_PREFIX)]
+            .into_iter()
+            .filter_map(|(on, prefix)| on.then_some(prefix))
+            .collect(),
+    };
+    for prefix in prefixes {
+        for (name, hash) in repo.list_refs(prefix)? {
+            println!("{} {}", hash, name);
+        }
+    }
+    Ok(())
+}
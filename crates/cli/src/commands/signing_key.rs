@@ -0,0 +1,75 @@
+// =============================================================================
+// cts signing-key
+// =============================================================================
+//
+// 사용법:
+//   cts signing-key generate         # ~/.cts/signing_key 생성
+//   cts signing-key show             # 공개키 출력 (서버에 등록할 값)
+//
+// 키 경로는 --file 또는 CTS_SIGNING_KEY 로 변경 가능
+// 생성한 키는 cts commit -S, cts tag -s 에서 사용
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+use core::config::LayeredConfig;
+use core::refs::Identity;
+use core::repo::Repository;
+use core::signing::{default_key_path, SigningKey};
+
+#[derive(Args)]
+pub struct SigningKeyArgs {
+    #[command(subcommand)]
+    command: SigningKeyCommand,
+    /// Secret key file (defaults to $CTS_SIGNING_KEY or ~/.cts/signing_key)
+    #[arg(long, global = true)]
+    file: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum SigningKeyCommand {
+    /// Generate a new ed25519 key pair
+    Generate,
+    /// Print the public key and its fingerprint
+    Show,
+}
+
+pub fn run(args: SigningKeyArgs) -> anyhow::Result<()> {
+    let path = match args.file {
+        Some(path) => path,
+        None => key_path()?,
+    };
+    match args.command {
+        SigningKeyCommand::Generate => {
+            let key = SigningKey::generate()?;
+            // 저장소 안이면 저장소 설정의 user.email 까지 반영
+            let identity = match Repository::from_env() {
+                Ok(repo) => repo.identity()?,
+                Err(_) => Identity::from_config(&LayeredConfig::load(None)?),
+            };
+            key.save(&path, &identity.email)?;
+            println!("Created signing key {}", path.display());
+            println!("{}", key.public_key());
+            println!("Fingerprint: {}", key.public_key().fingerprint());
+        }
+        SigningKeyCommand::Show => {
+            let key = SigningKey::load(&path)?;
+            println!("{}", key.public_key());
+            println!("Fingerprint: {}", key.public_key().fingerprint());
+        }
+    }
+    Ok(())
+}
+
+/// 서명에 사용할 비밀키 읽기 (-S, -s 옵션용)
+pub fn load_signing_key() -> anyhow::Result<SigningKey> {
+    let path = key_path()?;
+    SigningKey::load(&path).with_context(|| {
+        format!("Cannot sign without a key at {} (run `cts signing-key generate`)", path.display())
+    })
+}
+
+fn key_path() -> anyhow::Result<PathBuf> {
+    default_key_path().context("Cannot locate the home directory; set CTS_SIGNING_KEY")
+}
@@ -0,0 +1,88 @@
+// =============================================================================
+// cts sparse-checkout
+// =============================================================================
+//
+// 사용법:
+//   cts sparse-checkout set services/api libs   # 이 디렉토리들만 작업 디렉토리에 (cone)
+//   cts sparse-checkout add tools               # 디렉토리 추가
+//   cts sparse-checkout list
+//   cts sparse-checkout reapply                 # 패턴 파일을 직접 고친 뒤 다시 반영
+//   cts sparse-checkout disable                 # 모든 파일을 다시 풀어놓음
+//
+// 최상위 파일과 지정한 디렉토리의 상위 디렉토리에 바로 있는 파일은 항상 포함
+// 범위 밖 파일은 인덱스에 skip-worktree 로 남아 커밋에는 그대로 들어감
+
+use anyhow::bail;
+use clap::{Args, Subcommand};
+use core::repo::Repository;
+use core::sparse::{self, SparsePatterns, SparseUpdate};
+
+#[derive(Args)]
+pub struct SparseCheckoutArgs {
+    #[command(subcommand)]
+    command: SparseCheckoutCommand,
+}
+
+#[derive(Subcommand)]
+enum SparseCheckoutCommand {
+    /// Check out only these directories
+    Set {
+        /// Directories relative to the repository root
+        #[arg(required = true)]
+        dirs: Vec<String>,
+    },
+    /// Add directories to the sparse checkout
+    Add {
+        /// Directories relative to the repository root
+        #[arg(required = true)]
+        dirs: Vec<String>,
+    },
+    /// List the checked out directories
+    List,
+    /// Apply the current patterns to the working tree again
+    Reapply,
+    /// Check out every file again
+    Disable,
+}
+
+pub fn run(args: SparseCheckoutArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let current = SparsePatterns::load(repo.cts_dir())?;
+    let update = match args.command {
+        SparseCheckoutCommand::Set { dirs } => sparse::set_patterns(&repo, Some(&SparsePatterns::new(&dirs)?))?,
+        SparseCheckoutCommand::Add { dirs } => {
+            let Some(mut patterns) = current else {
+                bail!("Sparse checkout is not enabled; use 'cts sparse-checkout set'");
+            };
+            patterns.add(&dirs)?;
+            sparse::set_patterns(&repo, Some(&patterns))?
+        }
+        SparseCheckoutCommand::List => {
+            let Some(patterns) = current else {
+                bail!("Sparse checkout is not enabled");
+            };
+            for dir in patterns.dirs() {
+                println!("{}", dir);
+            }
+            return Ok(());
+        }
+        SparseCheckoutCommand::Reapply => {
+            if current.is_none() {
+                bail!("Sparse checkout is not enabled");
+            }
+            sparse::set_patterns(&repo, current.as_ref())?
+        }
+        SparseCheckoutCommand::Disable => sparse::set_patterns(&repo, None)?,
+    };
+    report(&update);
+    Ok(())
+}
+
+fn report(update: &SparseUpdate) {
+    if !update.materialized.is_empty() {
+        println!("Checked out {} file(s)", update.materialized.len());
+    }
+    if !update.removed.is_empty() {
+        println!("Removed {} file(s) outside the sparse checkout", update.removed.len());
+    }
+}
@@ -0,0 +1,128 @@
+// =============================================================================
+// cts stash
+// =============================================================================
+//
+// 사용법:
+//   cts stash                       # = cts stash push
+//   cts stash push -m "wip: parser" -u
+//   cts stash list
+//   cts stash show -p stash@{1}
+//   cts stash apply --index
+//   cts stash pop
+//   cts stash drop stash@{2}
+//
+// 스태시는 refs/stash 의 reflog 로 쌓임 → "stash@{n}" 은 다른 리비전 자리에도 사용 가능
+
+use anyhow::bail;
+use clap::{Args, Subcommand};
+use core::patch::{format_diff, format_diffstat};
+use core::repo::Repository;
+use core::stash::{parse_stash_index, Stash, StashApplyOutcome};
+
+#[derive(Args)]
+pub struct StashArgs {
+    #[command(subcommand)]
+    command: Option<StashCommand>,
+}
+
+#[derive(Subcommand)]
+enum StashCommand {
+    /// Save local changes and revert to HEAD (default)
+    Push(PushArgs),
+    /// List stashes, newest first
+    List,
+    /// Show the changes recorded in a stash
+    Show {
+        /// Show the full diff instead of a diffstat
+        #[arg(short, long)]
+        patch: bool,
+        #[arg(default_value = "stash@{0}")]
+        stash: String,
+    },
+    /// Apply a stash on top of the working tree
+    Apply {
+        /// Also restore the staged changes
+        #[arg(long)]
+        index: bool,
+        #[arg(default_value = "stash@{0}")]
+        stash: String,
+    },
+    /// Apply a stash and drop it unless there were conflicts
+    Pop {
+        /// Also restore the staged changes
+        #[arg(long)]
+        index: bool,
+        #[arg(default_value = "stash@{0}")]
+        stash: String,
+    },
+    /// Remove a stash
+    Drop {
+        #[arg(default_value = "stash@{0}")]
+        stash: String,
+    },
+}
+
+#[derive(Args, Default)]
+struct PushArgs {
+    /// Description of the stash
+    #[arg(short, long)]
+    message: Option<String>,
+    /// Also stash (and remove) untracked files
+    #[arg(short = 'u', long)]
+    include_untracked: bool,
+}
+
+pub fn run(args: StashArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let stash = Stash::open(&repo);
+
+    match args.command.unwrap_or(StashCommand::Push(PushArgs::default())) {
+        StashCommand::Push(push) => {
+            match stash.push(push.message.as_deref(), push.include_untracked, &repo.committer_identity()?)? {
+                Some(_) => println!("Saved working directory and index state {}", stash.get(0)?.message),
+                None => println!("No local changes to save"),
+            }
+        }
+        StashCommand::List => {
+            for entry in stash.list()? {
+                println!("{}: {}", entry.name(), entry.message);
+            }
+        }
+        StashCommand::Show { patch, stash: spec } => {
+            let files = stash.show(parse_stash_index(&spec)?)?;
+            if patch {
+                print!("{}", format_diff(&files));
+            } else {
+                print!("{}", format_diffstat(&files));
+            }
+        }
+        StashCommand::Apply { index, stash: spec } => {
+            let outcome = stash.apply(parse_stash_index(&spec)?, index)?;
+            report(&outcome)?;
+        }
+        StashCommand::Pop { index, stash: spec } => {
+            let n = parse_stash_index(&spec)?;
+            let (outcome, dropped) = stash.pop(n, index, &repo.identity()?)?;
+            if let Some(dropped) = dropped {
+                println!("Dropped stash@{{{}}} ({})", n, dropped);
+            }
+            report(&outcome)?;
+        }
+        StashCommand::Drop { stash: spec } => {
+            let n = parse_stash_index(&spec)?;
+            let dropped = stash.drop(n, &repo.identity()?)?;
+            println!("Dropped stash@{{{}}} ({})", n, dropped);
+        }
+    }
+    Ok(())
+}
+
+fn report(outcome: &StashApplyOutcome) -> anyhow::Result<()> {
+    if outcome.is_clean() {
+        return Ok(());
+    }
+    for path in &outcome.conflicts {
+        println!("CONFLICT (content): merge conflict in {}", path);
+    }
+    bail!("Conflicts while applying the stash; resolve them by hand (the stash is kept)")
+}
@@ -0,0 +1,86 @@
+// =============================================================================
+// cts status
+// =============================================================================
+//
+// 사용법:
+//   cts status          # 현재 브랜치, 커밋할 변경, 작업 디렉토리 변경, 추적하지 않는 파일
+//   cts status -s       # 짧은 형식: "{인덱스}{작업 디렉토리} {경로}", 추적하지 않는 파일은 "??"
+//
+// sparse checkout 범위 밖의 파일은 작업 디렉토리에 없어도 변경으로 보이지 않음
+
+use std::collections::BTreeMap;
+
+use clap::Args;
+use core::refs::HEADS_PREFIX;
+use core::repo::Repository;
+use core::sparse::SparsePatterns;
+use core::status::{status, ChangeKind, StatusEntry};
+
+#[derive(Args)]
+pub struct StatusArgs {
+    /// Print one line per path
+    #[arg(short, long)]
+    short: bool,
+}
+
+pub fn run(args: StatusArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let current = status(&repo)?;
+
+    if args.short {
+        // 경로별 (인덱스 상태, 작업 디렉토리 상태)
+        let mut codes: BTreeMap<&str, (char, char)> = BTreeMap::new();
+        for entry in &current.staged {
+            codes.entry(&entry.path).or_insert((' ', ' ')).0 = entry.kind.code();
+        }
+        for entry in &current.unstaged {
+            codes.entry(&entry.path).or_insert((' ', ' ')).1 = entry.kind.code();
+        }
+        for (path, (staged, unstaged)) in codes {
+            println!("{}{} {}", staged, unstaged, path);
+        }
+        for path in &current.untracked {
+            println!("?? {}", path);
+        }
+        return Ok(());
+    }
+
+    match repo.full_ref_name("HEAD")? {
+        Some(name) => println!("On branch {}", name.strip_prefix(HEADS_PREFIX).unwrap_or(&name)),
+        None => println!("HEAD detached at {}", repo.head()?.map_or(String::new(), |hash| hash[..8].to_string())),
+    }
+    if SparsePatterns::load(repo.cts_dir())?.is_some() {
+        println!("You are in a sparse checkout.");
+    }
+    print_section("Changes to be committed:", &current.staged);
+    print_section("Changes not staged for commit:", &current.unstaged);
+    if !current.untracked.is_empty() {
+        println!("\nUntracked files:");
+        for path in &current.untracked {
+            println!("\t{}", path);
+        }
+    }
+    if current.is_clean() {
+        if current.untracked.is_empty() {
+            println!("\nnothing to commit, working tree clean");
+        } else {
+            println!("\nnothing added to commit but untracked files present (use \"cts add\" to track)");
+        }
+    }
+    Ok(())
+}
+
+fn print_section(title: &str, entries: &[StatusEntry]) {
+    if entries.is_empty() {
+        return;
+    }
+    println!("\n{}", title);
+    for entry in entries {
+        let label = match entry.kind {
+            ChangeKind::Added => "new file:",
+            ChangeKind::Modified => "modified:",
+            ChangeKind::Deleted => "deleted:",
+        };
+        println!("\t{:<12}{}", label, entry.path);
+    }
+}
@@ -0,0 +1,99 @@
+// =============================================================================
+// cts submodule
+// =============================================================================
+//
+// 사용법:
+//   cts submodule add http://server/api/repositories/{id} libs/shared
+//   cts submodule add ../shared libs/shared     # origin 주소 기준 상대 주소
+//   cts submodule init [<path>...]              # .ctsmodules 의 주소를 설정에 복사
+//   cts submodule update [--init] [--recursive] [<path>...]
+//   cts submodule status
+//
+// status 출력 (앞 글자):
+//   ' '  기록된 커밋이 체크아웃됨
+//   '+'  다른 커밋이 체크아웃됨 (상위 프로젝트에서 커밋하면 기록이 바뀜)
+//   '-'  아직 받지 않음 (init + update 필요)
+//   뒤에 "(modified content)" 가 붙으면 서브모듈 안에 커밋하지 않은 변경이 있음
+
+use clap::{Args, Subcommand};
+use core::repo::Repository;
+use core::submodule::{self, SubmoduleState};
+
+/// 출력 시 표시할 해시 길이
+const SHORT_HASH_LENGTH: usize = 8;
+
+#[derive(Args)]
+pub struct SubmoduleArgs {
+    #[command(subcommand)]
+    command: SubmoduleCommand,
+}
+
+#[derive(Subcommand)]
+enum SubmoduleCommand {
+    /// Clone a repository into a subdirectory and record it as a submodule
+    Add {
+        /// Repository URL (or path relative to the origin URL)
+        url: String,
+        /// Directory for the submodule
+        path: String,
+    },
+    /// Copy submodule URLs from .ctsmodules into the repository config
+    Init {
+        /// Only these submodules
+        paths: Vec<String>,
+    },
+    /// Clone missing submodules and check out the recorded commits
+    Update {
+        /// Initialize submodules first
+        #[arg(long)]
+        init: bool,
+        /// Also update submodules inside submodules
+        #[arg(long)]
+        recursive: bool,
+        /// Only these submodules
+        paths: Vec<String>,
+    },
+    /// Show the checked out commit of each submodule
+    Status,
+}
+
+pub fn run(args: SubmoduleArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    match args.command {
+        SubmoduleCommand::Add { url, path } => {
+            let module = submodule::add(&repo, &url, &path)?;
+            println!("Added submodule '{}' from {}", module.path, module.url);
+        }
+        SubmoduleCommand::Init { paths } => report_init(&submodule::init(&repo, &paths)?),
+        SubmoduleCommand::Update { init, recursive, paths } => update(&repo, &paths, init, recursive)?,
+        SubmoduleCommand::Status => {
+            for status in submodule::status(&repo)? {
+                let (prefix, commit) = match &status.state {
+                    SubmoduleState::UpToDate => (' ', &status.recorded),
+                    SubmoduleState::Modified(head) => ('+', head),
+                    SubmoduleState::Uninitialized => ('-', &status.recorded),
+                };
+                let dirty = if status.dirty { " (modified content)" } else { "" };
+                println!("{}{} {}{}", prefix, &commit[..SHORT_HASH_LENGTH], status.path, dirty);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// init (선택) 후 update, clone --recurse-submodules 에서도 사용
+pub fn update(repo: &Repository, paths: &[String], init: bool, recursive: bool) -> anyhow::Result<()> {
+    if init {
+        report_init(&submodule::init(repo, paths)?);
+    }
+    for (path, commit) in submodule::update(repo, paths, recursive)? {
+        println!("Submodule path '{}': checked out '{}'", path, &commit[..SHORT_HASH_LENGTH]);
+    }
+    Ok(())
+}
+
+fn report_init(modules: &[submodule::Submodule]) {
+    for module in modules {
+        println!("Submodule '{}' ({}) registered for path '{}'", module.name, module.url, module.path);
+    }
+}
@@ -0,0 +1,89 @@
+// =============================================================================
+// cts tag
+// =============================================================================
+//
+// 사용법:
+//   cts tag                              # 태그 목록
+//   cts tag v1.0 [<rev>]                 # 가벼운 태그 (커밋을 직접 가리킴)
+//   cts tag -a v1.0 -m "Release" [<rev>] # 주석 태그 (태그 객체 생성)
+//   cts tag -s v1.0 -m "Release" [<rev>] # 서명된 주석 태그
+//   cts tag -d v1.0
+//
+// 이미 있는 태그는 덮어쓰지 않음
+
+use anyhow::{bail, Context};
+use clap::Args;
+use core::object::{Actor, Object, Tag};
+use core::refs::{check_ref_name, check_short_name, Expected, TAGS_PREFIX};
+use core::repo::Repository;
+
+use super::signing_key::load_signing_key;
+
+#[derive(Args)]
+pub struct TagArgs {
+    /// Tag name (lists tags when omitted)
+    name: Option<String>,
+    /// Commit to tag (defaults to HEAD)
+    rev: Option<String>,
+    /// Create an annotated tag object
+    #[arg(short, long)]
+    annotate: bool,
+    /// Create a signed annotated tag
+    #[arg(short, long)]
+    sign: bool,
+    /// Tag message (required for annotated tags)
+    #[arg(short, long)]
+    message: Option<String>,
+    /// Delete the tag
+    #[arg(short, long, requires = "name", conflicts_with_all = ["rev", "annotate", "sign", "message"])]
+    delete: bool,
+}
+
+pub fn run(args: TagArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+
+    let Some(name) = args.name else {
+        for (full, _) in repo.list_refs(TAGS_PREFIX)? {
+            println!("{}", &full[TAGS_PREFIX.len()..]);
+        }
+        return Ok(());
+    };
+    check_short_name(&name)?;
+    let full = format!("{}{}", TAGS_PREFIX, name);
+    check_ref_name(&full)?;
+
+    if args.delete {
+        let old = repo.read_ref(&full)?.with_context(|| format!("Tag {} not found", name))?;
+        repo.refs().delete(&full, Expected::Value(old.clone()))?;
+        println!("Deleted tag {} (was {})", name, &old[..8]);
+        return Ok(());
+    }
+
+    let commit = repo.resolve_rev(args.rev.as_deref().unwrap_or("HEAD"))?;
+    repo.objects().read_commit(&commit)?;
+    let annotated = args.annotate || args.sign || args.message.is_some();
+    // 태그 객체에는 태그한 사람이 남으므로 설정된 사용자가 필요 (가벼운 태그는 reflog 만)
+    let identity = if annotated { repo.committer_identity()? } else { repo.identity()? };
+    let target = if annotated {
+        let Some(message) = args.message else {
+            bail!("Annotated tags need a message (-m)");
+        };
+        let mut tag = Tag::new(
+            commit,
+            name.clone(),
+            format!("{}\n", message.trim_end()),
+            Actor::now(identity.name.clone(), identity.email.clone()),
+        );
+        if args.sign {
+            load_signing_key()?.sign_tag(&mut tag);
+        }
+        repo.objects().write(&Object::from(tag))?
+    } else {
+        commit
+    };
+
+    repo.refs()
+        .update(&full, &target, Expected::Missing, &identity, &format!("tag: {}", name))
+        .with_context(|| format!("Tag {} already exists", name))?;
+    Ok(())
+}
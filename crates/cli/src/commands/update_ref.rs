@@ -0,0 +1,99 @@
+// =============================================================================
+// cts update-ref
+// =============================================================================
+//
+// 사용법:
+//   cts update-ref refs/heads/main <new> [<old>]     # old 와 같을 때만 갱신
+//   cts update-ref -d refs/tags/v1.0 [<old>]
+//   cts update-ref --stdin < updates.txt             # 여러 참조를 한 트랜잭션으로
+//
+// --stdin 형식 (한 줄에 하나):
+//   update <ref> <new> [<old>]
+//   create <ref> <new>
+//   delete <ref> [<old>]
+
+use std::io::Read;
+
+use anyhow::{bail, Context};
+use clap::Args;
+use core::refs::{Expected, RefTransaction};
+use core::repo::Repository;
+
+#[derive(Args)]
+pub struct UpdateRefArgs {
+    /// Ref to update (e.g. refs/heads/main, HEAD)
+    #[arg(required_unless_present = "stdin")]
+    name: Option<String>,
+    /// New value (commit or revision), or old value with -d
+    new: Option<String>,
+    /// Expected current value; the update fails if the ref moved
+    old: Option<String>,
+    /// Delete the ref instead of updating it
+    #[arg(short, long)]
+    delete: bool,
+    /// Read update/create/delete instructions from standard input
+    #[arg(long, conflicts_with = "name")]
+    stdin: bool,
+    /// Reason recorded in the reflog
+    #[arg(short = 'm', long, default_value = "update-ref")]
+    message: String,
+}
+
+pub fn run(args: UpdateRefArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let mut transaction = repo.refs().transaction();
+
+    if args.stdin {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        for (number, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            queue(&repo, &mut transaction, line)
+                .with_context(|| format!("stdin line {}: {}", number + 1, line))?;
+        }
+    } else {
+        let name = args.name.as_deref().unwrap_or_default();
+        if args.delete {
+            if args.old.is_some() {
+                bail!("usage: cts update-ref -d <ref> [<old>]");
+            }
+            let expected = expected(&repo, args.new.as_deref())?;
+            transaction.delete(name, expected);
+        } else {
+            let new = args.new.as_deref().context("usage: cts update-ref <ref> <new> [<old>]")?;
+            let expected = expected(&repo, args.old.as_deref())?;
+            transaction.update(name, &repo.resolve_rev(new)?, expected);
+        }
+    }
+
+    transaction.commit(&repo.identity()?, &args.message)?;
+    Ok(())
+}
+
+/// --stdin 한 줄을 트랜잭션에 추가
+fn queue(repo: &Repository, transaction: &mut RefTransaction<'_>, line: &str) -> anyhow::Result<()> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        ["update", name, new] => transaction.update(name, &repo.resolve_rev(new)?, Expected::Any),
+        ["update", name, new, old] => {
+            transaction.update(name, &repo.resolve_rev(new)?, expected(repo, Some(old))?)
+        }
+        ["create", name, new] => transaction.update(name, &repo.resolve_rev(new)?, Expected::Missing),
+        ["delete", name] => transaction.delete(name, Expected::Any),
+        ["delete", name, old] => transaction.delete(name, expected(repo, Some(old))?),
+        _ => bail!("unrecognized instruction"),
+    };
+    Ok(())
+}
+
+/// 기대값 인자 해석 (빈 문자열 또는 0으로만 된 해시는 "없어야 함")
+fn expected(repo: &Repository, old: Option<&str>) -> anyhow::Result<Expected> {
+    Ok(match old {
+        None => Expected::Any,
+        Some(old) if old.is_empty() || old.chars().all(|c| c == '0') => Expected::Missing,
+        Some(old) => Expected::Value(repo.resolve_rev(old)?),
+    })
+}
@@ -0,0 +1,85 @@
+// =============================================================================
+// cts verify-commit / cts verify-tag
+// =============================================================================
+//
+// 사용법:
+//   cts verify-commit HEAD
+//   cts verify-tag v1.0
+//
+// 서명이 없거나 유효하지 않은 객체가 하나라도 있으면 실패 (종료 코드 1)
+// 서명한 키가 로컬 서명 키와 같으면 함께 표시
+
+use anyhow::bail;
+use clap::Args;
+use core::object::Object;
+use core::repo::Repository;
+use core::signing::{default_key_path, verify_commit, verify_tag, SigningKey, Verification};
+
+#[derive(Args)]
+pub struct VerifyCommitArgs {
+    /// Commits to verify
+    #[arg(required = true)]
+    commits: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct VerifyTagArgs {
+    /// Annotated tags to verify
+    #[arg(required = true)]
+    tags: Vec<String>,
+}
+
+pub fn run(args: VerifyCommitArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let mut results = Vec::new();
+    for rev in &args.commits {
+        let hash = repo.resolve_rev(rev)?;
+        let commit = repo.objects().read_commit(&hash)?;
+        results.push((format!("commit {}", hash), verify_commit(&commit)));
+    }
+    report(results)
+}
+
+pub fn run_tag(args: VerifyTagArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    let mut results = Vec::new();
+    for name in &args.tags {
+        let Some(full) = repo.full_ref_name(name)? else {
+            bail!("Tag {} not found", name);
+        };
+        let hash = repo.read_ref(&full)?.unwrap_or_default();
+        let Object::Tag(tag) = repo.objects().read(&hash)? else {
+            bail!("{} is not an annotated tag", name);
+        };
+        results.push((format!("tag {}", tag.name), verify_tag(&tag)));
+    }
+    report(results)
+}
+
+fn report(results: Vec<(String, Verification)>) -> anyhow::Result<()> {
+    let own_key = default_key_path()
+        .and_then(|path| SigningKey::load(path).ok())
+        .map(|key| key.public_key());
+
+    let mut failed = 0;
+    for (what, verification) in results {
+        match verification {
+            Verification::Good(key) => {
+                let own = if Some(key) == own_key { " (your key)" } else { "" };
+                println!("{}: good signature from {}{}", what, key.fingerprint(), own);
+            }
+            Verification::Unsigned => {
+                println!("{}: no signature", what);
+                failed += 1;
+            }
+            Verification::Bad(reason) => {
+                println!("{}: BAD signature ({})", what, reason);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{} object(s) failed verification", failed);
+    }
+    Ok(())
+}
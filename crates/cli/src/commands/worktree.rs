@@ -0,0 +1,194 @@
+// =============================================================================
+// cts worktree
+// =============================================================================
+//
+// 사용법:
+//   cts worktree add ../hotfix                 # "hotfix" 브랜치 (없으면 HEAD 에서 생성)
+//   cts worktree add ../review feature         # 기존 브랜치 체크아웃
+//   cts worktree add -b fix-42 ../fix main     # main 에서 새 브랜치 fix-42
+//   cts worktree add --detach ../old v1.0      # HEAD 분리 상태로
+//   cts worktree list
+//   cts worktree remove ../hotfix [--force]
+//   cts worktree prune                         # 직접 지운 작업 디렉토리 정리
+//
+// 같은 브랜치는 한 작업 디렉토리에서만 체크아웃 가능 (--force 로 무시)
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{Args, Subcommand};
+use core::refs::HEADS_PREFIX;
+use core::repo::Repository;
+use core::worktree::{self, WorktreeCheckout};
+
+use crate::hooks;
+
+/// 출력 시 표시할 해시 길이
+const SHORT_HASH_LENGTH: usize = 8;
+
+#[derive(Args)]
+pub struct WorktreeArgs {
+    #[command(subcommand)]
+    command: WorktreeCommand,
+}
+
+#[derive(Subcommand)]
+enum WorktreeCommand {
+    /// Create a new working tree attached to this repository
+    Add {
+        /// Directory for the new working tree
+        path: PathBuf,
+        /// Branch or commit to check out
+        commit: Option<String>,
+        /// Create a new branch with this name
+        #[arg(short = 'b', conflicts_with = "detach")]
+        new_branch: Option<String>,
+        /// Check out the commit with a detached HEAD
+        #[arg(long)]
+        detach: bool,
+        /// Allow a branch that is checked out in another working tree
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// List working trees
+    List,
+    /// Delete a linked working tree
+    Remove {
+        /// Name or path of the working tree
+        worktree: String,
+        /// Delete even with uncommitted changes or untracked files
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Clean up records of working trees whose directory was deleted
+    Prune {
+        /// Only report what would be removed
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+    },
+}
+
+pub fn run(args: WorktreeArgs) -> anyhow::Result<()> {
+    let repo = Repository::from_env()?;
+    match args.command {
+        WorktreeCommand::Add {
+            path,
+            commit,
+            new_branch,
+            detach,
+            force,
+        } => {
+            let checkout = checkout_target(&repo, &path, commit, new_branch, detach)?;
+            let added = worktree::add(&repo, &path, checkout, force, &repo.identity()?)?;
+            let head = added.head.as_deref().unwrap_or("");
+            match &added.branch {
+                Some(branch) => println!(
+                    "Preparing worktree (checking out '{}')",
+                    branch.trim_start_matches(HEADS_PREFIX)
+                ),
+                None => println!("Preparing worktree (detached HEAD {})", &head[..SHORT_HASH_LENGTH]),
+            }
+            // 훅은 새 작업 디렉토리에서 실행
+            if let Some(head) = &added.head {
+                hooks::post_checkout(&Repository::discover(&path)?, None, head)?;
+            }
+        }
+        WorktreeCommand::List => {
+            let worktrees = worktree::list(&repo)?;
+            let width = worktrees
+                .iter()
+                .map(|worktree| display_path(&repo, worktree.path.as_ref()).len())
+                .max()
+                .unwrap_or(0);
+            for worktree in worktrees {
+                let hash = worktree.head.as_deref().map(|head| &head[..SHORT_HASH_LENGTH]).unwrap_or("(none)");
+                let branch = match &worktree.branch {
+                    Some(branch) => format!("[{}]", branch.trim_start_matches(HEADS_PREFIX)),
+                    None => "(detached HEAD)".to_string(),
+                };
+                let prunable = if worktree.prunable { " prunable" } else { "" };
+                println!(
+                    "{:width$}  {:8} {}{}",
+                    display_path(&repo, worktree.path.as_ref()),
+                    hash,
+                    branch,
+                    prunable,
+                    width = width
+                );
+            }
+        }
+        WorktreeCommand::Remove { worktree, force } => {
+            let removed = worktree::remove(&repo, &worktree, force)?;
+            println!("Removed worktree {}", display_path(&repo, removed.path.as_ref()));
+        }
+        WorktreeCommand::Prune { dry_run } => {
+            let prunable: Vec<_> = worktree::list(&repo)?.into_iter().filter(|w| w.prunable).collect();
+            let pruned = if dry_run { prunable } else { worktree::prune(&repo)? };
+            for worktree in pruned {
+                println!(
+                    "Removing worktrees/{}: working tree directory no longer exists",
+                    worktree.name.unwrap_or_default()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// add 의 인자 조합을 체크아웃 대상으로
+///
+/// - -b: commit(기본 HEAD)에서 새 브랜치
+/// - --detach: commit(기본 HEAD)을 분리된 HEAD 로
+/// - 로컬 브랜치 이름: 그 브랜치
+/// - 그 밖의 리비전: 분리된 HEAD
+/// - 아무것도 없으면: 경로 이름의 브랜치 (없으면 HEAD 에서 생성)
+fn checkout_target(
+    repo: &Repository,
+    path: &std::path::Path,
+    commit: Option<String>,
+    new_branch: Option<String>,
+    detach: bool,
+) -> anyhow::Result<WorktreeCheckout> {
+    let start = |rev: Option<&str>| -> anyhow::Result<String> {
+        Ok(repo.resolve_rev(rev.unwrap_or("HEAD"))?)
+    };
+    if let Some(name) = new_branch {
+        return Ok(WorktreeCheckout::NewBranch {
+            name,
+            start: start(commit.as_deref())?,
+        });
+    }
+    if detach {
+        return Ok(WorktreeCheckout::Detached(start(commit.as_deref())?));
+    }
+    if let Some(commit) = commit {
+        return Ok(match repo.full_ref_name(&commit)? {
+            Some(full) if full.starts_with(HEADS_PREFIX) => WorktreeCheckout::Branch(full),
+            _ => WorktreeCheckout::Detached(start(Some(&commit))?),
+        });
+    }
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .context("Cannot derive a branch name from the path; use -b")?;
+    let full = format!("{}{}", HEADS_PREFIX, name);
+    if repo.refs().read(&full)?.is_some() {
+        return Ok(WorktreeCheckout::Branch(full));
+    }
+    if repo.head()?.is_none() {
+        bail!("HEAD has no commits yet");
+    }
+    Ok(WorktreeCheckout::NewBranch {
+        name,
+        start: start(None)?,
+    })
+}
+
+/// 출력용 경로 (bare 저장소의 기본 항목은 저장소 경로)
+fn display_path(repo: &Repository, path: Option<&PathBuf>) -> String {
+    match path {
+        Some(path) => path.display().to_string(),
+        None => format!("{} (bare)", repo.common_dir().display()),
+    }
+}
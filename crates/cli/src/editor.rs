@@ -0,0 +1,68 @@
+// =============================================================================
+// 편집기 실행
+// =============================================================================
+//
+// 커밋 메시지/rebase 할 일 목록을 사용자 편집기로 수정
+//
+// 편집기 선택 순서:
+//   메시지:    $CTS_EDITOR → $EDITOR → vi
+//   할 일 목록: $CTS_SEQUENCE_EDITOR → (메시지와 같은 순서)
+//
+// 편집기 값은 셸로 실행 → "code --wait" 처럼 인자를 붙이거나
+// CTS_SEQUENCE_EDITOR="cp plan.txt" 처럼 스크립트로 목록을 바꿀 수 있음
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context};
+use core::repo::Repository;
+use shared::error::AppError;
+
+/// 편집기 환경 변수가 없을 때
+const DEFAULT_EDITOR: &str = "vi";
+
+/// 편집 중인 커밋 메시지 파일 (.cts 기준)
+const COMMIT_EDITMSG: &str = "COMMIT_EDITMSG";
+
+const MESSAGE_HELP: &str = "\n# Please enter the commit message for your changes. Lines starting\n\
+                            # with '#' will be ignored, and an empty message aborts the commit.\n";
+
+/// 메시지 편집기 명령
+pub fn message_editor() -> String {
+    std::env::var("CTS_EDITOR")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| DEFAULT_EDITOR.to_string())
+}
+
+/// 할 일 목록 편집기 명령
+pub fn sequence_editor() -> String {
+    std::env::var("CTS_SEQUENCE_EDITOR").unwrap_or_else(|_| message_editor())
+}
+
+/// text 를 file 에 쓰고 편집기로 연 뒤 결과를 읽어 옴
+pub fn edit_file(editor: &str, file: &Path, text: &str) -> anyhow::Result<String> {
+    fs::write(file, text).with_context(|| format!("Failed to write {}", file.display()))?;
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$@\"", editor))
+        .arg(editor)
+        .arg(file)
+        .status()
+        .with_context(|| format!("Failed to run editor '{}'", editor))?;
+    if !status.success() {
+        bail!("Editor '{}' exited with {}", editor, status);
+    }
+    fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))
+}
+
+/// 커밋 메시지 편집기 (.cts/COMMIT_EDITMSG)
+///
+/// 편집 전 메시지를 받아 편집한 메시지를 돌려주는 함수 (주석 정리는 호출하는 쪽에서)
+pub fn commit_message_editor(repo: &Repository) -> impl FnMut(&str) -> Result<String, AppError> + '_ {
+    move |message: &str| {
+        let path = repo.cts_dir().join(COMMIT_EDITMSG);
+        edit_file(&message_editor(), &path, &format!("{}{}", message, MESSAGE_HELP))
+            .map_err(|err| AppError::Internal(format!("{:#}", err)))
+    }
+}
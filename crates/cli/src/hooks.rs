@@ -0,0 +1,124 @@
+// =============================================================================
+// 저장소 훅 실행
+// =============================================================================
+//
+// 정해진 시점에 훅 디렉토리의 실행 파일을 실행 (파일 이름 = 훅 이름)
+// 훅 디렉토리: 설정 core.hookspath (system / global / 저장소, 상대 경로는 작업 디렉토리 기준) → .cts/hooks
+// 연결된 작업 디렉토리도 공용 .cts/hooks 를 사용
+//
+// 훅          시점                      인자 / 표준 입력                         0 이 아닌 종료 코드
+// pre-commit  커밋 메시지를 받기 전      없음                                     커밋 중단
+// commit-msg  메시지를 정한 뒤           메시지 파일 (.cts/COMMIT_EDITMSG)        커밋 중단
+//                                      (훅이 파일을 고치면 고친 메시지로 커밋)
+// pre-push    객체를 보내기 전           {원격 이름} {주소}                       push 중단
+//                                      입력: 참조마다 "{로컬 참조} {로컬 해시} {원격 참조} {원격 해시}"
+//                                      (없는 쪽 해시는 0 으로 채움)
+// post-checkout 작업 디렉토리를 바꾼 뒤  {이전 HEAD} {새 HEAD} 1                  무시 (이미 끝난 작업)
+//
+// 훅은 작업 디렉토리(bare 면 .cts)에서 실행, 환경 변수 CTS_DIR 로 .cts 위치 전달
+// 파일이 없으면 건너뛰고, 실행 권한이 없으면 경고만 출력
+// pre-commit / commit-msg / pre-push 는 --no-verify 로 건너뜀
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+
+use anyhow::{bail, Context};
+use core::hash::HASH_HEX_LENGTH;
+use core::repo::Repository;
+
+/// 훅 디렉토리 기본 위치 (공용 .cts 기준)
+const HOOKS_DIR: &str = "hooks";
+
+/// 훅 디렉토리 설정 키
+const HOOKS_PATH_KEY: &str = "core.hookspath";
+
+/// commit-msg 훅에 넘기는 메시지 파일 (.cts 기준)
+const COMMIT_EDITMSG: &str = "COMMIT_EDITMSG";
+
+pub const PRE_COMMIT: &str = "pre-commit";
+pub const COMMIT_MSG: &str = "commit-msg";
+pub const PRE_PUSH: &str = "pre-push";
+pub const POST_CHECKOUT: &str = "post-checkout";
+
+/// 훅 인자에서 없는 커밋을 나타내는 해시
+pub fn null_hash() -> String {
+    "0".repeat(HASH_HEX_LENGTH)
+}
+
+/// 훅 디렉토리
+pub fn hooks_dir(repo: &Repository) -> anyhow::Result<PathBuf> {
+    match repo.layered_config()?.get(HOOKS_PATH_KEY) {
+        Some(path) => {
+            let path = Path::new(path);
+            Ok(match repo.work_dir() {
+                Some(work_dir) if path.is_relative() => work_dir.join(path),
+                _ => path.to_path_buf(),
+            })
+        }
+        None => Ok(repo.common_dir().join(HOOKS_DIR)),
+    }
+}
+
+/// 훅 실행
+///
+/// # Returns
+/// 종료 상태 (훅이 없거나 실행할 수 없으면 None)
+pub fn run_hook(repo: &Repository, name: &str, args: &[&str], input: Option<&[u8]>) -> anyhow::Result<Option<ExitStatus>> {
+    let path = hooks_dir(repo)?.join(name);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    if !is_executable(&path)? {
+        eprintln!("hint: The '{}' hook was ignored because it is not set as executable", path.display());
+        return Ok(None);
+    }
+
+    let mut child = Command::new(&path)
+        .args(args)
+        .current_dir(repo.work_dir().unwrap_or(repo.cts_dir()))
+        .env("CTS_DIR", repo.cts_dir())
+        .stdin(if input.is_some() { Stdio::piped() } else { Stdio::null() })
+        .spawn()
+        .with_context(|| format!("Failed to run the {} hook ({})", name, path.display()))?;
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+        // 입력을 다 읽지 않고 끝나는 훅도 있으므로 쓰기 실패는 무시
+        let _ = stdin.write_all(input);
+    }
+    Ok(Some(child.wait()?))
+}
+
+/// 작업을 막을 수 있는 훅: 0 이 아닌 종료 코드면 오류
+pub fn run_blocking(repo: &Repository, name: &str, args: &[&str], input: Option<&[u8]>) -> anyhow::Result<()> {
+    match run_hook(repo, name, args, input)? {
+        Some(status) if !status.success() => bail!("{} hook failed ({}); use --no-verify to bypass", name, status),
+        _ => Ok(()),
+    }
+}
+
+/// commit-msg 훅: 메시지를 파일로 넘기고, 훅이 고친 메시지를 돌려받음
+pub fn commit_msg(repo: &Repository, message: &str) -> anyhow::Result<String> {
+    let path = repo.cts_dir().join(COMMIT_EDITMSG);
+    fs::write(&path, message).with_context(|| format!("Failed to write {}", path.display()))?;
+    run_blocking(repo, COMMIT_MSG, &[&path.to_string_lossy()], None)?;
+    fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// post-checkout 훅 (종료 코드는 무시)
+pub fn post_checkout(repo: &Repository, old: Option<&str>, new: &str) -> anyhow::Result<()> {
+    let old = old.map_or_else(null_hash, str::to_string);
+    run_hook(repo, POST_CHECKOUT, &[&old, new, "1"], None)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> anyhow::Result<bool> {
+    use std::os::unix::fs::PermissionsExt;
+    Ok(fs::metadata(path)?.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> anyhow::Result<bool> {
+    Ok(true)
+}
@@ -3,11 +3,32 @@
 // =============================================================================
 //
 // 사용법:
-//   cts init
-//   cts add <file>
-//   cts commit -m "message"
-//   cts push
+//   cts init [--bare] [-b <branch>] [<directory>]
+//   cts add [-A | -u] [-n] [<pathspec>...]
+//   cts rm [-r] [--cached] <pathspec>... | cts mv <source>... <destination>
+//   cts commit [-a] [--amend [--no-edit]] [--author <author>] [--allow-empty] [-S]
+//              [-s] [--trailer <trailer>...] [-m "message"]
+//   cts push [<remote>] [<branch>...] [--no-verify]
 //   cts pull
+//   cts blame <path>
+//   cts bundle create <file> <rev-range>
+//   cts format-patch <range>
+//   cts am <patch>...
+//   cts update-ref <ref> <new> [<old>]
+//   cts reflog [<ref>]
+//   cts tag -s <name> -m "message"
+//   cts verify-commit <rev>...
+//   cts notes add -m "build: passed" <rev>
+//   cts stash push -u -m "wip"
+//   cts cherry-pick <commit>... | cts revert <commit>...
+//   cts rebase [-i] [--onto <newbase>] <upstream>
+//   cts config [--global|--system|--local] get|set|unset|list
+//
+// 훅 (.cts/hooks 또는 core.hookspath): pre-commit, commit-msg, pre-push, post-checkout
+
+mod commands;
+mod editor;
+mod hooks;
 
 use clap::{Parser, Subcommand};
 
@@ -22,68 +43,129 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize a new repository
-    Init,
-    /// Add file(s) to staging
-    Add {
-        /// Files to add
-        files: Vec<String>,
-    },
-    /// Create a commit
-    Commit {
-        /// Commit message
-        #[arg(short, long)]
-        message: String,
-    },
-    /// Push to remote server
-    Push,
+    Init(commands::init::InitArgs),
+    /// Add file contents to the index
+    Add(commands::add::AddArgs),
+    /// Remove files from the index and the working tree
+    Rm(commands::add::RemoveArgs),
+    /// Move or rename files in the index and the working tree
+    Mv(commands::add::MoveArgs),
+    /// Record the staged changes as a new commit
+    Commit(commands::commit::CommitArgs),
+    /// Push branches, tags and notes to a remote repository
+    Push(commands::push::PushArgs),
     /// Pull from remote server
     Pull,
     /// Clone a repository
-    Clone {
-        /// Repository URL
-        url: String,
-    },
+    Clone(commands::clone::CloneArgs),
+    /// Get and set configuration (system, global and repository)
+    Config(commands::config::ConfigArgs),
+    /// Download objects and refs from another repository
+    Fetch(commands::fetch::FetchArgs),
     /// Show commit history
-    Log,
+    Log(commands::log::LogArgs),
     /// Show current status
-    Status,
+    Status(commands::status::StatusArgs),
+    /// Show which commit last changed each line of a file
+    Blame(commands::blame::BlameArgs),
+    /// Create and inspect bundle files for offline transfer
+    Bundle(commands::bundle::BundleArgs),
+    /// Export a commit as a tar, tar.gz or zip archive
+    Archive(commands::archive::ArchiveArgs),
+    /// Import history from a `git fast-export` stream
+    FastImport(commands::fast_import::FastImportArgs),
+    /// Write history as a stream for `git fast-import`
+    FastExport(commands::fast_import::FastExportArgs),
+    /// Export commits as patch files
+    FormatPatch(commands::format_patch::FormatPatchArgs),
+    /// Apply a series of patches as commits
+    Am(commands::am::AmArgs),
+    /// Update a ref safely (compare-and-swap, transactions)
+    UpdateRef(commands::update_ref::UpdateRefArgs),
+    /// List refs and the commits they point to
+    ShowRef(commands::show_ref::ShowRefArgs),
+    /// Move loose refs into the packed-refs file
+    PackRefs(commands::pack_refs::PackRefsArgs),
+    /// Show the history of a ref
+    Reflog(commands::reflog::ReflogArgs),
+    /// Create, list or delete tags
+    Tag(commands::tag::TagArgs),
+    /// Manage the local ed25519 signing key
+    SigningKey(commands::signing_key::SigningKeyArgs),
+    /// Check the signatures of commits
+    VerifyCommit(commands::verify_commit::VerifyCommitArgs),
+    /// Check the signatures of annotated tags
+    VerifyTag(commands::verify_commit::VerifyTagArgs),
+    /// Attach, show and merge notes on commits
+    Notes(commands::notes::NotesArgs),
+    /// Stash away uncommitted changes and re-apply them later
+    Stash(commands::stash::StashArgs),
+    /// Apply the changes of existing commits on top of HEAD
+    CherryPick(commands::cherry_pick::CherryPickArgs),
+    /// Create commits that undo existing commits
+    Revert(commands::cherry_pick::RevertArgs),
+    /// Reapply commits on top of another base
+    Rebase(commands::rebase::RebaseArgs),
+    /// Find the commit that introduced a bug by binary search
+    ///
+    /// Searches the parent chain from the bad commit back to the good commits.
+    /// CTS commits have a single parent, so there are no merge branches to search.
+    Bisect(commands::bisect::BisectArgs),
+    /// Search file contents in the working tree or in revisions
+    Grep(commands::grep::GrepArgs),
+    /// Manage multiple working trees attached to this repository
+    Worktree(commands::worktree::WorktreeArgs),
+    /// Check out only some directories of the repository
+    SparseCheckout(commands::sparse_checkout::SparseCheckoutArgs),
+    /// Embed other repositories as submodules
+    Submodule(commands::submodule::SubmoduleArgs),
 }
 
-fn main() {
+fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Init => {
-            println!("Initializing repository...");
-            // TODO: 구현
-        }
-        Commands::Add { files } => {
-            println!("Adding files: {:?}", files);
-            // TODO: 구현
-        }
-        Commands::Commit { message } => {
-            println!("Creating commit: {}", message);
-            // TODO: 구현
-        }
-        Commands::Push => {
-            println!("Pushing to remote...");
-            // TODO: 구현
-        }
+        Commands::Init(args) => commands::init::run(args)?,
+        Commands::Add(args) => commands::add::run(args)?,
+        Commands::Rm(args) => commands::add::run_rm(args)?,
+        Commands::Mv(args) => commands::add::run_mv(args)?,
+        Commands::Commit(args) => commands::commit::run(args)?,
+        Commands::Push(args) => commands::push::run(args)?,
         Commands::Pull => {
             println!("Pulling from remote...");
             // TODO: 구현
         }
-        Commands::Clone { url } => {
-            println!("Cloning from: {}", url);
-            // TODO: 구현
-        }
-        Commands::Log => {
-            println!("Showing log...");
-            // TODO: 구현
-        }
-        Commands::Status => {
-            println!("Showing status...");
-            // TODO: 구현
-        }
+        Commands::Clone(args) => commands::clone::run(args)?,
+        Commands::Config(args) => commands::config::run(args)?,
+        Commands::Fetch(args) => commands::fetch::run(args)?,
+        Commands::Log(args) => commands::log::run(args)?,
+        Commands::Status(args) => commands::status::run(args)?,
+        Commands::Blame(args) => commands::blame::run(args)?,
+        Commands::Bundle(args) => commands::bundle::run(args)?,
+        Commands::Archive(args) => commands::archive::run(args)?,
+        Commands::FastImport(args) => commands::fast_import::run(args)?,
+        Commands::FastExport(args) => commands::fast_import::run_export(args)?,
+        Commands::FormatPatch(args) => commands::format_patch::run(args)?,
+        Commands::Am(args) => commands::am::run(args)?,
+        Commands::UpdateRef(args) => commands::update_ref::run(args)?,
+        Commands::ShowRef(args) => commands::show_ref::run(args)?,
+        Commands::PackRefs(args) => commands::pack_refs::run(args)?,
+        Commands::Reflog(args) => commands::reflog::run(args)?,
+        Commands::Tag(args) => commands::tag::run(args)?,
+        Commands::SigningKey(args) => commands::signing_key::run(args)?,
+        Commands::VerifyCommit(args) => commands::verify_commit::run(args)?,
+        Commands::VerifyTag(args) => commands::verify_commit::run_tag(args)?,
+        Commands::Notes(args) => commands::notes::run(args)?,
+        Commands::Stash(args) => commands::stash::run(args)?,
+        Commands::CherryPick(args) => commands::cherry_pick::run(args)?,
+        Commands::Revert(args) => commands::cherry_pick::run_revert(args)?,
+        Commands::Rebase(args) => commands::rebase::run(args)?,
+        Commands::Bisect(args) => commands::bisect::run(args)?,
+        Commands::Grep(args) => commands::grep::run(args)?,
+        Commands::Worktree(args) => commands::worktree::run(args)?,
+        Commands::SparseCheckout(args) => commands::sparse_checkout::run(args)?,
+        Commands::Submodule(args) => commands::submodule::run(args)?,
     }
+
+    Ok(())
 }
@@ -0,0 +1,796 @@
+// =============================================================================
+// 아카이브 (archive.rs)
+// =============================================================================
+//
+// 커밋의 트리를 작업 디렉토리 없이 객체 저장소에서 바로 tar / tar.gz / zip 으로 내보내기
+//
+// 같은 커밋이면 언제 어디서 만들어도 바이트 단위로 같은 아카이브 (재현 가능한 배포 파일):
+// - 항목 순서: 경로순 (디렉토리 항목은 "dir/")
+// - 권한: TreeEntry 의 모드 (100755 → 0755, 100644 → 0644, 디렉토리 0755)
+// - 수정 시각: 커밋의 committer 시각, 소유자: uid/gid 0 (root)
+// - tar: ustar + pax (100 바이트보다 긴 경로), 맨 앞 pax 전역 헤더에 커밋 해시 기록
+// - tar.gz: gzip 헤더의 시각도 커밋 시각
+// - zip: deflate, 파일 끝 주석에 커밋 해시 기록 (zip64 미지원 → 4GiB 이상 파일은 에러)
+//
+// .ctsattributes (트리 안의 어느 디렉토리에나, 해당 디렉토리 기준):
+//   tests/fixtures/  export-ignore     # 디렉토리와 그 아래 전부 제외
+//   *.psd            export-ignore     # 이름만 있는 패턴은 모든 깊이에서
+//   /docs/draft.md   export-ignore     # '/' 가 있으면 이 디렉토리 기준 경로
+//   keep.psd         -export-ignore    # 해제 (나중에 나온 줄이 우선)
+//
+// 서브모듈은 빈 디렉토리로 들어감
+//
+// 파일 위치: crates/core/src/archive.rs
+// =============================================================================
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc, GzBuilder};
+use shared::error::AppError;
+
+use crate::object::{Commit, TreeEntry};
+use crate::store::ObjectStore;
+
+/// 내보내기 속성 파일 이름
+pub const ATTRIBUTES_FILE: &str = ".ctsattributes";
+
+/// 내보내기에서 제외하는 속성
+const EXPORT_IGNORE: &str = "export-ignore";
+
+/// tar 블록 크기
+const TAR_BLOCK: usize = 512;
+
+/// tar 레코드 크기 (블록 20개, 아카이브 전체 길이를 이 배수로 맞춤)
+const TAR_RECORD: usize = TAR_BLOCK * 20;
+
+/// 디렉토리 권한
+const DIR_MODE: u32 = 0o755;
+
+// =============================================================================
+// 형식 / 옵션
+// =============================================================================
+
+/// 아카이브 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// 출력 파일 이름의 확장자로 형식 추측 (.tar, .tar.gz, .tgz, .zip)
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else {
+            None
+        }
+    }
+
+    /// 파일 확장자 (점 제외)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+
+    /// HTTP Content-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "application/x-tar",
+            ArchiveFormat::TarGz => "application/gzip",
+            ArchiveFormat::Zip => "application/zip",
+        }
+    }
+}
+
+impl std::str::FromStr for ArchiveFormat {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tar" => Ok(ArchiveFormat::Tar),
+            "tar.gz" | "tgz" => Ok(ArchiveFormat::TarGz),
+            "zip" => Ok(ArchiveFormat::Zip),
+            _ => Err(AppError::InvalidInput(format!(
+                "Unknown archive format '{}' (expected tar, tar.gz or zip)",
+                s
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for ArchiveFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// 아카이브 옵션
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchiveOptions {
+    /// 모든 경로 앞에 붙일 접두사 (예: "project-1.0/")
+    pub prefix: String,
+    /// 이 파일/디렉토리만 포함 (비어 있으면 전부)
+    pub paths: Vec<String>,
+}
+
+// =============================================================================
+// 아카이브 생성
+// =============================================================================
+
+/// 커밋의 트리를 아카이브로 기록
+///
+/// # Arguments
+/// * `commit_hash` - 내보낼 커밋 (수정 시각은 이 커밋의 committer 시각)
+/// * `out` - 출력 (순서대로 쓰기만 하므로 파일, 표준 출력, 네트워크 스트림 모두 가능)
+///
+/// # Returns
+/// 아카이브에 넣은 파일 수 (디렉토리 제외)
+///
+/// # Errors
+/// * `AppError::NotFound` - `options.paths` 중 트리에 없는 경로
+/// * `AppError::InvalidInput` - tar/zip 형식의 크기 제한을 넘는 파일
+pub fn write_archive<W: Write>(
+    store: &ObjectStore,
+    commit_hash: &str,
+    format: ArchiveFormat,
+    options: &ArchiveOptions,
+    out: W,
+) -> Result<usize, AppError> {
+    let commit: Commit = store.read_commit(commit_hash)?;
+    let mtime = commit.committer.time.timestamp();
+    let entries = archive_entries(store, &commit.tree_hash, options)?;
+
+    // partial clone: 필요한 Blob 을 한 번에 받아옴
+    let blobs: Vec<String> = entries.iter().filter_map(|entry| entry.blob.clone()).collect();
+    store.prefetch(&blobs)?;
+
+    match format {
+        ArchiveFormat::Tar => write_tar(store, commit_hash, mtime, &entries, out)?,
+        ArchiveFormat::TarGz => {
+            let mut gz = GzBuilder::new()
+                .mtime(mtime.clamp(0, u32::MAX as i64) as u32)
+                .write(out, Compression::default());
+            write_tar(store, commit_hash, mtime, &entries, &mut gz)?;
+            gz.finish()?;
+        }
+        ArchiveFormat::Zip => write_zip(store, commit_hash, &commit, &entries, out)?,
+    }
+    Ok(blobs.len())
+}
+
+/// 아카이브 항목 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ArchiveEntry {
+    /// 아카이브 안의 경로 (접두사 포함, 디렉토리는 '/' 로 끝남)
+    path: String,
+    /// 권한 비트 (0o755 등)
+    mode: u32,
+    /// 파일 내용 Blob (디렉토리는 None)
+    blob: Option<String>,
+}
+
+/// 트리에서 아카이브에 넣을 항목 목록 (경로순, 상위 디렉토리 항목 포함)
+fn archive_entries(
+    store: &ObjectStore,
+    tree_hash: &str,
+    options: &ArchiveOptions,
+) -> Result<Vec<ArchiveEntry>, AppError> {
+    let files = store.flatten_tree(tree_hash)?;
+    let attributes = ExportAttributes::load(store, &files)?;
+
+    let wanted: Vec<&str> = options.paths.iter().map(|path| path.trim_matches('/')).collect();
+    for path in &wanted {
+        let prefix = format!("{}/", path);
+        if !files.keys().any(|file| file == path || file.starts_with(&prefix)) {
+            return Err(AppError::NotFound(format!("Path '{}' not found in the tree", path)));
+        }
+    }
+    let selected = |path: &str| {
+        wanted.is_empty()
+            || wanted.iter().any(|want| path == *want || path.starts_with(&format!("{}/", want)))
+    };
+
+    let mut entries: BTreeMap<String, (u32, Option<String>)> = BTreeMap::new();
+    if options.prefix.ends_with('/') {
+        entries.insert(options.prefix.clone(), (DIR_MODE, None));
+    }
+    for (path, entry) in &files {
+        if !selected(path) || attributes.ignores(path) {
+            continue;
+        }
+        let mut parent = path.as_str();
+        while let Some((dir, _)) = parent.rsplit_once('/') {
+            entries.insert(format!("{}{}/", options.prefix, dir), (DIR_MODE, None));
+            parent = dir;
+        }
+        let value = if entry.is_submodule() {
+            (DIR_MODE, None)
+        } else {
+            (file_mode(entry), Some(entry.hash.clone()))
+        };
+        let name = if entry.is_submodule() { format!("{}/", path) } else { path.clone() };
+        entries.insert(format!("{}{}", options.prefix, name), value);
+    }
+    Ok(entries
+        .into_iter()
+        .map(|(path, (mode, blob))| ArchiveEntry { path, mode, blob })
+        .collect())
+}
+
+/// TreeEntry 모드의 권한 비트 ("100755" → 0o755)
+fn file_mode(entry: &TreeEntry) -> u32 {
+    u32::from_str_radix(&entry.mode, 8).map_or(0o644, |mode| mode & 0o777)
+}
+
+// =============================================================================
+// export-ignore
+// =============================================================================
+
+/// .ctsattributes 의 export-ignore 규칙 하나
+#[derive(Debug, Clone)]
+struct AttributeRule {
+    /// 속성 파일이 있는 디렉토리 ("" 또는 "dir/")
+    base: String,
+    /// 패턴 (앞의 '/' 와 뒤의 '/' 제거)
+    pattern: String,
+    /// '/' 가 있는 패턴 → base 기준 전체 경로와 비교
+    anchored: bool,
+    /// '/' 로 끝나는 패턴 → 디렉토리에만 적용
+    dir_only: bool,
+    /// export-ignore 설정(true) / 해제(false)
+    ignore: bool,
+}
+
+impl AttributeRule {
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        let Some(relative) = path.strip_prefix(&self.base) else {
+            return false;
+        };
+        if self.anchored {
+            glob_match(self.pattern.as_bytes(), relative.as_bytes())
+        } else {
+            let name = relative.rsplit('/').next().unwrap_or(relative);
+            glob_match(self.pattern.as_bytes(), name.as_bytes())
+        }
+    }
+}
+
+/// 트리 안 모든 .ctsattributes 의 export-ignore 규칙 (상위 디렉토리 파일부터)
+#[derive(Debug, Default)]
+struct ExportAttributes {
+    rules: Vec<AttributeRule>,
+}
+
+impl ExportAttributes {
+    fn load(store: &ObjectStore, files: &BTreeMap<String, TreeEntry>) -> Result<Self, AppError> {
+        let mut sources: Vec<(&String, &TreeEntry)> = files
+            .iter()
+            .filter(|(path, entry)| {
+                entry.is_file() && path.rsplit('/').next() == Some(ATTRIBUTES_FILE)
+            })
+            .collect();
+        sources.sort_by_key(|(path, _)| path.matches('/').count());
+
+        let mut rules = Vec::new();
+        for (path, entry) in sources {
+            let base = &path[..path.len() - ATTRIBUTES_FILE.len()];
+            let content = store.read_blob(&entry.hash)?;
+            for line in String::from_utf8_lossy(content.content()).lines() {
+                let mut fields = line.split_whitespace();
+                let Some(pattern) = fields.next().filter(|pattern| !pattern.starts_with('#')) else {
+                    continue;
+                };
+                for attribute in fields {
+                    let ignore = match attribute {
+                        EXPORT_IGNORE => true,
+                        _ if attribute.strip_prefix(['-', '!']) == Some(EXPORT_IGNORE) => false,
+                        _ => continue,
+                    };
+                    let dir_only = pattern.ends_with('/');
+                    let trimmed = pattern.trim_end_matches('/');
+                    rules.push(AttributeRule {
+                        base: base.to_string(),
+                        anchored: trimmed.contains('/'),
+                        pattern: trimmed.trim_start_matches('/').to_string(),
+                        dir_only,
+                        ignore,
+                    });
+                }
+            }
+        }
+        Ok(Self { rules })
+    }
+
+    /// 경로 자신이나 상위 디렉토리 중 하나라도 export-ignore 면 제외
+    fn ignores(&self, path: &str) -> bool {
+        let mut candidates = vec![(path, false)];
+        let mut parent = path;
+        while let Some((dir, _)) = parent.rsplit_once('/') {
+            candidates.push((dir, true));
+            parent = dir;
+        }
+        candidates.into_iter().any(|(candidate, is_dir)| {
+            self.rules
+                .iter()
+                .rev()
+                .find(|rule| rule.matches(candidate, is_dir))
+                .is_some_and(|rule| rule.ignore)
+        })
+    }
+}
+
+/// '*' (경로 구분자 제외 임의 문자열), '?' (한 글자) 패턴 매칭
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => {
+            (0..=text.len())
+                .take_while(|&skip| skip == 0 || text[skip - 1] != b'/')
+                .any(|skip| glob_match(rest, &text[skip..]))
+        }
+        Some((b'?', rest)) => {
+            matches!(text.split_first(), Some((&c, tail)) if c != b'/' && glob_match(rest, tail))
+        }
+        Some((&c, rest)) => matches!(text.split_first(), Some((&t, tail)) if t == c && glob_match(rest, tail)),
+    }
+}
+
+// =============================================================================
+// tar
+// =============================================================================
+
+fn write_tar<W: Write>(
+    store: &ObjectStore,
+    commit_hash: &str,
+    mtime: i64,
+    entries: &[ArchiveEntry],
+    mut out: W,
+) -> Result<(), AppError> {
+    let mut written = 0usize;
+
+    // pax 전역 헤더: 커밋 해시
+    let global = pax_record("comment", commit_hash);
+    emit(&mut out, &mut written, &tar_header(b"pax_global_header", b"", 0o666, global.len() as u64, mtime, b'g')?)?;
+    emit(&mut out, &mut written, &padded(global))?;
+
+    for entry in entries {
+        let content = match &entry.blob {
+            Some(hash) => store.read_blob(hash)?.content().to_vec(),
+            None => Vec::new(),
+        };
+        let typeflag = if entry.blob.is_some() { b'0' } else { b'5' };
+        let path = entry.path.as_bytes();
+        let header = match split_ustar_path(path) {
+            Some((prefix, name)) => tar_header(name, prefix, entry.mode, content.len() as u64, mtime, typeflag)?,
+            None => {
+                // 긴 경로: pax 확장 헤더에 전체 경로, ustar 헤더에는 잘린 이름
+                let record = pax_record("path", &entry.path);
+                emit(&mut out, &mut written, &tar_header(b"PaxHeader", b"", 0o666, record.len() as u64, mtime, b'x')?)?;
+                emit(&mut out, &mut written, &padded(record))?;
+                let short = &path[path.len() - 100..];
+                tar_header(short, b"", entry.mode, content.len() as u64, mtime, typeflag)?
+            }
+        };
+        emit(&mut out, &mut written, &header)?;
+        emit(&mut out, &mut written, &padded(content))?;
+    }
+
+    // 끝: 빈 블록 2개 + 레코드 크기까지 채움
+    emit(&mut out, &mut written, &[0u8; TAR_BLOCK * 2])?;
+    let remainder = written % TAR_RECORD;
+    if remainder != 0 {
+        emit(&mut out, &mut written, &vec![0u8; TAR_RECORD - remainder])?;
+    }
+    Ok(())
+}
+
+/// 출력에 쓰고 지금까지 쓴 길이 누적
+fn emit<W: Write>(out: &mut W, written: &mut usize, data: &[u8]) -> Result<(), AppError> {
+    out.write_all(data)?;
+    *written += data.len();
+    Ok(())
+}
+
+/// ustar 헤더 블록
+fn tar_header(
+    name: &[u8],
+    prefix: &[u8],
+    mode: u32,
+    size: u64,
+    mtime: i64,
+    typeflag: u8,
+) -> Result<[u8; TAR_BLOCK], AppError> {
+    let mut header = [0u8; TAR_BLOCK];
+    header[..name.len()].copy_from_slice(name);
+    write_octal(&mut header[100..108], mode as u64)?;
+    write_octal(&mut header[108..116], 0)?;
+    write_octal(&mut header[116..124], 0)?;
+    write_octal(&mut header[124..136], size)?;
+    write_octal(&mut header[136..148], mtime.max(0) as u64)?;
+    header[156] = typeflag;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[265..269].copy_from_slice(b"root");
+    header[297..301].copy_from_slice(b"root");
+    header[345..345 + prefix.len()].copy_from_slice(prefix);
+
+    // 체크섬: 체크섬 칸을 공백으로 두고 모든 바이트의 합
+    header[148..156].fill(b' ');
+    let checksum: u32 = header.iter().map(|&b| b as u32).sum();
+    header[148..155].copy_from_slice(format!("{:06o}\0", checksum).as_bytes());
+    Ok(header)
+}
+
+/// 0 으로 채운 8진수 + NUL
+fn write_octal(field: &mut [u8], value: u64) -> Result<(), AppError> {
+    let width = field.len() - 1;
+    let text = format!("{:0width$o}", value, width = width);
+    if text.len() > width {
+        return Err(AppError::InvalidInput(format!("Value {} is too large for a tar header", value)));
+    }
+    field[..width].copy_from_slice(text.as_bytes());
+    field[width] = 0;
+    Ok(())
+}
+
+/// ustar 의 (prefix 155, name 100) 로 나누기 (안 되면 None → pax)
+fn split_ustar_path(path: &[u8]) -> Option<(&[u8], &[u8])> {
+    if path.len() <= 100 {
+        return Some((b"", path));
+    }
+    // 디렉토리 항목의 끝 '/' 는 나눌 위치가 아님
+    let search = &path[..path.len() - 1];
+    search
+        .iter()
+        .enumerate()
+        .filter(|(i, &c)| c == b'/' && *i <= 155 && path.len() - i - 1 <= 100)
+        .map(|(i, _)| (&path[..i], &path[i + 1..]))
+        .next()
+}
+
+/// pax 레코드 "{길이} {키}={값}\n" (길이는 자기 자신의 자릿수 포함)
+fn pax_record(key: &str, value: &str) -> Vec<u8> {
+    let body = format!(" {}={}\n", key, value);
+    let mut length = body.len() + 1;
+    while length.to_string().len() + body.len() != length {
+        length = length.to_string().len() + body.len();
+    }
+    format!("{}{}", length, body).into_bytes()
+}
+
+/// 블록 크기 배수로 0 채우기
+fn padded(mut data: Vec<u8>) -> Vec<u8> {
+    let remainder = data.len() % TAR_BLOCK;
+    if remainder != 0 {
+        data.resize(data.len() + TAR_BLOCK - remainder, 0);
+    }
+    data
+}
+
+// =============================================================================
+// zip
+// =============================================================================
+
+/// zip 로컬 파일 헤더 / 중앙 디렉토리 / 끝 레코드 시그니처
+const ZIP_LOCAL_SIGNATURE: u32 = 0x0403_4b50;
+const ZIP_CENTRAL_SIGNATURE: u32 = 0x0201_4b50;
+const ZIP_END_SIGNATURE: u32 = 0x0605_4b50;
+
+/// 압축 해제에 필요한 버전 (2.0: deflate, 디렉토리)
+const ZIP_VERSION: u16 = 20;
+
+/// 만든 시스템: Unix (외부 속성에 권한 비트)
+const ZIP_MADE_BY_UNIX: u16 = 3 << 8;
+
+/// 일반 플래그: 파일 이름이 UTF-8
+const ZIP_FLAG_UTF8: u16 = 0x0800;
+
+/// 중앙 디렉토리에 기록할 항목 정보
+struct ZipRecord {
+    name: Vec<u8>,
+    method: u16,
+    crc: u32,
+    compressed: u32,
+    size: u32,
+    external: u32,
+    offset: u32,
+}
+
+fn write_zip<W: Write>(
+    store: &ObjectStore,
+    commit_hash: &str,
+    commit: &Commit,
+    entries: &[ArchiveEntry],
+    mut out: W,
+) -> Result<(), AppError> {
+    let (time, date) = dos_time(commit);
+    let too_large = || AppError::InvalidInput("Archive is too large for zip (zip64 is not supported)".to_string());
+    let to_u32 = |value: usize| u32::try_from(value).map_err(|_| too_large());
+
+    let mut offset = 0usize;
+    let mut records = Vec::new();
+    for entry in entries {
+        let (method, data, crc, size, external) = match &entry.blob {
+            Some(hash) => {
+                let blob = store.read_blob(hash)?;
+                let mut crc = Crc::new();
+                crc.update(blob.content());
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(blob.content())?;
+                let compressed = encoder.finish()?;
+                let external = (0o100000 | entry.mode) << 16;
+                (8u16, compressed, crc.sum(), blob.size(), external)
+            }
+            // 디렉토리: 권한 + MS-DOS 디렉토리 속성
+            None => (0u16, Vec::new(), 0, 0, ((0o040000 | entry.mode) << 16) | 0x10),
+        };
+        let record = ZipRecord {
+            name: entry.path.as_bytes().to_vec(),
+            method,
+            crc,
+            compressed: to_u32(data.len())?,
+            size: to_u32(size)?,
+            external,
+            offset: to_u32(offset)?,
+        };
+
+        let mut header = Vec::with_capacity(30 + record.name.len());
+        header.extend_from_slice(&ZIP_LOCAL_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        header.extend_from_slice(&ZIP_FLAG_UTF8.to_le_bytes());
+        header.extend_from_slice(&record.method.to_le_bytes());
+        header.extend_from_slice(&time.to_le_bytes());
+        header.extend_from_slice(&date.to_le_bytes());
+        header.extend_from_slice(&record.crc.to_le_bytes());
+        header.extend_from_slice(&record.compressed.to_le_bytes());
+        header.extend_from_slice(&record.size.to_le_bytes());
+        header.extend_from_slice(&(record.name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&record.name);
+        out.write_all(&header)?;
+        out.write_all(&data)?;
+        offset += header.len() + data.len();
+        records.push(record);
+    }
+
+    let central_start = offset;
+    for record in &records {
+        let mut header = Vec::with_capacity(46 + record.name.len());
+        header.extend_from_slice(&ZIP_CENTRAL_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&(ZIP_MADE_BY_UNIX | ZIP_VERSION).to_le_bytes());
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        header.extend_from_slice(&ZIP_FLAG_UTF8.to_le_bytes());
+        header.extend_from_slice(&record.method.to_le_bytes());
+        header.extend_from_slice(&time.to_le_bytes());
+        header.extend_from_slice(&date.to_le_bytes());
+        header.extend_from_slice(&record.crc.to_le_bytes());
+        header.extend_from_slice(&record.compressed.to_le_bytes());
+        header.extend_from_slice(&record.size.to_le_bytes());
+        header.extend_from_slice(&(record.name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&[0u8; 8]); // extra, comment 길이, 디스크 번호, 내부 속성
+        header.extend_from_slice(&record.external.to_le_bytes());
+        header.extend_from_slice(&record.offset.to_le_bytes());
+        header.extend_from_slice(&record.name);
+        out.write_all(&header)?;
+        offset += header.len();
+    }
+
+    let count = u16::try_from(records.len()).map_err(|_| too_large())?;
+    let mut end = Vec::with_capacity(22 + commit_hash.len());
+    end.extend_from_slice(&ZIP_END_SIGNATURE.to_le_bytes());
+    end.extend_from_slice(&[0u8; 4]); // 디스크 번호
+    end.extend_from_slice(&count.to_le_bytes());
+    end.extend_from_slice(&count.to_le_bytes());
+    end.extend_from_slice(&to_u32(offset - central_start)?.to_le_bytes());
+    end.extend_from_slice(&to_u32(central_start)?.to_le_bytes());
+    end.extend_from_slice(&(commit_hash.len() as u16).to_le_bytes());
+    end.extend_from_slice(commit_hash.as_bytes());
+    out.write_all(&end)?;
+    Ok(())
+}
+
+/// 커밋 시각(UTC)의 MS-DOS (시간, 날짜) 필드 (1980 년 이전은 1980-01-01)
+fn dos_time(commit: &Commit) -> (u16, u16) {
+    let stamp = commit.committer.time.format("%Y %m %d %H %M %S").to_string();
+    let fields: Vec<u16> = stamp.split(' ').filter_map(|field| field.parse().ok()).collect();
+    let [year, month, day, hour, minute, second] = fields[..] else {
+        return (0, (1 << 5) | 1);
+    };
+    if year < 1980 {
+        return (0, (1 << 5) | 1);
+    }
+    let time = (hour << 11) | (minute << 5) | (second / 2);
+    let date = ((year - 1980).min(127) << 9) | (month << 5) | day;
+    (time, date)
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Blob, Object};
+    use flate2::read::{DeflateDecoder, GzDecoder};
+    use std::io::Read;
+
+    fn commit_of(store: &ObjectStore, files: &[(&str, &str, bool)]) -> String {
+        let mut map = BTreeMap::new();
+        for (path, content, executable) in files {
+            let blob = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+            let entry = if *executable {
+                TreeEntry::executable(String::new(), blob)
+            } else {
+                TreeEntry::file(String::new(), blob)
+            };
+            map.insert(path.to_string(), entry);
+        }
+        let tree = store.write_flat_tree(&map).unwrap();
+        let actor = Actor::parse("T <t@example.com> 1700000000 +0900").unwrap();
+        store.write(&Object::from(Commit::initial(tree, "release".into(), actor.clone(), actor))).unwrap()
+    }
+
+    /// tar 를 (경로, 권한, 수정 시각, 내용) 목록으로 (pax 경로 반영)
+    fn read_tar(data: &[u8]) -> Vec<(String, u32, u64, Vec<u8>)> {
+        let octal = |field: &[u8]| {
+            let text = std::str::from_utf8(field).unwrap().trim_end_matches('\0');
+            u64::from_str_radix(text, 8).unwrap()
+        };
+        let mut entries = Vec::new();
+        let mut pax_path = None;
+        let mut pos = 0;
+        while data[pos..pos + TAR_BLOCK].iter().any(|&b| b != 0) {
+            let header = &data[pos..pos + TAR_BLOCK];
+            let mut check = header.to_vec();
+            check[148..156].fill(b' ');
+            assert_eq!(check.iter().map(|&b| b as u64).sum::<u64>(), octal(&header[148..155]));
+            let size = octal(&header[124..136]) as usize;
+            let content = data[pos + TAR_BLOCK..pos + TAR_BLOCK + size].to_vec();
+            let field = |range: std::ops::Range<usize>| {
+                String::from_utf8(header[range].iter().copied().take_while(|&b| b != 0).collect()).unwrap()
+            };
+            match header[156] {
+                b'g' => {}
+                b'x' => {
+                    let record = String::from_utf8(content.clone()).unwrap();
+                    pax_path = Some(record.split_once("path=").unwrap().1.trim_end().to_string());
+                }
+                _ => {
+                    let prefix = field(345..500);
+                    let name = field(0..100);
+                    let path = pax_path.take().unwrap_or(if prefix.is_empty() { name } else { format!("{}/{}", prefix, name) });
+                    entries.push((path, octal(&header[100..108]) as u32, octal(&header[136..148]), content));
+                }
+            }
+            pos += TAR_BLOCK + size.div_ceil(TAR_BLOCK) * TAR_BLOCK;
+        }
+        assert_eq!(data.len() % TAR_RECORD, 0);
+        entries
+    }
+
+    fn archive(store: &ObjectStore, commit: &str, format: ArchiveFormat, options: &ArchiveOptions) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_archive(store, commit, format, options, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_tar_is_deterministic_and_uses_tree_modes() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let long = format!("{}/file.txt", "nested/".repeat(40).trim_end_matches('/'));
+        let commit = commit_of(&store, &[
+            ("README", "hello\n", false),
+            ("bin/run.sh", "#!/bin/sh\n", true),
+            (&long, "deep\n", false),
+        ]);
+        let options = ArchiveOptions { prefix: "app-1.0/".into(), paths: vec![] };
+
+        let tar = archive(&store, &commit, ArchiveFormat::Tar, &options);
+        assert_eq!(tar, archive(&store, &commit, ArchiveFormat::Tar, &options));
+        let entries = read_tar(&tar);
+        let find = |path: &str| entries.iter().find(|(p, ..)| p == path).unwrap_or_else(|| panic!("{}", path));
+        assert_eq!(find("app-1.0/").1, 0o755);
+        assert_eq!(find("app-1.0/bin/").1, 0o755);
+        assert_eq!(find("app-1.0/bin/run.sh").1, 0o755);
+        assert_eq!(find("app-1.0/README").1, 0o644);
+        assert_eq!(find("app-1.0/README").2, 1_700_000_000);
+        assert_eq!(find("app-1.0/README").3, b"hello\n");
+        assert_eq!(find(&format!("app-1.0/{}", long)).3, b"deep\n");
+
+        // tar.gz 는 같은 tar 를 gzip 으로 감싼 것
+        let gz = archive(&store, &commit, ArchiveFormat::TarGz, &options);
+        assert_eq!(gz, archive(&store, &commit, ArchiveFormat::TarGz, &options));
+        let mut unpacked = Vec::new();
+        GzDecoder::new(gz.as_slice()).read_to_end(&mut unpacked).unwrap();
+        assert_eq!(unpacked, tar);
+    }
+
+    #[test]
+    fn test_export_ignore_and_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commit = commit_of(&store, &[
+            (".ctsattributes", "tests/ export-ignore\n*.psd export-ignore\nkeep.psd -export-ignore\n", false),
+            ("src/lib.rs", "lib\n", false),
+            ("src/art.psd", "x", false),
+            ("src/keep.psd", "y", false),
+            ("src/.ctsattributes", "/gen.rs export-ignore\n", false),
+            ("src/gen.rs", "gen\n", false),
+            ("tests/t.rs", "t\n", false),
+            ("docs/a.md", "a\n", false),
+        ]);
+        let names = |options: &ArchiveOptions| -> Vec<String> {
+            read_tar(&archive(&store, &commit, ArchiveFormat::Tar, options)).into_iter().map(|(p, ..)| p).collect()
+        };
+        assert_eq!(
+            names(&ArchiveOptions::default()),
+            [".ctsattributes", "docs/", "docs/a.md", "src/", "src/.ctsattributes", "src/keep.psd", "src/lib.rs"]
+        );
+        let only_src = ArchiveOptions { prefix: String::new(), paths: vec!["src/".into()] };
+        assert_eq!(names(&only_src), ["src/", "src/.ctsattributes", "src/keep.psd", "src/lib.rs"]);
+
+        let missing = ArchiveOptions { prefix: String::new(), paths: vec!["nope".into()] };
+        let result = write_archive(&store, &commit, ArchiveFormat::Tar, &missing, Vec::new());
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_zip_structure() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commit = commit_of(&store, &[("a/b.txt", "zip me zip me zip me\n", false), ("run", "x", true)]);
+        let zip = archive(&store, &commit, ArchiveFormat::Zip, &ArchiveOptions::default());
+        assert_eq!(zip, archive(&store, &commit, ArchiveFormat::Zip, &ArchiveOptions::default()));
+
+        // 끝 레코드: 항목 3개 (a/, a/b.txt, run), 주석은 커밋 해시
+        let end = zip.len() - 22 - commit.len();
+        assert_eq!(&zip[end..end + 4], &ZIP_END_SIGNATURE.to_le_bytes());
+        assert_eq!(u16::from_le_bytes([zip[end + 10], zip[end + 11]]), 3);
+        assert_eq!(&zip[end + 22..], commit.as_bytes());
+
+        // 첫 항목은 디렉토리, 두 번째 항목을 풀면 원래 내용
+        assert_eq!(&zip[30..32], b"a/");
+        let second = 32;
+        assert_eq!(&zip[second..second + 4], &ZIP_LOCAL_SIGNATURE.to_le_bytes());
+        let read_u32 = |at: usize| u32::from_le_bytes(zip[at..at + 4].try_into().unwrap());
+        let compressed = read_u32(second + 18) as usize;
+        let name_len = u16::from_le_bytes([zip[second + 26], zip[second + 27]]) as usize;
+        assert_eq!(&zip[second + 30..second + 30 + name_len], b"a/b.txt");
+        let data = &zip[second + 30 + name_len..second + 30 + name_len + compressed];
+        let mut content = Vec::new();
+        DeflateDecoder::new(data).read_to_end(&mut content).unwrap();
+        assert_eq!(content, b"zip me zip me zip me\n");
+        let mut crc = Crc::new();
+        crc.update(&content);
+        assert_eq!(read_u32(second + 14), crc.sum());
+        // 2023-11-14 22:13:20 UTC
+        assert_eq!(dos_time(&store.read_commit(&commit).unwrap()), ((22 << 11) | (13 << 5) | 10, (43 << 9) | (11 << 5) | 14));
+    }
+
+    #[test]
+    fn test_glob_and_pax_record() {
+        assert!(glob_match(b"*.psd", b"art.psd"));
+        assert!(!glob_match(b"*.psd", b"dir/art.psd"));
+        assert!(glob_match(b"docs/*/draft?.md", b"docs/v1/draft2.md"));
+        assert!(!glob_match(b"a?c", b"a/c"));
+        let record = pax_record("path", "x");
+        assert_eq!(record, b"9 path=x\n");
+        assert_eq!(pax_record("path", &"y".repeat(93)).len(), 103);
+    }
+}
@@ -0,0 +1,464 @@
+// =============================================================================
+// 이분 탐색 (bisect.rs)
+// =============================================================================
+//
+// 좋은(good) 커밋과 나쁜(bad) 커밋 사이에서 문제를 처음 만든 커밋 찾기
+//
+// 동작:
+// - 후보 = bad 에서 도달 가능하고 good 들에서는 도달 불가능한 커밋 (bad 포함)
+// - 각 후보의 "후보 안 조상 수" w 중 min(w, 후보 수 - w) 가 가장 큰 커밋을 다음 시험 대상으로
+//   → 커밋의 부모가 하나뿐이라 후보는 한 줄이고, w 는 후보 목록에서의 위치로 바로 나옴 (가운데 커밋)
+// - 부모가 둘 이상인 (병합) 커밋은 없음 (fast-import 도 병합 커밋은 거부)
+//   → 병합을 고려한 탐색은 하지 않고 부모 사슬 한 줄만 탐색
+// - skip 한 커밋은 시험 대상에서 제외 (남은 후보가 skip 뿐이면 범위만 보고)
+// - 시험 대상은 HEAD 를 분리(detach)해서 체크아웃
+//
+// 상태:
+//   refs/bisect/bad, refs/bisect/good-{해시}, refs/bisect/skip-{해시}
+//   .cts/BISECT_START   시작 전 HEAD (브랜치 참조 이름 또는 커밋 해시)
+//   .cts/BISECT_LOG     지금까지의 판정 기록
+//
+// 파일 위치: crates/core/src/bisect.rs
+// =============================================================================
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::str::FromStr;
+
+use shared::error::AppError;
+
+use crate::checkout::update_worktree;
+use crate::index::Index;
+use crate::refs::{Expected, Identity, RefValue};
+use crate::repo::Repository;
+use crate::revwalk::walk;
+
+/// bisect 참조 접두사
+pub const BISECT_PREFIX: &str = "refs/bisect/";
+
+/// 시작 전 HEAD 를 기록하는 파일 (.cts 기준)
+const BISECT_START: &str = "BISECT_START";
+
+/// 판정 기록 파일 (.cts 기준)
+const BISECT_LOG: &str = "BISECT_LOG";
+
+/// `bisect run` 스크립트가 "이 커밋은 시험할 수 없음" 을 알리는 종료 코드
+pub const SKIP_EXIT_CODE: i32 = 125;
+
+// =============================================================================
+// 판정 / 결과
+// =============================================================================
+
+/// 커밋 판정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    Good,
+    Bad,
+    Skip,
+}
+
+impl Mark {
+    /// `bisect run` 스크립트의 종료 코드 해석
+    ///
+    /// 0 → good, 125 → skip, 1~127 → bad, 그 밖(시그널로 종료 등) → 에러 (탐색 중단)
+    pub fn from_exit_code(code: Option<i32>) -> Result<Self, AppError> {
+        match code {
+            Some(0) => Ok(Mark::Good),
+            Some(SKIP_EXIT_CODE) => Ok(Mark::Skip),
+            Some(code) if (1..128).contains(&code) => Ok(Mark::Bad),
+            Some(code) => Err(AppError::Internal(format!("bisect run failed: exit code {}", code))),
+            None => Err(AppError::Internal("bisect run failed: command killed by a signal".to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Mark::Good => "good",
+            Mark::Bad => "bad",
+            Mark::Skip => "skip",
+        })
+    }
+}
+
+impl FromStr for Mark {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "good" | "old" => Ok(Mark::Good),
+            "bad" | "new" => Ok(Mark::Bad),
+            "skip" => Ok(Mark::Skip),
+            other => Err(AppError::InvalidInput(format!("Unknown bisect term: {}", other))),
+        }
+    }
+}
+
+/// 판정 후 다음 단계
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BisectStep {
+    /// good 또는 bad 가 아직 없음
+    NeedMore { bad: bool, good: bool },
+    /// 이 커밋을 체크아웃했으니 시험해서 판정할 것
+    Testing {
+        commit: String,
+        /// 이번 판정 뒤에도 시험해야 할 커밋 수 (판정이 어느 쪽이든 많은 쪽)
+        remaining: usize,
+        /// 남은 예상 단계 수
+        steps: u32,
+    },
+    /// 처음으로 나빠진 커밋
+    Found(String),
+    /// skip 한 커밋만 남아 하나로 좁힐 수 없음 (범인은 이 중 하나)
+    OnlySkipped(Vec<String>),
+}
+
+// =============================================================================
+// Bisect
+// =============================================================================
+
+/// 저장소의 bisect 세션
+#[derive(Debug)]
+pub struct Bisect<'a> {
+    repo: &'a Repository,
+    identity: Identity,
+}
+
+impl<'a> Bisect<'a> {
+    pub fn new(repo: &'a Repository, identity: Identity) -> Self {
+        Self { repo, identity }
+    }
+
+    /// 진행 중인 세션이 있는지
+    pub fn is_active(&self) -> bool {
+        self.repo.cts_dir().join(BISECT_START).exists()
+    }
+
+    /// 세션 시작 (bad 와 good 들을 함께 지정 가능)
+    ///
+    /// # Errors
+    /// * `AppError::Conflict` - 이미 진행 중
+    /// * `AppError::InvalidInput` - 커밋하지 않은 변경이 있음
+    pub fn start(&self, bad: Option<&str>, good: &[String]) -> Result<BisectStep, AppError> {
+        if self.is_active() {
+            return Err(AppError::Conflict(
+                "A bisect is already in progress; use 'bisect reset' first".to_string(),
+            ));
+        }
+        let work_dir = self.work_dir()?;
+        let store = self.repo.objects();
+        let head = self.repo.head()?;
+        if let Some(head) = &head {
+            let index = self.repo.read_index()?;
+            let head_tree = store.read_commit(head)?.tree_hash;
+            if index.write_tree(store)? != head_tree || !index.worktree_changes(work_dir)?.is_empty() {
+                return Err(AppError::InvalidInput(
+                    "You have uncommitted changes; commit or stash them before bisecting".to_string(),
+                ));
+            }
+        }
+
+        let start = match self.repo.refs().read("HEAD")? {
+            Some(RefValue::Symbolic(target)) => target,
+            Some(RefValue::Direct(hash)) => hash,
+            None => return Err(AppError::InvalidInput("HEAD is missing".to_string())),
+        };
+        fs::write(self.repo.cts_dir().join(BISECT_START), format!("{}\n", start))?;
+        fs::write(self.repo.cts_dir().join(BISECT_LOG), "cts bisect start\n")?;
+
+        if let Some(bad) = bad {
+            self.record(Mark::Bad, bad)?;
+        }
+        for good in good {
+            self.record(Mark::Good, good)?;
+        }
+        self.next()
+    }
+
+    /// 커밋 판정 후 다음 시험 대상 체크아웃
+    pub fn mark(&self, mark: Mark, commit: &str) -> Result<BisectStep, AppError> {
+        self.require_active()?;
+        self.record(mark, commit)?;
+        self.next()
+    }
+
+    /// 세션 종료, 시작 전 HEAD 로 복귀
+    ///
+    /// # Returns
+    /// 돌아간 HEAD (브랜치 참조 이름 또는 커밋 해시)
+    pub fn reset(&self) -> Result<String, AppError> {
+        self.require_active()?;
+        let start = fs::read_to_string(self.repo.cts_dir().join(BISECT_START))?.trim().to_string();
+        let store = self.repo.objects();
+
+        let target = if start.starts_with("refs/") {
+            self.repo.read_ref(&start)?
+        } else {
+            Some(start.clone())
+        };
+        if let (Some(head), Some(target)) = (self.repo.head()?, &target) {
+            let from = store.read_commit(&head)?.tree_hash;
+            let to = store.read_commit(target)?.tree_hash;
+            update_worktree(store, Some(&from), &to, self.work_dir()?)?;
+            self.repo.write_index(&Index::from_tree(store, &to)?)?;
+        }
+        let reason = format!("bisect reset: moving to {}", start);
+        if start.starts_with("refs/") {
+            self.repo.refs().set_symbolic("HEAD", &start, &self.identity, &reason)?;
+        } else {
+            self.repo.refs().set_detached("HEAD", &start, &self.identity, &reason)?;
+        }
+
+        let mut transaction = self.repo.refs().transaction();
+        for (name, _) in self.repo.list_refs(BISECT_PREFIX)? {
+            transaction.delete(&name, Expected::Any);
+        }
+        transaction.commit(&self.identity, "bisect reset")?;
+        fs::remove_file(self.repo.cts_dir().join(BISECT_START))?;
+        fs::remove_file(self.repo.cts_dir().join(BISECT_LOG))?;
+        Ok(start)
+    }
+
+    /// 판정 기록 텍스트
+    pub fn log(&self) -> Result<String, AppError> {
+        self.require_active()?;
+        Ok(fs::read_to_string(self.repo.cts_dir().join(BISECT_LOG))?)
+    }
+
+    /// 현재 판정들로 다음 단계 계산 (필요하면 시험 대상 체크아웃)
+    pub fn next(&self) -> Result<BisectStep, AppError> {
+        let store = self.repo.objects();
+        let bad = self.repo.read_ref(&format!("{}bad", BISECT_PREFIX))?;
+        let goods = self.marked("good-")?;
+        let bad = match bad {
+            Some(bad) if !goods.is_empty() => bad,
+            bad => {
+                return Ok(BisectStep::NeedMore {
+                    bad: bad.is_some(),
+                    good: !goods.is_empty(),
+                })
+            }
+        };
+
+        // 자식 → 부모 순서
+        let candidates = walk(store, std::slice::from_ref(&bad), &goods)?;
+        if candidates.is_empty() {
+            return Err(AppError::InvalidInput(
+                "The bad commit is an ancestor of a good commit; check your marks".to_string(),
+            ));
+        }
+        let skipped: HashSet<String> = self.marked("skip-")?.into_iter().collect();
+        let testable = |commit: &String| *commit != bad && !skipped.contains(commit);
+
+        if candidates.len() == 1 {
+            return Ok(BisectStep::Found(bad));
+        }
+        if !candidates.iter().any(testable) {
+            // 오래된 것부터
+            let mut remaining: Vec<String> = candidates.iter().rev().cloned().collect();
+            remaining.retain(|commit| *commit == bad || skipped.contains(commit));
+            return Ok(BisectStep::OnlySkipped(remaining));
+        }
+
+        // 후보는 한 줄이라 i 번째 후보의 후보 안 조상 수(자신 포함)는 total - i
+        let total = candidates.len();
+        let mut best: Option<(&String, usize)> = None;
+        for (i, commit) in candidates.iter().enumerate().filter(|(_, commit)| testable(commit)) {
+            let weight = total - i;
+            let score = weight.min(total - weight);
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((commit, score));
+            }
+        }
+        let (commit, score) = best.map(|(commit, score)| (commit.clone(), score)).unwrap_or((bad, 0));
+        self.checkout(&commit)?;
+
+        // bad 면 조상 w 개, good 이면 나머지 total - w 개가 후보로 남고 그중 bad 하나는 시험하지 않음
+        let remaining = total - score - 1;
+        Ok(BisectStep::Testing {
+            commit,
+            remaining,
+            steps: usize::BITS - remaining.leading_zeros(),
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // 내부
+    // -------------------------------------------------------------------------
+
+    /// 판정을 참조와 로그에 기록
+    fn record(&self, mark: Mark, rev: &str) -> Result<(), AppError> {
+        let commit = self.repo.resolve_rev(rev)?;
+        let name = match mark {
+            Mark::Bad => format!("{}bad", BISECT_PREFIX),
+            Mark::Good => format!("{}good-{}", BISECT_PREFIX, commit),
+            Mark::Skip => format!("{}skip-{}", BISECT_PREFIX, commit),
+        };
+        self.repo
+            .refs()
+            .update(&name, &commit, Expected::Any, &self.identity, &format!("bisect {}", mark))?;
+
+        let subject = self
+            .repo
+            .objects()
+            .read_commit(&commit)?
+            .message
+            .lines()
+            .next()
+            .unwrap_or("")
+            .to_string();
+        let mut log = fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(self.repo.cts_dir().join(BISECT_LOG))?;
+        log.write_all(format!("# {}: [{}] {}\ncts bisect {} {}\n", mark, commit, subject, mark, commit).as_bytes())?;
+        Ok(())
+    }
+
+    /// refs/bisect/{prefix}* 가 가리키는 커밋들
+    fn marked(&self, prefix: &str) -> Result<Vec<String>, AppError> {
+        Ok(self
+            .repo
+            .list_refs(&format!("{}{}", BISECT_PREFIX, prefix))?
+            .into_iter()
+            .map(|(_, hash)| hash)
+            .collect())
+    }
+
+    /// HEAD 를 분리해서 커밋 체크아웃
+    fn checkout(&self, commit: &str) -> Result<(), AppError> {
+        let store = self.repo.objects();
+        let to = store.read_commit(commit)?.tree_hash;
+        let from = match self.repo.head()? {
+            Some(head) => Some(store.read_commit(&head)?.tree_hash),
+            None => None,
+        };
+        update_worktree(store, from.as_deref(), &to, self.work_dir()?)?;
+        self.repo.write_index(&Index::from_tree(store, &to)?)?;
+        self.repo.refs().set_detached(
+            "HEAD",
+            commit,
+            &self.identity,
+            &format!("checkout: moving to {}", commit),
+        )
+    }
+
+    fn require_active(&self) -> Result<(), AppError> {
+        if self.is_active() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidInput("Not bisecting; use 'bisect start' first".to_string()))
+        }
+    }
+
+    fn work_dir(&self) -> Result<&'a std::path::Path, AppError> {
+        self.repo
+            .work_dir()
+            .ok_or_else(|| AppError::InvalidInput("Bisect requires a work tree".to_string()))
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkout::checkout_tree;
+    use crate::object::{Actor, Blob, Commit, Object, TreeEntry};
+    use std::collections::BTreeMap;
+
+    fn identity() -> Identity {
+        Identity::new("Tester", "tester@example.com")
+    }
+
+    /// "version" 파일 내용이 0..n 인 선형 히스토리, main 을 마지막 커밋에
+    fn setup(n: usize) -> (tempfile::TempDir, Repository, Vec<String>) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let store = repo.objects();
+        let mut commits: Vec<String> = Vec::new();
+        for i in 0..n {
+            let blob = store.write(&Object::from(Blob::new(format!("{}\n", i).into_bytes()))).unwrap();
+            let files = BTreeMap::from([("version".to_string(), TreeEntry::file("version".into(), blob))]);
+            let tree = store.write_flat_tree(&files).unwrap();
+            let actor = Actor::parse(&format!("Tester <tester@example.com> {} +0000", 1705314600 + i)).unwrap();
+            let commit = Commit::new(tree, commits.last().cloned(), format!("c{}\n", i), actor.clone(), actor);
+            commits.push(store.write(&Object::from(commit)).unwrap());
+        }
+        let last = commits.last().unwrap();
+        checkout_tree(store, &store.read_commit(last).unwrap().tree_hash, dir.path()).unwrap();
+        repo.refs().update("refs/heads/main", last, Expected::Any, &identity(), "test").unwrap();
+        (dir, repo, commits)
+    }
+
+    fn version(dir: &tempfile::TempDir) -> usize {
+        fs::read_to_string(dir.path().join("version")).unwrap().trim().parse().unwrap()
+    }
+
+    #[test]
+    fn test_bisect_finds_first_bad_commit() {
+        let (dir, repo, commits) = setup(20);
+        let bisect = Bisect::new(&repo, identity());
+
+        assert_eq!(bisect.start(None, &[]).unwrap(), BisectStep::NeedMore { bad: false, good: false });
+        assert!(matches!(bisect.start(None, &[]), Err(AppError::Conflict(_))));
+        assert_eq!(bisect.mark(Mark::Bad, "HEAD").unwrap(), BisectStep::NeedMore { bad: true, good: false });
+
+        // 버전 13 부터 나빠짐
+        let mut step = bisect.mark(Mark::Good, &commits[0]).unwrap();
+        // 후보 19 개 → 가운데를 시험하면 어느 쪽이든 9 개가 남음
+        assert!(matches!(step, BisectStep::Testing { remaining: 9, steps: 4, .. }), "{:?}", step);
+        let mut tested = 0;
+        loop {
+            match step {
+                BisectStep::Testing { ref commit, .. } => {
+                    tested += 1;
+                    assert_eq!(repo.head().unwrap().as_deref(), Some(commit.as_str()));
+                    let mark = if version(&dir) >= 13 { Mark::Bad } else { Mark::Good };
+                    step = bisect.mark(mark, "HEAD").unwrap();
+                }
+                BisectStep::Found(ref commit) => {
+                    assert_eq!(commit, &commits[13]);
+                    break;
+                }
+                other => panic!("unexpected step {:?}", other),
+            }
+        }
+        assert!(tested <= 5, "{} steps", tested);
+        assert!(bisect.log().unwrap().contains(&format!("cts bisect bad {}", commits[13])));
+
+        assert_eq!(bisect.reset().unwrap(), "refs/heads/main");
+        assert_eq!(repo.refs().read("HEAD").unwrap(), Some(RefValue::Symbolic("refs/heads/main".into())));
+        assert_eq!(version(&dir), 19);
+        assert!(repo.list_refs(BISECT_PREFIX).unwrap().is_empty());
+        assert!(!bisect.is_active());
+    }
+
+    #[test]
+    fn test_bisect_skip() {
+        let (_dir, repo, commits) = setup(4);
+        let bisect = Bisect::new(&repo, identity());
+
+        // 후보 c1, c2, c3(bad) → c1, c2 모두 skip 하면 범위만 알 수 있음
+        let step = bisect.start(Some(&commits[3]), std::slice::from_ref(&commits[0])).unwrap();
+        let BisectStep::Testing { commit, .. } = step else { panic!("{:?}", step) };
+        let step = bisect.mark(Mark::Skip, &commit).unwrap();
+        let BisectStep::Testing { commit, .. } = step else { panic!("{:?}", step) };
+        let step = bisect.mark(Mark::Skip, &commit).unwrap();
+        assert_eq!(step, BisectStep::OnlySkipped(commits[1..].to_vec()));
+    }
+
+    #[test]
+    fn test_mark_from_exit_code() {
+        assert_eq!(Mark::from_exit_code(Some(0)).unwrap(), Mark::Good);
+        assert_eq!(Mark::from_exit_code(Some(1)).unwrap(), Mark::Bad);
+        assert_eq!(Mark::from_exit_code(Some(125)).unwrap(), Mark::Skip);
+        assert!(Mark::from_exit_code(Some(128)).is_err());
+        assert!(Mark::from_exit_code(None).is_err());
+    }
+}
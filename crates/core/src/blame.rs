@@ -0,0 +1,469 @@
+// =============================================================================
+// Blame 모듈 (blame.rs)
+// =============================================================================
+//
+// 파일의 각 라인을 마지막으로 변경한 커밋 찾기 (git blame)
+//
+// 동작 방식:
+// 1. 시작 커밋의 파일 내용에서 모든 라인을 "미확정" 상태로 시작
+// 2. 부모 커밋의 같은 파일과 diff
+//    - 변경 없는 라인 → 부모에게 책임 전가 (라인 번호 변환)
+//    - 추가/변경된 라인 → 현재 커밋으로 확정
+// 3. 미확정 라인이 없을 때까지 부모 방향으로 반복
+//
+// 추가 기능:
+// - 이름 변경 추적: 부모에 같은 경로가 없으면 같은 내용/유사한 내용의 파일 탐색
+// - 범위 제한: "A..B" 에서 A 이전으로는 내려가지 않음 (boundary)
+// - 무시할 커밋: 일괄 포맷팅 같은 커밋은 건너뛰고 이전 커밋에 책임 전가
+//
+// 파일 위치: crates/core/src/blame.rs
+// =============================================================================
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use shared::error::AppError;
+
+use crate::diff::{changes, diff, similarity, split_lines, DiffOp};
+use crate::object::{Actor, Commit};
+use crate::revwalk::ancestors;
+use crate::store::ObjectStore;
+
+// -----------------------------------------------------------------------------
+// 상수
+// -----------------------------------------------------------------------------
+
+/// 이름 변경으로 판단할 최소 유사도 (50%)
+const RENAME_THRESHOLD: f64 = 0.5;
+
+// =============================================================================
+// 옵션 / 결과 타입
+// =============================================================================
+
+/// Blame 옵션
+#[derive(Debug, Clone, Default)]
+pub struct BlameOptions {
+    /// 범위 하한 ("A..B" 의 A)
+    /// 이 커밋들과 그 조상으로는 내려가지 않고, 남은 라인은 boundary 로 표시
+    pub exclude: Vec<String>,
+    /// 무시할 커밋 (일괄 포맷팅 등)
+    /// 이 커밋이 변경한 라인은 가능한 한 부모 커밋의 대응 라인에 책임 전가
+    pub ignore_revs: HashSet<String>,
+}
+
+/// Blame 결과의 한 라인
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BlameLine {
+    /// 최종 파일의 라인 번호 (1부터)
+    pub line_number: usize,
+    /// 이 라인을 마지막으로 변경한 커밋
+    pub commit: String,
+    /// 작성자 (이름, 이메일, 작성 시각)
+    pub author: Actor,
+    /// 커밋 메시지 첫 줄
+    pub summary: String,
+    /// 해당 커밋에서의 파일 경로 (이름 변경 추적 결과)
+    pub orig_path: String,
+    /// 해당 커밋에서의 라인 번호 (1부터)
+    pub orig_line_number: usize,
+    /// 범위 하한에 도달해서 확정된 라인인지
+    pub boundary: bool,
+    /// 라인 내용 (줄바꿈 제외)
+    pub content: String,
+}
+
+/// 추적 중인 라인: (최종 라인 인덱스, 현재 커밋에서의 라인 인덱스)
+type PendingLine = (usize, usize);
+
+// =============================================================================
+// Blame 계산
+// =============================================================================
+
+/// 파일의 라인별 blame 계산
+///
+/// # Arguments
+/// * `store` - 객체 저장소
+/// * `start` - 시작 커밋 해시 ("A..B" 의 B)
+/// * `path` - 저장소 루트 기준 파일 경로
+/// * `options` - 범위 / 무시할 커밋
+pub fn blame(
+    store: &ObjectStore,
+    start: &str,
+    path: &str,
+    options: &BlameOptions,
+) -> Result<Vec<BlameLine>, AppError> {
+    let excluded = ancestors(store, &options.exclude)?;
+
+    let start_commit = store.read_commit(start)?;
+    if store.has_promisor() {
+        prefetch_history(store, start, path, &excluded)?;
+    }
+    let entry = store
+        .lookup_path(&start_commit.tree_hash, path)?
+        .filter(|entry| entry.is_file())
+        .ok_or_else(|| AppError::NotFound(format!("File {} in {}", path, start)))?;
+    let final_content = store.read_blob(&entry.hash)?.content().to_vec();
+    let final_lines = split_lines(&final_content);
+
+    let mut result: Vec<Option<BlameLine>> = vec![None; final_lines.len()];
+    let mut pending: Vec<PendingLine> = (0..final_lines.len()).map(|i| (i, i)).collect();
+
+    let mut current = Cursor {
+        hash: start.to_string(),
+        commit: start_commit,
+        path: path.to_string(),
+        blob: entry.hash,
+        content: final_content.clone(),
+    };
+
+    while !pending.is_empty() {
+        if excluded.contains(&current.hash) {
+            assign(&mut result, &pending, &current, &final_lines, true);
+            break;
+        }
+
+        let parent = match find_parent_file(store, &current)? {
+            Some(parent) => parent,
+            None => {
+                // 첫 커밋이거나 이 커밋에서 파일이 생성됨
+                assign(&mut result, &pending, &current, &final_lines, false);
+                break;
+            }
+        };
+
+        let mapping = if parent.blob == current.blob {
+            // 내용이 같으면 라인 번호 그대로
+            (0..split_lines(&current.content).len()).map(Some).collect()
+        } else {
+            let ignored = options.ignore_revs.contains(&current.hash);
+            map_lines(&parent.content, &current.content, ignored)
+        };
+
+        let mut passed = Vec::new();
+        let mut own = Vec::new();
+        for &(final_index, index) in &pending {
+            match mapping[index] {
+                Some(parent_index) => passed.push((final_index, parent_index)),
+                None => own.push((final_index, index)),
+            }
+        }
+        assign(&mut result, &own, &current, &final_lines, false);
+
+        pending = passed;
+        current = parent;
+    }
+
+    Ok(result
+        .into_iter()
+        .map(|line| line.expect("every line is assigned"))
+        .collect())
+}
+
+// =============================================================================
+// 내부 구현
+// =============================================================================
+
+/// 탐색 중인 위치 (커밋 + 그 커밋에서의 파일)
+struct Cursor {
+    hash: String,
+    commit: Commit,
+    path: String,
+    blob: String,
+    content: Vec<u8>,
+}
+
+/// 라인들을 현재 커밋에 확정
+fn assign(
+    result: &mut [Option<BlameLine>],
+    lines: &[PendingLine],
+    cursor: &Cursor,
+    final_lines: &[&[u8]],
+    boundary: bool,
+) {
+    let summary = cursor.commit.message.lines().next().unwrap_or("").to_string();
+    for &(final_index, index) in lines {
+        result[final_index] = Some(BlameLine {
+            line_number: final_index + 1,
+            commit: cursor.hash.clone(),
+            author: cursor.commit.author.clone(),
+            summary: summary.clone(),
+            orig_path: cursor.path.clone(),
+            orig_line_number: index + 1,
+            boundary,
+            content: line_text(final_lines[final_index]),
+        });
+    }
+}
+
+/// 줄바꿈을 제외한 라인 텍스트
+fn line_text(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
+/// 현재 라인 인덱스 → 부모 라인 인덱스 매핑
+///
+/// 변경되지 않은 라인만 Some
+/// ignored 이면 교체된 라인도 부모의 대응 위치로 매핑 (순수 추가 라인 제외)
+fn map_lines(parent: &[u8], current: &[u8], ignored: bool) -> Vec<Option<usize>> {
+    let parent_lines = split_lines(parent);
+    let current_lines = split_lines(current);
+    let ops = diff(&parent_lines, &current_lines);
+
+    let mut mapping = vec![None; current_lines.len()];
+    for op in &ops {
+        if let DiffOp::Equal { old_index, new_index, len } = *op {
+            for i in 0..len {
+                mapping[new_index + i] = Some(old_index + i);
+            }
+        }
+    }
+
+    if ignored {
+        for change in changes(&ops) {
+            if change.old_len == 0 {
+                continue;
+            }
+            for i in 0..change.new_len {
+                mapping[change.new_start + i] = Some(change.old_start + i.min(change.old_len - 1));
+            }
+        }
+    }
+    mapping
+}
+
+/// partial clone: 같은 경로의 과거 Blob 들을 한 번에 받아옴 (트리는 로컬에 있음)
+fn prefetch_history(
+    store: &ObjectStore,
+    start: &str,
+    path: &str,
+    excluded: &HashSet<String>,
+) -> Result<(), AppError> {
+    let mut blobs = Vec::new();
+    let mut next = Some(start.to_string());
+    while let Some(hash) = next.filter(|hash| !excluded.contains(hash)) {
+        let commit = store.read_commit(&hash)?;
+        match store.lookup_path(&commit.tree_hash, path)? {
+            Some(entry) if entry.is_file() => blobs.push(entry.hash),
+            _ => break,
+        }
+        next = store.parent_of(&hash, &commit);
+    }
+    store.prefetch(&blobs)?;
+    Ok(())
+}
+
+/// 부모 커밋에서 같은 파일 찾기 (이름 변경 추적 포함)
+///
+/// # Returns
+/// 부모가 없거나 부모에 대응하는 파일이 없으면 None
+fn find_parent_file(store: &ObjectStore, current: &Cursor) -> Result<Option<Cursor>, AppError> {
+    let parent_hash = match store.parent_of(&current.hash, &current.commit) {
+        Some(hash) => hash,
+        None => return Ok(None),
+    };
+    let parent = store.read_commit(&parent_hash)?;
+
+    // 1. 같은 경로
+    if let Some(entry) = store.lookup_path(&parent.tree_hash, &current.path)? {
+        if entry.is_file() {
+            let content = store.read_blob(&entry.hash)?.content().to_vec();
+            return Ok(Some(Cursor {
+                hash: parent_hash,
+                commit: parent,
+                path: current.path.clone(),
+                blob: entry.hash,
+                content,
+            }));
+        }
+    }
+
+    // 2. 이름 변경 탐색: 현재 커밋에는 없는 부모의 파일들이 후보
+    let parent_files = store.flatten_tree(&parent.tree_hash)?;
+    let current_files = store.flatten_tree(&current.commit.tree_hash)?;
+    let candidates: Vec<_> = parent_files
+        .iter()
+        .filter(|(path, _)| !current_files.contains_key(*path))
+        .collect();
+
+    // 2-1. 내용이 완전히 같은 파일
+    if let Some((path, entry)) = candidates.iter().find(|(_, entry)| entry.hash == current.blob) {
+        let content = store.read_blob(&entry.hash)?.content().to_vec();
+        return Ok(Some(Cursor {
+            hash: parent_hash,
+            commit: parent,
+            path: (*path).clone(),
+            blob: entry.hash.clone(),
+            content,
+        }));
+    }
+
+    // 2-2. 가장 유사한 파일 (임계값 이상)
+    store.prefetch(&candidates.iter().map(|(_, entry)| entry.hash.clone()).collect::<Vec<_>>())?;
+    let current_lines = split_lines(&current.content);
+    let mut contents: HashMap<&str, Vec<u8>> = HashMap::new();
+    let mut best: Option<(&String, f64)> = None;
+    for (path, entry) in &candidates {
+        let content = store.read_blob(&entry.hash)?.content().to_vec();
+        let score = similarity(&split_lines(&content), &current_lines);
+        if score >= RENAME_THRESHOLD && best.is_none_or(|(_, s)| score > s) {
+            best = Some((path, score));
+        }
+        contents.insert(path.as_str(), content);
+    }
+
+    Ok(best.map(|(path, _)| {
+        let entry = &parent_files[path];
+        Cursor {
+            hash: parent_hash,
+            commit: parent,
+            path: path.clone(),
+            blob: entry.hash.clone(),
+            content: contents.remove(path.as_str()).unwrap_or_default(),
+        }
+    }))
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Blob, Object, Tree, TreeEntry};
+
+    /// 테스트용 히스토리 빌더
+    struct History {
+        _dir: tempfile::TempDir,
+        store: ObjectStore,
+        head: Option<String>,
+    }
+
+    impl History {
+        fn new() -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let store = ObjectStore::new(dir.path().join("objects"));
+            Self { _dir: dir, store, head: None }
+        }
+
+        /// 파일 목록으로 커밋 생성
+        fn commit(&mut self, author: &str, message: &str, files: &[(&str, &str)]) -> String {
+            let entries = files
+                .iter()
+                .map(|(name, content)| {
+                    let blob = Blob::new(content.as_bytes().to_vec());
+                    let hash = self.store.write(&Object::from(blob)).unwrap();
+                    TreeEntry::file(name.to_string(), hash)
+                })
+                .collect();
+            let tree = self.store.write(&Object::from(Tree::with_entries(entries))).unwrap();
+            let actor = Actor::parse(&format!("{} <{}@example.com> 1705314600 +0000", author, author.to_lowercase())).unwrap();
+            let commit = Commit::new(
+                tree,
+                self.head.clone(),
+                message.into(),
+                actor.clone(),
+                actor,
+            );
+            let hash = self.store.write(&Object::from(commit)).unwrap();
+            self.head = Some(hash.clone());
+            hash
+        }
+    }
+
+    fn authors(lines: &[BlameLine]) -> Vec<&str> {
+        lines.iter().map(|l| l.author.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_blame_linear_history() {
+        let mut history = History::new();
+        history.commit("Alice", "add file", &[("a.txt", "one\ntwo\nthree\n")]);
+        history.commit("Bob", "change two", &[("a.txt", "one\nTWO\nthree\n")]);
+        let head = history.commit("Carol", "append", &[("a.txt", "one\nTWO\nthree\nfour\n")]);
+
+        let lines = blame(&history.store, &head, "a.txt", &BlameOptions::default()).unwrap();
+        assert_eq!(authors(&lines), vec!["Alice", "Bob", "Alice", "Carol"]);
+        assert_eq!(lines[1].content, "TWO");
+        assert_eq!(lines[3].line_number, 4);
+        assert_eq!(lines[1].summary, "change two");
+    }
+
+    #[test]
+    fn test_blame_tracks_line_numbers() {
+        let mut history = History::new();
+        history.commit("Alice", "add", &[("a.txt", "x\ny\n")]);
+        let head = history.commit("Bob", "prepend", &[("a.txt", "new\nx\ny\n")]);
+
+        let lines = blame(&history.store, &head, "a.txt", &BlameOptions::default()).unwrap();
+        assert_eq!(authors(&lines), vec!["Bob", "Alice", "Alice"]);
+        // Alice 커밋에서 y 는 2번째 라인
+        assert_eq!(lines[2].orig_line_number, 2);
+    }
+
+    #[test]
+    fn test_blame_follows_rename() {
+        let mut history = History::new();
+        history.commit("Alice", "add", &[("old.txt", "a\nb\nc\nd\n")]);
+        history.commit("Bob", "rename", &[("new.txt", "a\nb\nc\nd\n")]);
+        let head = history.commit("Carol", "edit", &[("new.txt", "a\nb\nc\nD\n")]);
+
+        let lines = blame(&history.store, &head, "new.txt", &BlameOptions::default()).unwrap();
+        assert_eq!(authors(&lines), vec!["Alice", "Alice", "Alice", "Carol"]);
+        assert_eq!(lines[0].orig_path, "old.txt");
+    }
+
+    #[test]
+    fn test_blame_follows_rename_with_edit() {
+        let mut history = History::new();
+        history.commit("Alice", "add", &[("old.txt", "a\nb\nc\nd\n")]);
+        let head = history.commit("Bob", "rename+edit", &[("new.txt", "a\nb\nc\nX\n")]);
+
+        let lines = blame(&history.store, &head, "new.txt", &BlameOptions::default()).unwrap();
+        assert_eq!(authors(&lines), vec!["Alice", "Alice", "Alice", "Bob"]);
+    }
+
+    #[test]
+    fn test_blame_range_boundary() {
+        let mut history = History::new();
+        history.commit("Alice", "add", &[("a.txt", "a\nb\n")]);
+        let middle = history.commit("Bob", "edit", &[("a.txt", "a\nB\n")]);
+        let head = history.commit("Carol", "append", &[("a.txt", "a\nB\nc\n")]);
+
+        let options = BlameOptions {
+            exclude: vec![middle.clone()],
+            ..Default::default()
+        };
+        let lines = blame(&history.store, &head, "a.txt", &options).unwrap();
+        assert_eq!(authors(&lines), vec!["Bob", "Bob", "Carol"]);
+        assert!(lines[0].boundary && lines[1].boundary);
+        assert!(!lines[2].boundary);
+    }
+
+    #[test]
+    fn test_blame_ignore_revs() {
+        let mut history = History::new();
+        history.commit("Alice", "add", &[("a.rs", "fn a(){}\nfn b(){}\n")]);
+        let reformat = history.commit("Bot", "reformat", &[("a.rs", "fn a() {}\nfn b() {}\n")]);
+        let head = history.commit("Carol", "append", &[("a.rs", "fn a() {}\nfn b() {}\nfn c() {}\n")]);
+
+        let options = BlameOptions {
+            ignore_revs: [reformat].into_iter().collect(),
+            ..Default::default()
+        };
+        let lines = blame(&history.store, &head, "a.rs", &options).unwrap();
+        assert_eq!(authors(&lines), vec!["Alice", "Alice", "Carol"]);
+        // 내용은 최종 파일 기준
+        assert_eq!(lines[0].content, "fn a() {}");
+    }
+
+    #[test]
+    fn test_blame_missing_file() {
+        let mut history = History::new();
+        let head = history.commit("Alice", "add", &[("a.txt", "a\n")]);
+        let result = blame(&history.store, &head, "missing.txt", &BlameOptions::default());
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,327 @@
+// =============================================================================
+// 번들 모듈 (bundle.rs)
+// =============================================================================
+//
+// 서버 없이 히스토리를 옮기기 위한 단일 파일 포맷 (git bundle)
+// 네트워크가 차단된 빌드 머신 등에 USB/파일 복사로 전달
+//
+// 포맷:
+//   # v1 cts bundle\n
+//   -{prerequisite hash} {커밋 메시지 첫 줄}\n     (0개 이상)
+//   {hash} {ref name}\n                          (1개 이상)
+//   \n
+//   {pack}                                       (pack.rs 포맷)
+//
+// 전제 커밋(prerequisite):
+// - 번들은 이 커밋들과 그 조상을 받는 쪽이 이미 가지고 있다고 가정
+// - "A..B" 로 만든 번들의 전제 커밋은 A
+//
+// 파일 위치: crates/core/src/bundle.rs
+// =============================================================================
+
+use std::fs;
+use std::path::Path;
+
+use shared::error::AppError;
+
+use crate::pack::{build_pack, parse_pack, unpack};
+use crate::revwalk::reachable_objects;
+use crate::store::{is_valid_hash, ObjectStore};
+
+// -----------------------------------------------------------------------------
+// 상수
+// -----------------------------------------------------------------------------
+
+/// 번들 파일 첫 줄
+pub const BUNDLE_SIGNATURE: &str = "# v1 cts bundle";
+
+// =============================================================================
+// Bundle 구조체
+// =============================================================================
+
+/// 번들 파일 내용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bundle {
+    /// 전제 커밋 (해시, 설명)
+    pub prerequisites: Vec<(String, String)>,
+    /// 포함된 참조 (이름, 해시)
+    pub refs: Vec<(String, String)>,
+    /// 객체 팩
+    pub pack: Vec<u8>,
+}
+
+/// 번들 검증 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleVerification {
+    /// 팩 안의 객체 수
+    pub object_count: usize,
+    /// 로컬 저장소에 없는 전제 커밋
+    pub missing_prerequisites: Vec<String>,
+}
+
+impl BundleVerification {
+    /// 이 저장소에 적용 가능한지
+    pub fn is_ok(&self) -> bool {
+        self.missing_prerequisites.is_empty()
+    }
+}
+
+impl Bundle {
+    /// 번들 생성
+    ///
+    /// # Arguments
+    /// * `store` - 객체 저장소
+    /// * `refs` - 포함할 참조 (이름, 커밋 해시)
+    /// * `exclude` - 범위 하한 커밋 ("A..B" 의 A)
+    pub fn create(
+        store: &ObjectStore,
+        refs: Vec<(String, String)>,
+        exclude: &[String],
+    ) -> Result<Self, AppError> {
+        if refs.is_empty() {
+            return Err(AppError::InvalidInput("Refusing to create empty bundle".to_string()));
+        }
+        let tips: Vec<String> = refs.iter().map(|(_, hash)| hash.clone()).collect();
+        let (objects, boundary) = reachable_objects(store, &tips, exclude)?;
+        if objects.is_empty() {
+            return Err(AppError::InvalidInput("Refusing to create empty bundle".to_string()));
+        }
+
+        let prerequisites = boundary
+            .into_iter()
+            .map(|hash| {
+                let summary = store
+                    .read_commit(&hash)?
+                    .message
+                    .lines()
+                    .next()
+                    .unwrap_or("")
+                    .to_string();
+                Ok((hash, summary))
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        Ok(Self {
+            prerequisites,
+            refs,
+            pack: build_pack(store, &objects)?,
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // 직렬화
+    // -------------------------------------------------------------------------
+
+    /// 파일 바이트로 직렬화
+    pub fn encode(&self) -> Vec<u8> {
+        let mut header = format!("{}\n", BUNDLE_SIGNATURE);
+        for (hash, summary) in &self.prerequisites {
+            header.push_str(&format!("-{} {}\n", hash, summary));
+        }
+        for (name, hash) in &self.refs {
+            header.push_str(&format!("{} {}\n", hash, name));
+        }
+        header.push('\n');
+
+        let mut data = header.into_bytes();
+        data.extend_from_slice(&self.pack);
+        data
+    }
+
+    /// 파일 바이트 파싱 (팩 내용은 아직 검증하지 않음)
+    pub fn decode(data: &[u8]) -> Result<Self, AppError> {
+        let invalid = |what: &str| AppError::InvalidInput(format!("Invalid bundle: {}", what));
+
+        let end = data
+            .windows(2)
+            .position(|w| w == b"\n\n")
+            .ok_or_else(|| invalid("missing header terminator"))?;
+        let header = std::str::from_utf8(&data[..end]).map_err(|_| invalid("header is not UTF-8"))?;
+        let mut lines = header.lines();
+        if lines.next() != Some(BUNDLE_SIGNATURE) {
+            return Err(invalid("bad signature"));
+        }
+
+        let mut prerequisites = Vec::new();
+        let mut refs = Vec::new();
+        for line in lines {
+            if let Some(rest) = line.strip_prefix('-') {
+                let (hash, summary) = rest.split_once(' ').unwrap_or((rest, ""));
+                if !is_valid_hash(hash) {
+                    return Err(invalid(line));
+                }
+                prerequisites.push((hash.to_string(), summary.to_string()));
+            } else {
+                let (hash, name) = line.split_once(' ').ok_or_else(|| invalid(line))?;
+                if !is_valid_hash(hash) || (!name.starts_with("refs/") && name != "HEAD") {
+                    return Err(invalid(line));
+                }
+                refs.push((name.to_string(), hash.to_string()));
+            }
+        }
+        if refs.is_empty() {
+            return Err(invalid("no refs"));
+        }
+
+        Ok(Self {
+            prerequisites,
+            refs,
+            pack: data[end + 2..].to_vec(),
+        })
+    }
+
+    /// 번들 파일 쓰기
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<(), AppError> {
+        fs::write(path, self.encode())?;
+        Ok(())
+    }
+
+    /// 번들 파일 읽기
+    pub fn read_from<P: AsRef<Path>>(path: P) -> Result<Self, AppError> {
+        Self::decode(&fs::read(path)?)
+    }
+
+    /// 파일이 번들인지 확인 (첫 줄 검사)
+    pub fn is_bundle_file<P: AsRef<Path>>(path: P) -> bool {
+        match fs::read(path.as_ref()) {
+            Ok(data) => data.starts_with(format!("{}\n", BUNDLE_SIGNATURE).as_bytes()),
+            Err(_) => false,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // 검증 / 적용
+    // -------------------------------------------------------------------------
+
+    /// 팩 무결성과 전제 커밋 존재 여부 확인
+    ///
+    /// 팩이 손상되었으면 에러, 전제 커밋이 없으면 결과에 기록
+    pub fn verify(&self, store: &ObjectStore) -> Result<BundleVerification, AppError> {
+        let objects = parse_pack(&self.pack)?;
+        let missing_prerequisites = self
+            .prerequisites
+            .iter()
+            .filter(|(hash, _)| !store.contains(hash))
+            .map(|(hash, _)| hash.clone())
+            .collect();
+        Ok(BundleVerification {
+            object_count: objects.len(),
+            missing_prerequisites,
+        })
+    }
+
+    /// 번들의 객체를 저장소에 기록
+    ///
+    /// 전제 커밋이 없으면 아무것도 쓰지 않고 에러
+    /// 기록 후 모든 참조에서 필요한 객체가 빠짐없이 있는지 확인
+    ///
+    /// # Returns
+    /// 번들의 참조 목록 (이름, 해시)
+    pub fn unbundle(&self, store: &ObjectStore) -> Result<Vec<(String, String)>, AppError> {
+        let verification = self.verify(store)?;
+        if !verification.is_ok() {
+            return Err(AppError::NotFound(format!(
+                "Missing prerequisite commits: {}",
+                verification.missing_prerequisites.join(", ")
+            )));
+        }
+
+        unpack(store, &self.pack)?;
+
+        // 연결성 검사: 참조 → 전제 커밋 사이의 모든 객체가 존재해야 함
+        let tips: Vec<String> = self.refs.iter().map(|(_, hash)| hash.clone()).collect();
+        let prerequisites: Vec<String> = self.prerequisites.iter().map(|(hash, _)| hash.clone()).collect();
+        let (objects, _) = reachable_objects(store, &tips, &prerequisites)?;
+        if let Some(missing) = objects.iter().find(|hash| !store.contains(hash)) {
+            return Err(AppError::NotFound(format!("Bundle is incomplete: object {}", missing)));
+        }
+
+        Ok(self.refs.clone())
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Blob, Commit, Object, Tree, TreeEntry};
+
+    fn commit_file(store: &ObjectStore, parent: Option<String>, content: &str) -> String {
+        let blob = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+        let tree = store
+            .write(&Object::from(Tree::with_entries(vec![TreeEntry::file("f.txt".into(), blob)])))
+            .unwrap();
+        let commit = Commit::new(
+            tree,
+            parent,
+            format!("set {}", content),
+            Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap(),
+            Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap(),
+        );
+        store.write(&Object::from(commit)).unwrap()
+    }
+
+    #[test]
+    fn test_full_bundle_roundtrip() {
+        let src_dir = tempfile::tempdir().unwrap();
+        let src = ObjectStore::new(src_dir.path());
+        let c0 = commit_file(&src, None, "a");
+        let c1 = commit_file(&src, Some(c0), "b");
+
+        let bundle = Bundle::create(&src, vec![("refs/heads/main".into(), c1.clone())], &[]).unwrap();
+        assert!(bundle.prerequisites.is_empty());
+
+        let decoded = Bundle::decode(&bundle.encode()).unwrap();
+        assert_eq!(decoded, bundle);
+
+        let dst_dir = tempfile::tempdir().unwrap();
+        let dst = ObjectStore::new(dst_dir.path());
+        let refs = decoded.unbundle(&dst).unwrap();
+        assert_eq!(refs, vec![("refs/heads/main".to_string(), c1.clone())]);
+        assert_eq!(dst.list().unwrap(), src.list().unwrap());
+    }
+
+    #[test]
+    fn test_incremental_bundle_requires_prerequisite() {
+        let src_dir = tempfile::tempdir().unwrap();
+        let src = ObjectStore::new(src_dir.path());
+        let c0 = commit_file(&src, None, "a");
+        let c1 = commit_file(&src, Some(c0.clone()), "b");
+
+        let bundle =
+            Bundle::create(&src, vec![("refs/heads/main".into(), c1.clone())], std::slice::from_ref(&c0)).unwrap();
+        assert_eq!(bundle.prerequisites, vec![(c0.clone(), "set a".to_string())]);
+
+        // 전제 커밋이 없는 저장소
+        let empty_dir = tempfile::tempdir().unwrap();
+        let empty = ObjectStore::new(empty_dir.path());
+        let verification = bundle.verify(&empty).unwrap();
+        assert_eq!(verification.missing_prerequisites, vec![c0.clone()]);
+        assert!(bundle.unbundle(&empty).is_err());
+        assert!(empty.list().unwrap().is_empty());
+
+        // 전제 커밋이 있는 저장소
+        let full = Bundle::create(&src, vec![("refs/heads/main".into(), c0.clone())], &[]).unwrap();
+        full.unbundle(&empty).unwrap();
+        bundle.unbundle(&empty).unwrap();
+        assert!(empty.contains(&c1));
+    }
+
+    #[test]
+    fn test_empty_bundle_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let c0 = commit_file(&store, None, "a");
+        assert!(Bundle::create(&store, vec![("refs/heads/main".into(), c0.clone())], &[c0]).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert!(Bundle::decode(b"hello\n\n").is_err());
+        assert!(Bundle::decode(format!("{}\n\n", BUNDLE_SIGNATURE).as_bytes()).is_err());
+        assert!(Bundle::decode(format!("{}\nxyz refs/heads/main\n\n", BUNDLE_SIGNATURE).as_bytes()).is_err());
+    }
+}
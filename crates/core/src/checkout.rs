@@ -0,0 +1,297 @@
+// =============================================================================
+// 체크아웃 모듈 (checkout.rs)
+// =============================================================================
+//
+// 트리 객체를 작업 디렉토리의 실제 파일로 풀어놓기
+//
+// - Blob → 파일 (모드 100755 는 실행 권한 부여)
+// - Tree → 디렉토리
+// - 서브모듈 → 빈 디렉토리 (내용은 cts submodule update 가 채움, submodule.rs)
+// - update_worktree: 한 트리에서 다른 트리로 작업 디렉토리 전환
+//   (바뀐 파일만 기록/삭제, 로컬 수정이 있으면 거부)
+// - force_worktree: 로컬 수정을 무시하고 전환 (중단된 작업 되돌리기)
+// - sparse checkout 이 설정된 작업 디렉토리에서는 범위 안의 파일만 기록 (sparse.rs)
+//
+// 파일 위치: crates/core/src/checkout.rs
+// =============================================================================
+
+use std::fs;
+use std::path::Path;
+
+use shared::error::AppError;
+
+use crate::object::{Blob, TreeEntry};
+use crate::sparse::SparsePatterns;
+use crate::store::ObjectStore;
+
+/// 실행 파일 모드
+pub const MODE_EXECUTABLE: &str = "100755";
+
+/// 트리를 디렉토리에 풀어놓기
+///
+/// 기존 파일은 덮어씀, 트리에 없는 파일은 건드리지 않음
+/// sparse checkout 범위 밖의 파일은 풀지 않음
+///
+/// # Returns
+/// 기록한 파일 수
+pub fn checkout_tree(store: &ObjectStore, tree_hash: &str, dest: &Path) -> Result<usize, AppError> {
+    let sparse = SparsePatterns::for_work_dir(dest)?;
+    let mut files = store.flatten_tree(tree_hash)?;
+    if let Some(path) = files.keys().find(|path| !is_safe_path(path)) {
+        return Err(AppError::InvalidInput(format!("Unsafe path in tree: {}", path)));
+    }
+    files.retain(|path, _| sparse.as_ref().is_none_or(|sparse| sparse.includes(path)));
+    // partial clone: 필요한 Blob 을 한 번에 받아옴
+    store.prefetch(&blob_hashes(files.values()))?;
+
+    let mut count = 0;
+    for (path, entry) in files {
+        let target = dest.join(&path);
+        if entry.is_submodule() {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let blob = store.read_blob(&entry.hash)?;
+        fs::write(&target, blob.content())?;
+        set_executable(&target, entry.mode == MODE_EXECUTABLE)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// 작업 디렉토리를 `from` 트리 상태에서 `to` 트리 상태로 전환
+///
+/// 두 트리에서 달라진 파일만 기록하거나 삭제
+/// 바뀔 파일에 `from` 과 다른 로컬 수정이 있으면 아무것도 바꾸지 않고 에러
+///
+/// # Arguments
+/// * `from` - 현재 작업 디렉토리에 풀려 있는 트리 (없으면 빈 트리)
+/// * `to` - 새로 풀어놓을 트리
+pub fn update_worktree(
+    store: &ObjectStore,
+    from: Option<&str>,
+    to: &str,
+    dest: &Path,
+) -> Result<(), AppError> {
+    switch_worktree(store, from, to, dest, false)
+}
+
+/// update_worktree 와 같지만 로컬 수정을 검사하지 않고 덮어씀
+///
+/// 충돌로 멈춘 작업을 되돌릴 때처럼 작업 디렉토리 내용을 버려도 되는 경우에만 사용
+pub fn force_worktree(
+    store: &ObjectStore,
+    from: Option<&str>,
+    to: &str,
+    dest: &Path,
+) -> Result<(), AppError> {
+    switch_worktree(store, from, to, dest, true)
+}
+
+fn switch_worktree(
+    store: &ObjectStore,
+    from: Option<&str>,
+    to: &str,
+    dest: &Path,
+    force: bool,
+) -> Result<(), AppError> {
+    let old_files = match from {
+        Some(tree) => store.flatten_tree(tree)?,
+        None => Default::default(),
+    };
+    let new_files = store.flatten_tree(to)?;
+
+    let mut removed: Vec<&String> = Vec::new();
+    let mut written: Vec<(&String, &TreeEntry)> = Vec::new();
+    for (path, entry) in &old_files {
+        match new_files.get(path) {
+            None => removed.push(path),
+            Some(new) if new != entry => written.push((path, new)),
+            Some(_) => {}
+        }
+    }
+    for (path, entry) in &new_files {
+        if !old_files.contains_key(path) {
+            written.push((path, entry));
+        }
+    }
+    // sparse checkout 범위 밖의 파일은 작업 디렉토리에 없으므로 쓰지 않음
+    if let Some(sparse) = SparsePatterns::for_work_dir(dest)? {
+        written.retain(|(path, _)| sparse.includes(path));
+    }
+
+    // 먼저 전부 검사한 뒤에 변경 (중간에 실패해도 작업 디렉토리는 그대로)
+    for path in removed.iter().copied().chain(written.iter().map(|(path, _)| *path)) {
+        if !is_safe_path(path) {
+            return Err(AppError::InvalidInput(format!("Unsafe path in tree: {}", path)));
+        }
+        let expected = old_files
+            .get(path)
+            .map(|e| e.hash.as_str())
+            .or_else(|| new_files.get(path).map(|e| e.hash.as_str()));
+        if force {
+            continue;
+        }
+        if let Ok(content) = fs::read(dest.join(path)) {
+            let actual = Blob::new(content).hash().to_string();
+            if Some(actual.as_str()) != expected {
+                return Err(AppError::InvalidInput(format!(
+                    "Local changes to {} would be overwritten",
+                    path
+                )));
+            }
+        }
+    }
+
+    for path in removed {
+        remove_file_and_empty_parents(dest, path)?;
+    }
+    store.prefetch(&blob_hashes(written.iter().map(|(_, entry)| *entry)))?;
+    for (path, entry) in written {
+        let target = dest.join(path);
+        // 서브모듈의 커밋이 바뀌어도 내용은 cts submodule update 로만 갱신
+        if entry.is_submodule() {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, store.read_blob(&entry.hash)?.content())?;
+        set_executable(&target, entry.mode == MODE_EXECUTABLE)?;
+    }
+    Ok(())
+}
+
+/// 서브모듈을 뺀 엔트리들의 Blob 해시 (partial clone prefetch 대상)
+fn blob_hashes<'a>(entries: impl Iterator<Item = &'a TreeEntry>) -> Vec<String> {
+    entries.filter(|entry| !entry.is_submodule()).map(|entry| entry.hash.clone()).collect()
+}
+
+/// 작업 디렉토리의 파일을 지우고 비게 된 상위 디렉토리 정리
+///
+/// 서브모듈 디렉토리는 비어 있을 때만 지움 (체크아웃된 서브모듈 내용은 남겨 둠)
+pub(crate) fn remove_file_and_empty_parents(dest: &Path, path: &str) -> Result<(), AppError> {
+    let target = dest.join(path);
+    if target.is_dir() {
+        if fs::remove_dir(&target).is_err() {
+            return Ok(());
+        }
+    } else if target.exists() {
+        fs::remove_file(&target)?;
+    }
+    let mut parent = target.parent();
+    while let Some(dir) = parent.filter(|dir| *dir != dest) {
+        if fs::remove_dir(dir).is_err() {
+            break;
+        }
+        parent = dir.parent();
+    }
+    Ok(())
+}
+
+/// 트리 안의 경로가 작업 디렉토리 밖이나 .cts 를 가리키지 않는지 확인
+pub fn is_safe_path(path: &str) -> bool {
+    path.split('/').all(|part| {
+        !part.is_empty() && part != "." && part != ".." && part != crate::repo::CTS_DIR_NAME
+    })
+}
+
+#[cfg(unix)]
+pub(crate) fn set_executable(path: &Path, executable: bool) -> Result<(), AppError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    let mode = if executable { 0o755 } else { 0o644 };
+    permissions.set_mode(mode);
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn set_executable(_path: &Path, _executable: bool) -> Result<(), AppError> {
+    Ok(())
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Blob, Object, Tree, TreeEntry};
+
+    #[test]
+    fn test_checkout_nested_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path().join("objects"));
+
+        let script = store.write(&Object::from(Blob::new(b"#!/bin/sh\n".to_vec()))).unwrap();
+        let readme = store.write(&Object::from(Blob::new(b"hello\n".to_vec()))).unwrap();
+        let bin = store
+            .write(&Object::from(Tree::with_entries(vec![TreeEntry::executable("run.sh".into(), script)])))
+            .unwrap();
+        let root = store
+            .write(&Object::from(Tree::with_entries(vec![
+                TreeEntry::file("README".into(), readme),
+                TreeEntry::directory("bin".into(), bin),
+            ])))
+            .unwrap();
+
+        let dest = dir.path().join("work");
+        assert_eq!(checkout_tree(&store, &root, &dest).unwrap(), 2);
+        assert_eq!(fs::read(dest.join("README")).unwrap(), b"hello\n");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dest.join("bin/run.sh")).unwrap().permissions().mode();
+            assert_eq!(mode & 0o111, 0o111);
+        }
+    }
+
+    #[test]
+    fn test_update_worktree() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path().join("objects"));
+        let blob = |content: &[u8]| store.write(&Object::from(Blob::new(content.to_vec()))).unwrap();
+
+        let sub = store
+            .write(&Object::from(Tree::with_entries(vec![TreeEntry::file("gone.txt".into(), blob(b"x"))])))
+            .unwrap();
+        let old = store
+            .write(&Object::from(Tree::with_entries(vec![
+                TreeEntry::file("keep.txt".into(), blob(b"v1")),
+                TreeEntry::directory("sub".into(), sub),
+            ])))
+            .unwrap();
+        let new = store
+            .write(&Object::from(Tree::with_entries(vec![TreeEntry::file("keep.txt".into(), blob(b"v2"))])))
+            .unwrap();
+
+        let dest = dir.path().join("work");
+        checkout_tree(&store, &old, &dest).unwrap();
+
+        // 로컬 수정이 있으면 거부
+        fs::write(dest.join("keep.txt"), b"local").unwrap();
+        assert!(update_worktree(&store, Some(&old), &new, &dest).is_err());
+        assert!(dest.join("sub/gone.txt").exists());
+
+        fs::write(dest.join("keep.txt"), b"v1").unwrap();
+        update_worktree(&store, Some(&old), &new, &dest).unwrap();
+        assert_eq!(fs::read(dest.join("keep.txt")).unwrap(), b"v2");
+        assert!(!dest.join("sub").exists());
+    }
+
+    #[test]
+    fn test_is_safe_path() {
+        assert!(is_safe_path("src/main.rs"));
+        assert!(!is_safe_path("../etc/passwd"));
+        assert!(!is_safe_path(".cts/HEAD"));
+        assert!(!is_safe_path("a//b"));
+    }
+}
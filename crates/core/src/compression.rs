@@ -81,14 +81,18 @@ pub fn compress(data: &[u8]) -> Result<Vec<u8>> {
 ///
 /// # Example
 /// ```
+/// # fn main() -> std::io::Result<()> {
 /// use core::compression::compress_with_level;
 /// use flate2::Compression;
+/// # let data = b"hello world hello world";
 ///
 /// // 빠른 압축 (압축률 낮음)
 /// let fast = compress_with_level(data, Compression::fast())?;
 ///
 /// // 최대 압축 (느리지만 작음)
 /// let best = compress_with_level(data, Compression::best())?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn compress_with_level(data: &[u8], level: Compression) -> Result<Vec<u8>> {
     // ZlibEncoder: zlib 형식 압축기
@@ -116,6 +120,7 @@ pub fn compress_with_level(data: &[u8], level: Compression) -> Result<Vec<u8>> {
 ///
 /// # Example
 /// ```
+/// # fn main() -> std::io::Result<()> {
 /// use core::compression::{compress, decompress};
 ///
 /// let original = b"hello world";
@@ -123,6 +128,8 @@ pub fn compress_with_level(data: &[u8], level: Compression) -> Result<Vec<u8>> {
 /// let restored = decompress(&compressed)?;
 ///
 /// assert_eq!(original.as_slice(), restored.as_slice());
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// # 에러 케이스
@@ -148,8 +155,13 @@ pub fn decompress(data: &[u8]) -> Result<Vec<u8>> {
 ///
 /// # Example
 /// ```
+/// # fn main() -> std::io::Result<()> {
+/// # use core::compression::{compress, decompress_with_limit};
+/// # let compressed = compress(b"hello")?;
 /// // 최대 10MB로 제한
 /// let result = decompress_with_limit(&compressed, 10 * 1024 * 1024)?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn decompress_with_limit(data: &[u8], max_size: usize) -> Result<Vec<u8>> {
     let decoder = ZlibDecoder::new(data);
@@ -185,6 +197,8 @@ pub fn decompress_with_limit(data: &[u8], max_size: usize) -> Result<Vec<u8>> {
 ///
 /// # Example
 /// ```
+/// # use core::compression::compression_ratio;
+/// # let (original_size, compressed_size) = (100, 40);
 /// let ratio = compression_ratio(original_size, compressed_size);
 /// println!("압축률: {:.1}%", ratio * 100.0);
 /// ```
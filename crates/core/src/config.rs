@@ -0,0 +1,574 @@
+// =============================================================================
+// 저장소 설정 (config.rs)
+// =============================================================================
+//
+// .cts/config 파일 읽기/쓰기
+//
+// 포맷 (git config 와 같은 INI 형식):
+//   [core]
+//   	bare = false
+//   [remote "origin"]
+//   	url = http://localhost:3000/api/repositories/{id}
+//   	promisor = true
+//   	partialclonefilter = blob:none
+//
+// 키 이름: "{섹션}.{하위 섹션}.{이름}" (예: remote.origin.url)
+// - 섹션/이름은 대소문자 구분 없음 (소문자로 저장), 하위 섹션은 구분
+// - 같은 키가 여러 번 나오면 마지막 값
+// - '#', ';' 로 시작하는 줄은 주석
+//
+// 계층 설정 (LayeredConfig): 아래로 갈수록 우선
+//   system  /etc/ctsconfig       (CTS_CONFIG_SYSTEM 으로 경로 변경, CTS_CONFIG_NOSYSTEM=1 이면 건너뜀)
+//   global  ~/.ctsconfig         (CTS_CONFIG_GLOBAL 로 경로 변경)
+//   local   .cts/config
+//   env     CTS_CONFIG_COUNT=N, CTS_CONFIG_KEY_{i}=키, CTS_CONFIG_VALUE_{i}=값 (i = 0..N)
+// - [include] path = {파일} 은 그 자리에 다른 파일 내용을 끼워 넣음
+//   (상대 경로는 포함하는 파일 기준, "~/" 는 홈 디렉토리, 없는 파일은 무시)
+// - 값마다 계층과 파일을 기억 (cts config list --show-origin)
+//
+// 파일 위치: crates/core/src/config.rs
+// =============================================================================
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use shared::error::AppError;
+
+use crate::refs::LockFile;
+
+/// 설정 파일 이름 (.cts 기준)
+pub const CONFIG_FILE: &str = "config";
+
+/// 전역 설정 파일 이름 (홈 디렉토리 기준)
+pub const GLOBAL_CONFIG_FILE: &str = ".ctsconfig";
+
+/// 시스템 설정 파일 기본 경로
+pub const SYSTEM_CONFIG_PATH: &str = "/etc/ctsconfig";
+
+/// 다른 설정 파일을 끼워 넣는 키
+const INCLUDE_KEY: &str = "include.path";
+
+/// include 중첩 한도 (서로 포함하는 파일 방지)
+const MAX_INCLUDE_DEPTH: usize = 10;
+
+/// 환경 변수 설정 개수
+const ENV_COUNT: &str = "CTS_CONFIG_COUNT";
+
+// =============================================================================
+// Config 구조체
+// =============================================================================
+
+/// 설정 파일 내용 (키, 값) 목록 (파일에 나온 순서)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    entries: Vec<(String, String)>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 설정 파일 읽기 (없으면 빈 설정)
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// 설정 파일 기록
+    pub fn save(&self, path: &Path) -> Result<(), AppError> {
+        let lock = LockFile::acquire(path)?;
+        lock.write(self.format().as_bytes())?;
+        lock.commit()
+    }
+
+    /// 텍스트 파싱
+    ///
+    /// # Errors
+    /// * `AppError::InvalidInput` - 섹션 밖의 키, 잘못된 섹션 헤더
+    pub fn parse(text: &str) -> Result<Self, AppError> {
+        let invalid = |number: usize, line: &str| {
+            AppError::InvalidInput(format!("Invalid config line {}: {}", number + 1, line))
+        };
+
+        let mut config = Self::new();
+        let mut section: Option<String> = None;
+        for (number, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header.strip_suffix(']').ok_or_else(|| invalid(number, raw))?;
+                section = Some(parse_section(header).ok_or_else(|| invalid(number, raw))?);
+                continue;
+            }
+            let prefix = section.as_ref().ok_or_else(|| invalid(number, raw))?;
+            let (name, value) = match line.split_once('=') {
+                Some((name, value)) => (name.trim(), unquote(value.trim())),
+                // 값 없는 키는 true
+                None => (line, "true".to_string()),
+            };
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err(invalid(number, raw));
+            }
+            config.entries.push((format!("{}.{}", prefix, name.to_ascii_lowercase()), value));
+        }
+        Ok(config)
+    }
+
+    /// 파일 텍스트로 변환 (같은 섹션의 키는 처음 나온 섹션 아래에 모음)
+    pub fn format(&self) -> String {
+        let mut sections: Vec<(&str, Vec<(&str, &str)>)> = Vec::new();
+        for (key, value) in &self.entries {
+            let (section, name) = split_key(key);
+            match sections.iter_mut().find(|(s, _)| *s == section) {
+                Some((_, entries)) => entries.push((name, value)),
+                None => sections.push((section, vec![(name, value)])),
+            }
+        }
+
+        let mut out = String::new();
+        for (section, entries) in sections {
+            match section.split_once('.') {
+                Some((name, sub)) => out.push_str(&format!("[{} \"{}\"]\n", name, sub)),
+                None => out.push_str(&format!("[{}]\n", section)),
+            }
+            for (name, value) in entries {
+                out.push_str(&format!("\t{} = {}\n", name, quote(value)));
+            }
+        }
+        out
+    }
+
+    // -------------------------------------------------------------------------
+    // 조회 / 변경
+    // -------------------------------------------------------------------------
+
+    /// 키의 값 (여러 개면 마지막)
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let key = normalize_key(key);
+        self.entries
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 불리언 값 (true/yes/on/1, false/no/off/0)
+    pub fn get_bool(&self, key: &str) -> Result<Option<bool>, AppError> {
+        self.get(key).map(|value| parse_bool(key, value)).transpose()
+    }
+
+    /// 값 설정 (있으면 마지막 값을 바꾸고, 없으면 추가)
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), AppError> {
+        let key = normalize_key(key);
+        if !key.contains('.') {
+            return Err(AppError::InvalidInput(format!("Config key needs a section: {}", key)));
+        }
+        match self.entries.iter_mut().rev().find(|(k, _)| *k == key) {
+            Some((_, old)) => *old = value.to_string(),
+            None => self.entries.push((key, value.to_string())),
+        }
+        Ok(())
+    }
+
+    /// 키 삭제 (모든 값)
+    ///
+    /// # Returns
+    /// 삭제한 값이 있었는지
+    pub fn unset(&mut self, key: &str) -> bool {
+        let key = normalize_key(key);
+        let before = self.entries.len();
+        self.entries.retain(|(k, _)| *k != key);
+        self.entries.len() != before
+    }
+
+    /// 모든 (키, 값)
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.entries
+    }
+
+    /// 섹션의 하위 섹션 이름들 (예: "remote" → ["origin"])
+    pub fn subsections(&self, section: &str) -> Vec<String> {
+        let prefix = format!("{}.", section.to_ascii_lowercase());
+        let mut names: Vec<String> = Vec::new();
+        for (key, _) in &self.entries {
+            let Some((sub, _)) = key.strip_prefix(&prefix).and_then(|rest| rest.rsplit_once('.')) else {
+                continue;
+            };
+            if !names.iter().any(|name| name == sub) {
+                names.push(sub.to_string());
+            }
+        }
+        names
+    }
+}
+
+// =============================================================================
+// 계층 설정
+// =============================================================================
+
+/// 설정 값이 나온 계층 (뒤쪽이 우선)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigScope {
+    System,
+    Global,
+    Local,
+    Env,
+}
+
+impl ConfigScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigScope::System => "system",
+            ConfigScope::Global => "global",
+            ConfigScope::Local => "local",
+            ConfigScope::Env => "env",
+        }
+    }
+}
+
+impl fmt::Display for ConfigScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 계층 설정의 값 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigEntry {
+    pub key: String,
+    pub value: String,
+    pub scope: ConfigScope,
+    /// 값이 적힌 파일 (include 로 읽었으면 포함된 파일, 환경 변수면 None)
+    pub file: Option<PathBuf>,
+}
+
+/// 모든 계층을 합친 설정 (읽기 전용, 쓰기는 계층별 Config 로)
+#[derive(Debug, Clone, Default)]
+pub struct LayeredConfig {
+    entries: Vec<ConfigEntry>,
+}
+
+impl LayeredConfig {
+    /// system → global → local(있으면) → 환경 변수 순으로 읽기
+    pub fn load(local: Option<&Path>) -> Result<Self, AppError> {
+        let mut files = Vec::new();
+        if let Some(path) = system_config_path() {
+            files.push((ConfigScope::System, path));
+        }
+        if let Some(path) = global_config_path() {
+            files.push((ConfigScope::Global, path));
+        }
+        if let Some(path) = local {
+            files.push((ConfigScope::Local, path.to_path_buf()));
+        }
+        let mut config = Self::from_files(&files)?;
+        config.add_env_overrides(|name| std::env::var(name).ok())?;
+        Ok(config)
+    }
+
+    /// 파일들을 순서대로 읽기 (없는 파일은 빈 설정)
+    pub fn from_files(files: &[(ConfigScope, PathBuf)]) -> Result<Self, AppError> {
+        let mut config = Self::default();
+        for (scope, path) in files {
+            config.read_file(*scope, path, 0)?;
+        }
+        Ok(config)
+    }
+
+    fn read_file(&mut self, scope: ConfigScope, path: &Path, depth: usize) -> Result<(), AppError> {
+        if depth > MAX_INCLUDE_DEPTH {
+            return Err(AppError::InvalidInput(format!(
+                "Config includes nested too deeply at {}",
+                path.display()
+            )));
+        }
+        for (key, value) in Config::load(path)?.entries {
+            let include = (key == INCLUDE_KEY).then(|| {
+                let target = expand_path(&value);
+                match path.parent() {
+                    Some(dir) if target.is_relative() => dir.join(target),
+                    _ => target,
+                }
+            });
+            self.entries.push(ConfigEntry {
+                key,
+                value,
+                scope,
+                file: Some(path.to_path_buf()),
+            });
+            if let Some(target) = include {
+                self.read_file(scope, &target, depth + 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 환경 변수 설정 추가 (CTS_CONFIG_COUNT / CTS_CONFIG_KEY_{i} / CTS_CONFIG_VALUE_{i})
+    ///
+    /// # Errors
+    /// * `AppError::InvalidInput` - 개수가 숫자가 아님, 키나 값이 빠짐, 섹션 없는 키
+    pub fn add_env_overrides(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), AppError> {
+        let Some(count) = var(ENV_COUNT) else {
+            return Ok(());
+        };
+        let count: usize = count
+            .trim()
+            .parse()
+            .map_err(|_| AppError::InvalidInput(format!("Invalid {}: {}", ENV_COUNT, count)))?;
+        for i in 0..count {
+            let missing = |name: String| AppError::InvalidInput(format!("{} is not set", name));
+            let key_var = format!("CTS_CONFIG_KEY_{}", i);
+            let value_var = format!("CTS_CONFIG_VALUE_{}", i);
+            let key = var(&key_var).ok_or_else(|| missing(key_var))?;
+            let value = var(&value_var).ok_or_else(|| missing(value_var))?;
+            if !key.contains('.') {
+                return Err(AppError::InvalidInput(format!("Config key needs a section: {}", key)));
+            }
+            self.entries.push(ConfigEntry {
+                key: normalize_key(&key),
+                value,
+                scope: ConfigScope::Env,
+                file: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// 키의 값 (여러 계층에 있으면 가장 우선하는 계층의 마지막 값)
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.get_entry(key).map(|entry| entry.value.as_str())
+    }
+
+    /// 값과 출처
+    pub fn get_entry(&self, key: &str) -> Option<&ConfigEntry> {
+        let key = normalize_key(key);
+        self.entries.iter().rev().find(|entry| entry.key == key)
+    }
+
+    /// 키의 모든 값 (우선순위가 낮은 것부터)
+    pub fn get_all(&self, key: &str) -> Vec<&ConfigEntry> {
+        let key = normalize_key(key);
+        self.entries.iter().filter(|entry| entry.key == key).collect()
+    }
+
+    /// 불리언 값 (true/yes/on/1, false/no/off/0)
+    pub fn get_bool(&self, key: &str) -> Result<Option<bool>, AppError> {
+        self.get(key).map(|value| parse_bool(key, value)).transpose()
+    }
+
+    /// 정수 값 (k / m / g 접미사는 1024 배수)
+    pub fn get_int(&self, key: &str) -> Result<Option<i64>, AppError> {
+        self.get(key).map(|value| parse_int(key, value)).transpose()
+    }
+
+    /// 경로 값 ("~/" 는 홈 디렉토리)
+    pub fn get_path(&self, key: &str) -> Option<PathBuf> {
+        self.get(key).map(expand_path)
+    }
+
+    /// 모든 값 (우선순위가 낮은 것부터, 같은 계층은 파일 순서)
+    pub fn entries(&self) -> &[ConfigEntry] {
+        &self.entries
+    }
+}
+
+/// 시스템 설정 파일 경로 (CTS_CONFIG_NOSYSTEM 이 참이면 None)
+pub fn system_config_path() -> Option<PathBuf> {
+    let skip = std::env::var("CTS_CONFIG_NOSYSTEM")
+        .ok()
+        .map(|value| parse_bool("CTS_CONFIG_NOSYSTEM", &value).unwrap_or(true));
+    if skip == Some(true) {
+        return None;
+    }
+    Some(std::env::var_os("CTS_CONFIG_SYSTEM").map_or_else(|| PathBuf::from(SYSTEM_CONFIG_PATH), PathBuf::from))
+}
+
+/// 전역 설정 파일 경로 (홈 디렉토리를 모르면 None)
+pub fn global_config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("CTS_CONFIG_GLOBAL") {
+        return Some(PathBuf::from(path));
+    }
+    home_dir().map(|home| home.join(GLOBAL_CONFIG_FILE))
+}
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 불리언 값 해석 (빈 값은 false)
+pub fn parse_bool(key: &str, value: &str) -> Result<bool, AppError> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" | "" => Ok(false),
+        _ => Err(AppError::InvalidInput(format!("Invalid boolean for {}: {}", key, value))),
+    }
+}
+
+/// 정수 값 해석 ("64k" → 65536)
+pub fn parse_int(key: &str, value: &str) -> Result<i64, AppError> {
+    let invalid = || AppError::InvalidInput(format!("Invalid integer for {}: {}", key, value));
+    let value = value.trim();
+    let (number, factor) = match value.char_indices().last() {
+        Some((index, suffix)) if suffix.is_ascii_alphabetic() => {
+            let factor: i64 = match suffix.to_ascii_lowercase() {
+                'k' => 1 << 10,
+                'm' => 1 << 20,
+                'g' => 1 << 30,
+                _ => return Err(invalid()),
+            };
+            (&value[..index], factor)
+        }
+        _ => (value, 1),
+    };
+    number
+        .parse::<i64>()
+        .ok()
+        .and_then(|number| number.checked_mul(factor))
+        .ok_or_else(invalid)
+}
+
+/// "~/..." 를 홈 디렉토리 기준 경로로
+pub fn expand_path(value: &str) -> PathBuf {
+    match (value.strip_prefix("~/"), home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(value),
+    }
+}
+
+/// "[remote \"origin\"]" 의 안쪽 → "remote.origin"
+fn parse_section(header: &str) -> Option<String> {
+    let header = header.trim();
+    match header.split_once(char::is_whitespace) {
+        Some((name, sub)) => {
+            let sub = sub.trim().strip_prefix('"')?.strip_suffix('"')?;
+            Some(format!("{}.{}", name.to_ascii_lowercase(), sub))
+        }
+        None if !header.is_empty() => Some(header.to_ascii_lowercase()),
+        None => None,
+    }
+}
+
+/// 섹션(+하위 섹션)과 이름 나누기
+fn split_key(key: &str) -> (&str, &str) {
+    key.rsplit_once('.').unwrap_or(("", key))
+}
+
+/// 섹션과 이름은 소문자로 (하위 섹션은 그대로)
+fn normalize_key(key: &str) -> String {
+    let Some((head, name)) = key.rsplit_once('.') else {
+        return key.to_ascii_lowercase();
+    };
+    match head.split_once('.') {
+        Some((section, sub)) => format!("{}.{}.{}", section.to_ascii_lowercase(), sub, name.to_ascii_lowercase()),
+        None => format!("{}.{}", head.to_ascii_lowercase(), name.to_ascii_lowercase()),
+    }
+}
+
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\"").replace("\\\\", "\\"),
+        None => value.to_string(),
+    }
+}
+
+/// 앞뒤 공백이나 주석 문자가 있으면 따옴표로 감쌈
+fn quote(value: &str) -> String {
+    if value.trim() != value || value.contains(['#', ';', '"']) {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_roundtrip() {
+        let text = "# comment\n[Core]\n\tbare = false\n[remote \"Origin\"]\n\turl = http://h/r\n\tpromisor\n";
+        let mut config = Config::parse(text).unwrap();
+        assert_eq!(config.get("core.bare"), Some("false"));
+        assert_eq!(config.get("remote.Origin.URL"), Some("http://h/r"));
+        assert_eq!(config.get_bool("remote.Origin.promisor").unwrap(), Some(true));
+        assert_eq!(config.subsections("remote"), vec!["Origin"]);
+
+        config.set("remote.Origin.url", "file:///tmp/x").unwrap();
+        config.set("core.note", " spaced # value").unwrap();
+        assert!(config.unset("core.bare"));
+        let reparsed = Config::parse(&config.format()).unwrap();
+        assert_eq!(reparsed, config);
+        assert_eq!(reparsed.get("core.note"), Some(" spaced # value"));
+
+        assert!(Config::parse("key = value\n").is_err());
+        assert!(config.set("nosection", "x").is_err());
+    }
+
+    #[test]
+    fn test_layered_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let system = dir.path().join("system");
+        let global = dir.path().join("global");
+        let local = dir.path().join("local");
+        fs::write(&system, "[core]\n\tcompression = 1\n[user]\n\tname = System\n").unwrap();
+        fs::write(&global, "[user]\n\tname = Global\n\temail = g@example.com\n[include]\n\tpath = extra/more\n").unwrap();
+        fs::create_dir(dir.path().join("extra")).unwrap();
+        fs::write(dir.path().join("extra/more"), "[pack]\n\twindowMemory = 64k\n[include]\n\tpath = missing\n").unwrap();
+        fs::write(&local, "[user]\n\temail = l@example.com\n[core]\n\tbare = no\n").unwrap();
+
+        let mut config = LayeredConfig::from_files(&[
+            (ConfigScope::System, system.clone()),
+            (ConfigScope::Global, global.clone()),
+            (ConfigScope::Local, local.clone()),
+        ])
+        .unwrap();
+        assert_eq!(config.get("user.name"), Some("Global"));
+        assert_eq!(config.get("user.email"), Some("l@example.com"));
+        assert_eq!(config.get_all("user.name").len(), 2);
+        assert_eq!(config.get_int("core.compression").unwrap(), Some(1));
+        assert_eq!(config.get_bool("core.bare").unwrap(), Some(false));
+
+        // include 한 파일의 값은 포함한 계층, 실제 파일로 기록
+        let included = config.get_entry("pack.windowmemory").unwrap();
+        assert_eq!(included.scope, ConfigScope::Global);
+        assert_eq!(included.file.as_deref(), Some(dir.path().join("extra/more").as_path()));
+        assert_eq!(config.get_int("pack.windowMemory").unwrap(), Some(65536));
+
+        // 환경 변수가 가장 우선
+        let vars = [("CTS_CONFIG_COUNT", "1"), ("CTS_CONFIG_KEY_0", "User.Name"), ("CTS_CONFIG_VALUE_0", "Env")];
+        let lookup = |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v.to_string());
+        config.add_env_overrides(lookup).unwrap();
+        let entry = config.get_entry("user.name").unwrap();
+        assert_eq!((entry.value.as_str(), entry.scope, entry.file.as_ref()), ("Env", ConfigScope::Env, None));
+        assert!(config.add_env_overrides(|name| (name == "CTS_CONFIG_COUNT").then(|| "1".to_string())).is_err());
+
+        // 서로 포함하면 오류
+        fs::write(&local, "[include]\n\tpath = local\n").unwrap();
+        assert!(LayeredConfig::from_files(&[(ConfigScope::Local, local)]).is_err());
+    }
+
+    #[test]
+    fn test_typed_values() {
+        assert_eq!(parse_int("k", " 2m ").unwrap(), 2 << 20);
+        assert_eq!(parse_int("k", "-1").unwrap(), -1);
+        assert!(parse_int("k", "12x").is_err());
+        assert!(parse_int("k", "g").is_err());
+        assert!(parse_bool("k", "maybe").is_err());
+        assert!(parse_bool("k", "On").unwrap());
+        assert_eq!(expand_path("/abs/path"), PathBuf::from("/abs/path"));
+    }
+}
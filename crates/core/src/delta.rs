@@ -0,0 +1,217 @@
+// =============================================================================
+// 바이너리 델타 모듈 (delta.rs)
+// =============================================================================
+//
+// 원본(source) 바이트로부터 대상(target) 바이트를 만드는 명령 목록
+// 바이너리 파일 패치처럼 라인 diff 가 의미 없는 곳에서 사용
+//
+// 포맷 (정수는 LEB128 가변 길이):
+//   source_size, target_size
+//   명령 반복:
+//     0x00 offset len     원본의 [offset, offset + len) 복사
+//     0x01 len bytes...   다음 len 바이트를 그대로 삽입
+//
+// 생성 방식:
+// - 원본을 BLOCK_SIZE 단위 블록으로 색인
+// - 대상을 훑으며 같은 블록을 찾으면 앞뒤로 최대한 늘려 복사 명령으로
+//
+// 파일 위치: crates/core/src/delta.rs
+// =============================================================================
+
+use std::collections::HashMap;
+
+use shared::error::AppError;
+
+/// 색인 블록 크기 (이보다 짧은 일치는 삽입으로 처리)
+const BLOCK_SIZE: usize = 16;
+
+const OP_COPY: u8 = 0x00;
+const OP_INSERT: u8 = 0x01;
+
+/// 델타 생성
+///
+/// # Example
+/// ```
+/// use core::delta::{apply_delta, create_delta};
+///
+/// let source = b"The quick brown fox jumps over the lazy dog".repeat(4);
+/// let mut target = source.clone();
+/// target.extend_from_slice(b"!");
+///
+/// let delta = create_delta(&source, &target);
+/// assert!(delta.len() < target.len());
+/// assert_eq!(apply_delta(&source, &delta).unwrap(), target);
+/// ```
+pub fn create_delta(source: &[u8], target: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_varint(&mut out, source.len() as u64);
+    write_varint(&mut out, target.len() as u64);
+
+    // 블록 → 원본 내 첫 위치
+    let mut index: HashMap<&[u8], usize> = HashMap::new();
+    for offset in (0..source.len().saturating_sub(BLOCK_SIZE - 1)).step_by(BLOCK_SIZE) {
+        index.entry(&source[offset..offset + BLOCK_SIZE]).or_insert(offset);
+    }
+
+    let mut pending_start = 0;
+    let mut pos = 0;
+    while pos + BLOCK_SIZE <= target.len() {
+        let Some(&found) = index.get(&target[pos..pos + BLOCK_SIZE]) else {
+            pos += 1;
+            continue;
+        };
+
+        // 일치 구간을 앞(삽입 대기 구간 안쪽)과 뒤로 확장
+        let mut src_start = found;
+        let mut dst_start = pos;
+        while src_start > 0 && dst_start > pending_start && source[src_start - 1] == target[dst_start - 1] {
+            src_start -= 1;
+            dst_start -= 1;
+        }
+        let mut len = pos - dst_start + BLOCK_SIZE;
+        while src_start + len < source.len()
+            && dst_start + len < target.len()
+            && source[src_start + len] == target[dst_start + len]
+        {
+            len += 1;
+        }
+
+        write_insert(&mut out, &target[pending_start..dst_start]);
+        out.push(OP_COPY);
+        write_varint(&mut out, src_start as u64);
+        write_varint(&mut out, len as u64);
+
+        pos = dst_start + len;
+        pending_start = pos;
+    }
+    write_insert(&mut out, &target[pending_start..]);
+    out
+}
+
+/// 델타 적용
+///
+/// 원본 크기가 다르거나 명령이 범위를 벗어나면 에러
+pub fn apply_delta(source: &[u8], delta: &[u8]) -> Result<Vec<u8>, AppError> {
+    let invalid = || AppError::InvalidInput("Invalid delta".to_string());
+
+    let mut pos = 0;
+    let source_size = read_varint(delta, &mut pos).ok_or_else(invalid)? as usize;
+    let target_size = read_varint(delta, &mut pos).ok_or_else(invalid)? as usize;
+    if source_size != source.len() {
+        return Err(AppError::InvalidInput(format!(
+            "Delta expects source of {} bytes, got {}",
+            source_size,
+            source.len()
+        )));
+    }
+
+    let mut out = Vec::with_capacity(target_size);
+    while pos < delta.len() {
+        let op = delta[pos];
+        pos += 1;
+        match op {
+            OP_COPY => {
+                let offset = read_varint(delta, &mut pos).ok_or_else(invalid)? as usize;
+                let len = read_varint(delta, &mut pos).ok_or_else(invalid)? as usize;
+                let chunk = source.get(offset..offset + len).ok_or_else(invalid)?;
+                out.extend_from_slice(chunk);
+            }
+            OP_INSERT => {
+                let len = read_varint(delta, &mut pos).ok_or_else(invalid)? as usize;
+                let chunk = delta.get(pos..pos + len).ok_or_else(invalid)?;
+                out.extend_from_slice(chunk);
+                pos += len;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    if out.len() != target_size {
+        return Err(invalid());
+    }
+    Ok(out)
+}
+
+fn write_insert(out: &mut Vec<u8>, data: &[u8]) {
+    if data.is_empty() {
+        return;
+    }
+    out.push(OP_INSERT);
+    write_varint(out, data.len() as u64);
+    out.extend_from_slice(data);
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        if shift >= 64 {
+            return None;
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(source: &[u8], target: &[u8]) -> Vec<u8> {
+        let delta = create_delta(source, target);
+        assert_eq!(apply_delta(source, &delta).unwrap(), target);
+        delta
+    }
+
+    #[test]
+    fn test_roundtrip_edits() {
+        let source: Vec<u8> = (0..4096u32).map(|i| (i * 7 % 251) as u8).collect();
+
+        let mut middle = source.clone();
+        middle[2000] ^= 0xff;
+        assert!(roundtrip(&source, &middle).len() < 64);
+
+        let mut grown = b"header".to_vec();
+        grown.extend_from_slice(&source);
+        grown.extend_from_slice(b"trailer");
+        assert!(roundtrip(&source, &grown).len() < 64);
+
+        roundtrip(&source, &source[100..3000]);
+    }
+
+    #[test]
+    fn test_roundtrip_edge_cases() {
+        roundtrip(b"", b"");
+        roundtrip(b"", b"new content");
+        roundtrip(b"old content", b"");
+        roundtrip(b"short", b"other");
+    }
+
+    #[test]
+    fn test_apply_rejects_wrong_source() {
+        let delta = create_delta(b"0123456789abcdef0123", b"0123456789abcdef!");
+        assert!(apply_delta(b"different", &delta).is_err());
+        assert!(apply_delta(b"", &[0xff]).is_err());
+    }
+}
@@ -0,0 +1,463 @@
+// =============================================================================
+// 라인 Diff 모듈 (diff.rs)
+// =============================================================================
+//
+// 두 텍스트의 라인 단위 차이 계산 (Myers 알고리즘)
+//
+// Git과 같은 방식:
+// - 최소 편집 거리(삭제 + 삽입 수가 최소)인 편집 스크립트 생성
+// - 공통 접두사/접미사는 미리 제거해서 계산량 감소
+//
+// 파일 위치: crates/core/src/diff.rs
+//
+// 사용 예시:
+//   use core::diff::{diff, split_lines, DiffOp};
+//
+//   let old = split_lines(b"a\nb\nc\n");
+//   let new = split_lines(b"a\nx\nc\n");
+//   for op in diff(&old, &new) {
+//       println!("{:?}", op);
+//   }
+// =============================================================================
+
+// =============================================================================
+// 편집 연산
+// =============================================================================
+
+/// Diff 연산 (연속된 라인 묶음 단위)
+///
+/// 인덱스는 0부터 시작
+/// - old_index: 이전 버전 라인 위치
+/// - new_index: 새 버전 라인 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    /// 양쪽에 같은 라인들
+    Equal {
+        old_index: usize,
+        new_index: usize,
+        len: usize,
+    },
+    /// 이전 버전에만 있는 라인들 (삭제)
+    Delete {
+        old_index: usize,
+        old_len: usize,
+        new_index: usize,
+    },
+    /// 새 버전에만 있는 라인들 (삽입)
+    Insert {
+        old_index: usize,
+        new_index: usize,
+        new_len: usize,
+    },
+}
+
+/// 변경 구간 (Hunk)
+///
+/// 연속된 Delete/Insert 를 하나로 묶은 것
+/// - old_len == 0: 순수 삽입
+/// - new_len == 0: 순수 삭제
+/// - 둘 다 > 0: 교체
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub old_start: usize,
+    pub old_len: usize,
+    pub new_start: usize,
+    pub new_len: usize,
+}
+
+/// 문맥 라인을 포함한 출력 구간 (unified diff 의 "@@" 블록)
+///
+/// 문맥끼리 겹치거나 맞닿는 변경 구간은 하나의 Hunk 로 묶임
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_len: usize,
+    pub new_start: usize,
+    pub new_len: usize,
+    /// 이 구간에 속한 변경들 (순서대로)
+    pub changes: Vec<Change>,
+}
+
+// =============================================================================
+// 라인 분리
+// =============================================================================
+
+/// 바이트를 라인 단위로 분리 (줄바꿈 문자 포함)
+///
+/// 마지막 라인에 줄바꿈이 없으면 그대로 유지
+/// → "no newline at end of file" 여부를 비교 시 구분 가능
+///
+/// # Example
+/// ```
+/// use core::diff::split_lines;
+///
+/// let lines = split_lines(b"a\nb");
+/// assert_eq!(lines, vec![&b"a\n"[..], &b"b"[..]]);
+/// ```
+pub fn split_lines(data: &[u8]) -> Vec<&[u8]> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (i, &b) in data.iter().enumerate() {
+        if b == b'\n' {
+            lines.push(&data[start..=i]);
+            start = i + 1;
+        }
+    }
+    if start < data.len() {
+        lines.push(&data[start..]);
+    }
+    lines
+}
+
+// =============================================================================
+// Diff 계산
+// =============================================================================
+
+/// 두 시퀀스의 diff 계산
+///
+/// 결과 연산들을 순서대로 적용하면 old → new 변환
+/// 같은 종류의 연속 연산은 하나로 합쳐짐
+pub fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    // 공통 접두사/접미사 제거
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut ops = Vec::new();
+    push_op(&mut ops, Step::Equal, 0, 0, prefix);
+    for (step, old_index, new_index) in myers(old_mid, new_mid) {
+        push_op(&mut ops, step, prefix + old_index, prefix + new_index, 1);
+    }
+    push_op(&mut ops, Step::Equal, old.len() - suffix, new.len() - suffix, suffix);
+    ops
+}
+
+/// DiffOp 목록을 변경 구간(Change) 목록으로 변환
+pub fn changes(ops: &[DiffOp]) -> Vec<Change> {
+    let mut result: Vec<Change> = Vec::new();
+    let mut current: Option<Change> = None;
+
+    for op in ops {
+        match *op {
+            DiffOp::Equal { .. } => {
+                if let Some(change) = current.take() {
+                    result.push(change);
+                }
+            }
+            DiffOp::Delete { old_index, old_len, new_index } => {
+                let change = current.get_or_insert(Change {
+                    old_start: old_index,
+                    old_len: 0,
+                    new_start: new_index,
+                    new_len: 0,
+                });
+                change.old_len += old_len;
+            }
+            DiffOp::Insert { old_index, new_index, new_len } => {
+                let change = current.get_or_insert(Change {
+                    old_start: old_index,
+                    old_len: 0,
+                    new_start: new_index,
+                    new_len: 0,
+                });
+                change.new_len += new_len;
+            }
+        }
+    }
+    if let Some(change) = current {
+        result.push(change);
+    }
+    result
+}
+
+/// 변경 구간들을 앞뒤 문맥 라인과 함께 Hunk 로 묶기
+///
+/// # Arguments
+/// * `changes` - changes() 결과
+/// * `old_total` - 이전 버전 전체 라인 수
+/// * `context` - 앞뒤로 포함할 문맥 라인 수 (보통 3)
+pub fn hunks(changes: &[Change], old_total: usize, context: usize) -> Vec<Hunk> {
+    let mut result: Vec<Hunk> = Vec::new();
+
+    for change in changes {
+        let old_start = change.old_start.saturating_sub(context);
+        let old_end = (change.old_start + change.old_len + context).min(old_total);
+
+        if let Some(last) = result.last_mut() {
+            if old_start <= last.old_start + last.old_len {
+                last.old_len = old_end - last.old_start;
+                last.changes.push(*change);
+                continue;
+            }
+        }
+
+        let lead = change.old_start - old_start;
+        result.push(Hunk {
+            old_start,
+            old_len: old_end - old_start,
+            new_start: change.new_start - lead,
+            new_len: 0,
+            changes: vec![*change],
+        });
+    }
+
+    // 새 버전 길이 = 이전 길이 + 변경에 의한 증감
+    for hunk in &mut result {
+        let added: usize = hunk.changes.iter().map(|c| c.new_len).sum();
+        let removed: usize = hunk.changes.iter().map(|c| c.old_len).sum();
+        hunk.new_len = hunk.old_len + added - removed;
+    }
+    result
+}
+
+/// 두 시퀀스의 유사도 (0.0 ~ 1.0)
+///
+/// 공통 라인 수 * 2 / 전체 라인 수
+/// 이름 변경(rename) 감지에 사용
+pub fn similarity<T: PartialEq>(old: &[T], new: &[T]) -> f64 {
+    let total = old.len() + new.len();
+    if total == 0 {
+        return 1.0;
+    }
+    let common: usize = diff(old, new)
+        .iter()
+        .map(|op| match op {
+            DiffOp::Equal { len, .. } => *len,
+            _ => 0,
+        })
+        .sum();
+    (common * 2) as f64 / total as f64
+}
+
+// =============================================================================
+// 내부 구현
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// 연산 추가 (직전 연산과 종류가 같고 연속이면 합침)
+fn push_op(ops: &mut Vec<DiffOp>, step: Step, old_index: usize, new_index: usize, len: usize) {
+    if len == 0 {
+        return;
+    }
+    if let Some(last) = ops.last_mut() {
+        match (last, step) {
+            (DiffOp::Equal { len: l, .. }, Step::Equal) => {
+                *l += len;
+                return;
+            }
+            (DiffOp::Delete { old_len, .. }, Step::Delete) => {
+                *old_len += len;
+                return;
+            }
+            (DiffOp::Insert { new_len, .. }, Step::Insert) => {
+                *new_len += len;
+                return;
+            }
+            _ => {}
+        }
+    }
+    ops.push(match step {
+        Step::Equal => DiffOp::Equal { old_index, new_index, len },
+        Step::Delete => DiffOp::Delete { old_index, old_len: len, new_index },
+        Step::Insert => DiffOp::Insert { old_index, new_index, new_len: len },
+    });
+}
+
+/// Myers 최소 편집 스크립트
+///
+/// # Returns
+/// (연산, old 위치, new 위치) 목록 - 라인 하나당 하나
+fn myers<T: PartialEq>(old: &[T], new: &[T]) -> Vec<(Step, usize, usize)> {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let max = (n + m) as usize;
+    if max == 0 {
+        return Vec::new();
+    }
+
+    // v[k + offset] = 대각선 k 위에서 도달한 가장 먼 x
+    let offset = max as isize;
+    let mut v = vec![0isize; 2 * max + 2];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'outer: for d in 0..=max as isize {
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1] // 아래로 이동 (삽입)
+            } else {
+                v[idx - 1] + 1 // 오른쪽 이동 (삭제)
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+            k += 2;
+        }
+    }
+
+    // 역추적
+    let mut steps = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let idx = (k + offset) as usize;
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            steps.push((Step::Equal, x as usize, y as usize));
+        }
+        if d > 0 {
+            if x == prev_x {
+                y -= 1;
+                steps.push((Step::Insert, x as usize, y as usize));
+            } else {
+                x -= 1;
+                steps.push((Step::Delete, x as usize, y as usize));
+            }
+        }
+    }
+    steps.reverse();
+    steps
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ops 를 적용해서 new 를 복원할 수 있는지 확인
+    fn apply<'a>(old: &[&'a str], new: &[&'a str], ops: &[DiffOp]) -> Vec<&'a str> {
+        let mut result = Vec::new();
+        for op in ops {
+            match *op {
+                DiffOp::Equal { old_index, len, .. } => {
+                    result.extend_from_slice(&old[old_index..old_index + len])
+                }
+                DiffOp::Delete { .. } => {}
+                DiffOp::Insert { new_index, new_len, .. } => {
+                    result.extend_from_slice(&new[new_index..new_index + new_len])
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_split_lines() {
+        assert_eq!(split_lines(b""), Vec::<&[u8]>::new());
+        assert_eq!(split_lines(b"a\nb\n"), vec![&b"a\n"[..], &b"b\n"[..]]);
+        assert_eq!(split_lines(b"a\nb"), vec![&b"a\n"[..], &b"b"[..]]);
+    }
+
+    #[test]
+    fn test_identical() {
+        let lines = ["a", "b", "c"];
+        let ops = diff(&lines, &lines);
+        assert_eq!(ops, vec![DiffOp::Equal { old_index: 0, new_index: 0, len: 3 }]);
+        assert!(changes(&ops).is_empty());
+    }
+
+    #[test]
+    fn test_replace_middle() {
+        let old = ["a", "b", "c"];
+        let new = ["a", "x", "c"];
+        let ops = diff(&old, &new);
+        assert_eq!(apply(&old, &new, &ops), new);
+        assert_eq!(
+            changes(&ops),
+            vec![Change { old_start: 1, old_len: 1, new_start: 1, new_len: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_insert_and_delete() {
+        let old = ["a", "b", "c", "d"];
+        let new = ["b", "c", "e", "d", "f"];
+        let ops = diff(&old, &new);
+        assert_eq!(apply(&old, &new, &ops), new);
+
+        let edits: usize = changes(&ops).iter().map(|c| c.old_len + c.new_len).sum();
+        assert_eq!(edits, 3); // a 삭제, e 삽입, f 삽입
+    }
+
+    #[test]
+    fn test_empty_sides() {
+        let lines = ["a", "b"];
+        assert_eq!(
+            diff(&[] as &[&str], &lines),
+            vec![DiffOp::Insert { old_index: 0, new_index: 0, new_len: 2 }]
+        );
+        assert_eq!(
+            diff(&lines, &[] as &[&str]),
+            vec![DiffOp::Delete { old_index: 0, old_len: 2, new_index: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_minimal_edit() {
+        // 고전적인 Myers 예제: ABCABBA → CBABAC (편집 거리 5)
+        let old: Vec<char> = "ABCABBA".chars().collect();
+        let new: Vec<char> = "CBABAC".chars().collect();
+        let ops = diff(&old, &new);
+        let edits: usize = changes(&ops).iter().map(|c| c.old_len + c.new_len).sum();
+        assert_eq!(edits, 5);
+    }
+
+    #[test]
+    fn test_hunks_merge_nearby_changes() {
+        let old: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+        let mut new = old.clone();
+        new[2] = "x".into();
+        new[6] = "y".into();
+        new.insert(18, "z".into());
+
+        let result = hunks(&changes(&diff(&old, &new)), old.len(), 3);
+        // 2, 6 은 문맥이 겹쳐 하나로, 18 앞 삽입은 별도
+        assert_eq!(result.len(), 2);
+        assert_eq!((result[0].old_start, result[0].old_len), (0, 10));
+        assert_eq!((result[0].new_start, result[0].new_len), (0, 10));
+        assert_eq!(result[0].changes.len(), 2);
+        assert_eq!((result[1].old_start, result[1].old_len), (15, 5));
+        assert_eq!((result[1].new_start, result[1].new_len), (15, 6));
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity(&["a", "b"], &["a", "b"]), 1.0);
+        assert_eq!(similarity(&["a", "b"], &["c", "d"]), 0.0);
+        assert_eq!(similarity(&["a", "b"], &["a", "c"]), 0.5);
+    }
+}
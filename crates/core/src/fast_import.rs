@@ -0,0 +1,1303 @@
+// =============================================================================
+// Git fast-import 스트림 (fast_import.rs)
+// =============================================================================
+//
+// Git 히스토리를 CTS 로 옮기고, CTS 히스토리를 다시 Git 으로 내보내기
+// 네트워크 없이 `git fast-export` 가 만든 스트림 파일 하나로 동작
+//
+// 가져오기 (import_stream):
+//   git fast-export --all --show-original-ids > repo.stream
+//   cts fast-import repo.stream
+// - blob / commit / tag / reset 명령을 CTS 객체로 변환 (SHA-256 으로 다시 해싱)
+// - Git SHA-1 → CTS 해시 매핑을 .cts/git-map 에 기록 ("{sha1} {cts 해시}" 줄 단위)
+//   original-oid 가 없으면 Git 객체 형식 그대로 SHA-1 을 계산 (트리 전체를 해싱하므로 느림)
+// - 매핑에 있는 SHA-1 은 from / M 에서 그대로 참조 가능 → 증분 스트림도 이어서 가져옴
+// - CTS 커밋은 부모가 하나 → 병합 커밋(merge)이 있으면 가져오기 실패 (참조는 그대로)
+// - Git 서명(gpgsig)은 옮길 수 없어 버림
+// - 스트림 밖에서 정한 mark (git fast-export --import-marks) 는 import_stream_with_marks 로 전달
+//
+// 내보내기 (export_stream):
+//   cts fast-export --all > repo.stream
+//   git init repo && git -C repo fast-import < repo.stream
+// - 커밋마다 부모와 달라진 파일만 M / D 로 기록, blob 은 처음 나올 때 한 번만
+// - 받는 쪽이 가진 커밋(exclude)은 다시 보내지 않고 매핑의 SHA-1 로 참조 (git-remote-cts 의 fetch)
+// - 기록한 객체마다 git fast-import 가 만들 SHA-1 을 계산해서 매핑에 추가
+// - CTS 서명(signature 헤더)은 Git 에 대응하는 형식이 없어 생략
+//
+// 파일 위치: crates/core/src/fast_import.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use sha1::{Digest, Sha1};
+use shared::error::AppError;
+use shared::types::parse_epoch;
+
+use crate::checkout::is_safe_path;
+use crate::object::{Actor, Blob, Commit, Object, ObjectType, Tag, TreeEntry, MODE_SUBMODULE};
+use crate::refs::{Expected, Identity, TAGS_PREFIX};
+use crate::repo::Repository;
+use crate::revwalk::ancestors;
+use crate::store::{is_valid_hash, ObjectStore};
+
+/// 매핑 파일 이름 (.cts/git-map)
+pub const GIT_MAP_FILE: &str = "git-map";
+
+/// Git SHA-1 hex 길이
+const GIT_HASH_HEX_LENGTH: usize = 40;
+
+/// Git 트리 객체의 디렉토리 모드 (앞의 0 없음)
+const GIT_TREE_MODE: &str = "40000";
+
+// =============================================================================
+// GitMap (Git SHA-1 ↔ CTS 해시)
+// =============================================================================
+
+/// Git SHA-1 ↔ CTS 해시 매핑
+///
+/// 파일 형식: 한 줄에 "{Git SHA-1} {CTS 해시}", SHA-1 순으로 정렬
+#[derive(Debug, Default, Clone)]
+pub struct GitMap {
+    to_cts: BTreeMap<String, String>,
+    to_git: HashMap<String, String>,
+}
+
+impl GitMap {
+    /// 저장소의 기본 매핑 파일 경로
+    pub fn path(repo: &Repository) -> PathBuf {
+        repo.common_dir().join(GIT_MAP_FILE)
+    }
+
+    /// 매핑 파일 읽기 (없으면 빈 매핑)
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let mut map = Self::default();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(map),
+            Err(err) => return Err(err.into()),
+        };
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            match line.split_once(' ') {
+                Some((git, cts)) if is_git_hash(git) && is_valid_hash(cts) => map.insert(git, cts),
+                _ => {
+                    return Err(AppError::InvalidInput(format!(
+                        "Malformed line in {}: {}",
+                        path.display(),
+                        line
+                    )))
+                }
+            }
+        }
+        Ok(map)
+    }
+
+    /// 매핑 파일 기록 (임시 파일에 쓴 뒤 교체)
+    pub fn save(&self, path: &Path) -> Result<(), AppError> {
+        let mut text = String::new();
+        for (git, cts) in &self.to_cts {
+            text.push_str(&format!("{} {}\n", git, cts));
+        }
+        let temp = path.with_extension("tmp");
+        fs::write(&temp, text)?;
+        fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    pub fn insert(&mut self, git: &str, cts: &str) {
+        self.to_cts.insert(git.to_string(), cts.to_string());
+        self.to_git.insert(cts.to_string(), git.to_string());
+    }
+
+    /// Git SHA-1 에 대응하는 CTS 해시
+    pub fn cts(&self, git: &str) -> Option<&str> {
+        self.to_cts.get(git).map(String::as_str)
+    }
+
+    /// CTS 해시에 대응하는 Git SHA-1
+    pub fn git(&self, cts: &str) -> Option<&str> {
+        self.to_git.get(cts).map(String::as_str)
+    }
+
+    /// (Git SHA-1, CTS 해시), SHA-1 순
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.to_cts.iter().map(|(git, cts)| (git.as_str(), cts.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.to_cts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.to_cts.is_empty()
+    }
+}
+
+// =============================================================================
+// 가져오기
+// =============================================================================
+
+/// 가져오기 결과
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    pub blobs: usize,
+    pub commits: usize,
+    pub tags: usize,
+    /// Git SHA-1 을 알 수 없어 매핑에 기록하지 못한 커밋/태그 수
+    pub unmapped: usize,
+    /// 갱신한 참조 (이름, 해시)
+    pub refs: Vec<(String, String)>,
+}
+
+/// fast-export 스트림을 읽어 객체를 기록하고 참조 갱신
+///
+/// 참조는 스트림을 끝까지 읽은 뒤 한 트랜잭션으로 갱신 (도중에 실패하면 참조는 그대로)
+/// 매핑은 호출한 쪽에서 저장 (GitMap::save)
+///
+/// # Errors
+/// * `InvalidInput` - 스트림 형식 오류, 알 수 없는 mark / SHA-1, 안전하지 않은 경로
+pub fn import_stream<R: BufRead>(
+    repo: &Repository,
+    input: R,
+    map: &mut GitMap,
+    identity: &Identity,
+) -> Result<ImportSummary, AppError> {
+    import_stream_with_marks(repo, input, map, identity, &BTreeMap::new())
+}
+
+/// import_stream + 스트림이 정의하지 않고 참조하는 mark (mark → Git SHA-1)
+///
+/// `git fast-export --import-marks={파일}` 은 파일의 커밋을 다시 보내지 않고 ":mark" 로만 참조
+/// 매핑에 없는 SHA-1 의 mark 는 무시 (참조하면 unknown mark 오류)
+pub fn import_stream_with_marks<R: BufRead>(
+    repo: &Repository,
+    input: R,
+    map: &mut GitMap,
+    identity: &Identity,
+    marks: &BTreeMap<u64, String>,
+) -> Result<ImportSummary, AppError> {
+    let marks = marks
+        .iter()
+        .filter_map(|(mark, git)| {
+            let cts = map.cts(git)?.to_string();
+            Some((*mark, Resolved { cts, git: Some(git.clone()) }))
+        })
+        .collect();
+    let mut importer = Importer {
+        repo,
+        map,
+        marks,
+        refs: BTreeMap::new(),
+        files: None,
+        summary: ImportSummary::default(),
+    };
+    importer.run(&mut StreamReader::new(input))?;
+
+    let mut summary = importer.summary;
+    let mut transaction = repo.refs().transaction();
+    for (name, value) in importer.refs {
+        // 부모 없이 reset 만 하고 커밋이 없는 참조는 건드리지 않음
+        if let Some(hash) = value {
+            transaction.update(&name, &hash, Expected::Any);
+            summary.refs.push((name, hash));
+        }
+    }
+    if !transaction.is_empty() {
+        transaction.commit(identity, "fast-import")?;
+    }
+    Ok(summary)
+}
+
+/// mark / commit-ish 가 가리키는 객체 (Git SHA-1 은 모를 수도 있음)
+#[derive(Debug, Clone)]
+struct Resolved {
+    cts: String,
+    git: Option<String>,
+}
+
+struct Importer<'a> {
+    repo: &'a Repository,
+    map: &'a mut GitMap,
+    marks: HashMap<u64, Resolved>,
+    /// 스트림에서 바뀐 참조 (None = 부모 없이 reset)
+    refs: BTreeMap<String, Option<String>>,
+    /// 마지막 커밋과 그 파일 목록 (선형 히스토리에서 매번 트리를 펼치지 않기 위함)
+    files: Option<(String, BTreeMap<String, TreeEntry>)>,
+    summary: ImportSummary,
+}
+
+impl Importer<'_> {
+    fn store(&self) -> &ObjectStore {
+        self.repo.objects()
+    }
+
+    fn run<R: BufRead>(&mut self, reader: &mut StreamReader<R>) -> Result<(), AppError> {
+        while let Some(line) = reader.next_line()? {
+            let (command, argument) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+            match command {
+                "" => {}
+                "blob" => self.blob(reader)?,
+                "commit" => self.commit(reader, argument)?,
+                "tag" => self.tag(reader, argument)?,
+                "reset" => self.reset(reader, argument)?,
+                "alias" => {
+                    let mark = self.mark(reader)?.ok_or_else(|| reader.error("alias without mark"))?;
+                    let target = reader.expect("to ")?;
+                    let resolved = self.resolve(reader, &target)?;
+                    self.marks.insert(mark, resolved);
+                }
+                "feature" => match argument {
+                    "done" | "force" | "notes" | "date-format=raw" | "date-format=raw-permissive" => {}
+                    _ => return Err(reader.error(&format!("unsupported feature '{}'", argument))),
+                },
+                // 다른 도구용 option, 진행 표시 등은 무시
+                "option" | "progress" | "checkpoint" => {}
+                "done" => break,
+                _ => return Err(reader.error(&format!("unsupported command '{}'", line))),
+            }
+        }
+        Ok(())
+    }
+
+    fn mark<R: BufRead>(&self, reader: &mut StreamReader<R>) -> Result<Option<u64>, AppError> {
+        match reader.optional("mark :")? {
+            Some(number) => number.parse().map(Some).map_err(|_| reader.error("invalid mark")),
+            None => Ok(None),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // 명령
+    // -------------------------------------------------------------------------
+
+    fn blob<R: BufRead>(&mut self, reader: &mut StreamReader<R>) -> Result<(), AppError> {
+        let mark = self.mark(reader)?;
+        let original = reader.optional("original-oid ")?;
+        let content = reader.data()?;
+        let resolved = self.write_blob(content, original)?;
+        if let Some(mark) = mark {
+            self.marks.insert(mark, resolved);
+        }
+        Ok(())
+    }
+
+    fn write_blob(&mut self, content: Vec<u8>, original: Option<String>) -> Result<Resolved, AppError> {
+        let git = original.unwrap_or_else(|| git_object_id("blob", &content));
+        let cts = self.store().write(&Object::from(Blob::new(content)))?;
+        self.map.insert(&git, &cts);
+        self.summary.blobs += 1;
+        Ok(Resolved { cts, git: Some(git) })
+    }
+
+    fn commit<R: BufRead>(&mut self, reader: &mut StreamReader<R>, refname: &str) -> Result<(), AppError> {
+        let mark = self.mark(reader)?;
+        let original = reader.optional("original-oid ")?;
+        let author_line = reader.optional("author ")?;
+        let committer_line = reader.expect("committer ")?;
+        // Git 서명은 CTS 서명 형식으로 옮길 수 없음
+        if reader.optional("gpgsig ")?.is_some() {
+            reader.data()?;
+        }
+        let encoding = reader.optional("encoding ")?;
+        let message = reader.data()?;
+
+        let parent = match reader.optional("from ")? {
+            Some(spec) => Some(self.resolve(reader, &spec)?),
+            None => self.ref_value(refname)?.map(|cts| Resolved { git: self.map.git(&cts).map(str::to_string), cts }),
+        };
+        // 부모를 버리면 히스토리가 달라지므로 병합 커밋은 가져오지 않음
+        if reader.optional("merge ")?.is_some() {
+            return Err(reader.error(&format!(
+                "merge commit on {} is not supported (CTS commits have a single parent)",
+                refname
+            )));
+        }
+
+        let mut files = self.take_files(parent.as_ref().map(|parent| parent.cts.as_str()))?;
+        while let Some(line) = reader.next_line()? {
+            let (op, rest) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+            match op {
+                "M" => self.file_modify(reader, rest, &mut files)?,
+                "D" => {
+                    let (path, _) = parse_path(rest, true).map_err(|msg| reader.error(&msg))?;
+                    remove_path(&mut files, &path);
+                }
+                "C" | "R" => {
+                    let (source, rest) = parse_path(rest, false).map_err(|msg| reader.error(&msg))?;
+                    let (target, _) = parse_path(rest, true).map_err(|msg| reader.error(&msg))?;
+                    check_path(reader, &target)?;
+                    copy_path(&mut files, &source, &target, op == "R");
+                }
+                "deleteall" => files.clear(),
+                // 노트는 가져오지 않음 (인라인 내용만 건너뜀)
+                "N" => {
+                    if rest.starts_with("inline ") {
+                        reader.data()?;
+                    }
+                }
+                "" => break,
+                _ => {
+                    reader.push_back(line);
+                    break;
+                }
+            }
+        }
+
+        let store = self.store();
+        let tree = store.write_flat_tree(&files)?;
+        let committer = parse_ident(&committer_line)?;
+        let author = match &author_line {
+            Some(line) => parse_ident(line)?,
+            None => committer.clone(),
+        };
+        let commit = Commit::new(
+            tree,
+            parent.as_ref().map(|parent| parent.cts.clone()),
+            String::from_utf8_lossy(&message).into_owned(),
+            author,
+            committer,
+        );
+        let cts = store.write(&Object::from(commit))?;
+
+        let git = match original {
+            Some(original) => Some(original),
+            None => {
+                let header = GitCommitHeader {
+                    author: author_line.as_deref().unwrap_or(&committer_line),
+                    committer: &committer_line,
+                    encoding: encoding.as_deref(),
+                };
+                self.git_commit_id(&files, parent.as_ref(), &header, &message)?
+            }
+        };
+        self.record(&cts, git.as_deref());
+        self.summary.commits += 1;
+
+        let resolved = Resolved { cts: cts.clone(), git };
+        if let Some(mark) = mark {
+            self.marks.insert(mark, resolved);
+        }
+        self.refs.insert(refname.to_string(), Some(cts.clone()));
+        self.files = Some((cts, files));
+        Ok(())
+    }
+
+    /// M {모드} {dataref} {경로}
+    fn file_modify<R: BufRead>(
+        &mut self,
+        reader: &mut StreamReader<R>,
+        rest: &str,
+        files: &mut BTreeMap<String, TreeEntry>,
+    ) -> Result<(), AppError> {
+        let mut parts = rest.splitn(3, ' ');
+        let (Some(mode), Some(dataref), Some(path)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(reader.error(&format!("malformed filemodify 'M {}'", rest)));
+        };
+        let (path, _) = parse_path(path, true).map_err(|msg| reader.error(&msg))?;
+        check_path(reader, &path)?;
+        let mode = match mode {
+            "644" | "100644" => "100644",
+            "755" | "100755" => "100755",
+            "120000" => "120000",
+            MODE_SUBMODULE => MODE_SUBMODULE,
+            _ => return Err(reader.error(&format!("unsupported file mode {} for {}", mode, path))),
+        };
+        let name = path.rsplit('/').next().unwrap_or(&path).to_string();
+
+        let entry = if mode == MODE_SUBMODULE {
+            // 서브모듈 커밋은 다른 저장소에 있음 → 그 저장소를 같은 매핑 파일로 먼저 가져와야 함
+            let hash = if is_valid_hash(dataref) {
+                dataref.to_string()
+            } else {
+                self.map.cts(dataref).map(str::to_string).ok_or_else(|| {
+                    reader.error(&format!(
+                        "submodule {} points at Git commit {} which is not in the mapping (import the submodule first)",
+                        path, dataref
+                    ))
+                })?
+            };
+            TreeEntry::submodule(name, hash)
+        } else {
+            let hash = if dataref == "inline" {
+                let content = reader.data()?;
+                self.write_blob(content, None)?.cts
+            } else {
+                let resolved = self.resolve(reader, dataref)?;
+                if self.store().read(&resolved.cts)?.object_type() != ObjectType::Blob {
+                    return Err(reader.error(&format!("{} is not a blob", dataref)));
+                }
+                resolved.cts
+            };
+            TreeEntry {
+                name,
+                object_type: ObjectType::Blob,
+                hash,
+                mode: mode.to_string(),
+            }
+        };
+        // 같은 경로의 디렉토리(또는 파일 아래 경로)는 대체됨
+        remove_path(files, &path);
+        files.insert(path, entry);
+        Ok(())
+    }
+
+    fn tag<R: BufRead>(&mut self, reader: &mut StreamReader<R>, name: &str) -> Result<(), AppError> {
+        let name = name.strip_prefix(TAGS_PREFIX).unwrap_or(name);
+        let mark = self.mark(reader)?;
+        let from = reader.expect("from ")?;
+        let original = reader.optional("original-oid ")?;
+        let tagger_line = reader.optional("tagger ")?;
+        let message = reader.data()?;
+
+        let target = self.resolve(reader, &from)?;
+        let store = self.store();
+        // 태그의 태그는 커밋까지 벗겨서 가리킴 (CTS 태그는 커밋만 대상으로 함)
+        let commit = store.peel_to_commit(&target.cts)?;
+        let tagger = match &tagger_line {
+            Some(line) => parse_ident(line)?,
+            None => store.read_commit(&commit)?.committer,
+        };
+        let tag = Tag::new(commit.clone(), name.to_string(), String::from_utf8_lossy(&message).into_owned(), tagger);
+        let cts = store.write(&Object::from(tag))?;
+
+        let git = original.or_else(|| {
+            // 대상이 커밋이고 SHA-1 을 알 때만 Git 태그 객체 해시 계산 가능
+            let object = target.git.as_ref().filter(|_| target.cts == commit)?;
+            let mut body = format!("object {}\ntype commit\ntag {}\n", object, name).into_bytes();
+            if let Some(line) = &tagger_line {
+                body.extend_from_slice(format!("tagger {}\n", line).as_bytes());
+            }
+            body.push(b'\n');
+            body.extend_from_slice(&message);
+            Some(git_object_id("tag", &body))
+        });
+        self.record(&cts, git.as_deref());
+        self.summary.tags += 1;
+
+        if let Some(mark) = mark {
+            self.marks.insert(mark, Resolved { cts: cts.clone(), git });
+        }
+        self.refs.insert(format!("{}{}", TAGS_PREFIX, name), Some(cts));
+        Ok(())
+    }
+
+    fn reset<R: BufRead>(&mut self, reader: &mut StreamReader<R>, refname: &str) -> Result<(), AppError> {
+        let value = match reader.optional("from ")? {
+            Some(spec) => Some(self.resolve(reader, &spec)?.cts),
+            None => None,
+        };
+        self.refs.insert(refname.to_string(), value);
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // 참조 해석
+    // -------------------------------------------------------------------------
+
+    /// commit-ish / dataref 해석: ":mark", Git SHA-1 (매핑), CTS 해시, 참조 이름
+    fn resolve<R: BufRead>(&self, reader: &StreamReader<R>, spec: &str) -> Result<Resolved, AppError> {
+        if let Some(number) = spec.strip_prefix(':') {
+            let mark: u64 = number.parse().map_err(|_| reader.error(&format!("invalid mark {}", spec)))?;
+            return self.marks.get(&mark).cloned().ok_or_else(|| reader.error(&format!("unknown mark {}", spec)));
+        }
+        if is_git_hash(spec) {
+            let cts = self.map.cts(spec).ok_or_else(|| reader.error(&format!("unknown Git object {}", spec)))?;
+            return Ok(Resolved { cts: cts.to_string(), git: Some(spec.to_string()) });
+        }
+        if is_valid_hash(spec) && self.store().contains(spec) {
+            return Ok(Resolved { cts: spec.to_string(), git: self.map.git(spec).map(str::to_string) });
+        }
+        let name = spec.strip_suffix("^0").unwrap_or(spec);
+        let cts = self.ref_value(name)?.ok_or_else(|| reader.error(&format!("unknown commit-ish {}", spec)))?;
+        Ok(Resolved { git: self.map.git(&cts).map(str::to_string), cts })
+    }
+
+    /// 스트림에서 바뀐 값 우선, 없으면 저장소의 현재 값
+    fn ref_value(&self, name: &str) -> Result<Option<String>, AppError> {
+        match self.refs.get(name) {
+            Some(value) => Ok(value.clone()),
+            None => self.repo.refs().resolve(name),
+        }
+    }
+
+    /// 부모 커밋의 파일 목록 (마지막 커밋이 부모면 그대로 넘겨받음)
+    fn take_files(&mut self, parent: Option<&str>) -> Result<BTreeMap<String, TreeEntry>, AppError> {
+        let Some(parent) = parent else {
+            return Ok(BTreeMap::new());
+        };
+        match self.files.take() {
+            Some((hash, files)) if hash == parent => Ok(files),
+            _ => {
+                let tree = self.store().read_commit(parent)?.tree_hash;
+                self.store().flatten_tree(&tree)
+            }
+        }
+    }
+
+    fn record(&mut self, cts: &str, git: Option<&str>) {
+        match git {
+            Some(git) => self.map.insert(git, cts),
+            None => self.summary.unmapped += 1,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Git 객체 해시 계산 (original-oid 가 없을 때)
+    // -------------------------------------------------------------------------
+
+    /// Git 커밋 객체를 다시 만들어 SHA-1 계산 (부모나 서브모듈의 SHA-1 을 모르면 None)
+    fn git_commit_id(
+        &mut self,
+        files: &BTreeMap<String, TreeEntry>,
+        parent: Option<&Resolved>,
+        header: &GitCommitHeader,
+        message: &[u8],
+    ) -> Result<Option<String>, AppError> {
+        let Some(tree) = git_tree_id(self.repo.objects(), self.map, files)? else {
+            return Ok(None);
+        };
+        let mut body = format!("tree {}\n", tree);
+        if let Some(parent) = parent {
+            match &parent.git {
+                Some(git) => body.push_str(&format!("parent {}\n", git)),
+                None => return Ok(None),
+            }
+        }
+        body.push_str(&format!("author {}\ncommitter {}\n", header.author, header.committer));
+        if let Some(encoding) = header.encoding {
+            body.push_str(&format!("encoding {}\n", encoding));
+        }
+        body.push('\n');
+        let mut body = body.into_bytes();
+        body.extend_from_slice(message);
+        Ok(Some(git_object_id("commit", &body)))
+    }
+}
+
+/// Git 커밋 해시 계산에 필요한 원본 헤더 (스트림 그대로)
+struct GitCommitHeader<'a> {
+    author: &'a str,
+    committer: &'a str,
+    encoding: Option<&'a str>,
+}
+
+// =============================================================================
+// 스트림 읽기
+// =============================================================================
+
+struct StreamReader<R> {
+    input: R,
+    /// 다음 명령으로 되돌려 놓은 줄
+    pending: Option<String>,
+    line_number: usize,
+}
+
+impl<R: BufRead> StreamReader<R> {
+    fn new(input: R) -> Self {
+        Self { input, pending: None, line_number: 0 }
+    }
+
+    fn error(&self, message: &str) -> AppError {
+        AppError::InvalidInput(format!("fast-import stream line {}: {}", self.line_number, message))
+    }
+
+    fn next_line(&mut self) -> Result<Option<String>, AppError> {
+        if let Some(line) = self.pending.take() {
+            return Ok(Some(line));
+        }
+        let mut buffer = Vec::new();
+        if self.input.read_until(b'\n', &mut buffer)? == 0 {
+            return Ok(None);
+        }
+        self.line_number += 1;
+        if buffer.last() == Some(&b'\n') {
+            buffer.pop();
+        }
+        String::from_utf8(buffer).map(Some).map_err(|_| self.error("line is not UTF-8"))
+    }
+
+    fn push_back(&mut self, line: String) {
+        self.pending = Some(line);
+    }
+
+    /// 다음 줄이 prefix 로 시작하면 나머지 반환, 아니면 되돌려 놓고 None
+    fn optional(&mut self, prefix: &str) -> Result<Option<String>, AppError> {
+        let Some(line) = self.next_line()? else {
+            return Ok(None);
+        };
+        match line.strip_prefix(prefix) {
+            Some(rest) => Ok(Some(rest.to_string())),
+            None => {
+                self.push_back(line);
+                Ok(None)
+            }
+        }
+    }
+
+    fn expect(&mut self, prefix: &str) -> Result<String, AppError> {
+        self.optional(prefix)?
+            .ok_or_else(|| self.error(&format!("expected '{}'", prefix.trim_end())))
+    }
+
+    /// "data {길이}" + 내용, 또는 "data <<{구분자}" + 구분자 줄까지
+    fn data(&mut self) -> Result<Vec<u8>, AppError> {
+        let spec = self.expect("data ")?;
+        if let Some(delimiter) = spec.strip_prefix("<<") {
+            let mut content = Vec::new();
+            loop {
+                let line = self.next_line()?.ok_or_else(|| self.error("unterminated data"))?;
+                if line == delimiter {
+                    return Ok(content);
+                }
+                content.extend_from_slice(line.as_bytes());
+                content.push(b'\n');
+            }
+        }
+        let length: usize = spec.parse().map_err(|_| self.error(&format!("invalid data length '{}'", spec)))?;
+        let mut content = vec![0; length];
+        self.input
+            .read_exact(&mut content)
+            .map_err(|_| self.error("stream ends inside data"))?;
+        self.line_number += content.iter().filter(|&&b| b == b'\n').count();
+        // 내용 뒤의 줄바꿈은 있어도 되고 없어도 됨
+        if self.input.fill_buf()?.first() == Some(&b'\n') {
+            self.input.consume(1);
+            self.line_number += 1;
+        }
+        Ok(content)
+    }
+}
+
+fn check_path<R: BufRead>(reader: &StreamReader<R>, path: &str) -> Result<(), AppError> {
+    if is_safe_path(path) {
+        Ok(())
+    } else {
+        Err(reader.error(&format!("unsafe path '{}'", path)))
+    }
+}
+
+// =============================================================================
+// 내보내기
+// =============================================================================
+
+/// 내보내기 결과
+#[derive(Debug, Default)]
+pub struct ExportSummary {
+    pub blobs: usize,
+    pub commits: usize,
+    pub tags: usize,
+}
+
+/// 참조들의 히스토리를 fast-import 스트림으로 기록
+///
+/// - refs: (스트림에 쓸 참조 이름, 값), 주석 태그는 태그 객체 그대로 넘기면 tag 명령으로 기록
+///   (git fast-import 의 tag 는 항상 refs/tags/{태그 이름} 을 만듦 → 이때 참조 이름은 쓰지 않음)
+/// - exclude: 받는 쪽이 이미 가진 커밋 → 그 조상까지 다시 기록하지 않고 Git SHA-1 로 참조 (매핑 필요)
+/// - 기록한 객체의 Git SHA-1 (git fast-import 가 만들 해시) 을 매핑에 추가
+/// - 서브모듈 커밋은 매핑에 Git SHA-1 이 있으면 그것으로, 없으면 CTS 해시 그대로 기록
+///
+/// # Errors
+/// * `InvalidInput` - 참조가 커밋을 가리키지 않음, exclude 의 커밋이 매핑에 없음
+pub fn export_stream<W: Write>(
+    repo: &Repository,
+    refs: &[(String, String)],
+    exclude: &[String],
+    map: &mut GitMap,
+    out: W,
+) -> Result<ExportSummary, AppError> {
+    let store = repo.objects();
+    let mut exporter = Exporter {
+        store,
+        map,
+        out,
+        known: ancestors(store, exclude)?,
+        marks: HashMap::new(),
+        files: None,
+        summary: ExportSummary::default(),
+    };
+    // 히스토리를 먼저 모두 기록한 뒤 참조 위치를 정함 (커밋 명령의 참조는 임시 위치일 뿐)
+    let mut tips = Vec::with_capacity(refs.len());
+    for (name, value) in refs {
+        let (commit, tag) = match store.read(value)? {
+            Object::Tag(tag) => (store.peel_to_commit(value)?, Some((value, tag))),
+            Object::Commit(_) => (value.clone(), None),
+            _ => return Err(AppError::InvalidInput(format!("{} does not point at a commit", name))),
+        };
+        exporter.history(name, &commit)?;
+        tips.push((name, commit, tag));
+    }
+    for (name, commit, tag) in tips {
+        let from = exporter.commit_ref(&commit)?;
+        match tag {
+            Some((hash, tag)) => exporter.tag(&from, &commit, hash, &tag)?,
+            None => writeln!(exporter.out, "reset {}\nfrom {}\n", name, from)?,
+        }
+    }
+    exporter.out.flush()?;
+    Ok(exporter.summary)
+}
+
+struct Exporter<'a, W> {
+    store: &'a ObjectStore,
+    map: &'a mut GitMap,
+    out: W,
+    /// 받는 쪽이 가진 커밋 (exclude 와 그 조상)
+    known: HashSet<String>,
+    /// 기록한 blob / 커밋 / 태그의 mark (CTS 해시 → mark)
+    marks: HashMap<String, u64>,
+    /// 마지막으로 기록한 커밋과 그 파일 목록
+    files: Option<(String, BTreeMap<String, TreeEntry>)>,
+    summary: ExportSummary,
+}
+
+impl<W: Write> Exporter<'_, W> {
+    fn next_mark(&mut self, hash: &str) -> u64 {
+        let mark = self.marks.len() as u64 + 1;
+        self.marks.insert(hash.to_string(), mark);
+        mark
+    }
+
+    /// from 에 쓸 커밋 참조: 기록한 커밋은 ":{mark}", 받는 쪽이 가진 커밋은 Git SHA-1
+    fn commit_ref(&self, hash: &str) -> Result<String, AppError> {
+        if let Some(mark) = self.marks.get(hash) {
+            return Ok(format!(":{}", mark));
+        }
+        self.map
+            .git(hash)
+            .map(str::to_string)
+            .ok_or_else(|| AppError::InvalidInput(format!("No Git SHA-1 recorded for commit {}", hash)))
+    }
+
+    /// 아직 기록하지 않은 조상부터 순서대로 커밋 기록
+    fn history(&mut self, refname: &str, tip: &str) -> Result<(), AppError> {
+        let mut chain = Vec::new();
+        let mut current = Some(tip.to_string());
+        while let Some(hash) = current {
+            if self.marks.contains_key(&hash) || self.known.contains(&hash) {
+                break;
+            }
+            let commit = self.store.read_commit(&hash)?;
+            // shallow 경계 커밋은 부모 없이 기록
+            current = self.store.parent_of(&hash, &commit);
+            chain.push((hash, commit));
+        }
+        for (hash, commit) in chain.into_iter().rev() {
+            self.commit(refname, &hash, &commit)?;
+        }
+        Ok(())
+    }
+
+    fn commit(&mut self, refname: &str, hash: &str, commit: &Commit) -> Result<(), AppError> {
+        let parent = self.store.parent_of(hash, commit);
+        let parent_files = match (self.files.take(), &parent) {
+            (Some((last, files)), Some(parent)) if &last == parent => files,
+            (_, Some(parent)) => self.store.flatten_tree(&self.store.read_commit(parent)?.tree_hash)?,
+            (_, None) => BTreeMap::new(),
+        };
+        let files = self.store.flatten_tree(&commit.tree_hash)?;
+
+        // 처음 나오는 blob 먼저
+        for (path, entry) in &files {
+            if entry.is_submodule() || parent_files.get(path) == Some(entry) || self.marks.contains_key(&entry.hash) {
+                continue;
+            }
+            let mark = self.next_mark(&entry.hash);
+            writeln!(self.out, "blob\nmark :{}", mark)?;
+            write_data(&mut self.out, self.store.read_blob(&entry.hash)?.content())?;
+            self.summary.blobs += 1;
+        }
+
+        let from = parent.as_deref().map(|parent| self.commit_ref(parent)).transpose()?;
+        // 부모 없는 커밋은 참조의 이전 값을 부모로 잡지 않도록 reset
+        if from.is_none() {
+            writeln!(self.out, "reset {}", refname)?;
+        }
+        let mark = self.next_mark(hash);
+        writeln!(
+            self.out,
+            "commit {}\nmark :{}\nauthor {}\ncommitter {}",
+            refname, mark, commit.author, commit.committer
+        )?;
+        write_data(&mut self.out, commit.message.as_bytes())?;
+        if let Some(from) = &from {
+            writeln!(self.out, "from {}", from)?;
+        }
+        for path in parent_files.keys().filter(|path| !files.contains_key(*path)) {
+            writeln!(self.out, "D {}", quote_path(path))?;
+        }
+        for (path, entry) in &files {
+            if parent_files.get(path) == Some(entry) {
+                continue;
+            }
+            let dataref = if entry.is_submodule() {
+                self.map.git(&entry.hash).unwrap_or(&entry.hash).to_string()
+            } else {
+                format!(":{}", self.marks[&entry.hash])
+            };
+            writeln!(self.out, "M {} {} {}", entry.mode, dataref, quote_path(path))?;
+        }
+        writeln!(self.out)?;
+        self.summary.commits += 1;
+
+        // git fast-import 가 만들 커밋의 SHA-1 (부모의 SHA-1 을 알 때만)
+        let parent_git = match &parent {
+            Some(parent) => self.map.git(parent).map(|git| Some(git.to_string())),
+            None => Some(None),
+        };
+        if let (Some(parent_git), Some(tree)) = (parent_git, git_tree_id(self.store, self.map, &files)?) {
+            let mut body = format!("tree {}\n", tree);
+            if let Some(parent_git) = parent_git {
+                body.push_str(&format!("parent {}\n", parent_git));
+            }
+            body.push_str(&format!("author {}\ncommitter {}\n\n{}", commit.author, commit.committer, commit.message));
+            self.map.insert(&git_object_id("commit", body.as_bytes()), hash);
+        }
+        self.files = Some((hash.to_string(), files));
+        Ok(())
+    }
+
+    /// 주석 태그: git fast-import 는 reset 으로 태그 객체를 가리킬 수 없으므로 tag 명령만 기록
+    fn tag(&mut self, from: &str, commit: &str, hash: &str, tag: &Tag) -> Result<(), AppError> {
+        let mark = self.next_mark(hash);
+        writeln!(self.out, "tag {}\nmark :{}\nfrom {}\ntagger {}", tag.name, mark, from, tag.tagger)?;
+        write_data(&mut self.out, tag.message.as_bytes())?;
+        self.summary.tags += 1;
+
+        if let Some(commit_git) = self.map.git(commit) {
+            let body = format!(
+                "object {}\ntype commit\ntag {}\ntagger {}\n\n{}",
+                commit_git, tag.name, tag.tagger, tag.message
+            );
+            self.map.insert(&git_object_id("tag", body.as_bytes()), hash);
+        }
+        Ok(())
+    }
+}
+
+fn write_data<W: Write>(out: &mut W, content: &[u8]) -> Result<(), AppError> {
+    writeln!(out, "data {}", content.len())?;
+    out.write_all(content)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+// =============================================================================
+// 헬퍼 함수
+// =============================================================================
+
+/// 파일 목록의 Git 트리 해시 (서브모듈 커밋의 SHA-1 을 모르면 None)
+fn git_tree_id(
+    store: &ObjectStore,
+    map: &mut GitMap,
+    files: &BTreeMap<String, TreeEntry>,
+) -> Result<Option<String>, AppError> {
+    let mut entries = Vec::with_capacity(files.len());
+    for (path, entry) in files {
+        let id = if entry.is_submodule() {
+            match map.git(&entry.hash) {
+                Some(id) => id.to_string(),
+                None => return Ok(None),
+            }
+        } else {
+            git_blob_id(store, map, &entry.hash)?
+        };
+        entries.push((path.as_str(), entry.mode.as_str(), id));
+    }
+    Ok(Some(git_subtree_id(&entries)))
+}
+
+/// blob 의 Git SHA-1 (매핑에 없으면 내용으로 계산해서 매핑에도 추가)
+fn git_blob_id(store: &ObjectStore, map: &mut GitMap, cts: &str) -> Result<String, AppError> {
+    if let Some(git) = map.git(cts) {
+        return Ok(git.to_string());
+    }
+    let git = git_object_id("blob", store.read_blob(cts)?.content());
+    map.insert(&git, cts);
+    Ok(git)
+}
+
+/// Git 객체 해시: SHA-1("{타입} {길이}\0" + 본문)
+fn git_object_id(kind: &str, body: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(format!("{} {}\0", kind, body.len()).as_bytes());
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+/// 정렬된 (경로, 모드, SHA-1) 목록으로 Git 트리 해시 계산
+///
+/// Git 트리는 디렉토리 이름 뒤에 '/' 를 붙인 것처럼 정렬 ("a.txt" < "a/" < "a0")
+fn git_subtree_id(files: &[(&str, &str, String)]) -> String {
+    let mut records: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut i = 0;
+    while i < files.len() {
+        let (path, mode, id) = &files[i];
+        let (name, mode, id) = match path.split_once('/') {
+            None => {
+                i += 1;
+                (path.to_string(), mode.to_string(), id.clone())
+            }
+            Some((dir, _)) => {
+                let prefix = format!("{}/", dir);
+                let children: Vec<(&str, &str, String)> = files[i..]
+                    .iter()
+                    .take_while(|(p, _, _)| p.starts_with(&prefix))
+                    .map(|(p, m, id)| (&p[prefix.len()..], *m, id.clone()))
+                    .collect();
+                i += children.len();
+                (dir.to_string(), GIT_TREE_MODE.to_string(), git_subtree_id(&children))
+            }
+        };
+        let key = if mode == GIT_TREE_MODE { format!("{}/", name) } else { name.clone() };
+        let mut record = format!("{} {}\0", mode, name).into_bytes();
+        record.extend(hex::decode(&id).unwrap_or_default());
+        records.push((key, record));
+    }
+    records.sort_by(|a, b| a.0.cmp(&b.0));
+    let body: Vec<u8> = records.into_iter().flat_map(|(_, record)| record).collect();
+    git_object_id("tree", &body)
+}
+
+fn is_git_hash(value: &str) -> bool {
+    value.len() == GIT_HASH_HEX_LENGTH && value.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase())
+}
+
+/// "이름 <이메일> {epoch} {±HHMM}" (이름은 비어 있을 수 있음)
+fn parse_ident(value: &str) -> Result<Actor, AppError> {
+    let invalid = || AppError::InvalidInput(format!("Invalid identity in fast-import stream: {}", value));
+    let (name, rest) = value.split_once('<').ok_or_else(invalid)?;
+    let (email, stamp) = rest.split_once('>').ok_or_else(invalid)?;
+    let (time, offset) = parse_epoch(stamp.trim()).map_err(|_| invalid())?;
+    Ok(Actor::new(name.trim_end().to_string(), email.to_string(), time, offset))
+}
+
+/// 경로 하나 파싱: C 스타일 따옴표 경로 또는 그대로
+///
+/// last 가 아니면 따옴표 없는 경로는 첫 공백까지 (C / R 의 원본 경로)
+/// 반환: (경로, 나머지)
+fn parse_path(text: &str, last: bool) -> Result<(String, &str), String> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        let (path, rest) = if last { (text, "") } else { text.split_once(' ').unwrap_or((text, "")) };
+        return Ok((path.to_string(), rest));
+    };
+
+    let mut bytes = Vec::new();
+    let mut chars = quoted.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                let path = String::from_utf8(bytes).map_err(|_| format!("path is not UTF-8: {}", text))?;
+                let rest = &quoted[i + 1..];
+                return Ok((path, rest.strip_prefix(' ').unwrap_or(rest)));
+            }
+            '\\' => {
+                let (_, escaped) = chars.next().ok_or_else(|| format!("unterminated path: {}", text))?;
+                match escaped {
+                    'n' => bytes.push(b'\n'),
+                    't' => bytes.push(b'\t'),
+                    'r' => bytes.push(b'\r'),
+                    'a' => bytes.push(0x07),
+                    'b' => bytes.push(0x08),
+                    'f' => bytes.push(0x0c),
+                    'v' => bytes.push(0x0b),
+                    '"' | '\\' => bytes.push(escaped as u8),
+                    '0'..='7' => {
+                        // 8진수 세 자리 (UTF-8 바이트 하나)
+                        let mut value = escaped.to_digit(8).unwrap_or(0);
+                        for _ in 0..2 {
+                            let (_, digit) = chars.next().ok_or_else(|| format!("unterminated path: {}", text))?;
+                            let digit = digit.to_digit(8).ok_or_else(|| format!("invalid escape in path: {}", text))?;
+                            value = value * 8 + digit;
+                        }
+                        bytes.push(u8::try_from(value).map_err(|_| format!("invalid escape in path: {}", text))?);
+                    }
+                    _ => return Err(format!("invalid escape in path: {}", text)),
+                }
+            }
+            _ => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+    Err(format!("unterminated path: {}", text))
+}
+
+/// 따옴표가 필요한 경로면 C 스타일로 (줄바꿈, 따옴표로 시작, 공백 포함 → R/C 에서 모호하지 않게)
+fn quote_path(path: &str) -> String {
+    if !path.starts_with('"') && !path.contains('\n') && !path.contains(' ') {
+        return path.to_string();
+    }
+    let mut quoted = String::from("\"");
+    for c in path.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// 경로(파일 또는 디렉토리 전체) 삭제
+fn remove_path(files: &mut BTreeMap<String, TreeEntry>, path: &str) {
+    let prefix = format!("{}/", path);
+    files.retain(|p, _| p != path && !p.starts_with(&prefix));
+    // 파일이 있던 자리에 디렉토리가 생기는 경우: 상위 경로의 파일 제거
+    let mut parent = path;
+    while let Some((dir, _)) = parent.rsplit_once('/') {
+        files.remove(dir);
+        parent = dir;
+    }
+}
+
+/// 경로(파일 또는 디렉토리 전체) 복사 / 이름 변경
+fn copy_path(files: &mut BTreeMap<String, TreeEntry>, source: &str, target: &str, rename: bool) {
+    let prefix = format!("{}/", source);
+    let moved: Vec<(String, TreeEntry)> = files
+        .iter()
+        .filter(|(p, _)| *p == source || p.starts_with(&prefix))
+        .map(|(p, entry)| (format!("{}{}", target, &p[source.len()..]), entry.clone()))
+        .collect();
+    if rename {
+        remove_path(files, source);
+    }
+    remove_path(files, target);
+    for (path, mut entry) in moved {
+        entry.name = path.rsplit('/').next().unwrap_or(&path).to_string();
+        files.insert(path, entry);
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn identity() -> Identity {
+        Identity::new("Tester", "tester@example.com")
+    }
+
+    fn import(repo: &Repository, stream: &str, map: &mut GitMap) -> ImportSummary {
+        import_stream(repo, stream.as_bytes(), map, &identity()).unwrap()
+    }
+
+    fn files_at(repo: &Repository, rev: &str) -> BTreeMap<String, String> {
+        let commit = repo.objects().read_commit(&repo.resolve_rev(rev).unwrap()).unwrap();
+        repo.objects()
+            .flatten_tree(&commit.tree_hash)
+            .unwrap()
+            .into_iter()
+            .map(|(path, entry)| {
+                let content = String::from_utf8(repo.objects().entry_content(&entry).unwrap()).unwrap();
+                (path, format!("{} {}", entry.mode, content))
+            })
+            .collect()
+    }
+
+    /// `git fast-export --all` 형식 (original-oid 없음)
+    const STREAM: &str = "\
+blob
+mark :1
+data 6
+hello
+
+reset refs/heads/main
+commit refs/heads/main
+mark :2
+author Jane <jane@example.com> 1700000000 +0900
+committer Jane <jane@example.com> 1700000000 +0900
+data 6
+first
+M 100644 :1 hello.txt
+
+blob
+mark :3
+data <<EOF
+#!/bin/sh
+EOF
+commit refs/heads/main
+mark :4
+author Jane <jane@example.com> 1700000100 +0900
+committer Jane <jane@example.com> 1700000100 +0900
+data 7
+second
+from :2
+M 100755 :3 \"bin/run me.sh\"
+M 644 inline \"caf\\303\\251.txt\"
+data 4
+cafe
+R hello.txt docs/hello.txt
+
+commit refs/heads/topic
+mark :5
+author Jane <jane@example.com> 1700000200 +0900
+committer Jane <jane@example.com> 1700000200 +0900
+data 6
+topic
+from :4
+D bin
+
+commit refs/heads/main
+mark :6
+author Jane <jane@example.com> 1700000300 +0900
+committer Jane <jane@example.com> 1700000300 +0900
+data 9
+drop bin
+from :4
+D bin/run me.sh
+
+tag v1.0
+from :2
+tagger Jane <jane@example.com> 1700000400 +0900
+data 8
+release
+done
+";
+
+    /// 같은 스트림을 `git fast-import` 로 가져왔을 때의 해시
+    const FIRST_COMMIT_SHA1: &str = "f56b4a558a21776e9f42838926e4dc0d9b2c957c";
+    const MAIN_COMMIT_SHA1: &str = "4ad8576601992693806ee871d1fb4018975d6431";
+    const TAG_SHA1: &str = "b91ad59400cb585cb7a4a1f924bd857265ff3983";
+
+    #[test]
+    fn test_import_stream() {
+        let dir = TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let mut map = GitMap::default();
+        let summary = import(&repo, STREAM, &mut map);
+
+        assert_eq!((summary.blobs, summary.commits, summary.tags), (3, 4, 1));
+        assert_eq!(summary.unmapped, 0);
+        let names: Vec<&str> = summary.refs.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["refs/heads/main", "refs/heads/topic", "refs/tags/v1.0"]);
+
+        assert_eq!(
+            files_at(&repo, "main~1"),
+            BTreeMap::from([
+                ("bin/run me.sh".to_string(), "100755 #!/bin/sh\n".to_string()),
+                ("café.txt".to_string(), "100644 cafe".to_string()),
+                ("docs/hello.txt".to_string(), "100644 hello\n".to_string()),
+            ])
+        );
+        let main = repo.objects().read_commit(&repo.resolve_rev("main").unwrap()).unwrap();
+        assert_eq!(main.parent_hash, Some(repo.resolve_rev("main~1").unwrap()));
+        assert_eq!(files_at(&repo, "main"), files_at(&repo, "topic"));
+        assert_eq!(main.author.offset, 540);
+
+        let tag = repo.objects().read_tag(&repo.read_ref("refs/tags/v1.0").unwrap().unwrap()).unwrap();
+        assert_eq!(tag.object_hash, repo.resolve_rev("main~2").unwrap());
+        assert_eq!(tag.message, "release\n");
+
+        // original-oid 가 없어도 Git 과 같은 SHA-1 로 매핑
+        let tree = repo.objects().read_commit(&repo.resolve_rev("main").unwrap()).unwrap().tree_hash;
+        let hello = repo.objects().lookup_path(&tree, "docs/hello.txt").unwrap().unwrap();
+        assert_eq!(map.cts("ce013625030ba8dba906f756967f9e9ca394464a"), Some(hello.hash.as_str()));
+        assert_eq!(map.git(&repo.resolve_rev("main~2").unwrap()), Some(FIRST_COMMIT_SHA1));
+        assert_eq!(map.git(&repo.resolve_rev("main").unwrap()), Some(MAIN_COMMIT_SHA1));
+        assert_eq!(map.git(&repo.read_ref("refs/tags/v1.0").unwrap().unwrap()), Some(TAG_SHA1));
+
+        // 매핑의 SHA-1 로 이어서 가져오기
+        let path = GitMap::path(&repo);
+        map.save(&path).unwrap();
+        let mut map = GitMap::load(&path).unwrap();
+        let incremental = format!(
+            "commit refs/heads/main\ncommitter Jane <jane@example.com> 1700000500 +0900\ndata 5\nnext\nfrom {}\nD café.txt\n",
+            MAIN_COMMIT_SHA1
+        );
+        import(&repo, &incremental, &mut map);
+        assert_eq!(files_at(&repo, "main").len(), 1);
+
+        // 스트림 밖에서 정한 mark (fast-export --import-marks)
+        let marks = BTreeMap::from([(7, FIRST_COMMIT_SHA1.to_string())]);
+        let side = "commit refs/heads/side\ncommitter Jane <jane@example.com> 1700000600 +0900\ndata 5\nside\nfrom :7\n";
+        import_stream_with_marks(&repo, side.as_bytes(), &mut map, &identity(), &marks).unwrap();
+        let commit = repo.objects().read_commit(&repo.resolve_rev("side").unwrap()).unwrap();
+        assert_eq!(commit.parent_hash.as_deref(), map.cts(FIRST_COMMIT_SHA1));
+        assert!(import_stream(&repo, side.as_bytes(), &mut map, &identity()).is_err());
+    }
+
+    #[test]
+    fn test_import_rejects_merge() {
+        let dir = TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let mut map = GitMap::default();
+        import(&repo, STREAM, &mut map);
+        let main = repo.resolve_rev("main").unwrap();
+
+        // 부모를 버리지 않고 실패, 참조는 그대로
+        let merge = "commit refs/heads/main\ncommitter Jane <jane@example.com> 1700000500 +0900\ndata 6\nmerge\n\
+            from refs/heads/main\nmerge refs/heads/topic\n";
+        let err = import_stream(&repo, merge.as_bytes(), &mut map, &identity()).unwrap_err();
+        assert!(err.to_string().contains("merge commit"), "{}", err);
+        assert_eq!(repo.resolve_rev("main").unwrap(), main);
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        import(&repo, STREAM, &mut GitMap::default());
+
+        let refs: Vec<(String, String)> = repo
+            .list_refs("refs/")
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| {
+                let value = repo.read_ref(&name).unwrap().unwrap();
+                (name, value)
+            })
+            .collect();
+        let mut stream = Vec::new();
+        let mut map = GitMap::default();
+        let summary = export_stream(&repo, &refs, &[], &mut map, &mut stream).unwrap();
+        assert_eq!((summary.blobs, summary.commits, summary.tags), (3, 4, 1));
+        // git fast-import 가 만들 해시
+        assert_eq!(map.git(&repo.resolve_rev("main~2").unwrap()), Some(FIRST_COMMIT_SHA1));
+        assert_eq!(map.git(&repo.resolve_rev("main").unwrap()), Some(MAIN_COMMIT_SHA1));
+        assert_eq!(map.git(&repo.read_ref("refs/tags/v1.0").unwrap().unwrap()), Some(TAG_SHA1));
+
+        // 다시 가져오면 같은 해시
+        let other_dir = TempDir::new().unwrap();
+        let other = Repository::init(other_dir.path()).unwrap();
+        import(&other, std::str::from_utf8(&stream).unwrap(), &mut GitMap::default());
+        for (name, value) in &refs {
+            assert_eq!(other.read_ref(name).unwrap().as_ref(), Some(value), "{}", name);
+        }
+
+        // 받는 쪽이 가진 커밋은 SHA-1 로 참조
+        let main = vec![("refs/heads/main".to_string(), repo.resolve_rev("main").unwrap())];
+        let known = repo.resolve_rev("main~1").unwrap();
+        let mut incremental = Vec::new();
+        let summary = export_stream(&repo, &main, std::slice::from_ref(&known), &mut map, &mut incremental).unwrap();
+        assert_eq!((summary.blobs, summary.commits), (0, 1));
+        let text = String::from_utf8(incremental).unwrap();
+        assert!(text.contains(&format!("from {}\n", map.git(&known).unwrap())));
+        assert!(export_stream(&repo, &main, &[known], &mut GitMap::default(), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_git_object_ids() {
+        assert_eq!(git_object_id("blob", b"hello\n"), "ce013625030ba8dba906f756967f9e9ca394464a");
+        assert_eq!(git_subtree_id(&[]), "4b825dc642cb6eb9a060e54bf8d69288fbee4904");
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(parse_path("a b.txt", true).unwrap(), ("a b.txt".to_string(), ""));
+        assert_eq!(parse_path("old new", false).unwrap(), ("old".to_string(), "new"));
+        assert_eq!(parse_path("\"a \\\"q\\\"\" b", false).unwrap(), ("a \"q\"".to_string(), "b"));
+        assert_eq!(parse_path("\"caf\\303\\251\"", true).unwrap().0, "café");
+        assert!(parse_path("\"open", true).is_err());
+        assert_eq!(quote_path("a b"), "\"a b\"");
+        assert_eq!(parse_path(&quote_path("x\n\"y\\"), true).unwrap().0, "x\n\"y\\");
+    }
+}
@@ -0,0 +1,514 @@
+// =============================================================================
+// 내용 검색 (grep.rs)
+// =============================================================================
+//
+// 체크아웃하지 않고 객체 저장소의 트리/블롭을 직접 검색
+//
+// - Grep: 정규식(또는 고정 문자열)으로 파일 내용 검색
+//   - 여러 트리(리비전)를 한 번에 검색, 같은 블롭은 한 번만 읽음
+//   - 블롭 검색은 스레드로 나눠 병렬 실행
+//   - 앞뒤 문맥 라인, 경로 필터(pathspec), 바이너리 파일 구분
+// - Pickaxe: log -S / -G
+//   - -S: 패턴 등장 횟수가 부모와 달라진 커밋 (추가/삭제)
+//   - -G: 추가/삭제된 라인 중 패턴과 맞는 라인이 있는 커밋
+//
+// pathspec:
+//   "src"        src 파일 또는 src/ 아래 전부
+//   "*.rs"       glob ('*' 는 '/' 도 포함, '?' 는 한 글자)
+//
+// 파일 위치: crates/core/src/grep.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use regex::{Regex, RegexBuilder};
+use shared::error::AppError;
+
+use crate::diff::{diff, split_lines, DiffOp};
+use crate::object::Commit;
+use crate::store::ObjectStore;
+
+// =============================================================================
+// 옵션 / 결과
+// =============================================================================
+
+/// 검색 옵션
+#[derive(Debug, Clone, Default)]
+pub struct GrepOptions {
+    /// 대소문자 무시 (-i)
+    pub ignore_case: bool,
+    /// 패턴을 정규식이 아닌 문자열로 (-F)
+    pub fixed_strings: bool,
+    /// 단어 단위로만 매칭 (-w)
+    pub word_regexp: bool,
+    /// 매칭되지 않는 라인 출력 (-v)
+    pub invert: bool,
+    /// 매칭 라인 앞 문맥 라인 수 (-B)
+    pub before_context: usize,
+    /// 매칭 라인 뒤 문맥 라인 수 (-A)
+    pub after_context: usize,
+    /// 검색할 경로 (비어 있으면 전체)
+    pub pathspecs: Vec<String>,
+    /// 검색 스레드 수 (0 이면 CPU 수)
+    pub threads: usize,
+}
+
+/// 출력 라인 (매칭 또는 문맥)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrepLine {
+    /// 1부터 시작
+    pub number: usize,
+    /// 줄바꿈 제외
+    pub text: String,
+    /// false 면 문맥 라인
+    pub matched: bool,
+}
+
+/// 파일 하나의 검색 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrepHits {
+    /// 바이너리 파일이 매칭됨 (라인 출력 없음)
+    Binary,
+    /// 매칭/문맥 라인 (라인 번호순)
+    Lines(Vec<GrepLine>),
+}
+
+/// 매칭된 파일
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrepResult {
+    /// 검색한 리비전 이름 (작업 디렉토리면 None)
+    pub label: Option<String>,
+    pub path: String,
+    pub hits: GrepHits,
+}
+
+// =============================================================================
+// Grep
+// =============================================================================
+
+/// 컴파일된 검색기
+#[derive(Debug, Clone)]
+pub struct Grep {
+    regex: Regex,
+    invert: bool,
+    before: usize,
+    after: usize,
+    pathspecs: Vec<String>,
+    threads: usize,
+}
+
+impl Grep {
+    /// # Errors
+    /// * `AppError::InvalidInput` - 정규식 문법 오류
+    pub fn new(pattern: &str, options: &GrepOptions) -> Result<Self, AppError> {
+        let regex = build_regex(pattern, options.fixed_strings, options.ignore_case, options.word_regexp)?;
+        let threads = match options.threads {
+            0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            n => n,
+        };
+        Ok(Self {
+            regex,
+            invert: options.invert,
+            before: options.before_context,
+            after: options.after_context,
+            pathspecs: options.pathspecs.clone(),
+            threads,
+        })
+    }
+
+    /// 경로가 검색 대상인지
+    pub fn matches_path(&self, path: &str) -> bool {
+        self.pathspecs.is_empty() || self.pathspecs.iter().any(|spec| matches_pathspec(spec, path))
+    }
+
+    /// 내용 하나 검색
+    ///
+    /// # Returns
+    /// 매칭되는 라인이 없으면 None
+    pub fn search(&self, data: &[u8]) -> Option<GrepHits> {
+        if data.contains(&0) {
+            let text = String::from_utf8_lossy(data);
+            let found = if self.invert {
+                text.lines().any(|line| !self.regex.is_match(line))
+            } else {
+                self.regex.is_match(&text)
+            };
+            return found.then_some(GrepHits::Binary);
+        }
+
+        let text = String::from_utf8_lossy(data);
+        let lines: Vec<&str> = text.lines().collect();
+        let matched: Vec<usize> = (0..lines.len())
+            .filter(|&i| self.regex.is_match(lines[i]) != self.invert)
+            .collect();
+        if matched.is_empty() {
+            return None;
+        }
+
+        // 매칭 라인 + 문맥 라인 (겹치는 구간은 한 번만)
+        let mut shown: BTreeMap<usize, bool> = BTreeMap::new();
+        for &i in &matched {
+            let start = i.saturating_sub(self.before);
+            let end = (i + self.after).min(lines.len() - 1);
+            for j in start..=end {
+                shown.entry(j).or_insert(false);
+            }
+            shown.insert(i, true);
+        }
+        Some(GrepHits::Lines(
+            shown
+                .into_iter()
+                .map(|(i, matched)| GrepLine {
+                    number: i + 1,
+                    text: lines[i].to_string(),
+                    matched,
+                })
+                .collect(),
+        ))
+    }
+
+    /// 여러 트리 검색
+    ///
+    /// # Arguments
+    /// * `trees` - (라벨, 트리 해시) 목록, 결과도 이 순서 → 경로순
+    pub fn search_trees(
+        &self,
+        store: &ObjectStore,
+        trees: &[(String, String)],
+    ) -> Result<Vec<GrepResult>, AppError> {
+        let mut files: Vec<(String, String, String)> = Vec::new();
+        for (label, tree) in trees {
+            for (path, entry) in store.flatten_tree(tree)? {
+                if !entry.is_submodule() && self.matches_path(&path) {
+                    files.push((label.clone(), path, entry.hash));
+                }
+            }
+        }
+
+        // 같은 블롭은 한 번만 검색
+        let mut blobs: Vec<&str> = files.iter().map(|(_, _, hash)| hash.as_str()).collect();
+        blobs.sort_unstable();
+        blobs.dedup();
+        let hits = self.parallel(&blobs, |hash| Ok(self.search(store.read_blob(hash)?.content())))?;
+
+        Ok(files
+            .iter()
+            .filter_map(|(label, path, hash)| {
+                hits.get(hash.as_str()).cloned().flatten().map(|hits| GrepResult {
+                    label: Some(label.clone()),
+                    path: path.clone(),
+                    hits,
+                })
+            })
+            .collect())
+    }
+
+    /// 작업 디렉토리의 파일 검색
+    ///
+    /// # Arguments
+    /// * `paths` - 검색할 파일 (work_dir 기준 경로, 보통 인덱스의 파일 목록)
+    pub fn search_worktree(&self, work_dir: &Path, paths: &[String]) -> Result<Vec<GrepResult>, AppError> {
+        let paths: Vec<&str> = paths
+            .iter()
+            .map(String::as_str)
+            .filter(|path| self.matches_path(path))
+            .collect();
+        let hits = self.parallel(&paths, |path| match fs::read(work_dir.join(path)) {
+            Ok(data) => Ok(self.search(&data)),
+            // 인덱스에만 있고 지워진 파일
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        })?;
+
+        Ok(paths
+            .into_iter()
+            .filter_map(|path| {
+                hits.get(path).cloned().flatten().map(|hits| GrepResult {
+                    label: None,
+                    path: path.to_string(),
+                    hits,
+                })
+            })
+            .collect())
+    }
+
+    /// 키 목록을 스레드들이 나눠 처리
+    fn parallel<'k, F>(&self, keys: &[&'k str], work: F) -> Result<HashMap<&'k str, Option<GrepHits>>, AppError>
+    where
+        F: Fn(&str) -> Result<Option<GrepHits>, AppError> + Sync,
+    {
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new(HashMap::with_capacity(keys.len()));
+        let failure: Mutex<Option<AppError>> = Mutex::new(None);
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads.min(keys.len()).max(1) {
+                scope.spawn(|| loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&key) = keys.get(i) else { break };
+                    match work(key) {
+                        Ok(hits) => {
+                            results.lock().unwrap_or_else(|e| e.into_inner()).insert(key, hits);
+                        }
+                        Err(err) => {
+                            failure.lock().unwrap_or_else(|e| e.into_inner()).get_or_insert(err);
+                            // 남은 작업 건너뛰기
+                            next.store(keys.len(), Ordering::Relaxed);
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        if let Some(err) = failure.into_inner().unwrap_or_else(|e| e.into_inner()) {
+            return Err(err);
+        }
+        Ok(results.into_inner().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+// =============================================================================
+// Pickaxe (log -S / -G)
+// =============================================================================
+
+/// 커밋이 패턴을 추가/삭제했는지 판별
+#[derive(Debug, Clone)]
+pub enum Pickaxe {
+    /// -S: 등장 횟수 변화
+    Occurrences(Regex),
+    /// -G: 바뀐 라인에 매칭
+    ChangedLines(Regex),
+}
+
+impl Pickaxe {
+    /// -S (is_regex 가 false 면 문자열 그대로 검색)
+    pub fn occurrences(pattern: &str, is_regex: bool) -> Result<Self, AppError> {
+        Ok(Pickaxe::Occurrences(build_regex(pattern, !is_regex, false, false)?))
+    }
+
+    /// -G
+    pub fn changed_lines(pattern: &str) -> Result<Self, AppError> {
+        Ok(Pickaxe::ChangedLines(build_regex(pattern, false, false, false)?))
+    }
+
+    /// 커밋이 (첫 부모와 비교해서) 조건에 맞는 변경을 포함하는지
+    pub fn matches_commit(&self, store: &ObjectStore, hash: &str, commit: &Commit) -> Result<bool, AppError> {
+        let old_files = match store.parent_of(hash, commit) {
+            Some(parent) => store.flatten_tree(&store.read_commit(&parent)?.tree_hash)?,
+            None => BTreeMap::new(),
+        };
+        let new_files = store.flatten_tree(&commit.tree_hash)?;
+
+        let mut paths: Vec<&String> = old_files.keys().chain(new_files.keys()).collect();
+        paths.sort();
+        paths.dedup();
+        for path in paths {
+            // 서브모듈 커밋 변경은 내용 검색 대상이 아님
+            let old = old_files.get(path).filter(|entry| !entry.is_submodule()).map(|entry| &entry.hash);
+            let new = new_files.get(path).filter(|entry| !entry.is_submodule()).map(|entry| &entry.hash);
+            if old == new {
+                continue;
+            }
+            let old = match old {
+                Some(hash) => store.read_blob(hash)?.content().to_vec(),
+                None => Vec::new(),
+            };
+            let new = match new {
+                Some(hash) => store.read_blob(hash)?.content().to_vec(),
+                None => Vec::new(),
+            };
+            if self.matches_change(&old, &new) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 파일 하나의 변경이 조건에 맞는지
+    pub fn matches_change(&self, old: &[u8], new: &[u8]) -> bool {
+        match self {
+            Pickaxe::Occurrences(regex) => {
+                let count = |data: &[u8]| regex.find_iter(&String::from_utf8_lossy(data)).count();
+                count(old) != count(new)
+            }
+            Pickaxe::ChangedLines(regex) => {
+                let old_lines = split_lines(old);
+                let new_lines = split_lines(new);
+                let is_match = |line: &[u8]| regex.is_match(&String::from_utf8_lossy(line));
+                diff(&old_lines, &new_lines).into_iter().any(|op| match op {
+                    DiffOp::Equal { .. } => false,
+                    DiffOp::Delete { old_index, old_len, .. } => {
+                        old_lines[old_index..old_index + old_len].iter().any(|line| is_match(line))
+                    }
+                    DiffOp::Insert { new_index, new_len, .. } => {
+                        new_lines[new_index..new_index + new_len].iter().any(|line| is_match(line))
+                    }
+                })
+            }
+        }
+    }
+}
+
+// =============================================================================
+// 도우미
+// =============================================================================
+
+/// 경로가 pathspec 에 맞는지
+///
+/// glob 문자('*', '?')가 없으면 같은 경로이거나 그 아래 파일이면 매칭
+pub fn matches_pathspec(spec: &str, path: &str) -> bool {
+    let spec = spec.trim_end_matches('/');
+    if spec.is_empty() || spec == "." {
+        return true;
+    }
+    if spec.contains(['*', '?']) {
+        return glob_match(spec.as_bytes(), path.as_bytes());
+    }
+    path == spec || path.strip_prefix(spec).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// '*' (0글자 이상), '?' (한 글자) 만 지원하는 glob
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+fn build_regex(pattern: &str, fixed: bool, ignore_case: bool, word: bool) -> Result<Regex, AppError> {
+    let mut source = if fixed { regex::escape(pattern) } else { pattern.to_string() };
+    if word {
+        source = format!(r"\b(?:{})\b", source);
+    }
+    RegexBuilder::new(&source)
+        .case_insensitive(ignore_case)
+        .build()
+        .map_err(|err| AppError::InvalidInput(format!("Invalid pattern '{}': {}", pattern, err)))
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Blob, Object, TreeEntry};
+
+    fn tree(store: &ObjectStore, files: &[(&str, &str)]) -> String {
+        let mut map = BTreeMap::new();
+        for (path, content) in files {
+            let blob = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+            map.insert(path.to_string(), TreeEntry::file(path.to_string(), blob));
+        }
+        store.write_flat_tree(&map).unwrap()
+    }
+
+    fn lines(hits: &GrepHits) -> Vec<(usize, bool)> {
+        match hits {
+            GrepHits::Lines(lines) => lines.iter().map(|line| (line.number, line.matched)).collect(),
+            GrepHits::Binary => panic!("binary"),
+        }
+    }
+
+    #[test]
+    fn test_search_with_context() {
+        let options = GrepOptions {
+            before_context: 1,
+            after_context: 1,
+            ..Default::default()
+        };
+        let grep = Grep::new("fn \\w+", &options).unwrap();
+        let hits = grep.search(b"a\nfn one()\nb\nc\nd\nfn two()\n").unwrap();
+        assert_eq!(lines(&hits), vec![(1, false), (2, true), (3, false), (5, false), (6, true)]);
+        assert!(grep.search(b"nothing here\n").is_none());
+        assert_eq!(grep.search(b"\0fn x"), Some(GrepHits::Binary));
+
+        let options = GrepOptions {
+            ignore_case: true,
+            fixed_strings: true,
+            word_regexp: true,
+            ..Default::default()
+        };
+        let grep = Grep::new("A.B", &options).unwrap();
+        assert_eq!(lines(&grep.search(b"a.b\naxb\nca.b\n").unwrap()), vec![(1, true)]);
+        assert!(matches!(Grep::new("(", &GrepOptions::default()), Err(AppError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_search_trees_with_pathspec() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let main = tree(&store, &[("src/lib.rs", "TODO: a\n"), ("README", "TODO\n"), ("src/x.txt", "TODO\n")]);
+        let topic = tree(&store, &[("src/lib.rs", "TODO: a\n"), ("src/new.rs", "done\nTODO: b\n")]);
+
+        let options = GrepOptions {
+            pathspecs: vec!["src/*.rs".to_string()],
+            threads: 2,
+            ..Default::default()
+        };
+        let grep = Grep::new("TODO", &options).unwrap();
+        let results = grep
+            .search_trees(&store, &[("main".to_string(), main), ("topic".to_string(), topic)])
+            .unwrap();
+        let found: Vec<(&str, &str)> = results
+            .iter()
+            .map(|result| (result.label.as_deref().unwrap(), result.path.as_str()))
+            .collect();
+        assert_eq!(found, vec![("main", "src/lib.rs"), ("topic", "src/lib.rs"), ("topic", "src/new.rs")]);
+        assert_eq!(lines(&results[2].hits), vec![(2, true)]);
+
+        assert!(matches_pathspec("src", "src/a/b.rs"));
+        assert!(!matches_pathspec("src", "srcx/b.rs"));
+        assert!(matches_pathspec("*.rs", "src/a/b.rs"));
+        assert!(!matches_pathspec("src/?.rs", "src/ab.rs"));
+    }
+
+    #[test]
+    fn test_pickaxe() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let actor = Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap();
+        let mut parent = None;
+        let mut commits = Vec::new();
+        for content in ["a\n", "a\nneedle()\n", "a\nneedle( )\n", "b\nneedle( )\n"] {
+            let tree = tree(&store, &[("f", content)]);
+            let commit = Commit::new(tree, parent.clone(), "m\n".into(), actor.clone(), actor.clone());
+            let hash = store.write(&Object::from(commit.clone())).unwrap();
+            parent = Some(hash.clone());
+            commits.push((hash, commit));
+        }
+
+        let matching = |pickaxe: &Pickaxe| -> Vec<usize> {
+            (0..commits.len())
+                .filter(|&i| pickaxe.matches_commit(&store, &commits[i].0, &commits[i].1).unwrap())
+                .collect()
+        };
+        // 등장 횟수는 추가할 때만 바뀜, 라인 수정은 -G 로만 보임
+        assert_eq!(matching(&Pickaxe::occurrences("needle", false).unwrap()), vec![1]);
+        assert_eq!(matching(&Pickaxe::occurrences("needle()", false).unwrap()), vec![1, 2]);
+        assert_eq!(matching(&Pickaxe::changed_lines("needle").unwrap()), vec![1, 2]);
+        assert_eq!(matching(&Pickaxe::occurrences("^b$", true).unwrap()), vec![]);
+        assert!(Pickaxe::occurrences("(?m)^b$", true).unwrap().matches_change(b"a\n", b"b\n"));
+    }
+}
@@ -35,6 +35,9 @@ pub const HASH_LENGTH: usize = 32;
 /// 32바이트 * 2 = 64자
 pub const HASH_HEX_LENGTH: usize = 64;
 
+/// 해시 알고리즘 이름 (저장소 설정 core.objectformat)
+pub const HASH_ALGORITHM: &str = "sha256";
+
 /// 파일 읽기 버퍼 크기 (8KB)
 /// 큰 파일을 청크 단위로 읽어서 메모리 효율적으로 해싱
 const BUFFER_SIZE: usize = 8 * 1024;
@@ -48,7 +51,7 @@ const BUFFER_SIZE: usize = 8 * 1024;
 /// 바이트 배열, 문자열, 파일 등을 해싱
 ///
 /// # Example
-/// ```
+/// ```no_run
 /// use core::hash::Hasher;
 ///
 /// let hasher = Hasher::new();
@@ -87,6 +90,7 @@ impl Hasher {
     ///
     /// # Example
     /// ```
+    /// # let hasher = core::hash::Hasher::new();
     /// let hash = hasher.hash_bytes(b"hello world");
     /// assert_eq!(hash.len(), 64);
     /// ```
@@ -126,8 +130,12 @@ impl Hasher {
     /// * `Err` - 파일 읽기 실패
     ///
     /// # Example
-    /// ```
+    /// ```no_run
+    /// # fn main() -> std::io::Result<()> {
+    /// # let hasher = core::hash::Hasher::new();
     /// let hash = hasher.hash_file("large_file.bin")?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn hash_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<String> {
         let file = File::open(path)?;
@@ -167,6 +175,7 @@ impl Hasher {
     ///
     /// # Example
     /// ```
+    /// # let hasher = core::hash::Hasher::new();
     /// let data = b"hello";
     /// let hash = hasher.hash_bytes(data);
     ///
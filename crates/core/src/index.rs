@@ -0,0 +1,424 @@
+// =============================================================================
+// 인덱스 (index.rs)
+// =============================================================================
+//
+// 다음 커밋에 들어갈 파일 목록 (스테이징 영역)
+//
+// 파일 형식 (.cts/index, 경로순 정렬):
+//   # cts index v1
+//   {모드} {Blob 해시} {크기} {수정 시각 ns}[ skip-worktree]\t{경로}
+//
+// - 크기/수정 시각은 작업 디렉토리 파일이 그대로인지 빠르게 판단하는 캐시
+//   (0 이면 모름 → 내용을 해시해서 비교)
+// - 서브모듈은 모드 160000, 해시는 서브모듈 저장소의 커밋
+//   (작업 디렉토리 상태는 서브모듈의 HEAD, 아직 받지 않았으면 변경 없음)
+// - skip-worktree: sparse checkout 범위 밖이라 작업 디렉토리에 풀지 않은 파일
+//   (작업 디렉토리에 없어도 삭제가 아니라 변경 없음으로 취급)
+// - 인덱스 파일이 없으면 HEAD 트리와 같은 것으로 취급 (Repository::read_index)
+//
+// 파일 위치: crates/core/src/index.rs
+// =============================================================================
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use shared::error::AppError;
+
+use crate::checkout::MODE_EXECUTABLE;
+use crate::object::{Blob, Object, TreeEntry, MODE_SUBMODULE};
+use crate::refs::LockFile;
+use crate::repo::CTS_DIR_NAME;
+use crate::sparse::SparsePatterns;
+use crate::store::{is_valid_hash, ObjectStore};
+
+/// .cts 안의 인덱스 파일 이름
+pub const INDEX_FILE: &str = "index";
+
+/// 인덱스 파일 첫 줄
+const INDEX_HEADER: &str = "# cts index v1";
+
+/// 일반 파일 모드
+pub const MODE_REGULAR: &str = "100644";
+
+/// skip-worktree 표시 필드
+const SKIP_WORKTREE_FLAG: &str = "skip-worktree";
+
+// =============================================================================
+// 인덱스 엔트리
+// =============================================================================
+
+/// 인덱스의 파일 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexEntry {
+    /// 파일 모드 ("100644", "100755", 서브모듈 "160000")
+    pub mode: String,
+    /// Blob 해시 (서브모듈은 커밋 해시)
+    pub hash: String,
+    /// 기록 당시 파일 크기 (0: 모름)
+    pub size: u64,
+    /// 기록 당시 수정 시각, Unix epoch 기준 나노초 (0: 모름)
+    pub mtime: i64,
+    /// sparse checkout 범위 밖 (작업 디렉토리에 없음)
+    pub skip_worktree: bool,
+}
+
+impl IndexEntry {
+    /// 파일 상태 정보 없이 생성 (트리에서 읽은 엔트리 등)
+    pub fn new(mode: impl Into<String>, hash: impl Into<String>) -> Self {
+        Self {
+            mode: mode.into(),
+            hash: hash.into(),
+            size: 0,
+            mtime: 0,
+            skip_worktree: false,
+        }
+    }
+
+    /// 작업 디렉토리 파일의 상태 정보로 생성
+    pub fn with_stat(hash: impl Into<String>, metadata: &fs::Metadata) -> Self {
+        Self {
+            mode: file_mode(metadata).to_string(),
+            hash: hash.into(),
+            size: metadata.len(),
+            mtime: mtime_nanos(metadata),
+            skip_worktree: false,
+        }
+    }
+
+    /// 트리 엔트리로 변환 (이름은 경로의 마지막 요소)
+    pub fn to_tree_entry(&self, path: &str) -> TreeEntry {
+        let name = path.rsplit('/').next().unwrap_or(path).to_string();
+        if self.mode == MODE_EXECUTABLE {
+            TreeEntry::executable(name, self.hash.clone())
+        } else if self.mode == MODE_SUBMODULE {
+            TreeEntry::submodule(name, self.hash.clone())
+        } else {
+            TreeEntry::file(name, self.hash.clone())
+        }
+    }
+
+    /// 파일 상태가 기록 당시와 같은지 (같으면 내용도 같다고 간주)
+    pub fn matches_stat(&self, metadata: &fs::Metadata) -> bool {
+        self.mtime != 0
+            && self.size == metadata.len()
+            && self.mtime == mtime_nanos(metadata)
+            && self.mode == file_mode(metadata)
+    }
+}
+
+// =============================================================================
+// 인덱스
+// =============================================================================
+
+/// 스테이징 영역 (경로 → 엔트리)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Index {
+    entries: BTreeMap<String, IndexEntry>,
+}
+
+impl Index {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 인덱스 파일 읽기 (없으면 None)
+    pub fn load(path: &Path) -> Result<Option<Self>, AppError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        Self::parse(&content).map(Some)
+    }
+
+    /// 인덱스 파일 기록 (잠금 파일 + rename)
+    pub fn save(&self, path: &Path) -> Result<(), AppError> {
+        let lock = LockFile::acquire(path)?;
+        lock.write(self.format().as_bytes())?;
+        lock.commit()
+    }
+
+    /// 트리 내용으로 인덱스 생성
+    pub fn from_tree(store: &ObjectStore, tree_hash: &str) -> Result<Self, AppError> {
+        let entries = store
+            .flatten_tree(tree_hash)?
+            .into_iter()
+            .map(|(path, entry)| (path, IndexEntry::new(entry.mode, entry.hash)))
+            .collect();
+        Ok(Self { entries })
+    }
+
+    /// 인덱스 내용을 트리 객체로 기록
+    ///
+    /// # Returns
+    /// 루트 트리 해시
+    pub fn write_tree(&self, store: &ObjectStore) -> Result<String, AppError> {
+        store.write_flat_tree(&self.tree_entries())
+    }
+
+    /// "경로 → 트리 엔트리" 맵 (ObjectStore::flatten_tree 와 같은 형태)
+    pub fn tree_entries(&self) -> BTreeMap<String, TreeEntry> {
+        self.entries
+            .iter()
+            .map(|(path, entry)| (path.clone(), entry.to_tree_entry(path)))
+            .collect()
+    }
+
+    pub fn get(&self, path: &str) -> Option<&IndexEntry> {
+        self.entries.get(path)
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.entries.contains_key(path)
+    }
+
+    pub fn insert(&mut self, path: impl Into<String>, entry: IndexEntry) {
+        self.entries.insert(path.into(), entry);
+    }
+
+    pub fn remove(&mut self, path: &str) -> Option<IndexEntry> {
+        self.entries.remove(path)
+    }
+
+    /// 경로순 엔트리
+    pub fn entries(&self) -> &BTreeMap<String, IndexEntry> {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 작업 디렉토리 파일을 Blob 으로 기록하고 인덱스에 추가
+    ///
+    /// # Returns
+    /// Blob 해시
+    pub fn stage_file(&mut self, store: &ObjectStore, work_dir: &Path, path: &str) -> Result<String, AppError> {
+        let full = work_dir.join(path);
+        let metadata = fs::metadata(&full)?;
+        let hash = store.write(&Object::from(Blob::new(fs::read(&full)?)))?;
+        self.insert(path, IndexEntry::with_stat(hash.clone(), &metadata));
+        Ok(hash)
+    }
+
+    /// sparse checkout 범위에 따라 skip-worktree 표시 갱신 (None 이면 모두 해제)
+    pub fn apply_sparse(&mut self, patterns: Option<&SparsePatterns>) {
+        for (path, entry) in self.entries.iter_mut() {
+            entry.skip_worktree = patterns.is_some_and(|patterns| !patterns.includes(path));
+        }
+    }
+
+    /// 작업 디렉토리 내용이 인덱스와 다른 경로 (수정 또는 삭제, 경로순)
+    ///
+    /// skip-worktree 파일은 작업 디렉토리와 상관없이 변경 없음
+    pub fn worktree_changes(&self, work_dir: &Path) -> Result<Vec<String>, AppError> {
+        let mut changed = Vec::new();
+        for (path, entry) in self.entries.iter().filter(|(_, entry)| !entry.skip_worktree) {
+            match worktree_hash(work_dir, path, Some(entry))? {
+                Some(current) if current.hash == entry.hash && current.mode == entry.mode => {}
+                _ => changed.push(path.clone()),
+            }
+        }
+        Ok(changed)
+    }
+
+    // -------------------------------------------------------------------------
+    // 직렬화
+    // -------------------------------------------------------------------------
+
+    fn format(&self) -> String {
+        let mut out = format!("{}\n", INDEX_HEADER);
+        for (path, entry) in &self.entries {
+            let flag = if entry.skip_worktree {
+                format!(" {}", SKIP_WORKTREE_FLAG)
+            } else {
+                String::new()
+            };
+            out.push_str(&format!(
+                "{} {} {} {}{}\t{}\n",
+                entry.mode, entry.hash, entry.size, entry.mtime, flag, path
+            ));
+        }
+        out
+    }
+
+    fn parse(content: &str) -> Result<Self, AppError> {
+        let mut lines = content.lines();
+        if lines.next() != Some(INDEX_HEADER) {
+            return Err(AppError::InvalidInput("Corrupt index: missing header".to_string()));
+        }
+        let mut entries = BTreeMap::new();
+        for line in lines.filter(|line| !line.is_empty()) {
+            let invalid = || AppError::InvalidInput(format!("Corrupt index line: {}", line));
+            let (fields, path) = line.split_once('\t').ok_or_else(invalid)?;
+            let fields: Vec<&str> = fields.split(' ').collect();
+            let (mode, hash, size, mtime, skip_worktree) = match fields[..] {
+                [mode, hash, size, mtime] => (mode, hash, size, mtime, false),
+                [mode, hash, size, mtime, SKIP_WORKTREE_FLAG] => (mode, hash, size, mtime, true),
+                _ => return Err(invalid()),
+            };
+            if !is_valid_hash(hash) || path.is_empty() {
+                return Err(invalid());
+            }
+            let entry = IndexEntry {
+                mode: mode.to_string(),
+                hash: hash.to_string(),
+                size: size.parse().map_err(|_| invalid())?,
+                mtime: mtime.parse().map_err(|_| invalid())?,
+                skip_worktree,
+            };
+            entries.insert(path.to_string(), entry);
+        }
+        Ok(Self { entries })
+    }
+}
+
+// =============================================================================
+// 작업 디렉토리
+// =============================================================================
+
+/// 작업 디렉토리의 모든 파일 경로 ('/' 구분, 정렬, .cts 와 중첩된 저장소 제외)
+pub fn worktree_files(work_dir: &Path) -> Result<Vec<String>, AppError> {
+    let mut files = Vec::new();
+    collect_files(work_dir, "", &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(dir: &Path, prefix: &str, files: &mut Vec<String>) -> Result<(), AppError> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name == CTS_DIR_NAME {
+            continue;
+        }
+        let file_type = entry.file_type()?;
+        let path = format!("{}{}", prefix, name);
+        if file_type.is_dir() && entry.path().join(CTS_DIR_NAME).exists() {
+            continue;
+        } else if file_type.is_dir() {
+            collect_files(&entry.path(), &format!("{}/", path), files)?;
+        } else if file_type.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// 작업 디렉토리 파일의 현재 Blob 해시 (파일이 없으면 None)
+///
+/// 인덱스 엔트리의 상태 정보가 그대로면 해시하지 않고 엔트리 값을 사용
+/// 서브모듈은 체크아웃된 커밋 (받지 않은 서브모듈은 인덱스 값 그대로)
+pub fn worktree_hash(work_dir: &Path, path: &str, cached: Option<&IndexEntry>) -> Result<Option<IndexEntry>, AppError> {
+    let full = work_dir.join(path);
+    if cached.is_some_and(|entry| entry.mode == MODE_SUBMODULE) || full.join(CTS_DIR_NAME).exists() {
+        return Ok(match crate::submodule::checked_out_commit(&full)? {
+            Some(commit) => Some(IndexEntry::new(MODE_SUBMODULE, commit)),
+            None => cached.cloned(),
+        });
+    }
+    let metadata = match fs::symlink_metadata(&full) {
+        Ok(metadata) if metadata.is_file() => metadata,
+        Ok(_) => return Ok(None),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if let Some(entry) = cached.filter(|entry| entry.matches_stat(&metadata)) {
+        return Ok(Some(entry.clone()));
+    }
+    let hash = Blob::new(fs::read(&full)?).hash().to_string();
+    Ok(Some(IndexEntry::with_stat(hash, &metadata)))
+}
+
+#[cfg(unix)]
+fn file_mode(metadata: &fs::Metadata) -> &'static str {
+    use std::os::unix::fs::PermissionsExt;
+    if metadata.permissions().mode() & 0o111 != 0 {
+        MODE_EXECUTABLE
+    } else {
+        MODE_REGULAR
+    }
+}
+
+#[cfg(not(unix))]
+fn file_mode(_metadata: &fs::Metadata) -> &'static str {
+    MODE_REGULAR
+}
+
+fn mtime_nanos(metadata: &fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_nanos() as i64)
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_roundtrip_and_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path().join("objects"));
+        let work = dir.path().join("work");
+        fs::create_dir_all(work.join("src")).unwrap();
+        fs::write(work.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(work.join("README"), "hi\n").unwrap();
+
+        let mut index = Index::new();
+        index.stage_file(&store, &work, "src/main.rs").unwrap();
+        index.stage_file(&store, &work, "README").unwrap();
+
+        let path = dir.path().join("index");
+        index.save(&path).unwrap();
+        let loaded = Index::load(&path).unwrap().unwrap();
+        assert_eq!(loaded, index);
+        assert!(Index::load(&dir.path().join("missing")).unwrap().is_none());
+
+        // 트리 ↔ 인덱스 (상태 정보는 트리에 없음)
+        let tree = index.write_tree(&store).unwrap();
+        let from_tree = Index::from_tree(&store, &tree).unwrap();
+        assert_eq!(from_tree.write_tree(&store).unwrap(), tree);
+        assert_eq!(from_tree.get("README").unwrap().size, 0);
+
+        fs::write(&path, "garbage\n").unwrap();
+        assert!(Index::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_worktree_files_and_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let work = dir.path();
+        fs::create_dir_all(work.join(".cts/objects")).unwrap();
+        fs::create_dir_all(work.join("a/b")).unwrap();
+        fs::write(work.join("a/b/c.txt"), "c\n").unwrap();
+        fs::write(work.join("z.txt"), "z\n").unwrap();
+        fs::write(work.join(".cts/HEAD"), "ref: refs/heads/main\n").unwrap();
+
+        assert_eq!(worktree_files(work).unwrap(), vec!["a/b/c.txt", "z.txt"]);
+
+        let entry = worktree_hash(work, "z.txt", None).unwrap().unwrap();
+        assert_eq!(entry.hash, Blob::new(b"z\n".to_vec()).hash());
+        assert!(worktree_hash(work, "missing.txt", None).unwrap().is_none());
+
+        // 상태 정보가 같으면 캐시된 해시 사용
+        let cached = IndexEntry { hash: "f".repeat(64), ..entry.clone() };
+        assert_eq!(worktree_hash(work, "z.txt", Some(&cached)).unwrap().unwrap().hash, "f".repeat(64));
+
+        let mut index = Index::new();
+        index.insert("z.txt", entry);
+        index.insert("gone.txt", IndexEntry::new(MODE_REGULAR, "0".repeat(64)));
+        assert_eq!(index.worktree_changes(work).unwrap(), vec!["gone.txt"]);
+    }
+}
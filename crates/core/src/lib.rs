@@ -10,7 +10,37 @@
 // 모듈 구성:
 // - hash: SHA-256 해싱 (데이터 무결성, 객체 식별)
 // - compression: zlib 압축/해제 (저장 공간 절약)
-// - object: 객체 모델 (Blob, Tree, Commit 포맷)
+// - object: 객체 모델 (Blob, Tree, Commit, Tag 포맷)
+// - store: 객체 저장소 (.cts/objects)
+// - repo: 로컬 저장소 (.cts 디렉토리, 리비전 해석)
+// - refs: 참조 저장소 (원자적 갱신, packed-refs, reflog)
+// - revwalk: 커밋 그래프 탐색
+// - diff: 라인 단위 diff
+// - blame: 라인별 마지막 변경 커밋 추적
+// - pack: 여러 객체를 하나로 묶는 팩 포맷
+// - bundle: 서버 없이 히스토리를 옮기는 번들 파일
+// - checkout: 트리를 작업 디렉토리에 풀어놓기
+// - index: 스테이징 영역 (.cts/index)
+// - merge: 3-way 라인 병합
+// - delta: 바이너리 델타
+// - patch: 패치 파일 내보내기/적용
+// - signing: 커밋/태그 서명 (Ed25519)
+// - trailer: 커밋 메시지 트레일러 (Signed-off-by 등)
+// - notes: 커밋에 덧붙이는 노트 (refs/notes/*)
+// - stash: 커밋하지 않은 변경 임시 보관 (refs/stash)
+// - sequencer: cherry-pick / revert / rebase (중단 후 재개)
+// - bisect: 문제를 만든 커밋 이분 탐색
+// - grep: 트리/작업 디렉토리 내용 검색, log -S/-G
+// - worktree: 저장소 하나에 연결된 여러 작업 디렉토리
+// - sparse: 일부 디렉토리만 작업 디렉토리에 풀기 (sparse checkout)
+// - config: 저장소 설정 (.cts/config)
+// - transport: 원격 저장소에서 참조/객체 받기 (clone, fetch, partial clone)
+// - shallow: 최근 히스토리만 받은 저장소의 경계 커밋 (shallow clone)
+// - submodule: 다른 저장소의 커밋을 하위 디렉토리로 포함 (.ctsmodules)
+// - archive: 커밋을 tar / tar.gz / zip 으로 내보내기 (재현 가능)
+// - fast_import: Git fast-export 스트림 가져오기 / fast-import 스트림 내보내기
+// - staging: 인덱스에 추가 / 제거 / 이름 변경 (add, rm, mv)
+// - status: HEAD / 인덱스 / 작업 디렉토리 비교
 //
 // 사용 예시:
 //   use core::hash::Hasher;
@@ -44,6 +74,158 @@ pub mod compression;
 /// - Commit: 스냅샷 (커밋)
 pub mod object;
 
+/// 객체 저장소 모듈
+///
+/// 객체를 압축해서 .cts/objects/ 아래에 저장
+/// - 해시 기반 주소 (content-addressable)
+/// - 읽을 때 해시 검증
+pub mod store;
+
+/// 로컬 저장소 모듈
+///
+/// .cts 디렉토리 탐색, 참조 읽기, 리비전 해석
+pub mod repo;
+
+/// 참조 저장소 모듈
+///
+/// 브랜치/태그/원격 추적 참조, 잠금 파일 기반 원자적 갱신, reflog
+pub mod refs;
+
+/// 커밋 그래프 탐색 모듈
+///
+/// 조상 커밋 집합, "A..B" 범위 탐색
+pub mod revwalk;
+
+/// 라인 diff 모듈
+///
+/// Myers 알고리즘 기반 라인 단위 diff
+pub mod diff;
+
+/// Blame 모듈
+///
+/// 파일의 각 라인을 마지막으로 변경한 커밋 추적
+pub mod blame;
+
+/// 팩 모듈
+///
+/// 여러 객체를 체크섬과 함께 하나의 바이트 스트림으로 직렬화
+pub mod pack;
+
+/// 번들 모듈
+///
+/// 참조 + 팩을 담은 단일 파일 (오프라인 전송용)
+pub mod bundle;
+
+/// 체크아웃 모듈
+///
+/// 트리 객체를 작업 디렉토리 파일로 기록
+pub mod checkout;
+
+/// 인덱스 모듈
+///
+/// 다음 커밋에 들어갈 경로 → Blob 목록, 작업 디렉토리 파일 상태 캐시
+pub mod index;
+
+/// 3-way 병합 모듈
+///
+/// 공통 조상 기준 라인 단위 병합, 충돌 마커 생성
+pub mod merge;
+
+/// 바이너리 델타 모듈
+///
+/// 원본 대비 복사/삽입 명령으로 대상 바이트 표현
+pub mod delta;
+
+/// 패치 모듈
+///
+/// 커밋 ↔ 메일용 패치 텍스트 (unified diff + 바이너리 델타)
+pub mod patch;
+
+/// 서명 모듈
+///
+/// Ed25519 키 생성/보관, 커밋·태그 서명과 검증
+pub mod signing;
+
+/// 트레일러 모듈
+///
+/// 커밋 메시지 마지막 문단의 "Key: value" 메타데이터 파싱/추가
+pub mod trailer;
+
+/// 노트 모듈
+///
+/// 커밋 해시를 바꾸지 않고 노트 참조의 트리에 커밋별 메모 기록, 이름공간, 병합
+pub mod notes;
+
+/// 스태시 모듈
+///
+/// 인덱스/작업 디렉토리 변경을 커밋으로 저장해 두었다가 3-way 로 다시 적용
+pub mod stash;
+
+/// 시퀀서 모듈
+///
+/// 커밋을 하나씩 3-way 로 다시 적용 (cherry-pick, revert, rebase), .cts/sequencer 에 진행 상태 보관
+pub mod sequencer;
+
+/// 이분 탐색 모듈
+///
+/// good/bad 판정으로 후보 커밋을 반씩 줄여 처음 나빠진 커밋 찾기
+pub mod bisect;
+
+/// 내용 검색 모듈
+///
+/// 체크아웃 없이 여러 리비전의 파일 내용을 병렬로 검색, 패턴을 추가/삭제한 커밋 찾기
+pub mod grep;
+
+/// 연결된 작업 디렉토리 모듈
+///
+/// 객체와 참조는 공유하고 HEAD, index 는 작업 디렉토리마다 따로
+pub mod worktree;
+
+/// sparse checkout 모듈
+///
+/// cone 패턴에 맞는 경로만 풀어놓고 나머지는 인덱스에 skip-worktree 로 표시
+pub mod sparse;
+
+/// 설정 모듈
+///
+/// .cts/config 의 "섹션.이름" 키-값 읽기/쓰기
+pub mod config;
+
+/// 전송 모듈
+///
+/// 로컬 경로나 HTTP 서버에서 참조 목록과 팩 받기, Blob 을 생략하는 필터
+pub mod transport;
+
+/// shallow clone 모듈
+///
+/// 경계 커밋 기록과 깊이 제한에 맞춰 보낼 커밋 고르기
+pub mod shallow;
+
+/// 서브모듈 모듈
+///
+/// .ctsmodules 로 경로와 원격 주소를 기록하고 트리에는 서브모듈 커밋만 저장
+pub mod submodule;
+
+/// 아카이브 모듈
+///
+/// 객체 저장소에서 바로 결정적인 tar / zip 배포 파일 생성 (export-ignore 지원)
+pub mod archive;
+
+/// Git 가져오기/내보내기 모듈
+///
+/// fast-export 스트림을 SHA-256 객체로 다시 해싱하고 Git SHA-1 매핑(.cts/git-map) 기록
+pub mod fast_import;
+
+/// 스테이징 모듈
+///
+/// pathspec 으로 작업 디렉토리 변경을 인덱스에 반영 (add / rm / mv)
+pub mod staging;
+
+/// 작업 상태 모듈
+///
+/// 커밋할 변경, 작업 디렉토리 변경, 추적하지 않는 파일 (sparse 범위 밖은 변경 없음)
+pub mod status;
+
 // -----------------------------------------------------------------------------
 // 재내보내기 (Re-exports)
 // -----------------------------------------------------------------------------
@@ -52,4 +234,6 @@ pub mod object;
 
 pub use hash::Hasher;
 pub use compression::{compress, decompress};
-pub use object::{Blob, Tree, TreeEntry, Commit, ObjectType};
\ No newline at end of file
+pub use object::{Blob, Tree, TreeEntry, Commit, Tag, Object, ObjectType};
+pub use store::ObjectStore;
+pub use repo::Repository;
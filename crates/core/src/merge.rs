@@ -0,0 +1,345 @@
+// =============================================================================
+// 3-way 병합 모듈 (merge.rs)
+// =============================================================================
+//
+// 공통 조상(base)과 두 버전(ours, theirs)의 라인 단위 병합 (diff3)
+//
+// 동작:
+// - base → ours, base → theirs 각각의 변경 구간을 계산
+// - 한쪽만 바꾼 구간은 그쪽 내용을 사용
+// - 양쪽이 같은 구간을 다르게 바꾸면 충돌 마커로 표시
+//     <<<<<<< ours
+//     (ours 내용)
+//     =======
+//     (theirs 내용)
+//     >>>>>>> theirs
+// - 맞닿은 변경도 충돌로 취급 (Git 과 같은 보수적 기준)
+//
+// merge_trees: 트리 단위 병합 (경로별로 위 규칙 적용, cherry-pick/revert/rebase 에서 사용)
+// - 한쪽만 바꾼 경로는 그쪽 엔트리
+// - 양쪽이 바꾼 텍스트 파일은 merge3 (충돌이면 마커가 든 Blob 을 기록)
+// - 한쪽 삭제 + 다른 쪽 수정, 양쪽 바이너리 수정은 충돌 (남아 있는/우리 쪽 엔트리 유지)
+//
+// 파일 위치: crates/core/src/merge.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use shared::error::AppError;
+
+use crate::diff::{changes, diff, split_lines, Change};
+use crate::object::{Blob, Object, TreeEntry};
+use crate::store::ObjectStore;
+
+/// 병합 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeResult {
+    /// 병합된 내용 (충돌 구간은 마커 포함)
+    pub content: Vec<u8>,
+    /// 충돌 구간 수
+    pub conflicts: usize,
+}
+
+impl MergeResult {
+    /// 충돌 없이 병합되었는지
+    pub fn is_clean(&self) -> bool {
+        self.conflicts == 0
+    }
+}
+
+/// 3-way 라인 병합
+///
+/// # Arguments
+/// * `base` - 공통 조상 내용
+/// * `ours` / `theirs` - 양쪽 버전 내용
+/// * `labels` - 충돌 마커에 붙일 (ours, theirs) 이름
+///
+/// # Example
+/// ```
+/// use core::merge::merge3;
+///
+/// let result = merge3(b"a\nb\nc\n", b"A\nb\nc\n", b"a\nb\nC\n", ("ours", "theirs"));
+/// assert!(result.is_clean());
+/// assert_eq!(result.content, b"A\nb\nC\n");
+/// ```
+pub fn merge3(base: &[u8], ours: &[u8], theirs: &[u8], labels: (&str, &str)) -> MergeResult {
+    let base_lines = split_lines(base);
+    let our_lines = split_lines(ours);
+    let their_lines = split_lines(theirs);
+
+    let ours_changes = changes(&diff(&base_lines, &our_lines));
+    let theirs_changes = changes(&diff(&base_lines, &their_lines));
+
+    let mut out: Vec<u8> = Vec::new();
+    let mut conflicts = 0;
+    let mut pos = 0;
+    let (mut i, mut j) = (0, 0);
+    // 각 쪽에서 지금까지 적용된 변경에 의한 라인 수 증감
+    let (mut our_shift, mut their_shift) = (0isize, 0isize);
+
+    while i < ours_changes.len() || j < theirs_changes.len() {
+        // 가장 먼저 시작하는 변경부터 겹치는 변경들을 모아 하나의 구간으로
+        let start = match (ours_changes.get(i), theirs_changes.get(j)) {
+            (Some(a), Some(b)) => a.old_start.min(b.old_start),
+            (Some(a), None) => a.old_start,
+            (None, Some(b)) => b.old_start,
+            (None, None) => unreachable!(),
+        };
+        let mut end = start;
+        let (first_i, first_j) = (i, j);
+        loop {
+            if let Some(c) = ours_changes.get(i).filter(|c| c.old_start <= end) {
+                end = end.max(c.old_start + c.old_len);
+                i += 1;
+            } else if let Some(c) = theirs_changes.get(j).filter(|c| c.old_start <= end) {
+                end = end.max(c.old_start + c.old_len);
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        // 구간 앞의 변경 없는 라인
+        for line in &base_lines[pos..start] {
+            out.extend_from_slice(line);
+        }
+
+        let our_delta = shift(&ours_changes[first_i..i]);
+        let their_delta = shift(&theirs_changes[first_j..j]);
+        let our_part = &our_lines[offset(start, our_shift)..offset(end, our_shift + our_delta)];
+        let their_part = &their_lines[offset(start, their_shift)..offset(end, their_shift + their_delta)];
+
+        if first_j == j || our_part == their_part {
+            append(&mut out, our_part);
+        } else if first_i == i {
+            append(&mut out, their_part);
+        } else {
+            conflicts += 1;
+            marker(&mut out, &format!("<<<<<<< {}", labels.0));
+            append_terminated(&mut out, our_part);
+            marker(&mut out, "=======");
+            append_terminated(&mut out, their_part);
+            marker(&mut out, &format!(">>>>>>> {}", labels.1));
+        }
+
+        our_shift += our_delta;
+        their_shift += their_delta;
+        pos = end;
+    }
+
+    for line in &base_lines[pos..] {
+        out.extend_from_slice(line);
+    }
+    MergeResult { content: out, conflicts }
+}
+
+/// 트리 병합 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeMergeResult {
+    /// 병합된 루트 트리 (충돌 경로는 마커가 든 내용 또는 남아 있는 쪽)
+    pub tree: String,
+    /// 충돌이 난 경로 (경로순)
+    pub conflicts: Vec<String>,
+}
+
+impl TreeMergeResult {
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// 3-way 트리 병합
+///
+/// # Arguments
+/// * `base` - 공통 조상 트리 (None 이면 빈 트리)
+/// * `ours` / `theirs` - 양쪽 트리
+/// * `labels` - 충돌 마커에 붙일 (ours, theirs) 이름
+pub fn merge_trees(
+    store: &ObjectStore,
+    base: Option<&str>,
+    ours: &str,
+    theirs: &str,
+    labels: (&str, &str),
+) -> Result<TreeMergeResult, AppError> {
+    let base = match base {
+        Some(tree) => store.flatten_tree(tree)?,
+        None => BTreeMap::new(),
+    };
+    let ours = store.flatten_tree(ours)?;
+    let theirs = store.flatten_tree(theirs)?;
+
+    let mut merged: BTreeMap<String, TreeEntry> = BTreeMap::new();
+    let mut conflicts = Vec::new();
+    let paths: BTreeSet<&String> = base.keys().chain(ours.keys()).chain(theirs.keys()).collect();
+    for path in paths {
+        let (b, o, t) = (base.get(path), ours.get(path), theirs.get(path));
+        let result = if o == t || t == b {
+            o.cloned()
+        } else if o == b {
+            t.cloned()
+        } else {
+            match (o, t) {
+                // 양쪽에서 다른 커밋으로 바꾼 서브모듈은 병합할 내용이 없으므로 충돌
+                (Some(o), Some(t)) if o.is_submodule() || t.is_submodule() => {
+                    conflicts.push(path.clone());
+                    Some(o.clone())
+                }
+                (Some(o), Some(t)) => {
+                    // 양쪽에서 새로 만든 파일은 빈 내용을 기준으로
+                    let read = |hash: &str| store.read_blob(hash).map(|blob| blob.content().to_vec());
+                    let base_content = match b {
+                        Some(b) => read(&b.hash)?,
+                        None => Vec::new(),
+                    };
+                    let (our_content, their_content) = (read(&o.hash)?, read(&t.hash)?);
+                    if [&base_content, &our_content, &their_content].iter().any(|c| c.contains(&0)) {
+                        conflicts.push(path.clone());
+                        Some(o.clone())
+                    } else {
+                        let result = merge3(&base_content, &our_content, &their_content, labels);
+                        if !result.is_clean() {
+                            conflicts.push(path.clone());
+                        }
+                        let hash = store.write(&Object::from(Blob::new(result.content)))?;
+                        // 모드는 바뀐 쪽을 따름
+                        let mode = if b.is_some_and(|b| b.mode != o.mode) { &o.mode } else { &t.mode };
+                        Some(TreeEntry { hash, mode: mode.clone(), ..o.clone() })
+                    }
+                }
+                // 한쪽은 삭제, 다른 쪽은 수정
+                (o, t) => {
+                    conflicts.push(path.clone());
+                    o.or(t).cloned()
+                }
+            }
+        };
+        if let Some(entry) = result {
+            merged.insert(path.clone(), entry);
+        }
+    }
+
+    Ok(TreeMergeResult {
+        tree: store.write_flat_tree(&merged)?,
+        conflicts,
+    })
+}
+
+/// 변경들에 의한 라인 수 증감
+fn shift(changes: &[Change]) -> isize {
+    changes
+        .iter()
+        .map(|c| c.new_len as isize - c.old_len as isize)
+        .sum()
+}
+
+fn offset(index: usize, shift: isize) -> usize {
+    (index as isize + shift) as usize
+}
+
+fn append(out: &mut Vec<u8>, lines: &[&[u8]]) {
+    for line in lines {
+        out.extend_from_slice(line);
+    }
+}
+
+/// 마지막 라인에 줄바꿈이 없으면 붙여서 추가 (마커가 같은 줄에 붙지 않도록)
+fn append_terminated(out: &mut Vec<u8>, lines: &[&[u8]]) {
+    append(out, lines);
+    if out.last().is_some_and(|&b| b != b'\n') {
+        out.push(b'\n');
+    }
+}
+
+fn marker(out: &mut Vec<u8>, text: &str) {
+    out.extend_from_slice(text.as_bytes());
+    out.push(b'\n');
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABELS: (&str, &str) = ("ours", "theirs");
+
+    #[test]
+    fn test_non_overlapping_changes() {
+        let base = b"1\n2\n3\n4\n5\n6\n7\n";
+        let ours = b"1\nTWO\n3\n4\n5\n6\n7\n";
+        let theirs = b"1\n2\n3\n4\n5\nSIX\n7\n8\n";
+        let result = merge3(base, ours, theirs, LABELS);
+        assert!(result.is_clean());
+        assert_eq!(result.content, b"1\nTWO\n3\n4\n5\nSIX\n7\n8\n");
+    }
+
+    #[test]
+    fn test_identical_changes_are_clean() {
+        let result = merge3(b"a\nb\n", b"a\nx\n", b"a\nx\n", LABELS);
+        assert!(result.is_clean());
+        assert_eq!(result.content, b"a\nx\n");
+    }
+
+    #[test]
+    fn test_conflict_markers() {
+        let result = merge3(b"a\nb\nc\n", b"a\nours\nc\n", b"a\ntheirs\nc\n", LABELS);
+        assert_eq!(result.conflicts, 1);
+        assert_eq!(
+            String::from_utf8(result.content).unwrap(),
+            "a\n<<<<<<< ours\nours\n=======\ntheirs\n>>>>>>> theirs\nc\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_trees() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let tree = |files: &[(&str, &str)]| {
+            let flat = files
+                .iter()
+                .map(|(path, content)| {
+                    let hash = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+                    (path.to_string(), TreeEntry::file(path.rsplit('/').next().unwrap().to_string(), hash))
+                })
+                .collect();
+            store.write_flat_tree(&flat).unwrap()
+        };
+        let base = tree(&[("a", "1\n2\n3\n"), ("b", "b\n"), ("c", "c\n")]);
+        let ours = tree(&[("a", "one\n2\n3\n"), ("b", "b\n"), ("c", "ours\n"), ("new", "ours\n")]);
+        let theirs = tree(&[("a", "1\n2\nthree\n"), ("dir/d", "d\n"), ("new", "theirs\n")]);
+
+        let result = merge_trees(&store, Some(&base), &ours, &theirs, LABELS).unwrap();
+        // c: 우리는 수정, 상대는 삭제 / new: 양쪽에서 다르게 추가
+        assert_eq!(result.conflicts, vec!["c", "new"]);
+        let files = store.flatten_tree(&result.tree).unwrap();
+        let read = |path: &str| String::from_utf8(store.read_blob(&files[path].hash).unwrap().content().to_vec()).unwrap();
+        assert_eq!(read("a"), "one\n2\nthree\n");
+        assert!(!files.contains_key("b"));
+        assert_eq!(read("c"), "ours\n");
+        assert_eq!(read("dir/d"), "d\n");
+        assert!(read("new").contains("<<<<<<< ours"));
+
+        let clean = merge_trees(&store, Some(&base), &base, &theirs, LABELS).unwrap();
+        assert!(clean.is_clean());
+        assert_eq!(clean.tree, theirs);
+    }
+
+    #[test]
+    fn test_one_side_unchanged() {
+        let result = merge3(b"a\nb\n", b"a\nb\n", b"x\ny\nz", LABELS);
+        assert!(result.is_clean());
+        assert_eq!(result.content, b"x\ny\nz");
+    }
+
+    #[test]
+    fn test_conflict_without_trailing_newline() {
+        let result = merge3(b"a", b"b", b"c", LABELS);
+        assert_eq!(result.conflicts, 1);
+        assert_eq!(
+            String::from_utf8(result.content).unwrap(),
+            "<<<<<<< ours\nb\n=======\nc\n>>>>>>> theirs\n"
+        );
+    }
+}
@@ -0,0 +1,530 @@
+// =============================================================================
+// 노트 (notes.rs)
+// =============================================================================
+//
+// 커밋 해시를 바꾸지 않고 커밋에 덧붙이는 메모 (빌드 결과, 리뷰 승인 등)
+//
+// 구조:
+//   refs/notes/commits ──→ 노트 커밋 ──→ 트리
+//                              │           ├── {커밋 해시} → Blob (노트 내용)
+//                              │           └── {커밋 해시} → Blob
+//                              └─ parent: 이전 노트 커밋
+//
+// - 이름공간마다 참조가 따로 있음 (refs/notes/commits, refs/notes/review, ...)
+// - 노트를 바꿀 때마다 새 노트 커밋을 만들어 참조를 compare-and-swap 으로 갱신
+// - 노트 커밋은 평범한 커밋이므로 번들로 그대로 전송 가능
+//
+// 병합:
+// - 공통 조상 노트 커밋 기준으로 커밋별 3-way 비교
+// - 양쪽이 같은 커밋의 노트를 다르게 바꾸면 전략에 따라 해결
+// - 커밋은 부모가 하나뿐이라 병합 커밋에는 우리 쪽만 부모로 기록됨
+//   → 다음 병합 때 같은 노트가 다시 충돌할 수 있으나 union/cat_sort_uniq 은 결과가 같음
+//
+// 파일 위치: crates/core/src/notes.rs
+// =============================================================================
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use shared::error::AppError;
+
+use crate::object::{Actor, Blob, Commit, Object, TreeEntry};
+use crate::refs::{check_ref_name, Expected, Identity, NOTES_PREFIX};
+use crate::repo::Repository;
+use crate::revwalk::{ancestors, merge_base};
+use crate::store::ObjectStore;
+
+/// 기본 노트 참조
+pub const DEFAULT_NOTES_REF: &str = "refs/notes/commits";
+
+/// 커밋 해시 → 노트 Blob 해시
+pub type NoteMap = BTreeMap<String, String>;
+
+/// 노트 이름공간을 전체 참조 이름으로 ("review" → "refs/notes/review")
+///
+/// # Example
+/// ```
+/// use core::notes::notes_ref_name;
+///
+/// assert_eq!(notes_ref_name("review").unwrap(), "refs/notes/review");
+/// assert_eq!(notes_ref_name("refs/notes/ci").unwrap(), "refs/notes/ci");
+/// assert!(notes_ref_name("refs/heads/main").is_err());
+/// ```
+pub fn notes_ref_name(name: &str) -> Result<String, AppError> {
+    let full = if name.starts_with("refs/") {
+        name.to_string()
+    } else {
+        format!("{}{}", NOTES_PREFIX, name)
+    };
+    if !full.starts_with(NOTES_PREFIX) {
+        return Err(AppError::InvalidInput(format!("Notes refs must live under {}: {}", NOTES_PREFIX, name)));
+    }
+    check_ref_name(&full)?;
+    Ok(full)
+}
+
+// =============================================================================
+// 노트 트리 읽기/쓰기
+// =============================================================================
+
+/// 노트 커밋의 트리에서 노트 목록 읽기
+pub fn read_note_map(store: &ObjectStore, notes_commit: &str) -> Result<NoteMap, AppError> {
+    let tree = store.read_commit(notes_commit)?.tree_hash;
+    Ok(store
+        .flatten_tree(&tree)?
+        .into_iter()
+        .map(|(commit, entry)| (commit, entry.hash))
+        .collect())
+}
+
+/// 노트 목록으로 새 노트 커밋 생성
+pub fn write_notes_commit(
+    store: &ObjectStore,
+    parent: Option<String>,
+    notes: &NoteMap,
+    message: &str,
+    actor: Actor,
+) -> Result<String, AppError> {
+    let files = notes
+        .iter()
+        .map(|(commit, blob)| (commit.clone(), TreeEntry::file(commit.clone(), blob.clone())))
+        .collect();
+    let tree = store.write_flat_tree(&files)?;
+    let commit = Commit::new(tree, parent, format!("{}\n", message), actor.clone(), actor);
+    store.write(&Object::from(commit))
+}
+
+// =============================================================================
+// 병합
+// =============================================================================
+
+/// 같은 커밋의 노트가 양쪽에서 다르게 바뀌었을 때 해결 방법
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotesMergeStrategy {
+    /// 해결하지 않고 충돌로 보고
+    #[default]
+    Manual,
+    /// 우리 쪽 노트
+    Ours,
+    /// 상대 쪽 노트
+    Theirs,
+    /// 우리 노트 뒤에 상대 노트를 이어 붙임
+    Union,
+    /// 양쪽 줄을 합쳐 정렬 후 중복/빈 줄 제거 (빌드 결과 목록 등)
+    CatSortUniq,
+}
+
+impl FromStr for NotesMergeStrategy {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manual" => Ok(Self::Manual),
+            "ours" => Ok(Self::Ours),
+            "theirs" => Ok(Self::Theirs),
+            "union" => Ok(Self::Union),
+            "cat_sort_uniq" => Ok(Self::CatSortUniq),
+            _ => Err(AppError::InvalidInput(format!(
+                "Unknown notes merge strategy '{}' (manual, ours, theirs, union, cat_sort_uniq)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for NotesMergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Manual => "manual",
+            Self::Ours => "ours",
+            Self::Theirs => "theirs",
+            Self::Union => "union",
+            Self::CatSortUniq => "cat_sort_uniq",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 노트 목록 병합 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteMapMerge {
+    /// 병합된 노트 (Manual 충돌은 우리 쪽 노트 유지)
+    pub notes: NoteMap,
+    /// 해결하지 못한 커밋 해시 (Manual 전략일 때만)
+    pub conflicts: Vec<String>,
+}
+
+/// 노트 목록 3-way 병합
+///
+/// 커밋별로 base/ours/theirs 노트 Blob 을 비교
+/// - 한쪽만 바꾸면(추가/수정/삭제) 그쪽을 따름
+/// - 양쪽이 다르게 바꾸면 strategy 로 해결 (Union/CatSortUniq 는 새 Blob 기록)
+pub fn merge_note_maps(
+    store: &ObjectStore,
+    base: &NoteMap,
+    ours: &NoteMap,
+    theirs: &NoteMap,
+    strategy: NotesMergeStrategy,
+) -> Result<NoteMapMerge, AppError> {
+    let mut notes = NoteMap::new();
+    let mut conflicts = Vec::new();
+
+    let mut commits: Vec<&String> = ours.keys().chain(theirs.keys()).collect();
+    commits.sort();
+    commits.dedup();
+
+    for commit in commits {
+        let b = base.get(commit);
+        let o = ours.get(commit);
+        let t = theirs.get(commit);
+
+        let merged = if o == t || t == b {
+            o.cloned()
+        } else if o == b {
+            t.cloned()
+        } else {
+            match (strategy, o, t) {
+                (NotesMergeStrategy::Manual, _, _) => {
+                    conflicts.push(commit.clone());
+                    o.cloned()
+                }
+                (NotesMergeStrategy::Ours, _, _) => o.cloned(),
+                (NotesMergeStrategy::Theirs, _, _) => t.cloned(),
+                // 한쪽이 삭제했으면 남은 쪽
+                (_, None, _) => t.cloned(),
+                (_, _, None) => o.cloned(),
+                (NotesMergeStrategy::Union, Some(o), Some(t)) => {
+                    let mut content = store.read_blob(o)?.content().to_vec();
+                    if !content.is_empty() && !content.ends_with(b"\n") {
+                        content.push(b'\n');
+                    }
+                    content.extend_from_slice(store.read_blob(t)?.content());
+                    Some(store.write(&Object::from(Blob::new(content)))?)
+                }
+                (NotesMergeStrategy::CatSortUniq, Some(o), Some(t)) => {
+                    let ours = String::from_utf8_lossy(store.read_blob(o)?.content()).into_owned();
+                    let theirs = String::from_utf8_lossy(store.read_blob(t)?.content()).into_owned();
+                    let mut lines: Vec<&str> =
+                        ours.lines().chain(theirs.lines()).filter(|line| !line.trim().is_empty()).collect();
+                    lines.sort_unstable();
+                    lines.dedup();
+                    let content = lines.iter().map(|line| format!("{}\n", line)).collect::<String>();
+                    Some(store.write(&Object::from(Blob::new(content.into_bytes())))?)
+                }
+            }
+        };
+        if let Some(blob) = merged {
+            notes.insert(commit.clone(), blob);
+        }
+    }
+    Ok(NoteMapMerge { notes, conflicts })
+}
+
+/// 노트 참조 병합 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotesMergeOutcome {
+    /// 상대 노트가 이미 포함됨
+    UpToDate,
+    /// 상대 노트 커밋으로 참조만 이동
+    FastForward(String),
+    /// 새 병합 노트 커밋 생성
+    Merged(String),
+    /// Manual 전략에서 해결하지 못한 커밋들 (참조는 그대로)
+    Conflicts(Vec<String>),
+}
+
+// =============================================================================
+// 노트 이름공간
+// =============================================================================
+
+/// 저장소의 노트 이름공간 하나 (refs/notes/...)
+pub struct Notes<'a> {
+    repo: &'a Repository,
+    name: String,
+}
+
+impl<'a> Notes<'a> {
+    /// 이름공간 열기 ("commits", "review" 또는 전체 참조 이름)
+    pub fn open(repo: &'a Repository, name: &str) -> Result<Self, AppError> {
+        Ok(Self {
+            repo,
+            name: notes_ref_name(name)?,
+        })
+    }
+
+    /// 전체 참조 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 현재 노트 커밋 (노트가 하나도 없었으면 None)
+    pub fn tip(&self) -> Result<Option<String>, AppError> {
+        self.repo.read_ref(&self.name)
+    }
+
+    /// 모든 노트 (커밋 해시 → 노트 Blob 해시)
+    pub fn list(&self) -> Result<NoteMap, AppError> {
+        match self.tip()? {
+            Some(tip) => read_note_map(self.repo.objects(), &tip),
+            None => Ok(NoteMap::new()),
+        }
+    }
+
+    /// 커밋의 노트 내용
+    pub fn get(&self, commit: &str) -> Result<Option<Vec<u8>>, AppError> {
+        match self.list()?.get(commit) {
+            Some(blob) => Ok(Some(self.repo.objects().read_blob(blob)?.content().to_vec())),
+            None => Ok(None),
+        }
+    }
+
+    /// 노트 추가
+    ///
+    /// # Errors
+    /// * `AppError::AlreadyExists` - 이미 노트가 있고 force 가 아님
+    pub fn add(&self, commit: &str, content: &[u8], force: bool, identity: &Identity) -> Result<(), AppError> {
+        if !force && self.list()?.contains_key(commit) {
+            return Err(AppError::AlreadyExists(format!(
+                "Note for {} (use --force to overwrite)",
+                commit
+            )));
+        }
+        self.write(commit, Some(content), identity, "Notes added by 'cts notes add'")
+    }
+
+    /// 기존 노트 뒤에 덧붙이기 (없으면 새로 추가)
+    pub fn append(&self, commit: &str, content: &[u8], identity: &Identity) -> Result<(), AppError> {
+        let mut merged = self.get(commit)?.unwrap_or_default();
+        if !merged.is_empty() {
+            if !merged.ends_with(b"\n") {
+                merged.push(b'\n');
+            }
+            merged.push(b'\n');
+        }
+        merged.extend_from_slice(content);
+        self.write(commit, Some(&merged), identity, "Notes added by 'cts notes append'")
+    }
+
+    /// 노트 삭제
+    ///
+    /// # Errors
+    /// * `AppError::NotFound` - 노트가 없음
+    pub fn remove(&self, commit: &str, identity: &Identity) -> Result<(), AppError> {
+        if !self.list()?.contains_key(commit) {
+            return Err(AppError::NotFound(format!("Note for {}", commit)));
+        }
+        self.write(commit, None, identity, "Notes removed by 'cts notes remove'")
+    }
+
+    /// 다른 노트 커밋(원격에서 가져온 노트 등)을 병합
+    pub fn merge(
+        &self,
+        theirs: &str,
+        strategy: NotesMergeStrategy,
+        identity: &Identity,
+    ) -> Result<NotesMergeOutcome, AppError> {
+        let store = self.repo.objects();
+        let reason = format!("notes: merge {}", &theirs[..theirs.len().min(8)]);
+        let Some(ours) = self.tip()? else {
+            self.repo.refs().update(&self.name, theirs, Expected::Missing, identity, &reason)?;
+            return Ok(NotesMergeOutcome::FastForward(theirs.to_string()));
+        };
+
+        if ancestors(store, std::slice::from_ref(&ours))?.contains(theirs) {
+            return Ok(NotesMergeOutcome::UpToDate);
+        }
+        let base = merge_base(store, &ours, theirs)?;
+        if base.as_deref() == Some(ours.as_str()) {
+            self.repo.refs().update(&self.name, theirs, Expected::Value(ours), identity, &reason)?;
+            return Ok(NotesMergeOutcome::FastForward(theirs.to_string()));
+        }
+
+        let base_notes = match &base {
+            Some(base) => read_note_map(store, base)?,
+            None => NoteMap::new(),
+        };
+        let merge = merge_note_maps(
+            store,
+            &base_notes,
+            &read_note_map(store, &ours)?,
+            &read_note_map(store, theirs)?,
+            strategy,
+        )?;
+        if !merge.conflicts.is_empty() {
+            return Ok(NotesMergeOutcome::Conflicts(merge.conflicts));
+        }
+
+        let message = format!(
+            "Notes merged by 'cts notes merge' from {} (strategy: {})",
+            theirs, strategy
+        );
+        let actor = Actor::now(identity.name.clone(), identity.email.clone());
+        let commit = write_notes_commit(store, Some(ours.clone()), &merge.notes, &message, actor)?;
+        self.repo.refs().update(&self.name, &commit, Expected::Value(ours), identity, &reason)?;
+        Ok(NotesMergeOutcome::Merged(commit))
+    }
+
+    /// 노트 하나를 바꾼 새 노트 커밋을 만들고 참조 갱신
+    fn write(&self, commit: &str, content: Option<&[u8]>, identity: &Identity, message: &str) -> Result<(), AppError> {
+        let store = self.repo.objects();
+        let tip = self.tip()?;
+        let mut notes = match &tip {
+            Some(tip) => read_note_map(store, tip)?,
+            None => NoteMap::new(),
+        };
+        match content {
+            Some(content) => {
+                let blob = store.write(&Object::from(Blob::new(content.to_vec())))?;
+                notes.insert(commit.to_string(), blob);
+            }
+            None => {
+                notes.remove(commit);
+            }
+        }
+
+        let actor = Actor::now(identity.name.clone(), identity.email.clone());
+        let new = write_notes_commit(store, tip.clone(), &notes, message, actor)?;
+        let expected = match tip {
+            Some(tip) => Expected::Value(tip),
+            None => Expected::Missing,
+        };
+        self.repo.refs().update(&self.name, &new, expected, identity, message)
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Tree;
+
+    fn setup() -> (tempfile::TempDir, Repository, Vec<String>) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let tree = repo.objects().write(&Object::from(Tree::new())).unwrap();
+        let mut commits = Vec::new();
+        for i in 0..3 {
+            let actor = Actor::parse(&format!("Tester <tester@example.com> {} +0000", 1705314600 + i)).unwrap();
+            let commit = Commit::new(tree.clone(), commits.last().cloned(), format!("c{}\n", i), actor.clone(), actor);
+            commits.push(repo.objects().write(&Object::from(commit)).unwrap());
+        }
+        (dir, repo, commits)
+    }
+
+    fn identity() -> Identity {
+        Identity::new("Tester", "tester@example.com")
+    }
+
+    #[test]
+    fn test_add_show_remove() {
+        let (_dir, repo, commits) = setup();
+        let notes = Notes::open(&repo, "commits").unwrap();
+        assert_eq!(notes.name(), DEFAULT_NOTES_REF);
+        assert!(notes.tip().unwrap().is_none());
+
+        notes.add(&commits[0], b"build: passed\n", false, &identity()).unwrap();
+        assert_eq!(notes.get(&commits[0]).unwrap().unwrap(), b"build: passed\n");
+        assert!(matches!(
+            notes.add(&commits[0], b"again\n", false, &identity()),
+            Err(AppError::AlreadyExists(_))
+        ));
+
+        notes.append(&commits[0], b"deploy: staging\n", &identity()).unwrap();
+        assert_eq!(notes.get(&commits[0]).unwrap().unwrap(), b"build: passed\n\ndeploy: staging\n");
+
+        // 노트를 붙여도 커밋 해시는 그대로, 노트 히스토리는 쌓임
+        let tip = notes.tip().unwrap().unwrap();
+        assert!(repo.objects().read_commit(&tip).unwrap().parent_hash.is_some());
+        assert_eq!(repo.objects().read_commit(&commits[0]).unwrap().message, "c0\n");
+
+        notes.remove(&commits[0], &identity()).unwrap();
+        assert!(notes.get(&commits[0]).unwrap().is_none());
+        assert!(matches!(notes.remove(&commits[0], &identity()), Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_namespaces_are_independent() {
+        let (_dir, repo, commits) = setup();
+        let ci = Notes::open(&repo, "ci").unwrap();
+        let review = Notes::open(&repo, "refs/notes/review").unwrap();
+        ci.add(&commits[1], b"green\n", false, &identity()).unwrap();
+        review.add(&commits[1], b"Approved-by: Jane\n", false, &identity()).unwrap();
+
+        assert_eq!(ci.get(&commits[1]).unwrap().unwrap(), b"green\n");
+        assert_eq!(review.get(&commits[1]).unwrap().unwrap(), b"Approved-by: Jane\n");
+        assert!(Notes::open(&repo, "refs/heads/main").is_err());
+    }
+
+    #[test]
+    fn test_merge_concurrent_updates() {
+        let (_dir, repo, commits) = setup();
+        let notes = Notes::open(&repo, "commits").unwrap();
+        notes.add(&commits[0], b"base\n", false, &identity()).unwrap();
+        let base = notes.tip().unwrap().unwrap();
+
+        // 상대: commits[1] 노트 추가, commits[0] 노트 수정
+        notes.add(&commits[1], b"theirs only\n", false, &identity()).unwrap();
+        notes.add(&commits[0], b"theirs\n", true, &identity()).unwrap();
+        let theirs = notes.tip().unwrap().unwrap();
+
+        // 우리: base 에서 commits[2] 노트 추가, commits[0] 노트 수정
+        repo.refs()
+            .update(DEFAULT_NOTES_REF, &base, Expected::Value(theirs.clone()), &identity(), "reset")
+            .unwrap();
+        notes.add(&commits[2], b"ours only\n", false, &identity()).unwrap();
+        notes.add(&commits[0], b"ours\n", true, &identity()).unwrap();
+        let ours = notes.tip().unwrap().unwrap();
+
+        let outcome = notes.merge(&theirs, NotesMergeStrategy::Manual, &identity()).unwrap();
+        assert_eq!(outcome, NotesMergeOutcome::Conflicts(vec![commits[0].clone()]));
+        assert_eq!(notes.tip().unwrap().unwrap(), ours);
+
+        let outcome = notes.merge(&theirs, NotesMergeStrategy::Union, &identity()).unwrap();
+        assert!(matches!(outcome, NotesMergeOutcome::Merged(_)));
+        assert_eq!(notes.get(&commits[0]).unwrap().unwrap(), b"ours\ntheirs\n");
+        assert_eq!(notes.get(&commits[1]).unwrap().unwrap(), b"theirs only\n");
+        assert_eq!(notes.get(&commits[2]).unwrap().unwrap(), b"ours only\n");
+
+        assert_eq!(
+            notes.merge(&base, NotesMergeStrategy::Manual, &identity()).unwrap(),
+            NotesMergeOutcome::UpToDate
+        );
+    }
+
+    #[test]
+    fn test_merge_fast_forward_and_strategies() {
+        let (_dir, repo, commits) = setup();
+        let remote = Notes::open(&repo, "remotes/origin/commits").unwrap();
+        remote.add(&commits[0], b"b\na\n", false, &identity()).unwrap();
+        let theirs = remote.tip().unwrap().unwrap();
+
+        let notes = Notes::open(&repo, "commits").unwrap();
+        assert_eq!(
+            notes.merge(&theirs, NotesMergeStrategy::Manual, &identity()).unwrap(),
+            NotesMergeOutcome::FastForward(theirs.clone())
+        );
+
+        let store = repo.objects();
+        let blob = |text: &str| store.write(&Object::from(Blob::new(text.as_bytes().to_vec()))).unwrap();
+        let base = NoteMap::from([(commits[0].clone(), blob("a\n"))]);
+        let ours = NoteMap::from([(commits[0].clone(), blob("c\na\n"))]);
+        let theirs = NoteMap::from([(commits[0].clone(), blob("b\na\n"))]);
+        let merged = |strategy| {
+            let merge = merge_note_maps(store, &base, &ours, &theirs, strategy).unwrap();
+            store.read_blob(&merge.notes[&commits[0]]).unwrap().content().to_vec()
+        };
+        assert_eq!(merged(NotesMergeStrategy::Ours), b"c\na\n");
+        assert_eq!(merged(NotesMergeStrategy::Theirs), b"b\na\n");
+        assert_eq!(merged(NotesMergeStrategy::CatSortUniq), b"a\nb\nc\n");
+
+        // 한쪽 삭제 + 다른 쪽 수정 → 남은 쪽
+        let deleted = NoteMap::new();
+        let merge = merge_note_maps(store, &base, &deleted, &theirs, NotesMergeStrategy::Union).unwrap();
+        assert_eq!(merge.notes, theirs);
+        assert_eq!("cat_sort_uniq".parse::<NotesMergeStrategy>().unwrap(), NotesMergeStrategy::CatSortUniq);
+    }
+}
@@ -9,6 +9,14 @@
 // - Blob: 파일 내용 (바이너리/텍스트)
 // - Tree: 디렉토리 구조 (파일/폴더 목록)
 // - Commit: 스냅샷 (tree + 메타데이터)
+// - Tag: 주석 태그 (대상 객체 + 태거 + 메시지)
+//
+// 트리 엔트리는 다른 저장소의 커밋을 가리킬 수도 있음 (서브모듈, 모드 160000)
+// - 가리키는 커밋은 이 저장소에 없음 → 객체 탐색/전송 대상이 아님
+//
+// Commit 과 Tag 는 선택적으로 서명(signature 헤더)을 가질 수 있음
+// - 서명 대상(payload): signature 헤더를 뺀 본문
+// - 객체 해시: signature 헤더를 포함한 본문 → 서명도 히스토리의 일부
 //
 // 파일 위치: crates/core/src/object.rs
 //
@@ -19,7 +27,13 @@
 // =============================================================================
 
 use serde::{Deserialize, Serialize};
+use shared::error::AppError;
+use shared::types::{format_epoch, local_offset_minutes, now, parse_epoch, with_offset, LocalTimestamp, Timestamp};
 use crate::hash::{Hasher, HASH_HEX_LENGTH};
+use crate::trailer::{parse_trailers, Trailer};
+
+/// 서브모듈(다른 저장소의 커밋) 트리 엔트리 모드
+pub const MODE_SUBMODULE: &str = "160000";
 
 // =============================================================================
 // 객체 타입 열거형
@@ -27,7 +41,7 @@ use crate::hash::{Hasher, HASH_HEX_LENGTH};
 
 /// CTS 객체 타입
 ///
-/// 모든 CTS 객체는 이 세 가지 타입 중 하나
+/// 모든 CTS 객체는 이 네 가지 타입 중 하나
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ObjectType {
@@ -37,6 +51,32 @@ pub enum ObjectType {
     Tree,
     /// 커밋 (스냅샷)
     Commit,
+    /// 주석 태그
+    Tag,
+}
+
+impl ObjectType {
+    /// 트리 엔트리 모드로부터 객체 타입 추론
+    ///
+    /// "040000" = 디렉토리 (Tree), "160000" = 서브모듈 (Commit), 그 외 = 파일 (Blob)
+    pub fn from_mode(mode: &str) -> Self {
+        match mode {
+            "040000" => ObjectType::Tree,
+            MODE_SUBMODULE => ObjectType::Commit,
+            _ => ObjectType::Blob,
+        }
+    }
+
+    /// 객체 헤더의 타입 문자열 파싱
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "blob" => Some(ObjectType::Blob),
+            "tree" => Some(ObjectType::Tree),
+            "commit" => Some(ObjectType::Commit),
+            "tag" => Some(ObjectType::Tag),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ObjectType {
@@ -45,6 +85,7 @@ impl std::fmt::Display for ObjectType {
             ObjectType::Blob => write!(f, "blob"),
             ObjectType::Tree => write!(f, "tree"),
             ObjectType::Commit => write!(f, "commit"),
+            ObjectType::Tag => write!(f, "tag"),
         }
     }
 }
@@ -67,7 +108,7 @@ impl std::fmt::Display for ObjectType {
 /// use core::object::Blob;
 ///
 /// let content = b"hello world";
-/// let blob = Blob::new(content.to_vec());
+/// let mut blob = Blob::new(content.to_vec());
 ///
 /// println!("Hash: {}", blob.hash());
 /// println!("Size: {} bytes", blob.size());
@@ -119,12 +160,9 @@ impl Blob {
     /// 처음 호출 시 계산, 이후 캐시된 값 반환
     pub fn hash(&mut self) -> &str {
         if self.hash.is_none() {
-            let hasher = Hasher::new();
             // Blob 해시: "blob {size}\0{content}" 형식 (Git 호환)
-            let header = format!("blob {}\0", self.content.len());
-            let mut data = header.into_bytes();
-            data.extend_from_slice(&self.content);
-            self.hash = Some(hasher.hash_bytes(&data));
+            let data = encode_object(ObjectType::Blob, &self.content);
+            self.hash = Some(Hasher::new().hash_bytes(&data));
         }
         self.hash.as_ref().unwrap()
     }
@@ -153,16 +191,16 @@ impl Blob {
 
 /// TreeEntry - 트리의 개별 항목
 ///
-/// 파일 또는 하위 디렉토리를 나타냄
+/// 파일, 하위 디렉토리 또는 서브모듈 커밋을 나타냄
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TreeEntry {
     /// 파일/디렉토리 이름 (경로 아님, 이름만)
     pub name: String,
-    /// 객체 타입 (blob 또는 tree)
+    /// 객체 타입 (blob, tree 또는 서브모듈의 commit)
     pub object_type: ObjectType,
     /// 참조하는 객체의 해시
     pub hash: String,
-    /// 파일 모드 (예: "100644" = 일반 파일, "100755" = 실행 파일, "040000" = 디렉토리)
+    /// 파일 모드 (예: "100644" = 일반 파일, "100755" = 실행 파일, "040000" = 디렉토리, "160000" = 서브모듈)
     pub mode: String,
 }
 
@@ -197,6 +235,16 @@ impl TreeEntry {
         }
     }
 
+    /// 새 서브모듈 엔트리 생성 (hash 는 서브모듈 저장소의 커밋)
+    pub fn submodule(name: String, hash: String) -> Self {
+        Self {
+            name,
+            object_type: ObjectType::Commit,
+            hash,
+            mode: MODE_SUBMODULE.to_string(),
+        }
+    }
+
     /// 파일인지 확인
     pub fn is_file(&self) -> bool {
         self.object_type == ObjectType::Blob
@@ -206,6 +254,11 @@ impl TreeEntry {
     pub fn is_directory(&self) -> bool {
         self.object_type == ObjectType::Tree
     }
+
+    /// 서브모듈인지 확인
+    pub fn is_submodule(&self) -> bool {
+        self.object_type == ObjectType::Commit
+    }
 }
 
 /// Tree - 디렉토리 구조
@@ -281,23 +334,57 @@ impl Tree {
     /// 해시 계산
     pub fn hash(&mut self) -> &str {
         if self.hash.is_none() {
-            let hasher = Hasher::new();
-            // Tree 해시: 모든 엔트리의 정렬된 직렬화
-            let mut data = Vec::new();
-            for entry in &self.entries {
-                // "{mode} {name}\0{hash_bytes}" 형식 (Git 유사)
-                let line = format!("{} {}\0", entry.mode, entry.name);
-                data.extend_from_slice(line.as_bytes());
-                // 해시는 hex가 아닌 raw bytes로 (간단히 hex 사용)
-                data.extend_from_slice(entry.hash.as_bytes());
-            }
-            let header = format!("tree {}\0", data.len());
-            let mut full_data = header.into_bytes();
-            full_data.extend(data);
-            self.hash = Some(hasher.hash_bytes(&full_data));
+            let data = encode_object(ObjectType::Tree, &self.body());
+            self.hash = Some(Hasher::new().hash_bytes(&data));
         }
         self.hash.as_ref().unwrap()
     }
+
+    /// 트리 본문 직렬화 (헤더 제외)
+    ///
+    /// 모든 엔트리의 정렬된 직렬화
+    /// "{mode} {name}\0{hash}" 형식 (Git 유사, 해시는 raw bytes 대신 hex 사용)
+    pub fn body(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        for entry in &self.entries {
+            let line = format!("{} {}\0", entry.mode, entry.name);
+            data.extend_from_slice(line.as_bytes());
+            data.extend_from_slice(entry.hash.as_bytes());
+        }
+        data
+    }
+
+    /// 트리 본문 파싱
+    ///
+    /// body()의 역변환
+    /// 각 엔트리의 해시는 HASH_HEX_LENGTH 길이의 hex 문자열이어야 함
+    pub fn parse(body: &[u8]) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput("Malformed tree object".to_string());
+        let mut entries = Vec::new();
+        let mut rest = body;
+
+        while !rest.is_empty() {
+            let space = rest.iter().position(|&b| b == b' ').ok_or_else(invalid)?;
+            let nul = rest.iter().position(|&b| b == 0).ok_or_else(invalid)?;
+            if nul < space || rest.len() < nul + 1 + HASH_HEX_LENGTH {
+                return Err(invalid());
+            }
+            let mode = std::str::from_utf8(&rest[..space]).map_err(|_| invalid())?;
+            let name = std::str::from_utf8(&rest[space + 1..nul]).map_err(|_| invalid())?;
+            let hash = std::str::from_utf8(&rest[nul + 1..nul + 1 + HASH_HEX_LENGTH])
+                .map_err(|_| invalid())?;
+
+            entries.push(TreeEntry {
+                name: name.to_string(),
+                object_type: ObjectType::from_mode(mode),
+                hash: hash.to_string(),
+                mode: mode.to_string(),
+            });
+            rest = &rest[nul + 1 + HASH_HEX_LENGTH..];
+        }
+
+        Ok(Self::with_entries(entries))
+    }
 }
 
 impl Default for Tree {
@@ -306,6 +393,69 @@ impl Default for Tree {
     }
 }
 
+// =============================================================================
+// Actor (작성자/커미터/태거)
+// =============================================================================
+
+/// Actor - 누가 언제
+///
+/// 헤더 형식: "{이름} <{이메일}> {epoch 초} {±HHMM}"
+/// - time: UTC 시각
+/// - offset: 작성 당시 시간대 (UTC 대비 분, 동쪽이 양수)
+///
+/// # Example
+/// ```
+/// use core::object::Actor;
+///
+/// let actor = Actor::parse("Jane Doe <jane@example.com> 1705314600 +0900").unwrap();
+/// assert_eq!(actor.name, "Jane Doe");
+/// assert_eq!(actor.offset, 540);
+/// assert_eq!(actor.local_time().to_rfc3339(), "2024-01-15T19:30:00+09:00");
+/// assert_eq!(actor.to_string(), "Jane Doe <jane@example.com> 1705314600 +0900");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Actor {
+    /// 이름
+    pub name: String,
+    /// 이메일
+    pub email: String,
+    /// 시각 (UTC)
+    pub time: Timestamp,
+    /// UTC 오프셋 (분)
+    pub offset: i32,
+}
+
+impl Actor {
+    pub fn new(name: String, email: String, time: Timestamp, offset: i32) -> Self {
+        Self { name, email, time, offset }
+    }
+
+    /// 현재 시각, 시스템 시간대로 생성
+    pub fn now(name: String, email: String) -> Self {
+        Self::new(name, email, now(), local_offset_minutes())
+    }
+
+    /// 작성 당시 현지 시각
+    pub fn local_time(&self) -> LocalTimestamp {
+        with_offset(&self.time, self.offset)
+    }
+
+    /// 헤더 값 파싱 (시각과 오프셋 검증 포함)
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput(format!("Invalid identity: {}", value));
+        let (name, rest) = value.rsplit_once(" <").ok_or_else(invalid)?;
+        let (email, stamp) = rest.split_once("> ").ok_or_else(invalid)?;
+        let (time, offset) = parse_epoch(stamp)?;
+        Ok(Self::new(name.to_string(), email.to_string(), time, offset))
+    }
+}
+
+impl std::fmt::Display for Actor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}> {}", self.name, self.email, format_epoch(&self.time, self.offset))
+    }
+}
+
 // =============================================================================
 // Commit (커밋)
 // =============================================================================
@@ -321,8 +471,9 @@ impl Default for Tree {
 ///   ├── tree_hash ──→ Tree (루트 디렉토리)
 ///   ├── parent_hash ──→ 이전 Commit (없으면 첫 커밋)
 ///   ├── message: "커밋 메시지"
-///   ├── author: "이름 <이메일>"
-///   └── timestamp: "2024-01-15T10:30:00Z"
+///   ├── author: 변경을 작성한 사람 + 작성 시각
+///   ├── committer: 커밋을 만든 사람 + 커밋 시각 (am, rebase 등에서는 author 와 다름)
+///   └── signature: 서명 (선택)
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Commit {
@@ -332,12 +483,13 @@ pub struct Commit {
     pub parent_hash: Option<String>,
     /// 커밋 메시지
     pub message: String,
-    /// 작성자 이름
-    pub author_name: String,
-    /// 작성자 이메일
-    pub author_email: String,
-    /// 커밋 시간 (ISO 8601 형식)
-    pub timestamp: String,
+    /// 작성자
+    pub author: Actor,
+    /// 커미터
+    pub committer: Actor,
+    /// 서명 (서명되지 않은 커밋이면 None)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
     /// 커밋 해시 (캐시)
     #[serde(skip_serializing_if = "Option::is_none")]
     hash: Option<String>,
@@ -349,30 +501,23 @@ impl Commit {
         tree_hash: String,
         parent_hash: Option<String>,
         message: String,
-        author_name: String,
-        author_email: String,
-        timestamp: String,
+        author: Actor,
+        committer: Actor,
     ) -> Self {
         Self {
             tree_hash,
             parent_hash,
             message,
-            author_name,
-            author_email,
-            timestamp,
+            author,
+            committer,
+            signature: None,
             hash: None,
         }
     }
 
     /// 첫 커밋 생성 (부모 없음)
-    pub fn initial(
-        tree_hash: String,
-        message: String,
-        author_name: String,
-        author_email: String,
-        timestamp: String,
-    ) -> Self {
-        Self::new(tree_hash, None, message, author_name, author_email, timestamp)
+    pub fn initial(tree_hash: String, message: String, author: Actor, committer: Actor) -> Self {
+        Self::new(tree_hash, None, message, author, committer)
     }
 
     /// 첫 커밋인지 확인
@@ -380,34 +525,347 @@ impl Commit {
         self.parent_hash.is_none()
     }
 
+    /// 메시지의 트레일러 ("Signed-off-by: ..." 등)
+    pub fn trailers(&self) -> Vec<Trailer> {
+        parse_trailers(&self.message)
+    }
+
     /// 해시 계산
     pub fn hash(&mut self) -> &str {
         if self.hash.is_none() {
-            let hasher = Hasher::new();
-            // Commit 해시: 메타데이터 직렬화
-            let parent = self.parent_hash.as_deref().unwrap_or("");
-            let content = format!(
-                "tree {}\nparent {}\nauthor {} <{}>\ndate {}\n\n{}",
-                self.tree_hash,
-                parent,
-                self.author_name,
-                self.author_email,
-                self.timestamp,
-                self.message
-            );
-            let header = format!("commit {}\0", content.len());
-            let full_data = format!("{}{}", header, content);
-            self.hash = Some(hasher.hash_bytes(full_data.as_bytes()));
+            let data = encode_object(ObjectType::Commit, self.body().as_bytes());
+            self.hash = Some(Hasher::new().hash_bytes(&data));
         }
         self.hash.as_ref().unwrap()
     }
 
+    /// 커밋 본문 직렬화 (헤더 제외)
+    ///
+    /// 메타데이터 + 빈 줄 + 메시지
+    pub fn body(&self) -> String {
+        self.format(self.signature.as_ref())
+    }
+
+    /// 서명 대상 본문 (signature 헤더 제외)
+    pub fn signed_payload(&self) -> String {
+        self.format(None)
+    }
+
+    /// 서명 설정 (캐시된 해시는 무효화)
+    pub fn set_signature(&mut self, signature: Option<Signature>) {
+        self.signature = signature;
+        self.hash = None;
+    }
+
+    fn format(&self, signature: Option<&Signature>) -> String {
+        let parent = self.parent_hash.as_deref().unwrap_or("");
+        let mut text = format!(
+            "tree {}\nparent {}\nauthor {}\ncommitter {}\n",
+            self.tree_hash, parent, self.author, self.committer,
+        );
+        if let Some(signature) = signature {
+            text.push_str(&format!("signature {}\n", signature));
+        }
+        text.push('\n');
+        text.push_str(&self.message);
+        text
+    }
+
+    /// 커밋 본문 파싱
+    ///
+    /// body()의 역변환
+    /// 작성자/커미터의 시각과 오프셋이 형식에 맞지 않으면 에러
+    pub fn parse(body: &[u8]) -> Result<Self, AppError> {
+        let invalid = |what: &str| AppError::InvalidInput(format!("Malformed commit object: {}", what));
+        let text = std::str::from_utf8(body).map_err(|_| invalid("not UTF-8"))?;
+        let (headers, message) = text.split_once("\n\n").ok_or_else(|| invalid("missing message"))?;
+
+        let mut tree_hash = None;
+        let mut parent_hash = None;
+        let mut author = None;
+        let mut committer = None;
+        let mut signature = None;
+
+        for line in headers.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "tree" => tree_hash = Some(value.to_string()),
+                "parent" if !value.is_empty() => parent_hash = Some(value.to_string()),
+                "parent" => {}
+                "author" => author = Some(Actor::parse(value).map_err(|_| invalid("author"))?),
+                "committer" => committer = Some(Actor::parse(value).map_err(|_| invalid("committer"))?),
+                "signature" => signature = Some(Signature::parse(value).ok_or_else(|| invalid("signature"))?),
+                _ => return Err(invalid(key)),
+            }
+        }
+
+        let mut commit = Self::new(
+            tree_hash.ok_or_else(|| invalid("missing tree"))?,
+            parent_hash,
+            message.to_string(),
+            author.ok_or_else(|| invalid("missing author"))?,
+            committer.ok_or_else(|| invalid("missing committer"))?,
+        );
+        commit.signature = signature;
+        Ok(commit)
+    }
+
     /// 캐시된 해시 반환
     pub fn cached_hash(&self) -> Option<&str> {
         self.hash.as_deref()
     }
 }
 
+// =============================================================================
+// Tag (주석 태그)
+// =============================================================================
+
+/// Tag - 주석 태그
+///
+/// 가벼운 태그(refs/tags/x → 커밋)와 달리 태거, 메시지, 서명을 가진 객체
+/// refs/tags/x 가 이 객체를 가리킴
+///
+/// # 구조
+/// ```text
+/// object {대상 해시}
+/// type commit
+/// tag v1.0
+/// tagger 이름 <이메일> 1705314600 +0900
+/// signature ed25519 {공개키} {서명}   (선택)
+///
+/// 메시지
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    /// 대상 객체 해시
+    pub object_hash: String,
+    /// 대상 객체 타입
+    pub object_type: ObjectType,
+    /// 태그 이름 ("refs/tags/" 제외)
+    pub name: String,
+    /// 태거
+    pub tagger: Actor,
+    /// 태그 메시지
+    pub message: String,
+    /// 서명 (서명되지 않은 태그면 None)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+}
+
+impl Tag {
+    /// 커밋을 가리키는 새 태그 생성
+    pub fn new(commit_hash: String, name: String, message: String, tagger: Actor) -> Self {
+        Self {
+            object_hash: commit_hash,
+            object_type: ObjectType::Commit,
+            name,
+            tagger,
+            message,
+            signature: None,
+        }
+    }
+
+    /// 태그 본문 직렬화 (헤더 제외)
+    pub fn body(&self) -> String {
+        self.format(self.signature.as_ref())
+    }
+
+    /// 서명 대상 본문 (signature 헤더 제외)
+    pub fn signed_payload(&self) -> String {
+        self.format(None)
+    }
+
+    fn format(&self, signature: Option<&Signature>) -> String {
+        let mut text = format!(
+            "object {}\ntype {}\ntag {}\ntagger {}\n",
+            self.object_hash, self.object_type, self.name, self.tagger,
+        );
+        if let Some(signature) = signature {
+            text.push_str(&format!("signature {}\n", signature));
+        }
+        text.push('\n');
+        text.push_str(&self.message);
+        text
+    }
+
+    /// 태그 본문 파싱
+    ///
+    /// body()의 역변환
+    pub fn parse(body: &[u8]) -> Result<Self, AppError> {
+        let invalid = |what: &str| AppError::InvalidInput(format!("Malformed tag object: {}", what));
+        let text = std::str::from_utf8(body).map_err(|_| invalid("not UTF-8"))?;
+        let (headers, message) = text.split_once("\n\n").ok_or_else(|| invalid("missing message"))?;
+
+        let mut object_hash = None;
+        let mut object_type = None;
+        let mut name = None;
+        let mut tagger = None;
+        let mut signature = None;
+
+        for line in headers.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "object" => object_hash = Some(value.to_string()),
+                "type" => object_type = Some(ObjectType::parse(value).ok_or_else(|| invalid("type"))?),
+                "tag" => name = Some(value.to_string()),
+                "tagger" => tagger = Some(Actor::parse(value).map_err(|_| invalid("tagger"))?),
+                "signature" => signature = Some(Signature::parse(value).ok_or_else(|| invalid("signature"))?),
+                _ => return Err(invalid(key)),
+            }
+        }
+
+        Ok(Self {
+            object_hash: object_hash.ok_or_else(|| invalid("missing object"))?,
+            object_type: object_type.ok_or_else(|| invalid("missing type"))?,
+            name: name.ok_or_else(|| invalid("missing tag"))?,
+            tagger: tagger.ok_or_else(|| invalid("missing tagger"))?,
+            message: message.to_string(),
+            signature,
+        })
+    }
+}
+
+// =============================================================================
+// Signature (서명)
+// =============================================================================
+
+/// 커밋/태그 서명
+///
+/// 헤더 형식: "signature {알고리즘} {공개키 hex} {서명 hex}"
+/// 생성과 검증은 signing 모듈에서 담당 (여기서는 형식만 다룸)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Signature {
+    /// 서명 알고리즘 (현재 "ed25519" 만 사용)
+    pub algorithm: String,
+    /// 서명한 공개키 (hex)
+    pub public_key: String,
+    /// 서명 값 (hex)
+    pub value: String,
+}
+
+impl Signature {
+    /// 헤더 값 파싱 ("{알고리즘} {공개키} {서명}")
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split(' ');
+        let signature = Self {
+            algorithm: parts.next()?.to_string(),
+            public_key: parts.next()?.to_string(),
+            value: parts.next()?.to_string(),
+        };
+        if parts.next().is_some() || signature.public_key.is_empty() || signature.value.is_empty() {
+            return None;
+        }
+        Some(signature)
+    }
+}
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.algorithm, self.public_key, self.value)
+    }
+}
+
+// =============================================================================
+// Object (저장 단위)
+// =============================================================================
+
+/// 객체 직렬화: "{type} {size}\0{body}"
+///
+/// 해시 계산과 객체 저장소가 같은 바이트를 사용하므로
+/// 저장된 파일의 해시 = 객체 해시
+fn encode_object(object_type: ObjectType, body: &[u8]) -> Vec<u8> {
+    let mut data = format!("{} {}\0", object_type, body.len()).into_bytes();
+    data.extend_from_slice(body);
+    data
+}
+
+/// Object - 타입에 무관한 객체
+///
+/// 객체 저장소에서 읽고 쓰는 단위
+/// 헤더("{type} {size}\0")로 타입을 구분
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Object {
+    Blob(Blob),
+    Tree(Tree),
+    Commit(Commit),
+    Tag(Tag),
+}
+
+impl Object {
+    /// 객체 타입
+    pub fn object_type(&self) -> ObjectType {
+        match self {
+            Object::Blob(_) => ObjectType::Blob,
+            Object::Tree(_) => ObjectType::Tree,
+            Object::Commit(_) => ObjectType::Commit,
+            Object::Tag(_) => ObjectType::Tag,
+        }
+    }
+
+    /// 헤더 포함 직렬화
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Object::Blob(blob) => encode_object(ObjectType::Blob, blob.content()),
+            Object::Tree(tree) => encode_object(ObjectType::Tree, &tree.body()),
+            Object::Commit(commit) => encode_object(ObjectType::Commit, commit.body().as_bytes()),
+            Object::Tag(tag) => encode_object(ObjectType::Tag, tag.body().as_bytes()),
+        }
+    }
+
+    /// 헤더 포함 바이트 파싱
+    ///
+    /// 헤더의 크기와 실제 본문 크기가 다르면 에러
+    pub fn decode(data: &[u8]) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput("Malformed object header".to_string());
+        let nul = data.iter().position(|&b| b == 0).ok_or_else(invalid)?;
+        let header = std::str::from_utf8(&data[..nul]).map_err(|_| invalid())?;
+        let (kind, size) = header.split_once(' ').ok_or_else(invalid)?;
+        let object_type = ObjectType::parse(kind).ok_or_else(invalid)?;
+        let size: usize = size.parse().map_err(|_| invalid())?;
+
+        let body = &data[nul + 1..];
+        if body.len() != size {
+            return Err(invalid());
+        }
+
+        Ok(match object_type {
+            ObjectType::Blob => Object::Blob(Blob::new(body.to_vec())),
+            ObjectType::Tree => Object::Tree(Tree::parse(body)?),
+            ObjectType::Commit => Object::Commit(Commit::parse(body)?),
+            ObjectType::Tag => Object::Tag(Tag::parse(body)?),
+        })
+    }
+
+    /// 객체 해시 (SHA-256, hex)
+    pub fn hash(&self) -> String {
+        Hasher::new().hash_bytes(&self.encode())
+    }
+}
+
+impl From<Blob> for Object {
+    fn from(blob: Blob) -> Self {
+        Object::Blob(blob)
+    }
+}
+
+impl From<Tree> for Object {
+    fn from(tree: Tree) -> Self {
+        Object::Tree(tree)
+    }
+}
+
+impl From<Commit> for Object {
+    fn from(commit: Commit) -> Self {
+        Object::Commit(commit)
+    }
+}
+
+impl From<Tag> for Object {
+    fn from(tag: Tag) -> Self {
+        Object::Tag(tag)
+    }
+}
+
 // =============================================================================
 // 테스트
 // =============================================================================
@@ -416,6 +874,10 @@ impl Commit {
 mod tests {
     use super::*;
 
+    fn actor(name: &str) -> Actor {
+        Actor::parse(&format!("{} <{}@example.com> 1705314600 +0900", name, name.to_lowercase())).unwrap()
+    }
+
     #[test]
     fn test_blob_new() {
         let content = b"hello world";
@@ -458,6 +920,12 @@ mod tests {
         assert!(!dir.is_file());
         assert!(dir.is_directory());
         assert_eq!(dir.mode, "040000");
+
+        let module = TreeEntry::submodule("lib".into(), "0".repeat(64));
+        assert!(module.is_submodule() && !module.is_file() && !module.is_directory());
+        let mut tree = Tree::with_entries(vec![module.clone()]);
+        assert_eq!(Tree::parse(&tree.body()).unwrap().entries(), &[module]);
+        assert!(!tree.hash().is_empty());
     }
 
     #[test]
@@ -494,9 +962,8 @@ mod tests {
         let mut commit = Commit::initial(
             "tree_hash_123".into(),
             "Initial commit".into(),
-            "John Doe".into(),
-            "john@example.com".into(),
-            "2024-01-15T10:30:00Z".into(),
+            actor("John Doe"),
+            actor("John Doe"),
         );
 
         assert!(commit.is_initial());
@@ -509,9 +976,8 @@ mod tests {
             "tree_hash_456".into(),
             Some("parent_hash_123".into()),
             "Second commit".into(),
-            "Jane Doe".into(),
-            "jane@example.com".into(),
-            "2024-01-15T11:00:00Z".into(),
+            actor("Jane Doe"),
+            actor("Jane Doe"),
         );
 
         assert!(!commit.is_initial());
@@ -523,5 +989,127 @@ mod tests {
         assert_eq!(format!("{}", ObjectType::Blob), "blob");
         assert_eq!(format!("{}", ObjectType::Tree), "tree");
         assert_eq!(format!("{}", ObjectType::Commit), "commit");
+        assert_eq!(format!("{}", ObjectType::Tag), "tag");
+    }
+
+    #[test]
+    fn test_object_roundtrip() {
+        let blob = Object::from(Blob::new(b"hello\0world".to_vec()));
+        assert_eq!(Object::decode(&blob.encode()).unwrap(), blob);
+
+        let tree = Object::from(Tree::with_entries(vec![
+            TreeEntry::file("a.txt".into(), "1".repeat(HASH_HEX_LENGTH)),
+            TreeEntry::directory("src".into(), "2".repeat(HASH_HEX_LENGTH)),
+        ]));
+        assert_eq!(Object::decode(&tree.encode()).unwrap(), tree);
+
+        let commit = Object::from(Commit::new(
+            "3".repeat(HASH_HEX_LENGTH),
+            Some("4".repeat(HASH_HEX_LENGTH)),
+            "Subject\n\nBody line".into(),
+            actor("Jane Doe"),
+            actor("Jane Doe"),
+        ));
+        assert_eq!(Object::decode(&commit.encode()).unwrap(), commit);
+    }
+
+    #[test]
+    fn test_object_hash_matches_cached_hash() {
+        let mut blob = Blob::new(b"content".to_vec());
+        let expected = blob.hash().to_string();
+        assert_eq!(Object::from(Blob::new(b"content".to_vec())).hash(), expected);
+
+        let mut commit = Commit::initial(
+            "5".repeat(HASH_HEX_LENGTH),
+            "msg".into(),
+            actor("A"),
+            actor("A"),
+        );
+        let expected = commit.hash().to_string();
+        let mut fresh = commit.clone();
+        fresh.hash = None;
+        assert_eq!(Object::from(fresh).hash(), expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_object_decode_rejects_bad_size() {
+        assert!(Object::decode(b"blob 10\0short").is_err());
+        assert!(Object::decode(b"unknown 0\0").is_err());
+    }
+
+    #[test]
+    fn test_signature_excluded_from_payload_but_hashed() {
+        let mut commit = Commit::initial(
+            "6".repeat(HASH_HEX_LENGTH),
+            "Signed commit".into(),
+            actor("A"),
+            actor("A"),
+        );
+        let unsigned_hash = commit.hash().to_string();
+        let payload = commit.signed_payload();
+
+        commit.set_signature(Some(Signature {
+            algorithm: "ed25519".into(),
+            public_key: "ab".repeat(32),
+            value: "cd".repeat(64),
+        }));
+        assert_eq!(commit.signed_payload(), payload);
+        assert!(commit.body().contains("\nsignature ed25519 "));
+        assert_ne!(commit.hash(), unsigned_hash);
+
+        let object = Object::from(commit);
+        assert_eq!(Object::decode(&object.encode()).unwrap().encode(), object.encode());
+    }
+
+    #[test]
+    fn test_tag_roundtrip() {
+        let mut tag = Tag::new(
+            "7".repeat(HASH_HEX_LENGTH),
+            "v1.0".into(),
+            "Release 1.0\n".into(),
+            actor("Jane Doe"),
+        );
+        let object = Object::from(tag.clone());
+        assert_eq!(object.object_type(), ObjectType::Tag);
+        assert_eq!(Object::decode(&object.encode()).unwrap(), object);
+
+        tag.signature = Signature::parse(&format!("ed25519 {} {}", "1".repeat(64), "2".repeat(128)));
+        assert!(tag.signature.is_some());
+        let signed = Object::from(tag);
+        assert_eq!(Object::decode(&signed.encode()).unwrap(), signed);
+        assert_ne!(signed.hash(), object.hash());
+
+        assert!(Signature::parse("ed25519 onlykey").is_none());
+    }
+
+    #[test]
+    fn test_author_and_committer_with_timezone() {
+        let author = Actor::parse("Jane Doe <jane@example.com> 1705314600 -0230").unwrap();
+        let committer = Actor::parse("Maintainer <m@example.com> 1705400000 +0900").unwrap();
+        let commit = Commit::initial("8".repeat(HASH_HEX_LENGTH), "Applied\n".into(), author, committer);
+
+        let body = commit.body();
+        assert!(body.contains("\nauthor Jane Doe <jane@example.com> 1705314600 -0230\n"));
+        assert!(body.contains("\ncommitter Maintainer <m@example.com> 1705400000 +0900\n"));
+
+        let decoded = Commit::parse(body.as_bytes()).unwrap();
+        assert_eq!(decoded, commit);
+        assert_eq!(decoded.author.offset, -150);
+        assert_eq!(decoded.author.local_time().to_rfc3339(), "2024-01-15T08:00:00-02:30");
+    }
+
+    #[test]
+    fn test_commit_decode_validates_timestamps() {
+        let body = |stamp: &str| {
+            format!("tree {}\nparent \nauthor A <a@example.com> {}\ncommitter A <a@example.com> 0 +0000\n\nmsg", "9".repeat(HASH_HEX_LENGTH), stamp)
+        };
+        assert!(Commit::parse(body("1705314600 +0000").as_bytes()).is_ok());
+        for bad in ["2024-01-15T10:30:00Z", "1705314600", "1705314600 0900", "1705314600 +09", "1705314600 +0960", "abc +0000"] {
+            assert!(Commit::parse(body(bad).as_bytes()).is_err(), "{}", bad);
+        }
+
+        // 커미터 누락
+        let no_committer = format!("tree {}\nparent \nauthor A <a@example.com> 0 +0000\n\nmsg", "9".repeat(HASH_HEX_LENGTH));
+        assert!(Commit::parse(no_committer.as_bytes()).is_err());
+    }
+}
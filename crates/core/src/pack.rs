@@ -0,0 +1,233 @@
+// =============================================================================
+// 팩 파일 모듈 (pack.rs)
+// =============================================================================
+//
+// 여러 객체를 하나의 바이트 스트림으로 묶는 포맷
+// 번들 파일, 네트워크 전송 등 객체를 한꺼번에 옮길 때 사용
+//
+// 포맷 (정수는 모두 big-endian):
+//   "CPCK"                      매직 (4바이트)
+//   version: u32                포맷 버전 (현재 1)
+//   count: u32                  객체 개수
+//   count × {
+//     length: u32               압축된 객체 길이
+//     data: [u8; length]        zlib 압축된 "{type} {size}\0{body}"
+//   }
+//   checksum: [u8; 64]          앞의 모든 바이트의 SHA-256 (hex)
+//
+// 팩은 네트워크에서 받은 그대로 파싱하므로 헤더의 개수와 압축 해제 결과를 믿지 않음
+// (개수는 남은 본문 길이로, 압축 해제는 객체별/전체 크기 제한으로 확인)
+//
+// 파일 위치: crates/core/src/pack.rs
+// =============================================================================
+
+use shared::error::AppError;
+
+use crate::compression::{compress, decompress_with_limit};
+use crate::hash::{Hasher, HASH_HEX_LENGTH};
+use crate::store::ObjectStore;
+
+// -----------------------------------------------------------------------------
+// 상수
+// -----------------------------------------------------------------------------
+
+/// 팩 매직 바이트
+const PACK_MAGIC: &[u8; 4] = b"CPCK";
+
+/// 팩 포맷 버전
+const PACK_VERSION: u32 = 1;
+
+/// 객체 하나의 최대 크기 (압축 해제 후)
+const MAX_OBJECT_SIZE: usize = 256 * 1024 * 1024;
+
+/// 팩 전체의 최대 크기 (압축 해제 후)
+const MAX_UNPACKED_SIZE: usize = 2 * 1024 * 1024 * 1024;
+
+/// 객체 항목의 최소 크기 (길이 필드)
+const ENTRY_HEADER_SIZE: usize = 4;
+
+// =============================================================================
+// 쓰기
+// =============================================================================
+
+/// 객체들을 팩으로 직렬화
+///
+/// # Arguments
+/// * `store` - 객체를 읽을 저장소
+/// * `hashes` - 포함할 객체 해시 목록
+pub fn build_pack(store: &ObjectStore, hashes: &[String]) -> Result<Vec<u8>, AppError> {
+    let mut pack = Vec::new();
+    pack.extend_from_slice(PACK_MAGIC);
+    pack.extend_from_slice(&PACK_VERSION.to_be_bytes());
+    pack.extend_from_slice(&(hashes.len() as u32).to_be_bytes());
+
+    for hash in hashes {
+        let compressed = compress(&store.read_raw(hash)?)?;
+        pack.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        pack.extend_from_slice(&compressed);
+    }
+
+    let checksum = Hasher::new().hash_bytes(&pack);
+    pack.extend_from_slice(checksum.as_bytes());
+    Ok(pack)
+}
+
+// =============================================================================
+// 읽기
+// =============================================================================
+
+/// 팩 파싱 (체크섬 검증 포함)
+///
+/// # Returns
+/// 직렬화된 객체 목록 ("{type} {size}\0{body}")
+///
+/// # Errors
+/// * `AppError::HashMismatch` - 체크섬 불일치
+/// * `AppError::InvalidInput` - 형식 오류, 본문보다 많은 객체 개수, 크기 제한 초과
+pub fn parse_pack(pack: &[u8]) -> Result<Vec<Vec<u8>>, AppError> {
+    parse_pack_with_limits(pack, MAX_OBJECT_SIZE, MAX_UNPACKED_SIZE)
+}
+
+fn parse_pack_with_limits(pack: &[u8], max_object: usize, max_total: usize) -> Result<Vec<Vec<u8>>, AppError> {
+    let invalid = |what: &str| AppError::InvalidInput(format!("Invalid pack: {}", what));
+
+    if pack.len() < 12 + HASH_HEX_LENGTH || &pack[..4] != PACK_MAGIC {
+        return Err(invalid("bad header"));
+    }
+    let (body, checksum) = pack.split_at(pack.len() - HASH_HEX_LENGTH);
+    let actual = Hasher::new().hash_bytes(body);
+    if actual.as_bytes() != checksum {
+        return Err(AppError::HashMismatch {
+            expected: String::from_utf8_lossy(checksum).into_owned(),
+            actual,
+        });
+    }
+
+    let version = read_u32(body, 4).ok_or_else(|| invalid("bad header"))?;
+    if version != PACK_VERSION {
+        return Err(invalid(&format!("unsupported version {}", version)));
+    }
+    let count = read_u32(body, 8).ok_or_else(|| invalid("bad header"))? as usize;
+    // 항목마다 길이 필드가 있으므로 남은 본문보다 많을 수 없음
+    if count > (body.len() - 12) / ENTRY_HEADER_SIZE {
+        return Err(invalid(&format!("object count {} exceeds pack size", count)));
+    }
+
+    let mut objects = Vec::with_capacity(count);
+    let mut offset = 12;
+    let mut unpacked = 0;
+    for _ in 0..count {
+        let length = read_u32(body, offset).ok_or_else(|| invalid("truncated"))? as usize;
+        offset += ENTRY_HEADER_SIZE;
+        let data = body
+            .get(offset..offset + length)
+            .ok_or_else(|| invalid("truncated"))?;
+        let limit = max_object.min(max_total - unpacked);
+        let object = decompress_with_limit(data, limit).map_err(|err| invalid(&err.to_string()))?;
+        unpacked += object.len();
+        objects.push(object);
+        offset += length;
+    }
+    if offset != body.len() {
+        return Err(invalid("trailing data"));
+    }
+    Ok(objects)
+}
+
+/// 팩의 모든 객체를 저장소에 기록
+///
+/// # Returns
+/// 기록된 객체 해시 목록 (팩 안의 순서)
+pub fn unpack(store: &ObjectStore, pack: &[u8]) -> Result<Vec<String>, AppError> {
+    parse_pack(pack)?
+        .iter()
+        .map(|data| {
+            // 형식 검증 후 저장
+            crate::object::Object::decode(data)?;
+            store.write_raw(data)
+        })
+        .collect()
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+    Some(u32::from_be_bytes(bytes))
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Blob, Object};
+
+    #[test]
+    fn test_pack_roundtrip() {
+        let src_dir = tempfile::tempdir().unwrap();
+        let src = ObjectStore::new(src_dir.path());
+        let hashes: Vec<String> = ["one", "two", "three"]
+            .iter()
+            .map(|c| src.write(&Object::from(Blob::new(c.as_bytes().to_vec()))).unwrap())
+            .collect();
+
+        let pack = build_pack(&src, &hashes).unwrap();
+
+        let dst_dir = tempfile::tempdir().unwrap();
+        let dst = ObjectStore::new(dst_dir.path());
+        let written = unpack(&dst, &pack).unwrap();
+        assert_eq!(written, hashes);
+        assert_eq!(dst.read_blob(&hashes[1]).unwrap().content(), b"two");
+    }
+
+    #[test]
+    fn test_empty_pack() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let pack = build_pack(&store, &[]).unwrap();
+        assert!(parse_pack(&pack).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_corrupted_pack() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let hash = store.write(&Object::from(Blob::new(b"data".to_vec()))).unwrap();
+
+        let mut pack = build_pack(&store, &[hash]).unwrap();
+        pack[14] ^= 0xff;
+        assert!(matches!(parse_pack(&pack), Err(AppError::HashMismatch { .. })));
+
+        assert!(parse_pack(b"not a pack").is_err());
+    }
+
+    /// 헤더와 체크섬만 맞춘 팩
+    fn forge(count: u32, entries: &[Vec<u8>]) -> Vec<u8> {
+        let mut pack = PACK_MAGIC.to_vec();
+        pack.extend_from_slice(&PACK_VERSION.to_be_bytes());
+        pack.extend_from_slice(&count.to_be_bytes());
+        for entry in entries {
+            pack.extend_from_slice(&(entry.len() as u32).to_be_bytes());
+            pack.extend_from_slice(entry);
+        }
+        let checksum = Hasher::new().hash_bytes(&pack);
+        pack.extend_from_slice(checksum.as_bytes());
+        pack
+    }
+
+    #[test]
+    fn test_untrusted_pack_limits() {
+        // 본문에 비해 터무니없는 개수는 할당 전에 거부
+        let err = parse_pack(&forge(u32::MAX, &[])).unwrap_err();
+        assert!(err.to_string().contains("exceeds pack size"), "{}", err);
+
+        // 압축 해제 크기 제한 (객체별, 전체)
+        let bomb = compress(&vec![0u8; 4096]).unwrap();
+        assert!(bomb.len() < 100);
+        let pack = forge(2, &[bomb.clone(), bomb]);
+        assert_eq!(parse_pack_with_limits(&pack, 4096, 8192).unwrap().len(), 2);
+        assert!(matches!(parse_pack_with_limits(&pack, 4095, 8192), Err(AppError::InvalidInput(_))));
+        assert!(matches!(parse_pack_with_limits(&pack, 4096, 8191), Err(AppError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,1185 @@
+// =============================================================================
+// 패치 모듈 (patch.rs)
+// =============================================================================
+//
+// 커밋을 메일로 보낼 수 있는 텍스트 패치로 내보내고 다시 커밋으로 적용
+// (git format-patch / git am 과 같은 역할)
+//
+// 패치 파일 형식:
+//   From {커밋 해시} Mon Sep 17 00:00:00 2001
+//   From: 이름 <이메일>
+//   Date: {작성 시각, RFC 2822 (작성자 시간대)}
+//   Subject: [PATCH n/m] {메시지 첫 줄}
+//
+//   {메시지 나머지}
+//   ---
+//    {경로} | {변경 라인 수} +++--            (diffstat)
+//    N files changed, X insertions(+), Y deletions(-)
+//
+//   diff --cts a/{경로} b/{경로}
+//   index {이전 Blob 해시}..{새 Blob 해시} {모드}
+//   --- a/{경로}
+//   +++ b/{경로}
+//   @@ -l,s +l,s @@
+//   ...                                      (unified diff, 문맥 3줄)
+//   --
+//   cts {버전}
+//
+// 바이너리 파일:
+//   "CTS binary patch" 다음 줄에 "literal {크기}" 또는 "delta {크기}"
+//   내용은 zlib 압축 후 base85 로 인코딩 (한 줄 최대 52바이트)
+//
+// 3-way 폴백:
+//   index 줄의 전체 Blob 해시로 패치가 만들어진 원본을 찾아
+//   원본에 패치를 적용한 결과와 현재 내용을 merge3 로 병합
+//
+// 파일 위치: crates/core/src/patch.rs
+// =============================================================================
+
+use std::collections::BTreeMap;
+
+use shared::error::AppError;
+use shared::types::{LocalTimestamp, Timestamp};
+
+use crate::checkout::is_safe_path;
+use crate::compression::{compress, decompress};
+use crate::delta::{apply_delta, create_delta};
+use crate::diff::{changes, diff, hunks, split_lines};
+use crate::merge::merge3;
+use crate::object::{Actor, Blob, Object, ObjectType, TreeEntry, MODE_SUBMODULE};
+use crate::store::{is_valid_hash, ObjectStore, SUBPROJECT_PREFIX};
+
+// -----------------------------------------------------------------------------
+// 상수
+// -----------------------------------------------------------------------------
+
+/// 변경 구간 앞뒤 문맥 라인 수
+pub const CONTEXT_LINES: usize = 3;
+
+/// 일반 파일 모드
+const MODE_FILE: &str = "100644";
+
+/// 파일이 없음을 나타내는 해시 / 경로
+const NULL_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+const NULL_PATH: &str = "/dev/null";
+
+/// 줄바꿈 없이 끝나는 라인 표시
+const NO_NEWLINE_MARKER: &str = "\\ No newline at end of file";
+
+/// mbox "From " 줄의 고정 날짜 (Git 과 같은 값)
+const MBOX_DATE: &str = "Mon Sep 17 00:00:00 2001";
+
+const BINARY_HEADER: &str = "CTS binary patch";
+
+/// base85 한 줄에 담는 최대 바이트 수
+const BASE85_LINE_BYTES: usize = 52;
+
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+// =============================================================================
+// 데이터 구조
+// =============================================================================
+
+/// 커밋 하나에 해당하는 패치
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patch {
+    /// 원래 커밋 해시 (알 수 없으면 None)
+    pub commit: Option<String>,
+    /// 원래 작성자 (적용해서 만드는 커밋의 author 로 보존)
+    pub author: Actor,
+    /// 커밋 메시지 (제목 + 빈 줄 + 본문)
+    pub message: String,
+    pub files: Vec<FilePatch>,
+}
+
+/// 파일 하나의 변경
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePatch {
+    /// 이전 경로 (새 파일이면 None)
+    pub old_path: Option<String>,
+    /// 새 경로 (삭제면 None)
+    pub new_path: Option<String>,
+    pub old_mode: Option<String>,
+    pub new_mode: Option<String>,
+    /// 이전 Blob 해시 (3-way 폴백에 사용)
+    pub old_hash: Option<String>,
+    pub new_hash: Option<String>,
+    pub content: PatchContent,
+}
+
+/// 파일 변경 내용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchContent {
+    /// 텍스트 diff (모드만 바뀌면 빈 목록)
+    Text(Vec<PatchHunk>),
+    /// 바이너리 내용
+    Binary(BinaryPatch),
+}
+
+/// unified diff 의 "@@" 블록 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchHunk {
+    /// 이전 버전 시작 라인 (0부터)
+    pub old_start: usize,
+    /// 새 버전 시작 라인 (0부터)
+    pub new_start: usize,
+    pub lines: Vec<HunkLine>,
+}
+
+/// Hunk 의 라인 (줄바꿈 포함)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HunkLine {
+    Context(Vec<u8>),
+    Delete(Vec<u8>),
+    Insert(Vec<u8>),
+}
+
+/// 바이너리 파일 변경
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryPatch {
+    /// 새 내용 전체
+    Literal(Vec<u8>),
+    /// 이전 내용 기준 델타 (delta.rs 포맷)
+    Delta(Vec<u8>),
+}
+
+/// 패치 적용 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyOutcome {
+    /// 적용 후 루트 트리 해시 (충돌 파일은 충돌 마커 포함)
+    pub tree: String,
+    /// 3-way 병합에서 충돌이 난 경로
+    pub conflicts: Vec<String>,
+}
+
+impl PatchHunk {
+    fn old_len(&self) -> usize {
+        self.lines.iter().filter(|l| !matches!(l, HunkLine::Insert(_))).count()
+    }
+
+    fn new_len(&self) -> usize {
+        self.lines.iter().filter(|l| !matches!(l, HunkLine::Delete(_))).count()
+    }
+
+    /// 적용 전 내용 (문맥 + 삭제 라인)
+    fn preimage(&self) -> Vec<&[u8]> {
+        self.lines
+            .iter()
+            .filter_map(|l| match l {
+                HunkLine::Context(line) | HunkLine::Delete(line) => Some(line.as_slice()),
+                HunkLine::Insert(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl FilePatch {
+    /// 표시용 경로 (새 경로 우선)
+    pub fn path(&self) -> &str {
+        self.new_path.as_deref().or(self.old_path.as_deref()).unwrap_or("")
+    }
+}
+
+// =============================================================================
+// 내보내기
+// =============================================================================
+
+/// 커밋을 패치 텍스트로 변환
+///
+/// # Arguments
+/// * `store` - 객체 저장소
+/// * `commit_hash` - 내보낼 커밋 (부모와의 차이를 패치로)
+/// * `number` - 시리즈 안의 번호 (n, 전체 m) → 제목의 "[PATCH n/m]"
+pub fn format_patch(
+    store: &ObjectStore,
+    commit_hash: &str,
+    number: Option<(usize, usize)>,
+) -> Result<String, AppError> {
+    let commit = store.read_commit(commit_hash)?;
+    let parent_tree = match store.parent_of(commit_hash, &commit) {
+        Some(parent) => Some(store.read_commit(&parent)?.tree_hash),
+        None => None,
+    };
+    let patch = Patch {
+        commit: Some(commit_hash.to_string()),
+        files: diff_trees(store, parent_tree.as_deref(), &commit.tree_hash)?,
+        author: commit.author,
+        message: commit.message,
+    };
+    Ok(patch.format(number))
+}
+
+/// 두 트리의 차이를 파일 패치 목록으로 (경로순)
+///
+/// `old_tree` 가 None 이면 빈 트리와 비교
+pub fn diff_trees(
+    store: &ObjectStore,
+    old_tree: Option<&str>,
+    new_tree: &str,
+) -> Result<Vec<FilePatch>, AppError> {
+    let old_files = match old_tree {
+        Some(tree) => store.flatten_tree(tree)?,
+        None => BTreeMap::new(),
+    };
+    let new_files = store.flatten_tree(new_tree)?;
+
+    let mut paths: Vec<&String> = old_files.keys().chain(new_files.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    paths.retain(|path| old_files.get(*path) != new_files.get(*path));
+
+    // partial clone: 바뀐 파일의 Blob 을 한 번에 받아옴
+    let blobs: Vec<String> = paths
+        .iter()
+        .flat_map(|path| [old_files.get(*path), new_files.get(*path)])
+        .flatten()
+        .filter(|entry| !entry.is_submodule())
+        .map(|entry| entry.hash.clone())
+        .collect();
+    store.prefetch(&blobs)?;
+
+    let mut result = Vec::new();
+    for path in paths {
+        result.push(diff_file(store, path, old_files.get(path), new_files.get(path))?);
+    }
+    Ok(result)
+}
+
+fn diff_file(
+    store: &ObjectStore,
+    path: &str,
+    old: Option<&TreeEntry>,
+    new: Option<&TreeEntry>,
+) -> Result<FilePatch, AppError> {
+    let read = |entry: Option<&TreeEntry>| -> Result<Vec<u8>, AppError> {
+        match entry {
+            Some(entry) => store.entry_content(entry),
+            None => Ok(Vec::new()),
+        }
+    };
+    let old_content = read(old)?;
+    let new_content = read(new)?;
+
+    let content = if is_binary(&old_content) || is_binary(&new_content) {
+        let literal = BinaryPatch::Literal(new_content.clone());
+        if old.is_some() && new.is_some() {
+            let delta = create_delta(&old_content, &new_content);
+            if delta.len() < new_content.len() {
+                PatchContent::Binary(BinaryPatch::Delta(delta))
+            } else {
+                PatchContent::Binary(literal)
+            }
+        } else {
+            PatchContent::Binary(literal)
+        }
+    } else {
+        PatchContent::Text(text_hunks(&old_content, &new_content))
+    };
+
+    Ok(FilePatch {
+        old_path: old.map(|_| path.to_string()),
+        new_path: new.map(|_| path.to_string()),
+        old_mode: old.map(|e| e.mode.clone()),
+        new_mode: new.map(|e| e.mode.clone()),
+        old_hash: old.map(|e| e.hash.clone()),
+        new_hash: new.map(|e| e.hash.clone()),
+        content,
+    })
+}
+
+/// NUL 바이트가 있거나 UTF-8 이 아니면 바이너리
+fn is_binary(content: &[u8]) -> bool {
+    content.contains(&0) || std::str::from_utf8(content).is_err()
+}
+
+/// 텍스트 내용의 unified diff Hunk 목록
+fn text_hunks(old: &[u8], new: &[u8]) -> Vec<PatchHunk> {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let changes = changes(&diff(&old_lines, &new_lines));
+
+    hunks(&changes, old_lines.len(), CONTEXT_LINES)
+        .into_iter()
+        .map(|hunk| {
+            let mut lines = Vec::new();
+            let mut pos = hunk.old_start;
+            for change in &hunk.changes {
+                for line in &old_lines[pos..change.old_start] {
+                    lines.push(HunkLine::Context(line.to_vec()));
+                }
+                for line in &old_lines[change.old_start..change.old_start + change.old_len] {
+                    lines.push(HunkLine::Delete(line.to_vec()));
+                }
+                for line in &new_lines[change.new_start..change.new_start + change.new_len] {
+                    lines.push(HunkLine::Insert(line.to_vec()));
+                }
+                pos = change.old_start + change.old_len;
+            }
+            for line in &old_lines[pos..hunk.old_start + hunk.old_len] {
+                lines.push(HunkLine::Context(line.to_vec()));
+            }
+            PatchHunk {
+                old_start: hunk.old_start,
+                new_start: hunk.new_start,
+                lines,
+            }
+        })
+        .collect()
+}
+
+// =============================================================================
+// 텍스트 형식
+// =============================================================================
+
+impl Patch {
+    /// 메시지 첫 줄
+    pub fn subject(&self) -> &str {
+        self.message.lines().next().unwrap_or("")
+    }
+
+    /// 패치 파일 텍스트 생성
+    pub fn format(&self, number: Option<(usize, usize)>) -> String {
+        let mut out = String::new();
+        let commit = self.commit.as_deref().unwrap_or(NULL_HASH);
+        out.push_str(&format!("From {} {}\n", commit, MBOX_DATE));
+        out.push_str(&format!("From: {} <{}>\n", self.author.name, self.author.email));
+        out.push_str(&format!("Date: {}\n", self.author.local_time().to_rfc2822()));
+        let prefix = match number {
+            Some((n, total)) if total > 1 => format!("[PATCH {}/{}]", n, total),
+            _ => "[PATCH]".to_string(),
+        };
+        out.push_str(&format!("Subject: {} {}\n\n", prefix, self.subject()));
+
+        let body = self.message.lines().skip(1).collect::<Vec<_>>().join("\n");
+        let body = body.trim_matches('\n');
+        if !body.is_empty() {
+            out.push_str(body);
+            out.push_str("\n\n");
+        }
+        out.push_str("---\n");
+        out.push_str(&format_diffstat(&self.files));
+        out.push('\n');
+        out.push_str(&format_diff(&self.files));
+        out.push_str(&format!("-- \ncts {}\n\n", env!("CARGO_PKG_VERSION")));
+        out
+    }
+
+    // -------------------------------------------------------------------------
+    // 파싱
+    // -------------------------------------------------------------------------
+
+    /// 패치 파일 텍스트 파싱 (format() 의 역변환)
+    pub fn parse(text: &str) -> Result<Self, AppError> {
+        let invalid = |what: &str| AppError::InvalidInput(format!("Invalid patch: {}", what));
+        // lines() 는 "\r\n" 의 "\r" 까지 떼어 내므로 '\n' 으로만 나눔 (CRLF 파일의 라인 내용 보존)
+        let mut lines = text.split('\n').peekable();
+
+        let mut commit = None;
+        let mut author = None;
+        let mut timestamp = None;
+        let mut subject = None;
+
+        // 헤더 (빈 줄까지)
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            if let Some(rest) = line.strip_prefix("From: ") {
+                let (name, email) = rest
+                    .rsplit_once(" <")
+                    .and_then(|(name, email)| Some((name, email.strip_suffix('>')?)))
+                    .ok_or_else(|| invalid("bad From header"))?;
+                author = Some((name.to_string(), email.to_string()));
+            } else if let Some(rest) = line.strip_prefix("From ") {
+                let hash = rest.split(' ').next().unwrap_or("");
+                if is_valid_hash(hash) && hash != NULL_HASH {
+                    commit = Some(hash.to_string());
+                }
+            } else if let Some(rest) = line.strip_prefix("Date: ") {
+                let date = LocalTimestamp::parse_from_rfc2822(rest).map_err(|_| invalid("bad Date header"))?;
+                timestamp = Some((Timestamp::from(date), date.offset().local_minus_utc() / 60));
+            } else if let Some(rest) = line.strip_prefix("Subject: ") {
+                subject = Some(strip_subject_prefix(rest).to_string());
+            }
+        }
+        let (name, email) = author.ok_or_else(|| invalid("missing From header"))?;
+        let (time, offset) = timestamp.ok_or_else(|| invalid("missing Date header"))?;
+        let subject = subject.ok_or_else(|| invalid("missing Subject header"))?;
+
+        // 본문 ("---" 까지)
+        let mut body = Vec::new();
+        for line in lines.by_ref() {
+            if line == "---" {
+                break;
+            }
+            body.push(line);
+        }
+        let body = body.join("\n");
+        let body = body.trim_matches('\n');
+        let message = if body.is_empty() {
+            subject
+        } else {
+            format!("{}\n\n{}", subject, body)
+        };
+
+        // diffstat 건너뛰기
+        while lines.peek().is_some_and(|line| !line.starts_with("diff --cts ")) {
+            lines.next();
+        }
+
+        let mut files = Vec::new();
+        while let Some(line) = lines.next() {
+            if line == "-- " {
+                break;
+            }
+            let header = line
+                .strip_prefix("diff --cts ")
+                .ok_or_else(|| invalid(line))?;
+            files.push(parse_file(header, &mut lines)?);
+        }
+
+        Ok(Self {
+            commit,
+            author: Actor::new(name, email, time, offset),
+            message,
+            files,
+        })
+    }
+}
+
+/// 여러 패치가 이어 붙은 텍스트(mbox)를 패치별로 분리
+pub fn split_series(text: &str) -> Vec<&str> {
+    let mut starts: Vec<usize> = Vec::new();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.starts_with("From ") && line.trim_end().ends_with(MBOX_DATE) {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+    if starts.is_empty() {
+        return vec![text];
+    }
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| &text[start..starts.get(i + 1).copied().unwrap_or(text.len())])
+        .collect()
+}
+
+/// "[PATCH 1/3] 제목" → "제목"
+/// 파일별 변경 라인 수 요약 (diffstat)
+pub fn format_diffstat(files: &[FilePatch]) -> String {
+    let width = files.iter().map(|f| f.path().len()).max().unwrap_or(0);
+    let (mut insertions, mut deletions) = (0, 0);
+    let mut out = String::new();
+    for file in files {
+        match &file.content {
+            PatchContent::Text(hunks) => {
+                let added = hunks.iter().map(|h| h.lines.iter().filter(|l| matches!(l, HunkLine::Insert(_))).count()).sum::<usize>();
+                let removed = hunks.iter().map(|h| h.lines.iter().filter(|l| matches!(l, HunkLine::Delete(_))).count()).sum::<usize>();
+                insertions += added;
+                deletions += removed;
+                out.push_str(&format!(
+                    " {:<width$} | {} {}{}\n",
+                    file.path(),
+                    added + removed,
+                    "+".repeat(added.min(40)),
+                    "-".repeat(removed.min(40)),
+                ));
+            }
+            PatchContent::Binary(_) => {
+                out.push_str(&format!(" {:<width$} | Bin\n", file.path()));
+            }
+        }
+    }
+    out.push_str(&format!(
+        " {} file{} changed, {} insertion{}(+), {} deletion{}(-)\n",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" },
+        insertions,
+        if insertions == 1 { "" } else { "s" },
+        deletions,
+        if deletions == 1 { "" } else { "s" },
+    ));
+    out
+}
+
+/// 파일 변경 목록을 unified diff 텍스트로
+pub fn format_diff(files: &[FilePatch]) -> String {
+    let mut out = String::new();
+    for file in files {
+        format_file(&mut out, file);
+    }
+    out
+}
+
+fn strip_subject_prefix(subject: &str) -> &str {
+    if subject.starts_with('[') {
+        if let Some(end) = subject.find("] ") {
+            return &subject[end + 2..];
+        }
+    }
+    subject
+}
+
+fn format_file(out: &mut String, file: &FilePatch) {
+    let old_name = file.old_path.as_deref().or(file.new_path.as_deref()).unwrap_or("");
+    let new_name = file.new_path.as_deref().or(file.old_path.as_deref()).unwrap_or("");
+    out.push_str(&format!("diff --cts a/{} b/{}\n", old_name, new_name));
+
+    match (&file.old_mode, &file.new_mode) {
+        (None, Some(mode)) => out.push_str(&format!("new file mode {}\n", mode)),
+        (Some(mode), None) => out.push_str(&format!("deleted file mode {}\n", mode)),
+        (Some(old), Some(new)) if old != new => {
+            out.push_str(&format!("old mode {}\nnew mode {}\n", old, new));
+        }
+        _ => {}
+    }
+
+    let old_hash = file.old_hash.as_deref().unwrap_or(NULL_HASH);
+    let new_hash = file.new_hash.as_deref().unwrap_or(NULL_HASH);
+    match (&file.old_mode, &file.new_mode) {
+        (Some(old), Some(new)) if old == new => {
+            out.push_str(&format!("index {}..{} {}\n", old_hash, new_hash, old));
+        }
+        _ => out.push_str(&format!("index {}..{}\n", old_hash, new_hash)),
+    }
+
+    match &file.content {
+        PatchContent::Text(hunks) => {
+            if hunks.is_empty() {
+                return;
+            }
+            let path_line = |prefix: &str, path: &Option<String>| match path {
+                Some(path) => format!("{}/{}", prefix, path),
+                None => NULL_PATH.to_string(),
+            };
+            out.push_str(&format!("--- {}\n", path_line("a", &file.old_path)));
+            out.push_str(&format!("+++ {}\n", path_line("b", &file.new_path)));
+            for hunk in hunks {
+                format_hunk(out, hunk);
+            }
+        }
+        PatchContent::Binary(binary) => {
+            out.push_str(BINARY_HEADER);
+            out.push('\n');
+            let (kind, data) = match binary {
+                BinaryPatch::Literal(data) => ("literal", data),
+                BinaryPatch::Delta(data) => ("delta", data),
+            };
+            out.push_str(&format!("{} {}\n", kind, data.len()));
+            // compress 는 메모리 버퍼에 대해 실패하지 않음
+            let compressed = compress(data).unwrap_or_default();
+            for chunk in compressed.chunks(BASE85_LINE_BYTES) {
+                out.push(encode_line_length(chunk.len()));
+                out.push_str(&encode_base85(chunk));
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+    }
+}
+
+fn format_hunk(out: &mut String, hunk: &PatchHunk) {
+    // 길이가 0인 쪽의 시작 위치는 "그 앞 라인 번호"로 표기
+    let range = |start: usize, len: usize| {
+        let start = if len == 0 { start } else { start + 1 };
+        if len == 1 {
+            start.to_string()
+        } else {
+            format!("{},{}", start, len)
+        }
+    };
+    out.push_str(&format!(
+        "@@ -{} +{} @@\n",
+        range(hunk.old_start, hunk.old_len()),
+        range(hunk.new_start, hunk.new_len())
+    ));
+    for line in &hunk.lines {
+        let (marker, content) = match line {
+            HunkLine::Context(content) => (' ', content),
+            HunkLine::Delete(content) => ('-', content),
+            HunkLine::Insert(content) => ('+', content),
+        };
+        out.push(marker);
+        out.push_str(&String::from_utf8_lossy(content));
+        if !content.ends_with(b"\n") {
+            out.push('\n');
+            out.push_str(NO_NEWLINE_MARKER);
+            out.push('\n');
+        }
+    }
+}
+
+fn parse_file<'a, I: Iterator<Item = &'a str>>(
+    header: &str,
+    lines: &mut std::iter::Peekable<I>,
+) -> Result<FilePatch, AppError> {
+    let invalid = |what: &str| AppError::InvalidInput(format!("Invalid patch: {}", what));
+
+    let (old_name, new_name) = header
+        .strip_prefix("a/")
+        .and_then(|rest| rest.split_once(" b/"))
+        .ok_or_else(|| invalid(header))?;
+    let mut file = FilePatch {
+        old_path: Some(old_name.to_string()),
+        new_path: Some(new_name.to_string()),
+        old_mode: None,
+        new_mode: None,
+        old_hash: None,
+        new_hash: None,
+        content: PatchContent::Text(Vec::new()),
+    };
+
+    // 확장 헤더
+    let mut mode_known = false;
+    while let Some(&line) = lines.peek() {
+        if let Some(mode) = line.strip_prefix("new file mode ") {
+            file.old_path = None;
+            file.new_mode = Some(mode.to_string());
+        } else if let Some(mode) = line.strip_prefix("deleted file mode ") {
+            file.new_path = None;
+            file.old_mode = Some(mode.to_string());
+        } else if let Some(mode) = line.strip_prefix("old mode ") {
+            file.old_mode = Some(mode.to_string());
+        } else if let Some(mode) = line.strip_prefix("new mode ") {
+            file.new_mode = Some(mode.to_string());
+        } else if let Some(rest) = line.strip_prefix("index ") {
+            let (hashes, mode) = rest.split_once(' ').unwrap_or((rest, ""));
+            let (old, new) = hashes.split_once("..").ok_or_else(|| invalid(line))?;
+            let hash = |h: &str| (h != NULL_HASH && is_valid_hash(h)).then(|| h.to_string());
+            file.old_hash = hash(old);
+            file.new_hash = hash(new);
+            if !mode.is_empty() {
+                file.old_mode = Some(mode.to_string());
+                file.new_mode = Some(mode.to_string());
+                mode_known = true;
+            }
+        } else {
+            break;
+        }
+        lines.next();
+    }
+    if !mode_known && file.old_mode.is_none() && file.old_path.is_some() {
+        file.old_mode = Some(MODE_FILE.to_string());
+    }
+
+    match lines.peek().copied() {
+        Some(line) if line.starts_with("--- ") => {
+            lines.next();
+            lines.next_if(|l| l.starts_with("+++ ")).ok_or_else(|| invalid("missing +++ line"))?;
+            let mut hunks = Vec::new();
+            while let Some(line) = lines.next_if(|l| l.starts_with("@@ ")) {
+                hunks.push(parse_hunk(line, lines)?);
+            }
+            file.content = PatchContent::Text(hunks);
+        }
+        Some(BINARY_HEADER) => {
+            lines.next();
+            let spec = lines.next().ok_or_else(|| invalid("missing binary size"))?;
+            let (kind, size) = spec.split_once(' ').ok_or_else(|| invalid(spec))?;
+            let size: usize = size.parse().map_err(|_| invalid(spec))?;
+
+            let mut compressed = Vec::new();
+            while let Some(line) = lines.next_if(|l| !l.is_empty()) {
+                compressed.extend(decode_base85_line(line).ok_or_else(|| invalid("bad base85 data"))?);
+            }
+            lines.next_if(|l| l.is_empty());
+
+            let data = decompress(&compressed)?;
+            if data.len() != size {
+                return Err(invalid("binary size mismatch"));
+            }
+            file.content = PatchContent::Binary(match kind {
+                "literal" => BinaryPatch::Literal(data),
+                "delta" => BinaryPatch::Delta(data),
+                _ => return Err(invalid(spec)),
+            });
+        }
+        _ => {}
+    }
+    Ok(file)
+}
+
+fn parse_hunk<'a, I: Iterator<Item = &'a str>>(
+    header: &str,
+    lines: &mut std::iter::Peekable<I>,
+) -> Result<PatchHunk, AppError> {
+    let invalid = || AppError::InvalidInput(format!("Invalid hunk header: {}", header));
+
+    // "@@ -l,s +l,s @@"
+    let ranges = header
+        .strip_prefix("@@ -")
+        .and_then(|rest| rest.split(" @@").next())
+        .ok_or_else(invalid)?;
+    let (old, new) = ranges.split_once(" +").ok_or_else(invalid)?;
+    let parse_range = |range: &str| -> Option<(usize, usize)> {
+        let (start, len) = match range.split_once(',') {
+            Some((start, len)) => (start.parse().ok()?, len.parse().ok()?),
+            None => (range.parse().ok()?, 1),
+        };
+        // 길이가 0이면 start 는 그 앞 라인 번호
+        Some((if len == 0 { start } else { start.checked_sub(1)? }, len))
+    };
+    let (old_start, mut old_remaining) = parse_range(old).ok_or_else(invalid)?;
+    let (new_start, mut new_remaining) = parse_range(new).ok_or_else(invalid)?;
+
+    let mut result = PatchHunk { old_start, new_start, lines: Vec::new() };
+    while old_remaining > 0 || new_remaining > 0 {
+        let line = lines.next().ok_or_else(invalid)?;
+        // 메일 전송 중 공백만 있는 문맥 라인이 빈 줄이 되는 경우
+        let marker = line.chars().next().unwrap_or(' ');
+        let content = line.get(marker.len_utf8()..).unwrap_or("");
+        let content = format!("{}\n", content).into_bytes();
+        match marker {
+            ' ' if old_remaining > 0 && new_remaining > 0 => {
+                old_remaining -= 1;
+                new_remaining -= 1;
+                result.lines.push(HunkLine::Context(content));
+            }
+            '-' if old_remaining > 0 => {
+                old_remaining -= 1;
+                result.lines.push(HunkLine::Delete(content));
+            }
+            '+' if new_remaining > 0 => {
+                new_remaining -= 1;
+                result.lines.push(HunkLine::Insert(content));
+            }
+            _ => return Err(invalid()),
+        }
+        if lines.next_if(|l| *l == NO_NEWLINE_MARKER).is_some() {
+            if let Some(
+                HunkLine::Context(content) | HunkLine::Delete(content) | HunkLine::Insert(content),
+            ) = result.lines.last_mut()
+            {
+                content.pop();
+            }
+        }
+    }
+    Ok(result)
+}
+
+// =============================================================================
+// 적용
+// =============================================================================
+
+/// 트리에 패치 적용
+///
+/// # Arguments
+/// * `tree` - 패치를 적용할 루트 트리
+/// * `three_way` - 그대로 적용되지 않을 때 원본 Blob 으로 3-way 병합할지
+///
+/// # Returns
+/// 새 트리 (기록 완료) 와 충돌 경로 목록
+/// 충돌이 있으면 해당 파일은 충돌 마커가 포함된 내용으로 기록됨
+pub fn apply_patch(
+    store: &ObjectStore,
+    tree: &str,
+    patch: &Patch,
+    three_way: bool,
+) -> Result<ApplyOutcome, AppError> {
+    let mut files = store.flatten_tree(tree)?;
+    let mut conflicts = Vec::new();
+    let label = format!("patch ({})", patch.subject());
+
+    for file in &patch.files {
+        for path in file.old_path.iter().chain(&file.new_path) {
+            if !is_safe_path(path) {
+                return Err(AppError::InvalidInput(format!("Unsafe path in patch: {}", path)));
+            }
+        }
+        let does_not_apply =
+            || AppError::InvalidInput(format!("Patch does not apply: {}", file.path()));
+
+        let current = match &file.old_path {
+            Some(path) => {
+                let entry = files.get(path).ok_or_else(does_not_apply)?;
+                Some((entry.clone(), store.entry_content(entry)?))
+            }
+            None => {
+                let path = file.new_path.as_deref().unwrap_or("");
+                if files.contains_key(path) {
+                    return Err(AppError::AlreadyExists(format!("Patch creates existing file: {}", path)));
+                }
+                None
+            }
+        };
+        let current_content = current.as_ref().map(|(_, c)| c.as_slice()).unwrap_or(&[]);
+
+        let new_content = match &file.content {
+            PatchContent::Text(hunks) => match apply_hunks(current_content, hunks) {
+                Some(content) => content,
+                None if three_way => {
+                    let base_hash = file.old_hash.as_deref().ok_or_else(does_not_apply)?;
+                    let base = store.read_blob(base_hash).map_err(|_| {
+                        AppError::NotFound(format!(
+                            "Cannot fall back to three-way merge for {}: base blob {} not found",
+                            file.path(),
+                            base_hash
+                        ))
+                    })?;
+                    let theirs = apply_hunks(base.content(), hunks).ok_or_else(does_not_apply)?;
+                    let merged = merge3(base.content(), current_content, &theirs, ("ours", &label));
+                    if !merged.is_clean() {
+                        conflicts.push(file.path().to_string());
+                    }
+                    merged.content
+                }
+                None => return Err(does_not_apply()),
+            },
+            PatchContent::Binary(binary) => {
+                let current_hash = current.as_ref().map(|(entry, _)| entry.hash.as_str());
+                if current_hash.is_some() && current_hash == file.new_hash.as_deref() {
+                    // 이미 적용된 바이너리 변경
+                    current_content.to_vec()
+                } else if current_hash != file.old_hash.as_deref() {
+                    return Err(AppError::InvalidInput(format!(
+                        "Binary patch does not apply (cannot merge binary files): {}",
+                        file.path()
+                    )));
+                } else {
+                    match binary {
+                        BinaryPatch::Literal(data) => data.clone(),
+                        BinaryPatch::Delta(delta) => apply_delta(current_content, delta)?,
+                    }
+                }
+            }
+        };
+
+        if let Some(path) = &file.old_path {
+            files.remove(path);
+        }
+        if let Some(path) = &file.new_path {
+            let mode = file
+                .new_mode
+                .clone()
+                .or_else(|| current.as_ref().map(|(entry, _)| entry.mode.clone()))
+                .unwrap_or_else(|| MODE_FILE.to_string());
+            // 서브모듈은 "Subproject commit {해시}" 내용에서 커밋만 꺼냄
+            if mode == MODE_SUBMODULE {
+                let commit = std::str::from_utf8(&new_content)
+                    .ok()
+                    .and_then(|text| text.trim_end().strip_prefix(SUBPROJECT_PREFIX))
+                    .filter(|hash| is_valid_hash(hash))
+                    .ok_or_else(does_not_apply)?;
+                files.insert(path.clone(), TreeEntry::submodule(String::new(), commit.to_string()));
+                continue;
+            }
+            let hash = store.write(&Object::from(Blob::new(new_content)))?;
+            if let (PatchContent::Binary(_), Some(expected)) = (&file.content, &file.new_hash) {
+                if &hash != expected {
+                    return Err(AppError::HashMismatch { expected: expected.clone(), actual: hash });
+                }
+            }
+            files.insert(
+                path.clone(),
+                TreeEntry { name: String::new(), object_type: ObjectType::Blob, hash, mode },
+            );
+        }
+    }
+
+    Ok(ApplyOutcome {
+        tree: store.write_flat_tree(&files)?,
+        conflicts,
+    })
+}
+
+/// Hunk 들을 내용에 적용 (문맥이 정확히 일치해야 함)
+///
+/// 라인 번호가 어긋나면 가장 가까운 일치 위치를 찾음
+/// 적용할 수 없으면 None
+fn apply_hunks(content: &[u8], hunks: &[PatchHunk]) -> Option<Vec<u8>> {
+    let lines = split_lines(content);
+    let mut out = Vec::with_capacity(content.len());
+    let mut pos = 0;
+    let mut drift: isize = 0;
+
+    for hunk in hunks {
+        let preimage = hunk.preimage();
+        let expected = (hunk.old_start as isize + drift).max(pos as isize) as usize;
+        let last = lines.len().checked_sub(preimage.len())?;
+        if last < pos {
+            return None;
+        }
+
+        let matches = |at: usize| lines[at..at + preimage.len()] == preimage[..];
+        let found = (0..=last.max(expected) - pos).find_map(|distance| {
+            [expected.checked_add(distance), expected.checked_sub(distance)]
+                .into_iter()
+                .flatten()
+                .find(|&at| at >= pos && at <= last && matches(at))
+        })?;
+
+        for line in &lines[pos..found] {
+            out.extend_from_slice(line);
+        }
+        for line in &hunk.lines {
+            match line {
+                HunkLine::Context(content) | HunkLine::Insert(content) => out.extend_from_slice(content),
+                HunkLine::Delete(_) => {}
+            }
+        }
+        pos = found + preimage.len();
+        drift = found as isize - hunk.old_start as isize;
+    }
+    for line in &lines[pos..] {
+        out.extend_from_slice(line);
+    }
+    Some(out)
+}
+
+// =============================================================================
+// base85 (Git binary patch 와 같은 인코딩)
+// =============================================================================
+
+/// 한 줄의 바이트 수 표시 문자 (1..=26 → 'A'..='Z', 27..=52 → 'a'..='z')
+fn encode_line_length(len: usize) -> char {
+    if len <= 26 {
+        (b'A' + len as u8 - 1) as char
+    } else {
+        (b'a' + len as u8 - 27) as char
+    }
+}
+
+fn encode_base85(data: &[u8]) -> String {
+    let mut out = String::new();
+    for chunk in data.chunks(4) {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(word);
+        let mut encoded = [0u8; 5];
+        for slot in encoded.iter_mut().rev() {
+            *slot = BASE85_ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        out.push_str(std::str::from_utf8(&encoded).unwrap_or_default());
+    }
+    out
+}
+
+fn decode_base85_line(line: &str) -> Option<Vec<u8>> {
+    let bytes = line.as_bytes();
+    let len = match *bytes.first()? {
+        c @ b'A'..=b'Z' => (c - b'A' + 1) as usize,
+        c @ b'a'..=b'z' => (c - b'a' + 27) as usize,
+        _ => return None,
+    };
+    let encoded = &bytes[1..];
+    if encoded.len() != len.div_ceil(4) * 5 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(len + 3);
+    for group in encoded.chunks(5) {
+        let mut value: u64 = 0;
+        for &c in group {
+            let digit = BASE85_ALPHABET.iter().position(|&a| a == c)?;
+            value = value * 85 + digit as u64;
+        }
+        out.extend_from_slice(&u32::try_from(value).ok()?.to_be_bytes());
+    }
+    out.truncate(len);
+    Some(out)
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Commit;
+
+    fn tree_of(store: &ObjectStore, files: &[(&str, &[u8])]) -> String {
+        let map: BTreeMap<String, TreeEntry> = files
+            .iter()
+            .map(|(path, content)| {
+                let hash = store.write(&Object::from(Blob::new(content.to_vec()))).unwrap();
+                (path.to_string(), TreeEntry::file(String::new(), hash))
+            })
+            .collect();
+        store.write_flat_tree(&map).unwrap()
+    }
+
+    fn author() -> Actor {
+        Actor::parse("Jane Contributor <jane@example.com> 1709283600 +0900").unwrap()
+    }
+
+    fn commit(store: &ObjectStore, parent: Option<String>, tree: String, message: &str) -> String {
+        let committer = Actor::parse("Maintainer <maintainer@example.com> 1709370000 +0000").unwrap();
+        let commit = Commit::new(tree, parent, message.into(), author(), committer);
+        store.write(&Object::from(commit)).unwrap()
+    }
+
+    fn read(store: &ObjectStore, tree: &str, path: &str) -> Vec<u8> {
+        let entry = store.lookup_path(tree, path).unwrap().unwrap();
+        store.read_blob(&entry.hash).unwrap().content().to_vec()
+    }
+
+    fn numbered(n: usize) -> Vec<u8> {
+        (1..=n).map(|i| format!("line {}\n", i)).collect::<String>().into_bytes()
+    }
+
+    #[test]
+    fn test_format_parse_apply_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+
+        let mut image: Vec<u8> = vec![0, 1, 2, 3];
+        image.extend(std::iter::repeat_n(7u8, 300));
+        let mut new_image = image.clone();
+        new_image[100] = 9;
+
+        let base = tree_of(&store, &[
+            ("src/lib.rs", &numbered(10)),
+            ("img.bin", &image),
+            ("old.txt", b"bye\n"),
+        ]);
+        let c0 = commit(&store, None, base.clone(), "initial");
+
+        let mut changed = numbered(10);
+        changed.splice(0..0, b"// header\n".iter().copied());
+        changed.extend_from_slice(b"tail without newline");
+        let next = tree_of(&store, &[
+            ("src/lib.rs", &changed),
+            ("img.bin", &new_image),
+            ("new.txt", b"hello\n"),
+        ]);
+        let c1 = commit(&store, Some(c0), next.clone(), "Rework lib\n\nLonger explanation.");
+
+        let text = format_patch(&store, &c1, Some((1, 2))).unwrap();
+        assert!(text.contains("Subject: [PATCH 1/2] Rework lib"));
+        assert!(text.contains("Date: Fri, 1 Mar 2024 18:00:00 +0900"));
+        assert!(text.contains(NO_NEWLINE_MARKER));
+        assert!(text.contains("delta "));
+
+        let patch = Patch::parse(&text).unwrap();
+        assert_eq!(patch.commit.as_deref(), Some(c1.as_str()));
+        assert_eq!(patch.author, author());
+        assert_eq!(patch.message, "Rework lib\n\nLonger explanation.");
+        assert_eq!(patch.files.len(), 4);
+
+        let outcome = apply_patch(&store, &base, &patch, false).unwrap();
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(outcome.tree, next);
+    }
+
+    #[test]
+    fn test_apply_with_shifted_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+
+        let mut edited = numbered(20);
+        edited.extend_from_slice(b"extra\n");
+        let base = tree_of(&store, &[("a.txt", &numbered(20))]);
+        let next = tree_of(&store, &[("a.txt", &edited)]);
+        let files = diff_trees(&store, Some(&base), &next).unwrap();
+        let patch = Patch {
+            commit: None,
+            author: author(),
+            message: "append".into(),
+            files,
+        };
+
+        // 앞쪽에 라인이 추가된 트리에도 적용됨
+        let mut shifted = b"new first\nnew second\n".to_vec();
+        shifted.extend(numbered(20));
+        let target = tree_of(&store, &[("a.txt", &shifted)]);
+        let outcome = apply_patch(&store, &target, &patch, false).unwrap();
+        let result = read(&store, &outcome.tree, "a.txt");
+        assert!(result.starts_with(b"new first\n"));
+        assert!(result.ends_with(b"line 20\nextra\n"));
+    }
+
+    #[test]
+    fn test_crlf_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+
+        let base = tree_of(&store, &[("a.txt", b"a\r\nb\r\nc\r\n")]);
+        let next = tree_of(&store, &[("a.txt", b"a\r\nB\r\nc\r\n")]);
+        let c0 = commit(&store, None, base.clone(), "base");
+        let c1 = commit(&store, Some(c0), next.clone(), "upper b");
+        let patch = Patch::parse(&format_patch(&store, &c1, None).unwrap()).unwrap();
+
+        assert_eq!(apply_patch(&store, &base, &patch, false).unwrap().tree, next);
+        assert_eq!(apply_patch(&store, &base, &patch, true).unwrap().tree, next);
+    }
+
+    #[test]
+    fn test_three_way_fallback() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+
+        let base = tree_of(&store, &[("a.txt", &numbered(8))]);
+        let theirs = tree_of(&store, &[("a.txt", b"line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\nLINE 8\n")]);
+        let c0 = commit(&store, None, base, "base");
+        let c1 = commit(&store, Some(c0), theirs, "upper 8");
+        let patch = Patch::parse(&format_patch(&store, &c1, None).unwrap()).unwrap();
+
+        // 문맥 라인(6)이 바뀌어 그대로는 적용 불가
+        let ours = tree_of(&store, &[("a.txt", b"line 1\nline 2\nline 3\nline 4\nline 5\nsix\nline 7\nline 8\n")]);
+        assert!(apply_patch(&store, &ours, &patch, false).is_err());
+
+        let outcome = apply_patch(&store, &ours, &patch, true).unwrap();
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(
+            read(&store, &outcome.tree, "a.txt"),
+            b"line 1\nline 2\nline 3\nline 4\nline 5\nsix\nline 7\nLINE 8\n"
+        );
+
+        // 같은 라인을 바꾸면 충돌 마커
+        let clash = tree_of(&store, &[("a.txt", b"line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nseven\neight\n")]);
+        let outcome = apply_patch(&store, &clash, &patch, true).unwrap();
+        assert_eq!(outcome.conflicts, vec!["a.txt".to_string()]);
+        assert!(String::from_utf8(read(&store, &outcome.tree, "a.txt")).unwrap().contains("<<<<<<< ours"));
+    }
+
+    #[test]
+    fn test_submodule_diff_and_apply() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let (old, new) = ("1".repeat(64), "2".repeat(64));
+        let with_module = |hash: &str| {
+            let mut map = BTreeMap::new();
+            map.insert("lib".to_string(), TreeEntry::submodule(String::new(), hash.to_string()));
+            store.write_flat_tree(&map).unwrap()
+        };
+        let (base, next) = (with_module(&old), with_module(&new));
+        let c0 = commit(&store, None, base.clone(), "add lib");
+        let c1 = commit(&store, Some(c0), next.clone(), "bump lib");
+
+        let text = format_patch(&store, &c1, None).unwrap();
+        assert!(text.contains(&format!("-{}{}\n+{}{}\n", SUBPROJECT_PREFIX, old, SUBPROJECT_PREFIX, new)));
+        let outcome = apply_patch(&store, &base, &Patch::parse(&text).unwrap(), false).unwrap();
+        assert_eq!(outcome.tree, next);
+    }
+
+    #[test]
+    fn test_split_series() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let t0 = tree_of(&store, &[("a", b"1\n")]);
+        let t1 = tree_of(&store, &[("a", b"2\n")]);
+        let c0 = commit(&store, None, t0, "one");
+        let c1 = commit(&store, Some(c0.clone()), t1, "two");
+
+        let mbox = format_patch(&store, &c0, Some((1, 2))).unwrap() + &format_patch(&store, &c1, Some((2, 2))).unwrap();
+        let parts = split_series(&mbox);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(Patch::parse(parts[1]).unwrap().message, "two");
+    }
+
+    #[test]
+    fn test_base85_roundtrip() {
+        for len in [1, 4, 5, 26, 27, 52] {
+            let data: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(37)).collect();
+            let line = format!("{}{}", encode_line_length(len), encode_base85(&data));
+            assert_eq!(decode_base85_line(&line).unwrap(), data);
+        }
+    }
+}
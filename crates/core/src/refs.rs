@@ -0,0 +1,1126 @@
+// =============================================================================
+// 참조 저장소 (refs.rs)
+// =============================================================================
+//
+// 브랜치, 태그, 원격 추적 브랜치, 심볼릭 참조(HEAD)의 저장과 갱신
+//
+// 구조:
+//   .cts/
+//   ├── HEAD                    # "ref: refs/heads/main" 또는 커밋 해시
+//   ├── packed-refs             # 한 파일에 모은 참조 ("{hash} {name}" 줄)
+//   ├── refs/
+//   │   ├── heads/main          # 개별(loose) 참조 파일 - packed-refs 보다 우선
+//   │   ├── tags/v1.0
+//   │   └── remotes/origin/main
+//   └── logs/                   # 참조별 reflog
+//       ├── HEAD
+//       └── refs/heads/main
+//
+// 연결된 작업 디렉토리(worktree)의 참조 저장소:
+// - HEAD 처럼 "refs/" 밖의 참조와 refs/bisect/ 는 작업 디렉토리별 (.cts/worktrees/{이름}/)
+// - 나머지(브랜치, 태그, packed-refs)는 모든 작업 디렉토리가 공유
+//
+// 원자적 갱신:
+// - 참조 파일 옆에 "{name}.lock" 을 O_EXCL 로 만들어 잠금
+// - 잠금을 잡은 상태에서 현재 값이 기대값과 같은지 확인 (compare-and-swap)
+// - 새 값을 lock 파일에 쓰고 rename 으로 교체
+// - 트랜잭션은 모든 참조의 잠금을 먼저 잡고 검사를 모두 통과해야 기록
+//
+// reflog 형식 (한 줄에 한 번의 갱신):
+//   {이전 해시} {새 해시} {이름} <{이메일}> {epoch 초} +0000\t{사유}
+//
+// 파일 위치: crates/core/src/refs.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use shared::error::AppError;
+use shared::types::{now, Timestamp};
+
+use crate::config::LayeredConfig;
+use crate::store::is_valid_hash;
+
+// -----------------------------------------------------------------------------
+// 상수
+// -----------------------------------------------------------------------------
+
+/// 브랜치 참조 접두사
+pub const HEADS_PREFIX: &str = "refs/heads/";
+
+/// 태그 참조 접두사
+pub const TAGS_PREFIX: &str = "refs/tags/";
+
+/// 원격 추적 브랜치 접두사
+pub const REMOTES_PREFIX: &str = "refs/remotes/";
+
+/// 노트 참조 접두사
+pub const NOTES_PREFIX: &str = "refs/notes/";
+
+/// 심볼릭 참조 접두사
+const SYMREF_PREFIX: &str = "ref: ";
+
+/// packed-refs 파일 이름과 첫 줄
+const PACKED_REFS: &str = "packed-refs";
+const PACKED_REFS_HEADER: &str = "# cts packed-refs";
+
+/// 잠금 파일 확장자
+const LOCK_SUFFIX: &str = ".lock";
+
+/// reflog 디렉토리
+const LOGS_DIR: &str = "logs";
+
+/// "참조 없음"을 나타내는 해시
+const ZERO_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 심볼릭 참조를 따라가는 최대 깊이
+const MAX_SYMREF_DEPTH: usize = 10;
+
+// =============================================================================
+// 타입
+// =============================================================================
+
+/// 참조 파일에 저장된 값
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefValue {
+    /// 커밋(또는 태그) 해시
+    Direct(String),
+    /// 다른 참조 이름 ("ref: refs/heads/main")
+    Symbolic(String),
+}
+
+/// 갱신 전 참조 값 조건 (compare-and-swap)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    /// 조건 없음
+    Any,
+    /// 참조가 아직 없어야 함
+    Missing,
+    /// 참조가 이 해시여야 함
+    Value(String),
+}
+
+/// reflog 에 기록하는 사용자
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+impl Identity {
+    pub fn new(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            email: email.into(),
+        }
+    }
+
+    /// 환경 변수에서 사용자 정보 읽기
+    ///
+    /// CTS_COMMITTER_NAME / CTS_COMMITTER_EMAIL, 없으면 USER 기반 기본값
+    pub fn from_env() -> Self {
+        let user = std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_else(|_| "unknown".to_string());
+        let name = std::env::var("CTS_COMMITTER_NAME").unwrap_or_else(|_| user.clone());
+        let email = std::env::var("CTS_COMMITTER_EMAIL").unwrap_or_else(|_| format!("{}@localhost", user));
+        Self { name, email }
+    }
+
+    /// 설정에서 사용자 정보 읽기
+    ///
+    /// CTS_COMMITTER_NAME / CTS_COMMITTER_EMAIL → user.name / user.email → USER 기반 기본값
+    pub fn from_config(config: &LayeredConfig) -> Self {
+        let fallback = Self::from_env();
+        let lookup = |var: &str, key: &str, default: String| {
+            std::env::var(var)
+                .ok()
+                .or_else(|| config.get(key).map(str::to_string))
+                .unwrap_or(default)
+        };
+        Self {
+            name: lookup("CTS_COMMITTER_NAME", "user.name", fallback.name),
+            email: lookup("CTS_COMMITTER_EMAIL", "user.email", fallback.email),
+        }
+    }
+
+    /// 커밋에 기록할 사용자 (USER 기반 기본값 없이)
+    ///
+    /// CTS_COMMITTER_NAME / CTS_COMMITTER_EMAIL → user.name / user.email
+    ///
+    /// # Errors
+    /// * `AppError::InvalidInput` - 이름이나 이메일이 설정되지 않음
+    pub fn configured(config: &LayeredConfig) -> Result<Self, AppError> {
+        Self::configured_with(config, |name| std::env::var(name).ok())
+    }
+
+    fn configured_with(config: &LayeredConfig, var: impl Fn(&str) -> Option<String>) -> Result<Self, AppError> {
+        let lookup = |name: &str, key: &str| {
+            var(name)
+                .or_else(|| config.get(key).map(str::to_string))
+                .filter(|value| !value.trim().is_empty())
+        };
+        match (lookup("CTS_COMMITTER_NAME", "user.name"), lookup("CTS_COMMITTER_EMAIL", "user.email")) {
+            (Some(name), Some(email)) => Ok(Self { name, email }),
+            _ => Err(AppError::InvalidInput(
+                "Author identity unknown; tell cts who you are:\n\n  \
+                 cts config set --global user.name \"Your Name\"\n  \
+                 cts config set --global user.email \"you@example.com\""
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+/// reflog 한 줄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflogEntry {
+    /// 이전 해시 (새로 만든 참조면 None)
+    pub old: Option<String>,
+    /// 새 해시 (삭제면 None)
+    pub new: Option<String>,
+    pub identity: Identity,
+    pub time: Timestamp,
+    /// 갱신 사유 ("commit: 메시지", "clone: from ..." 등)
+    pub reason: String,
+}
+
+/// 트랜잭션 안의 갱신 하나
+#[derive(Debug, Clone)]
+struct RefUpdate {
+    name: String,
+    /// None 이면 삭제
+    new: Option<String>,
+    expected: Expected,
+}
+
+// =============================================================================
+// RefStore
+// =============================================================================
+
+/// 참조 저장소 (.cts 디렉토리 기준)
+#[derive(Debug, Clone)]
+pub struct RefStore {
+    root: PathBuf,
+    /// 연결된 작업 디렉토리의 관리 디렉토리 (작업 디렉토리별 참조 위치)
+    worktree: Option<PathBuf>,
+}
+
+impl RefStore {
+    /// 저장소 디렉토리(.cts)를 루트로 하는 참조 저장소
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self {
+            root: root.into(),
+            worktree: None,
+        }
+    }
+
+    /// 연결된 작업 디렉토리용 참조 저장소
+    ///
+    /// # Arguments
+    /// * `root` - 공유 저장소 디렉토리 (.cts)
+    /// * `worktree` - 작업 디렉토리별 관리 디렉토리 (.cts/worktrees/{이름})
+    pub fn with_worktree<P: Into<PathBuf>, W: Into<PathBuf>>(root: P, worktree: W) -> Self {
+        Self {
+            root: root.into(),
+            worktree: Some(worktree.into()),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // 읽기
+    // -------------------------------------------------------------------------
+
+    /// 참조 값 그대로 읽기 (loose 파일 우선, 없으면 packed-refs)
+    pub fn read(&self, name: &str) -> Result<Option<RefValue>, AppError> {
+        check_ref_name(name)?;
+        if let Some(value) = self.read_loose(name)? {
+            return Ok(Some(value));
+        }
+        if self.worktree.is_some() && is_per_worktree(name) {
+            return Ok(None);
+        }
+        Ok(self.read_packed()?.remove(name).map(RefValue::Direct))
+    }
+
+    /// 심볼릭 참조를 끝까지 따라간 최종 참조 이름
+    ///
+    /// 최종 참조가 아직 없어도 (커밋 없는 브랜치) 이름은 반환
+    pub fn resolve_name(&self, name: &str) -> Result<String, AppError> {
+        let mut name = name.to_string();
+        for _ in 0..MAX_SYMREF_DEPTH {
+            match self.read(&name)? {
+                Some(RefValue::Symbolic(target)) => name = target,
+                _ => return Ok(name),
+            }
+        }
+        Err(AppError::InvalidInput(format!("Symbolic ref loop: {}", name)))
+    }
+
+    /// 참조가 가리키는 해시 (심볼릭 참조는 따라감)
+    pub fn resolve(&self, name: &str) -> Result<Option<String>, AppError> {
+        let name = self.resolve_name(name)?;
+        match self.read(&name)? {
+            Some(RefValue::Direct(hash)) => Ok(Some(hash)),
+            _ => Ok(None),
+        }
+    }
+
+    /// 접두사 아래의 모든 참조 (loose + packed)
+    ///
+    /// # Returns
+    /// (전체 참조 이름, 해시) 목록, 이름순 정렬
+    /// 심볼릭 참조(refs/remotes/origin/HEAD 등)는 따라간 해시
+    pub fn list(&self, prefix: &str) -> Result<Vec<(String, String)>, AppError> {
+        let mut refs: BTreeMap<String, String> = self
+            .read_packed()?
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(prefix))
+            .filter(|(name, _)| self.worktree.is_none() || !is_per_worktree(name))
+            .collect();
+
+        let mut loose = Vec::new();
+        let dir = self.root.join("refs");
+        if dir.is_dir() {
+            collect_loose(&self.root, &dir, &mut loose)?;
+        }
+        if let Some(worktree) = &self.worktree {
+            // 공유 디렉토리의 작업 디렉토리별 참조는 기본 작업 디렉토리 것
+            loose.retain(|name| !is_per_worktree(name));
+            let dir = worktree.join("refs");
+            if dir.is_dir() {
+                collect_loose(worktree, &dir, &mut loose)?;
+            }
+        }
+        for name in loose.into_iter().filter(|name| name.starts_with(prefix)) {
+            match self.resolve(&name)? {
+                Some(hash) => {
+                    refs.insert(name, hash);
+                }
+                None => {
+                    refs.remove(&name);
+                }
+            }
+        }
+        Ok(refs.into_iter().collect())
+    }
+
+    // -------------------------------------------------------------------------
+    // 쓰기
+    // -------------------------------------------------------------------------
+
+    /// 여러 참조를 한 번에 갱신하는 트랜잭션 시작
+    pub fn transaction(&self) -> RefTransaction<'_> {
+        RefTransaction {
+            store: self,
+            updates: Vec::new(),
+        }
+    }
+
+    /// 참조 하나 갱신 (compare-and-swap)
+    pub fn update(
+        &self,
+        name: &str,
+        new: &str,
+        expected: Expected,
+        identity: &Identity,
+        reason: &str,
+    ) -> Result<(), AppError> {
+        let mut transaction = self.transaction();
+        transaction.update(name, new, expected);
+        transaction.commit(identity, reason)
+    }
+
+    /// 참조 하나 삭제 (compare-and-swap)
+    pub fn delete(&self, name: &str, expected: Expected) -> Result<(), AppError> {
+        let mut transaction = self.transaction();
+        transaction.delete(name, expected);
+        transaction.commit(&Identity::new("", ""), "delete")
+    }
+
+    /// 심볼릭 참조 기록 (예: HEAD → refs/heads/main)
+    ///
+    /// 가리키는 커밋이 바뀌면 reflog 에 기록 (브랜치 전환 이력)
+    pub fn set_symbolic(
+        &self,
+        name: &str,
+        target: &str,
+        identity: &Identity,
+        reason: &str,
+    ) -> Result<(), AppError> {
+        check_ref_name(name)?;
+        check_ref_name(target)?;
+        let old = self.resolve(name)?;
+
+        let lock = LockFile::acquire(&self.ref_path(name))?;
+        lock.write(format!("{}{}\n", SYMREF_PREFIX, target).as_bytes())?;
+        lock.commit()?;
+
+        let new = self.resolve(name)?;
+        if old.is_some() && new.is_some() && old != new {
+            self.append_reflog(name, old.as_deref(), new.as_deref(), identity, reason)?;
+        }
+        Ok(())
+    }
+
+    /// 심볼릭 참조를 따라가지 않고 참조 자체에 해시 기록 (예: HEAD 분리)
+    ///
+    /// HEAD 가 브랜치를 가리키고 있어도 브랜치는 그대로 두고 HEAD 만 커밋을 가리키게 됨
+    pub fn set_detached(
+        &self,
+        name: &str,
+        hash: &str,
+        identity: &Identity,
+        reason: &str,
+    ) -> Result<(), AppError> {
+        check_ref_name(name)?;
+        if !is_valid_hash(hash) {
+            return Err(AppError::InvalidInput(format!("Invalid hash for {}: {}", name, hash)));
+        }
+        let old = self.resolve(name)?;
+
+        let lock = LockFile::acquire(&self.ref_path(name))?;
+        lock.write(format!("{}\n", hash).as_bytes())?;
+        lock.commit()?;
+
+        if old.as_deref() != Some(hash) {
+            self.append_reflog(name, old.as_deref(), Some(hash), identity, reason)?;
+        }
+        Ok(())
+    }
+
+    /// loose 참조를 모두 packed-refs 로 옮기기
+    ///
+    /// 심볼릭 참조와 작업 디렉토리별 참조(refs/bisect/)는 loose 로 남김
+    ///
+    /// # Returns
+    /// packed-refs 로 옮긴 참조 수
+    pub fn pack_refs(&self) -> Result<usize, AppError> {
+        let packed_path = self.root.join(PACKED_REFS);
+        let packed_lock = LockFile::acquire(&packed_path)?;
+
+        let mut loose = Vec::new();
+        let dir = self.root.join("refs");
+        if dir.is_dir() {
+            collect_loose(&self.root, &dir, &mut loose)?;
+        }
+
+        // 옮길 참조 파일도 잠가서 그 사이의 갱신과 경합하지 않도록
+        let mut packed = self.read_packed()?;
+        let mut moved = Vec::new();
+        for name in loose.into_iter().filter(|name| !is_per_worktree(name)) {
+            let path = self.ref_path(&name);
+            let lock = LockFile::acquire(&path)?;
+            if let Some(RefValue::Direct(hash)) = self.read_loose(&name)? {
+                packed.insert(name, hash);
+                moved.push((path, lock));
+            }
+        }
+
+        packed_lock.write(format_packed(&packed).as_bytes())?;
+        packed_lock.commit()?;
+
+        let count = moved.len();
+        for (path, lock) in moved {
+            fs::remove_file(&path)?;
+            drop(lock);
+            remove_empty_parents(&self.root.join("refs"), &path);
+        }
+        Ok(count)
+    }
+
+    // -------------------------------------------------------------------------
+    // reflog
+    // -------------------------------------------------------------------------
+
+    /// 참조의 reflog (오래된 것부터)
+    pub fn reflog(&self, name: &str) -> Result<Vec<ReflogEntry>, AppError> {
+        check_ref_name(name)?;
+        let content = match fs::read_to_string(self.log_path(name)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(parse_reflog_line)
+            .collect()
+    }
+
+    /// reflog 전체를 주어진 항목들로 교체 (오래된 것부터, 비어 있으면 삭제)
+    ///
+    /// 참조 값은 바꾸지 않음 (stash drop 처럼 중간 항목을 지울 때 사용)
+    pub fn rewrite_reflog(&self, name: &str, entries: &[ReflogEntry]) -> Result<(), AppError> {
+        check_ref_name(name)?;
+        let path = self.log_path(name);
+        if entries.is_empty() {
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+            remove_empty_parents(&self.root_for(name).join(LOGS_DIR), &path);
+            return Ok(());
+        }
+        let content: String = entries
+            .iter()
+            .map(|entry| {
+                format_reflog_line(
+                    entry.old.as_deref(),
+                    entry.new.as_deref(),
+                    &entry.identity,
+                    entry.time.timestamp(),
+                    &entry.reason,
+                )
+            })
+            .collect();
+        let lock = LockFile::acquire(&path)?;
+        lock.write(content.as_bytes())?;
+        lock.commit()
+    }
+
+    fn append_reflog(
+        &self,
+        name: &str,
+        old: Option<&str>,
+        new: Option<&str>,
+        identity: &Identity,
+        reason: &str,
+    ) -> Result<(), AppError> {
+        let path = self.log_path(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let line = format_reflog_line(old, new, identity, now().timestamp(), reason);
+        // O_APPEND 한 번의 write 는 다른 프로세스의 기록과 섞이지 않음
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // 내부
+    // -------------------------------------------------------------------------
+
+    /// 참조가 저장되는 디렉토리 (작업 디렉토리별 참조면 관리 디렉토리)
+    fn root_for(&self, name: &str) -> &Path {
+        match &self.worktree {
+            Some(worktree) if is_per_worktree(name) => worktree,
+            _ => &self.root,
+        }
+    }
+
+    fn ref_path(&self, name: &str) -> PathBuf {
+        self.root_for(name).join(name)
+    }
+
+    fn log_path(&self, name: &str) -> PathBuf {
+        self.root_for(name).join(LOGS_DIR).join(name)
+    }
+
+    fn read_loose(&self, name: &str) -> Result<Option<RefValue>, AppError> {
+        let path = self.ref_path(name);
+        if path.is_dir() {
+            return Ok(None);
+        }
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            // 상위 경로가 파일인 경우 ("refs/heads/a" 가 있을 때 "refs/heads/a/b")
+            Err(err)
+                if matches!(err.kind(), std::io::ErrorKind::NotFound | std::io::ErrorKind::NotADirectory) =>
+            {
+                return Ok(None)
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let content = content.trim();
+        if let Some(target) = content.strip_prefix(SYMREF_PREFIX) {
+            return Ok(Some(RefValue::Symbolic(target.to_string())));
+        }
+        if !is_valid_hash(content) {
+            return Err(AppError::InvalidInput(format!("Corrupt ref {}: {:?}", name, content)));
+        }
+        Ok(Some(RefValue::Direct(content.to_string())))
+    }
+
+    fn read_packed(&self) -> Result<BTreeMap<String, String>, AppError> {
+        let content = match fs::read_to_string(self.root.join(PACKED_REFS)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut refs = BTreeMap::new();
+        for line in content.lines().filter(|line| !line.starts_with('#') && !line.is_empty()) {
+            let (hash, name) = line
+                .split_once(' ')
+                .filter(|(hash, _)| is_valid_hash(hash))
+                .ok_or_else(|| AppError::InvalidInput(format!("Corrupt packed-refs line: {}", line)))?;
+            refs.insert(name.to_string(), hash.to_string());
+        }
+        Ok(refs)
+    }
+
+    /// 참조의 현재 해시 (loose → packed, 심볼릭 참조는 None)
+    fn current(&self, name: &str, packed: &BTreeMap<String, String>) -> Result<Option<String>, AppError> {
+        match self.read_loose(name)? {
+            Some(RefValue::Direct(hash)) => Ok(Some(hash)),
+            Some(RefValue::Symbolic(_)) => Ok(None),
+            None => Ok(packed.get(name).cloned()),
+        }
+    }
+
+    /// 새 참조 이름이 기존 참조와 디렉토리/파일로 겹치지 않는지
+    /// ("refs/heads/a" 가 있으면 "refs/heads/a/b" 는 만들 수 없음)
+    fn check_no_dir_conflict(&self, name: &str, existing: &BTreeSet<String>) -> Result<(), AppError> {
+        let prefix = format!("{}/", name);
+        let conflict = existing.iter().find(|other| {
+            other.starts_with(&prefix) || name.starts_with(&format!("{}/", other))
+        });
+        match conflict {
+            Some(other) => Err(AppError::Conflict(format!(
+                "Cannot create {}: {} exists",
+                name, other
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+// =============================================================================
+// 트랜잭션
+// =============================================================================
+
+/// 여러 참조의 원자적 갱신
+///
+/// commit() 에서 모든 잠금을 잡고 모든 조건을 확인한 뒤에만 기록
+/// 하나라도 실패하면 아무 참조도 바뀌지 않음
+pub struct RefTransaction<'a> {
+    store: &'a RefStore,
+    updates: Vec<RefUpdate>,
+}
+
+impl RefTransaction<'_> {
+    /// 참조를 new 로 갱신 (심볼릭 참조면 가리키는 참조를 갱신)
+    pub fn update(&mut self, name: &str, new: &str, expected: Expected) -> &mut Self {
+        self.updates.push(RefUpdate {
+            name: name.to_string(),
+            new: Some(new.to_string()),
+            expected,
+        });
+        self
+    }
+
+    /// 참조 삭제
+    pub fn delete(&mut self, name: &str, expected: Expected) -> &mut Self {
+        self.updates.push(RefUpdate {
+            name: name.to_string(),
+            new: None,
+            expected,
+        });
+        self
+    }
+
+    /// 갱신할 참조가 없는지
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty()
+    }
+
+    /// 트랜잭션 적용
+    ///
+    /// # Errors
+    /// * `Conflict` - 다른 프로세스가 잠금 중이거나 현재 값이 기대값과 다름
+    /// * `InvalidInput` - 잘못된 참조 이름 또는 해시
+    pub fn commit(self, identity: &Identity, reason: &str) -> Result<(), AppError> {
+        let store = self.store;
+
+        // 1. 이름 검증, 심볼릭 참조 해석 (HEAD → refs/heads/main)
+        let mut updates: Vec<(String, RefUpdate)> = Vec::new();
+        for update in self.updates {
+            check_ref_name(&update.name)?;
+            if let Some(new) = &update.new {
+                if !is_valid_hash(new) {
+                    return Err(AppError::InvalidInput(format!("Invalid hash for {}: {}", update.name, new)));
+                }
+            }
+            let target = store.resolve_name(&update.name)?;
+            if updates.iter().any(|(t, _)| t == &target) {
+                return Err(AppError::InvalidInput(format!("Ref updated twice in one transaction: {}", target)));
+            }
+            updates.push((target, update));
+        }
+        updates.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // 2. 잠금 (이름순으로 잡아 교착 방지)
+        let mut locks = Vec::new();
+        for (target, _) in &updates {
+            locks.push(LockFile::acquire(&store.ref_path(target))?);
+        }
+        let packed = store.read_packed()?;
+        let deletes_packed = updates
+            .iter()
+            .any(|(target, update)| update.new.is_none() && packed.contains_key(target));
+        let packed_lock = if deletes_packed {
+            Some(LockFile::acquire(&store.root.join(PACKED_REFS))?)
+        } else {
+            None
+        };
+        // 잠금 후 다시 읽어야 그 사이의 변경을 놓치지 않음
+        let packed = store.read_packed()?;
+
+        // 3. 조건 검사
+        let mut existing: BTreeSet<String> = store.list("refs/")?.into_iter().map(|(name, _)| name).collect();
+        let mut olds = Vec::new();
+        for (target, update) in &updates {
+            let current = store.current(target, &packed)?;
+            let ok = match &update.expected {
+                Expected::Any => true,
+                Expected::Missing => current.is_none(),
+                Expected::Value(hash) => current.as_ref() == Some(hash),
+            };
+            if !ok {
+                return Err(AppError::Conflict(format!(
+                    "Ref {} is at {} but expected {}",
+                    target,
+                    current.as_deref().unwrap_or("(none)"),
+                    match &update.expected {
+                        Expected::Value(hash) => hash.as_str(),
+                        _ => "(none)",
+                    }
+                )));
+            }
+            if update.new.is_some() && current.is_none() {
+                existing.remove(target);
+                store.check_no_dir_conflict(target, &existing)?;
+                existing.insert(target.clone());
+            }
+            olds.push(current);
+        }
+
+        // 4. 기록
+        for ((target, update), lock) in updates.iter().zip(locks) {
+            match &update.new {
+                Some(new) => {
+                    lock.write(format!("{}\n", new).as_bytes())?;
+                    lock.commit()?;
+                }
+                None => {
+                    let path = store.ref_path(target);
+                    if path.is_file() {
+                        fs::remove_file(&path)?;
+                        remove_empty_parents(&store.root_for(target).join("refs"), &path);
+                    }
+                    drop(lock);
+                }
+            }
+        }
+        if let Some(lock) = packed_lock {
+            let remaining: BTreeMap<String, String> = packed
+                .into_iter()
+                .filter(|(name, _)| !updates.iter().any(|(t, u)| t == name && u.new.is_none()))
+                .collect();
+            lock.write(format_packed(&remaining).as_bytes())?;
+            lock.commit()?;
+        }
+
+        // 5. reflog (HEAD 를 통해 갱신하거나 HEAD 가 가리키는 브랜치면 HEAD 에도)
+        let head_target = store.resolve_name("HEAD").ok();
+        for ((target, update), old) in updates.iter().zip(olds) {
+            match &update.new {
+                Some(new) => {
+                    store.append_reflog(target, old.as_deref(), Some(new), identity, reason)?;
+                    if target != "HEAD" && head_target.as_deref() == Some(target.as_str()) {
+                        store.append_reflog("HEAD", old.as_deref(), Some(new), identity, reason)?;
+                    }
+                }
+                None => {
+                    let log = store.log_path(target);
+                    if log.is_file() {
+                        fs::remove_file(&log)?;
+                        remove_empty_parents(&store.root_for(target).join(LOGS_DIR), &log);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// 잠금 파일
+// =============================================================================
+
+/// "{path}.lock" 잠금 파일
+///
+/// commit() 하면 대상 파일로 rename, 그 전에 drop 되면 잠금 해제 (삭제)
+pub(crate) struct LockFile {
+    target: PathBuf,
+    lock_path: PathBuf,
+    committed: bool,
+}
+
+impl LockFile {
+    pub(crate) fn acquire(target: &Path) -> Result<Self, AppError> {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|_| {
+                AppError::Conflict(format!("Cannot create directory for {}", target.display()))
+            })?;
+        }
+        let mut lock_path = target.as_os_str().to_owned();
+        lock_path.push(LOCK_SUFFIX);
+        let lock_path = PathBuf::from(lock_path);
+
+        match OpenOptions::new().write(true).create_new(true).open(&lock_path) {
+            Ok(_) => Ok(Self {
+                target: target.to_path_buf(),
+                lock_path,
+                committed: false,
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Err(AppError::Conflict(format!(
+                "Unable to lock {}: another process holds {}",
+                target.display(),
+                lock_path.display()
+            ))),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub(crate) fn write(&self, data: &[u8]) -> Result<(), AppError> {
+        let mut file = File::create(&self.lock_path)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    pub(crate) fn commit(mut self) -> Result<(), AppError> {
+        fs::rename(&self.lock_path, &self.target)?;
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = fs::remove_file(&self.lock_path);
+        }
+    }
+}
+
+// =============================================================================
+// 헬퍼 함수
+// =============================================================================
+
+/// 참조 이름 검증 (Git check-ref-format 규칙 기준)
+///
+/// - "HEAD" 같은 최상위 대문자 이름 또는 "refs/..." 만 허용
+/// - 빈 요소, "." 으로 시작하는 요소, ".lock" 으로 끝나는 요소 금지
+/// - "..", "@{", 공백, 제어 문자, ~^:?*[\ 금지
+pub fn check_ref_name(name: &str) -> Result<(), AppError> {
+    let invalid = || AppError::InvalidInput(format!("Invalid ref name: {}", name));
+
+    let top_level = !name.contains('/')
+        && !name.is_empty()
+        && name.chars().all(|c| c.is_ascii_uppercase() || c == '_');
+    if !top_level && !name.starts_with("refs/") {
+        return Err(invalid());
+    }
+    if name.contains("..") || name.contains("@{") || name.ends_with('.') {
+        return Err(invalid());
+    }
+    if name
+        .chars()
+        .any(|c| c.is_control() || c == ' ' || "~^:?*[\\".contains(c))
+    {
+        return Err(invalid());
+    }
+    let components_ok = name
+        .split('/')
+        .all(|part| !part.is_empty() && !part.starts_with('.') && !part.ends_with(LOCK_SUFFIX));
+    if !components_ok {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// 짧은 브랜치/태그 이름 검증 ("feature/x" → "refs/heads/feature/x" 가 유효한지)
+pub fn check_short_name(name: &str) -> Result<(), AppError> {
+    if name.starts_with('-') || name == "HEAD" {
+        return Err(AppError::InvalidInput(format!("Invalid ref name: {}", name)));
+    }
+    check_ref_name(&format!("{}{}", HEADS_PREFIX, name))
+}
+
+fn format_packed(refs: &BTreeMap<String, String>) -> String {
+    let mut out = format!("{}\n", PACKED_REFS_HEADER);
+    for (name, hash) in refs {
+        out.push_str(&format!("{} {}\n", hash, name));
+    }
+    out
+}
+
+fn format_reflog_line(
+    old: Option<&str>,
+    new: Option<&str>,
+    identity: &Identity,
+    seconds: i64,
+    reason: &str,
+) -> String {
+    format!(
+        "{} {} {} <{}> {} +0000\t{}\n",
+        old.unwrap_or(ZERO_HASH),
+        new.unwrap_or(ZERO_HASH),
+        identity.name,
+        identity.email,
+        seconds,
+        reason.lines().next().unwrap_or("")
+    )
+}
+
+fn parse_reflog_line(line: &str) -> Result<ReflogEntry, AppError> {
+    let invalid = || AppError::InvalidInput(format!("Corrupt reflog line: {}", line));
+    let (head, reason) = line.split_once('\t').unwrap_or((line, ""));
+    let (old, rest) = head.split_once(' ').ok_or_else(invalid)?;
+    let (new, rest) = rest.split_once(' ').ok_or_else(invalid)?;
+    let (name, rest) = rest.split_once(" <").ok_or_else(invalid)?;
+    let (email, rest) = rest.split_once("> ").ok_or_else(invalid)?;
+    let seconds: i64 = rest.split(' ').next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+
+    let hash = |h: &str| (h != ZERO_HASH).then(|| h.to_string());
+    Ok(ReflogEntry {
+        old: hash(old),
+        new: hash(new),
+        identity: Identity::new(name, email),
+        time: Timestamp::from_timestamp(seconds, 0).ok_or_else(invalid)?,
+        reason: reason.to_string(),
+    })
+}
+
+/// 작업 디렉토리마다 따로 두는 참조인지 (HEAD 등 refs/ 밖의 참조, refs/bisect/)
+pub fn is_per_worktree(name: &str) -> bool {
+    !name.starts_with("refs/") || name.starts_with("refs/bisect/")
+}
+
+/// refs/ 아래의 loose 참조 이름 재귀 수집 (잠금 파일 제외)
+fn collect_loose(root: &Path, dir: &Path, out: &mut Vec<String>) -> Result<(), AppError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_loose(root, &path, out)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let parts: Vec<String> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect();
+            let name = parts.join("/");
+            if !name.ends_with(LOCK_SUFFIX) {
+                out.push(name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 삭제 후 비게 된 상위 디렉토리 정리 (stop 디렉토리는 남김)
+fn remove_empty_parents(stop: &Path, path: &Path) {
+    let mut parent = path.parent();
+    while let Some(dir) = parent.filter(|dir| *dir != stop && dir.starts_with(stop)) {
+        if fs::remove_dir(dir).is_err() {
+            break;
+        }
+        parent = dir.parent();
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigScope;
+
+    const A: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const B: &str = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+    #[test]
+    fn test_configured_identity() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config");
+        let config = |content: &str| {
+            fs::write(&path, content).unwrap();
+            LayeredConfig::from_files(&[(ConfigScope::Global, path.clone())]).unwrap()
+        };
+        let no_env = |_: &str| None;
+
+        // 설정이 없으면 기본값으로 커밋하지 않음
+        let err = Identity::configured_with(&config(""), no_env).unwrap_err();
+        assert!(err.to_string().contains("cts config set --global user.name"), "{}", err);
+        assert!(Identity::configured_with(&config("[user]\n\tname = Jane\n"), no_env).is_err());
+        assert!(Identity::configured_with(&config("[user]\n\tname = \n\temail = j@example.com\n"), no_env).is_err());
+
+        let jane = config("[user]\n\tname = Jane\n\temail = jane@example.com\n");
+        assert_eq!(
+            Identity::configured_with(&jane, no_env).unwrap(),
+            Identity::new("Jane", "jane@example.com")
+        );
+        // 환경 변수가 설정보다 우선
+        let env = |name: &str| (name == "CTS_COMMITTER_EMAIL").then(|| "ci@example.com".to_string());
+        assert_eq!(Identity::configured_with(&jane, env).unwrap(), Identity::new("Jane", "ci@example.com"));
+        assert_eq!(
+            Identity::configured_with(&config(""), |name: &str| Some(name.to_lowercase())).unwrap(),
+            Identity::new("cts_committer_name", "cts_committer_email")
+        );
+    }
+
+    fn setup() -> (tempfile::TempDir, RefStore, Identity) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = RefStore::new(dir.path());
+        let identity = Identity::new("Tester", "tester@example.com");
+        store.set_symbolic("HEAD", "refs/heads/main", &identity, "init").unwrap();
+        (dir, store, identity)
+    }
+
+    #[test]
+    fn test_compare_and_swap() {
+        let (_dir, store, id) = setup();
+        store.update("refs/heads/main", A, Expected::Missing, &id, "create").unwrap();
+        assert_eq!(store.resolve("HEAD").unwrap(), Some(A.to_string()));
+
+        // 기대값이 틀리면 거부
+        let err = store.update("refs/heads/main", B, Expected::Missing, &id, "x").unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        let err = store.update("refs/heads/main", B, Expected::Value(B.into()), &id, "x").unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+
+        store.update("refs/heads/main", B, Expected::Value(A.into()), &id, "advance").unwrap();
+        assert_eq!(store.resolve("refs/heads/main").unwrap(), Some(B.to_string()));
+    }
+
+    #[test]
+    fn test_lock_held_by_other_process() {
+        let (dir, store, id) = setup();
+        fs::create_dir_all(dir.path().join("refs/heads")).unwrap();
+        fs::write(dir.path().join("refs/heads/main.lock"), "").unwrap();
+
+        let err = store.update("refs/heads/main", A, Expected::Any, &id, "x").unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        // 남의 잠금 파일은 건드리지 않음
+        assert!(dir.path().join("refs/heads/main.lock").exists());
+    }
+
+    #[test]
+    fn test_transaction_is_all_or_nothing() {
+        let (dir, store, id) = setup();
+        store.update("refs/tags/v1", A, Expected::Missing, &id, "tag").unwrap();
+
+        let mut transaction = store.transaction();
+        transaction
+            .update("refs/heads/main", A, Expected::Missing)
+            .update("refs/tags/v1", B, Expected::Missing); // 실패
+        assert!(transaction.commit(&id, "batch").is_err());
+        assert_eq!(store.resolve("refs/heads/main").unwrap(), None);
+        assert!(!dir.path().join("refs/heads/main.lock").exists());
+
+        let mut transaction = store.transaction();
+        transaction
+            .update("refs/heads/main", A, Expected::Missing)
+            .update("refs/remotes/origin/main", A, Expected::Any)
+            .delete("refs/tags/v1", Expected::Value(A.into()));
+        transaction.commit(&id, "batch").unwrap();
+        assert_eq!(
+            store.list("refs/").unwrap(),
+            vec![
+                ("refs/heads/main".to_string(), A.to_string()),
+                ("refs/remotes/origin/main".to_string(), A.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_packed_refs() {
+        let (dir, store, id) = setup();
+        for i in 0..50 {
+            store.update(&format!("refs/tags/v{}", i), A, Expected::Missing, &id, "tag").unwrap();
+        }
+        store.update("refs/heads/main", A, Expected::Missing, &id, "c").unwrap();
+
+        assert_eq!(store.pack_refs().unwrap(), 51);
+        assert!(!dir.path().join("refs/tags").exists());
+        assert_eq!(store.list(TAGS_PREFIX).unwrap().len(), 50);
+        assert_eq!(store.resolve("HEAD").unwrap(), Some(A.to_string()));
+
+        // loose 갱신이 packed 값보다 우선
+        store.update("refs/heads/main", B, Expected::Value(A.into()), &id, "c").unwrap();
+        assert_eq!(store.resolve("refs/heads/main").unwrap(), Some(B.to_string()));
+
+        // packed 참조 삭제
+        store.delete("refs/tags/v7", Expected::Value(A.into())).unwrap();
+        assert_eq!(store.resolve("refs/tags/v7").unwrap(), None);
+        assert_eq!(store.list(TAGS_PREFIX).unwrap().len(), 49);
+    }
+
+    #[test]
+    fn test_reflog() {
+        let (_dir, store, id) = setup();
+        store.update("HEAD", A, Expected::Missing, &id, "commit (initial): first").unwrap();
+        store.update("HEAD", B, Expected::Value(A.into()), &id, "commit: second").unwrap();
+
+        let log = store.reflog("refs/heads/main").unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].old, None);
+        assert_eq!(log[1].old.as_deref(), Some(A));
+        assert_eq!(log[1].new.as_deref(), Some(B));
+        assert_eq!(log[1].identity, id);
+        assert_eq!(log[1].reason, "commit: second");
+
+        // HEAD 를 통한 갱신은 HEAD reflog 에도 기록
+        assert_eq!(store.reflog("HEAD").unwrap().len(), 2);
+
+        store.delete("refs/heads/main", Expected::Any).unwrap();
+        assert!(store.reflog("refs/heads/main").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_detached_head_and_rewrite_reflog() {
+        let (_dir, store, id) = setup();
+        store.update("HEAD", A, Expected::Missing, &id, "commit (initial): first").unwrap();
+
+        // 분리해도 브랜치는 그대로
+        store.set_detached("HEAD", B, &id, "checkout: moving to b").unwrap();
+        assert_eq!(store.read("HEAD").unwrap(), Some(RefValue::Direct(B.to_string())));
+        assert_eq!(store.resolve("refs/heads/main").unwrap(), Some(A.to_string()));
+        assert_eq!(store.reflog("HEAD").unwrap().last().unwrap().reason, "checkout: moving to b");
+
+        let mut log = store.reflog("HEAD").unwrap();
+        log.remove(0);
+        store.rewrite_reflog("HEAD", &log).unwrap();
+        assert_eq!(store.reflog("HEAD").unwrap(), log);
+        store.rewrite_reflog("HEAD", &[]).unwrap();
+        assert!(store.reflog("HEAD").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_directory_conflict() {
+        let (_dir, store, id) = setup();
+        store.update("refs/heads/feature", A, Expected::Missing, &id, "x").unwrap();
+        let err = store.update("refs/heads/feature/x", A, Expected::Missing, &id, "x").unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn test_check_ref_name() {
+        assert!(check_ref_name("HEAD").is_ok());
+        assert!(check_ref_name("refs/heads/feature/login").is_ok());
+        assert!(check_ref_name("refs/remotes/origin/main").is_ok());
+        for bad in ["main", "refs/heads/../x", "refs/heads/a..b", "refs/heads/.hidden", "refs/heads/x.lock",
+            "refs/heads/a b", "refs/heads/a~1", "refs/heads/", "refs//x", "refs/heads/x@{1}"] {
+            assert!(check_ref_name(bad).is_err(), "{}", bad);
+        }
+        assert!(check_short_name("feature/x").is_ok());
+        assert!(check_short_name("-x").is_err());
+    }
+}
@@ -0,0 +1,812 @@
+// =============================================================================
+// 로컬 저장소 (repo.rs)
+// =============================================================================
+//
+// .cts/ 디렉토리로 표현되는 로컬 저장소
+//
+// 구조:
+//   .cts/
+//   ├── HEAD            # 현재 브랜치 ("ref: refs/heads/main") 또는 커밋 해시
+//   ├── config          # 설정 (형식 버전, 해시 알고리즘, 원격 주소, partial clone 등)
+//   ├── shallow         # shallow clone 의 경계 커밋 (없으면 전체 히스토리)
+//   ├── index           # 스테이징 영역 (Index)
+//   ├── objects/        # 객체 저장소 (ObjectStore)
+//   ├── packed-refs     # 모아 둔 참조 (RefStore)
+//   ├── logs/           # 참조별 reflog
+//   └── refs/
+//       ├── heads/      # 브랜치
+//       ├── tags/       # 태그
+//       └── remotes/    # 원격 추적 브랜치
+//
+// 연결된 작업 디렉토리 (worktree.rs):
+//   {작업 디렉토리}/.cts          # 파일: "ctsdir: {관리 디렉토리}"
+//   .cts/worktrees/{이름}/        # 관리 디렉토리 = 이 작업 디렉토리의 cts_dir
+//   ├── HEAD, index, ...          # 작업 디렉토리별 상태
+//   ├── commondir                 # 공유 .cts 경로 (objects, refs)
+//   └── workdir                   # 작업 디렉토리 경로
+//
+// bare 저장소 (서버 보관용): 작업 디렉토리 없이 디렉토리 자체가 .cts 와 같은 구조 (core.bare = true)
+//
+// 명령은 Repository::from_env 로 저장소를 찾음
+// (환경 변수 CTS_DIR 이 있으면 그 디렉토리, 없으면 현재 디렉토리부터 상위로 .cts 또는 bare 저장소 탐색)
+//
+// 파일 위치: crates/core/src/repo.rs
+//
+// 사용 예시:
+//   use core::repo::Repository;
+//
+//   let repo = Repository::from_env()?;
+//   let head = repo.resolve_rev("HEAD~2")?;
+// =============================================================================
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use shared::error::AppError;
+
+use crate::config::{parse_int, Config, LayeredConfig, CONFIG_FILE};
+use crate::hash::HASH_ALGORITHM;
+use crate::index::{Index, INDEX_FILE};
+use crate::object::Object;
+use crate::refs::{check_ref_name, Identity, RefStore, RefValue, HEADS_PREFIX};
+use crate::shallow;
+use crate::sparse::SparsePatterns;
+use crate::store::{is_valid_hash, ObjectStore};
+use crate::transport::{self, Transport};
+use crate::worktree::{read_link, COMMONDIR_FILE, WORKDIR_FILE};
+
+// -----------------------------------------------------------------------------
+// 상수
+// -----------------------------------------------------------------------------
+
+/// 저장소 메타데이터 디렉토리 이름
+pub const CTS_DIR_NAME: &str = ".cts";
+
+/// 기본 브랜치 이름
+pub const DEFAULT_BRANCH: &str = "main";
+
+/// 저장소 형식 버전 (core.repositoryformatversion 이 이보다 높으면 열지 않음)
+pub const REPOSITORY_FORMAT_VERSION: i64 = 0;
+
+/// 탐색 대신 사용할 .cts 디렉토리를 지정하는 환경 변수
+pub const CTS_DIR_ENV: &str = "CTS_DIR";
+
+/// 기본 브랜치 이름 설정 키
+const DEFAULT_BRANCH_KEY: &str = "init.defaultbranch";
+
+/// 저장소 생성 옵션
+#[derive(Debug, Clone, Default)]
+pub struct InitOptions {
+    /// 작업 디렉토리 없이 지정한 디렉토리 자체를 저장소로 (서버 보관용)
+    pub bare: bool,
+    /// HEAD 가 가리킬 첫 브랜치 (없으면 설정 init.defaultbranch → main)
+    pub initial_branch: Option<String>,
+}
+
+// =============================================================================
+// Repository 구조체
+// =============================================================================
+
+/// 로컬 CTS 저장소
+#[derive(Debug, Clone)]
+pub struct Repository {
+    /// .cts 디렉토리 경로 (연결된 작업 디렉토리면 관리 디렉토리)
+    cts_dir: PathBuf,
+    /// 객체와 참조를 담은 공유 .cts 디렉토리
+    common_dir: PathBuf,
+    /// 작업 디렉토리 (bare 저장소면 None)
+    work_dir: Option<PathBuf>,
+    /// 객체 저장소
+    objects: ObjectStore,
+    /// 참조 저장소
+    refs: RefStore,
+}
+
+impl Repository {
+    /// .cts 디렉토리(또는 bare 저장소 디렉토리)를 직접 열기
+    ///
+    /// 작업 디렉토리는 .cts 의 부모 디렉토리 (이름이 .cts 가 아니거나 core.bare = true 면 bare)
+    /// 연결된 작업 디렉토리의 관리 디렉토리면 공유 저장소와 기록된 작업 디렉토리 사용
+    pub fn open<P: AsRef<Path>>(cts_dir: P) -> Result<Self, AppError> {
+        let cts_dir = cts_dir.as_ref().to_path_buf();
+        if !is_cts_dir(&cts_dir) {
+            return Err(AppError::NotFound(format!(
+                "Not a CTS repository: {}",
+                cts_dir.display()
+            )));
+        }
+
+        let (common_dir, work_dir, refs) = if cts_dir.join(COMMONDIR_FILE).is_file() {
+            let common_dir = read_link(&cts_dir.join(COMMONDIR_FILE))?;
+            let work_dir = read_link(&cts_dir.join(WORKDIR_FILE))?;
+            let refs = RefStore::with_worktree(&common_dir, &cts_dir);
+            (common_dir, Some(work_dir), refs)
+        } else {
+            let work_dir = match cts_dir.file_name() {
+                Some(name) if name == CTS_DIR_NAME => cts_dir.parent().map(Path::to_path_buf),
+                _ => None,
+            };
+            (cts_dir.clone(), work_dir, RefStore::new(&cts_dir))
+        };
+
+        let config = Config::load(&common_dir.join(CONFIG_FILE))?;
+        check_format(&config)?;
+        // 연결된 작업 디렉토리는 bare 저장소에서 만들었어도 자기 작업 디렉토리가 있음
+        let work_dir = if cts_dir == common_dir && config.get_bool("core.bare")? == Some(true) {
+            None
+        } else {
+            work_dir
+        };
+        let mut objects = ObjectStore::new(common_dir.join("objects")).with_shallow(shallow::load(&common_dir)?);
+        if let Some(remote) = promisor_remote(&config)? {
+            objects = objects.with_promisor(remote);
+        }
+        let settings = LayeredConfig::load(Some(&common_dir.join(CONFIG_FILE)))?;
+        if let Some(level) = compression_level(&settings)? {
+            objects = objects.with_compression(level);
+        }
+        Ok(Self {
+            cts_dir,
+            common_dir,
+            work_dir,
+            objects,
+            refs,
+        })
+    }
+
+    /// 새 저장소 생성 (work_dir/.cts)
+    ///
+    /// HEAD 는 아직 커밋이 없는 기본 브랜치를 가리킴
+    pub fn init<P: AsRef<Path>>(work_dir: P) -> Result<Self, AppError> {
+        Self::init_with(work_dir, &InitOptions::default())
+    }
+
+    /// 옵션을 지정해서 새 저장소 생성
+    ///
+    /// bare 면 path 자체가 저장소 디렉토리, 아니면 path/.cts
+    /// 설정에 형식 버전과 해시 알고리즘을 기록
+    pub fn init_with<P: AsRef<Path>>(path: P, options: &InitOptions) -> Result<Self, AppError> {
+        let path = path.as_ref();
+        let cts_dir = if options.bare { path.to_path_buf() } else { path.join(CTS_DIR_NAME) };
+        if is_cts_dir(&cts_dir) || cts_dir.is_file() {
+            return Err(AppError::AlreadyExists(format!(
+                "Repository {}",
+                cts_dir.display()
+            )));
+        }
+
+        // 아직 저장소 설정이 없으므로 시스템 / 전역 설정만
+        let settings = LayeredConfig::load(None)?;
+        let branch = match &options.initial_branch {
+            Some(branch) => branch.as_str(),
+            None => settings.get(DEFAULT_BRANCH_KEY).unwrap_or(DEFAULT_BRANCH),
+        };
+        let head = format!("{}{}", HEADS_PREFIX, branch);
+        check_ref_name(&head)?;
+
+        fs::create_dir_all(cts_dir.join("objects"))?;
+        fs::create_dir_all(cts_dir.join("refs/heads"))?;
+        fs::create_dir_all(cts_dir.join("refs/tags"))?;
+        let mut config = Config::new();
+        config.set("core.repositoryformatversion", &REPOSITORY_FORMAT_VERSION.to_string())?;
+        config.set("core.objectformat", HASH_ALGORITHM)?;
+        config.set("core.bare", &options.bare.to_string())?;
+        config.save(&cts_dir.join(CONFIG_FILE))?;
+        RefStore::new(&cts_dir).set_symbolic("HEAD", &head, &Identity::from_config(&settings), "init")?;
+        Self::open(cts_dir)
+    }
+
+    /// 현재 디렉토리의 저장소 (명령들이 공통으로 사용)
+    ///
+    /// 환경 변수 CTS_DIR 이 있으면 탐색 없이 그 디렉토리를 염
+    /// (상대 경로는 현재 디렉토리 기준, 이름이 .cts 가 아니거나 core.bare = true 면 bare 로 취급)
+    pub fn from_env() -> Result<Self, AppError> {
+        let cwd = std::env::current_dir()?;
+        match std::env::var_os(CTS_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => Self::open(cwd.join(dir)),
+            _ => Self::discover(cwd),
+        }
+    }
+
+    /// 시작 디렉토리부터 상위로 올라가며 .cts 디렉토리 탐색
+    ///
+    /// 디렉토리 자체가 bare 저장소 (core.bare = true) 면 그 저장소
+    pub fn discover<P: AsRef<Path>>(start: P) -> Result<Self, AppError> {
+        let start = start.as_ref();
+        let start = fs::canonicalize(start).unwrap_or_else(|_| start.to_path_buf());
+
+        for dir in start.ancestors() {
+            let candidate = dir.join(CTS_DIR_NAME);
+            // 연결된 작업 디렉토리: .cts 파일이 관리 디렉토리를 가리킴
+            if candidate.is_file() {
+                return Self::open(read_link(&candidate)?);
+            }
+            if is_cts_dir(&candidate) {
+                return Self::open(candidate);
+            }
+            if is_bare_dir(dir)? {
+                return Self::open(dir);
+            }
+        }
+        Err(AppError::NotFound(format!(
+            "Not a CTS repository (or any parent): {}",
+            start.display()
+        )))
+    }
+
+    /// .cts 디렉토리 경로 (HEAD, index 등 작업 디렉토리별 상태 위치)
+    pub fn cts_dir(&self) -> &Path {
+        &self.cts_dir
+    }
+
+    /// 모든 작업 디렉토리가 공유하는 .cts 디렉토리 (객체, 참조)
+    pub fn common_dir(&self) -> &Path {
+        &self.common_dir
+    }
+
+    /// 연결된 작업 디렉토리인지 (기본 작업 디렉토리면 false)
+    pub fn is_linked_worktree(&self) -> bool {
+        self.cts_dir != self.common_dir
+    }
+
+    /// 작업 디렉토리 경로 (bare 저장소면 None)
+    pub fn work_dir(&self) -> Option<&Path> {
+        self.work_dir.as_deref()
+    }
+
+    /// 객체 저장소
+    pub fn objects(&self) -> &ObjectStore {
+        &self.objects
+    }
+
+    /// 참조 저장소 (갱신은 여기서 트랜잭션으로)
+    pub fn refs(&self) -> &RefStore {
+        &self.refs
+    }
+
+    /// 설정 파일 경로 (.cts/config, 작업 디렉토리끼리 공유)
+    pub fn config_path(&self) -> PathBuf {
+        self.common_dir.join(CONFIG_FILE)
+    }
+
+    /// 설정 읽기 (이 저장소의 .cts/config 만, 값을 바꿀 때)
+    pub fn config(&self) -> Result<Config, AppError> {
+        Config::load(&self.config_path())
+    }
+
+    /// 시스템 / 전역 / 저장소 설정과 환경 변수를 합친 설정 (값을 읽을 때)
+    pub fn layered_config(&self) -> Result<LayeredConfig, AppError> {
+        LayeredConfig::load(Some(&self.config_path()))
+    }
+
+    /// 커밋과 reflog 에 기록할 사용자 (환경 변수 → user.name / user.email → 기본값)
+    pub fn identity(&self) -> Result<Identity, AppError> {
+        Ok(Identity::from_config(&self.layered_config()?))
+    }
+
+    /// 새 커밋, 태그 객체, 노트 커밋에 기록할 사용자 (설정되지 않았으면 오류, Identity::configured)
+    pub fn committer_identity(&self) -> Result<Identity, AppError> {
+        Identity::configured(&self.layered_config()?)
+    }
+
+    // -------------------------------------------------------------------------
+    // 인덱스 (Index)
+    // -------------------------------------------------------------------------
+
+    /// 인덱스 파일 경로 (.cts/index)
+    pub fn index_path(&self) -> PathBuf {
+        self.cts_dir.join(INDEX_FILE)
+    }
+
+    /// 인덱스 읽기
+    ///
+    /// 인덱스 파일이 없으면 HEAD 트리 내용 (커밋이 없으면 빈 인덱스)
+    pub fn read_index(&self) -> Result<Index, AppError> {
+        if let Some(index) = Index::load(&self.index_path())? {
+            return Ok(index);
+        }
+        match self.head()? {
+            Some(head) => {
+                let tree = self.objects.read_commit(&head)?.tree_hash;
+                let mut index = Index::from_tree(&self.objects, &tree)?;
+                index.apply_sparse(SparsePatterns::load(&self.cts_dir)?.as_ref());
+                Ok(index)
+            }
+            None => Ok(Index::new()),
+        }
+    }
+
+    /// 인덱스 기록
+    ///
+    /// sparse checkout 범위 밖의 엔트리는 skip-worktree 로 표시해서 기록
+    pub fn write_index(&self, index: &Index) -> Result<(), AppError> {
+        let mut index = index.clone();
+        index.apply_sparse(SparsePatterns::load(&self.cts_dir)?.as_ref());
+        index.save(&self.index_path())
+    }
+
+    // -------------------------------------------------------------------------
+    // 참조 (Refs)
+    // -------------------------------------------------------------------------
+
+    /// 참조가 가리키는 해시 (심볼릭 참조는 끝까지 따라감)
+    ///
+    /// # Returns
+    /// * `Ok(Some(hash))` - 커밋 해시
+    /// * `Ok(None)` - 참조가 없거나 아직 커밋이 없는 브랜치
+    pub fn read_ref(&self, name: &str) -> Result<Option<String>, AppError> {
+        self.refs.resolve(name)
+    }
+
+    /// 짧은 이름을 전체 참조 이름으로 변환 ("main" → "refs/heads/main")
+    ///
+    /// HEAD 는 가리키는 브랜치 이름으로 변환 (detached 면 None)
+    /// 일치하는 참조가 없으면 None
+    pub fn full_ref_name(&self, name: &str) -> Result<Option<String>, AppError> {
+        if name == "HEAD" {
+            return match self.refs.read("HEAD")? {
+                Some(RefValue::Symbolic(target)) => Ok(Some(target)),
+                _ => Ok(None),
+            };
+        }
+        for candidate in ref_candidates(name) {
+            if candidate.starts_with("refs/")
+                && check_ref_name(&candidate).is_ok()
+                && self.refs.read(&candidate)?.is_some()
+            {
+                return Ok(Some(candidate));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 접두사 아래의 모든 참조 ("refs/heads/" 등)
+    ///
+    /// # Returns
+    /// (전체 참조 이름, 커밋 해시) 목록, 이름순 정렬
+    pub fn list_refs(&self, prefix: &str) -> Result<Vec<(String, String)>, AppError> {
+        self.refs.list(prefix)
+    }
+
+    /// HEAD 가 가리키는 커밋 해시 (커밋이 없으면 None)
+    pub fn head(&self) -> Result<Option<String>, AppError> {
+        self.read_ref("HEAD")
+    }
+
+    // -------------------------------------------------------------------------
+    // 리비전 해석
+    // -------------------------------------------------------------------------
+
+    /// 리비전 문자열을 커밋 해시로 해석
+    ///
+    /// 지원 형식:
+    /// - HEAD, 브랜치 이름, 태그 이름, refs/... 전체 이름
+    /// - 전체 해시 또는 4자 이상 해시 접두사
+    /// - 접미사: `~N` (N번째 조상), `^` (부모)
+    /// - 주석 태그는 가리키는 커밋으로 해석
+    pub fn resolve_rev(&self, spec: &str) -> Result<String, AppError> {
+        let (base, suffix) = match spec.find(['~', '^']) {
+            Some(pos) => spec.split_at(pos),
+            None => (spec, ""),
+        };
+
+        let mut hash = self
+            .resolve_name(base)?
+            .ok_or_else(|| AppError::NotFound(format!("Unknown revision: {}", spec)))?;
+        // 주석 태그는 가리키는 커밋으로
+        if matches!(self.objects.read(&hash), Ok(Object::Tag(_))) {
+            hash = self.objects.peel_to_commit(&hash)?;
+        }
+
+        let mut rest = suffix;
+        while !rest.is_empty() {
+            let op = rest.as_bytes()[0];
+            let digits: String = rest[1..].chars().take_while(|c| c.is_ascii_digit()).collect();
+            rest = &rest[1 + digits.len()..];
+
+            let count = if digits.is_empty() {
+                1
+            } else {
+                digits
+                    .parse::<usize>()
+                    .map_err(|_| AppError::InvalidInput(format!("Invalid revision: {}", spec)))?
+            };
+            // ^N 은 N번째 부모 (현재는 부모가 하나뿐)
+            let generations = if op == b'^' && count > 1 {
+                return Err(AppError::NotFound(format!("Unknown revision: {}", spec)));
+            } else if op == b'^' {
+                count.min(1)
+            } else {
+                count
+            };
+
+            for _ in 0..generations {
+                let commit = self.objects.read_commit(&hash)?;
+                hash = self
+                    .objects
+                    .parent_of(&hash, &commit)
+                    .ok_or_else(|| AppError::NotFound(format!("Unknown revision: {}", spec)))?;
+            }
+        }
+        Ok(hash)
+    }
+
+    /// 리비전 범위 해석 ("A..B" 또는 "B")
+    ///
+    /// # Returns
+    /// (제외할 커밋 목록, 포함할 끝 커밋)
+    /// - "A..B" → ([A], B)
+    /// - "A.."  → ([A], HEAD)
+    /// - "B"    → ([], B)
+    pub fn resolve_range(&self, spec: &str) -> Result<(Vec<String>, String), AppError> {
+        match spec.split_once("..") {
+            Some((from, to)) => {
+                let to = if to.is_empty() { "HEAD" } else { to };
+                let from = if from.is_empty() { "HEAD" } else { from };
+                Ok((vec![self.resolve_rev(from)?], self.resolve_rev(to)?))
+            }
+            None => Ok((Vec::new(), self.resolve_rev(spec)?)),
+        }
+    }
+
+    /// 이름(접미사 제외) 해석
+    fn resolve_name(&self, name: &str) -> Result<Option<String>, AppError> {
+        if name.is_empty() {
+            return Ok(None);
+        }
+        if name == "HEAD" {
+            return self.head();
+        }
+        if is_valid_hash(name) {
+            return Ok(Some(name.to_string()));
+        }
+        // "main@{2}": reflog 에서 두 번 전 값
+        if let Some((base, rest)) = name.split_once("@{") {
+            let n: usize = rest
+                .strip_suffix('}')
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(|| AppError::InvalidInput(format!("Invalid revision: {}", name)))?;
+            let full = match base {
+                "" | "HEAD" => "HEAD".to_string(),
+                _ => match self.full_ref_name(base)? {
+                    Some(full) => full,
+                    None => return Ok(None),
+                },
+            };
+            let log = self.refs.reflog(&full)?;
+            return Ok(log.iter().rev().nth(n).and_then(|entry| entry.new.clone()));
+        }
+        for candidate in ref_candidates(name) {
+            if check_ref_name(&candidate).is_err() {
+                continue;
+            }
+            if let Some(hash) = self.read_ref(&candidate)? {
+                return Ok(Some(hash));
+            }
+        }
+        self.objects.find_by_prefix(name)
+    }
+
+    // -------------------------------------------------------------------------
+    // 경로
+    // -------------------------------------------------------------------------
+
+    /// 작업 디렉토리 기준 상대 경로 ('/' 구분)로 변환
+    ///
+    /// # Arguments
+    /// * `cwd` - 현재 디렉토리 (path 가 상대 경로일 때 기준)
+    /// * `path` - 사용자가 입력한 경로
+    pub fn relative_path(&self, cwd: &Path, path: &Path) -> Result<String, AppError> {
+        let work_dir = self
+            .work_dir
+            .as_ref()
+            .ok_or_else(|| AppError::InvalidInput("Bare repository has no working tree".to_string()))?;
+        let work_dir = fs::canonicalize(work_dir).unwrap_or_else(|_| work_dir.clone());
+        let cwd = fs::canonicalize(cwd).unwrap_or_else(|_| cwd.to_path_buf());
+
+        let absolute = normalize(&cwd.join(path));
+        let relative = absolute.strip_prefix(&work_dir).map_err(|_| {
+            AppError::InvalidInput(format!("Path is outside repository: {}", path.display()))
+        })?;
+
+        let parts: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        Ok(parts.join("/"))
+    }
+}
+
+// =============================================================================
+// 헬퍼 함수
+// =============================================================================
+
+/// partial clone 으로 받은 원격 (remote.{이름}.promisor = true 인 첫 원격)
+fn promisor_remote(config: &Config) -> Result<Option<Arc<dyn Transport>>, AppError> {
+    for name in config.subsections("remote") {
+        if config.get_bool(&format!("remote.{}.promisor", name))? != Some(true) {
+            continue;
+        }
+        if let Some(url) = config.get(&format!("remote.{}.url", name)) {
+            return transport::connect(url).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// core.compression (-1 = 기본값, 0 = 압축 안 함 ~ 9 = 최대)
+fn compression_level(config: &LayeredConfig) -> Result<Option<flate2::Compression>, AppError> {
+    match config.get_int("core.compression")? {
+        None | Some(-1) => Ok(None),
+        Some(level @ 0..=9) => Ok(Some(flate2::Compression::new(level as u32))),
+        Some(level) => Err(AppError::InvalidInput(format!(
+            "core.compression must be between -1 and 9: {}",
+            level
+        ))),
+    }
+}
+
+/// 이 구현이 읽을 수 있는 형식 버전과 해시 알고리즘인지 확인 (없으면 초기 형식)
+fn check_format(config: &Config) -> Result<(), AppError> {
+    if let Some(version) = config.get("core.repositoryformatversion") {
+        let version = parse_int("core.repositoryformatversion", version)?;
+        if version > REPOSITORY_FORMAT_VERSION {
+            return Err(AppError::InvalidInput(format!(
+                "Unsupported repository format version: {}",
+                version
+            )));
+        }
+    }
+    match config.get("core.objectformat") {
+        Some(format) if !format.eq_ignore_ascii_case(HASH_ALGORITHM) => Err(AppError::InvalidInput(format!(
+            "Unsupported object format: {} (expected {})",
+            format, HASH_ALGORITHM
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// 저장소 디렉토리 형태인지 확인 (HEAD 파일 + objects 디렉토리 또는 commondir 파일)
+fn is_cts_dir(path: &Path) -> bool {
+    path.join("HEAD").is_file() && (path.join("objects").is_dir() || path.join(COMMONDIR_FILE).is_file())
+}
+
+/// 디렉토리 자체가 bare 저장소인지 (core.bare = true, 작업 디렉토리의 .cts 는 false)
+fn is_bare_dir(path: &Path) -> Result<bool, AppError> {
+    if !is_cts_dir(path) || !path.join(CONFIG_FILE).is_file() {
+        return Ok(false);
+    }
+    Ok(Config::load(&path.join(CONFIG_FILE))?.get_bool("core.bare")? == Some(true))
+}
+
+/// 짧은 이름을 해석할 때 시도하는 전체 이름 (우선순위 순)
+fn ref_candidates(name: &str) -> [String; 6] {
+    [
+        name.to_string(),
+        format!("refs/{}", name),
+        format!("refs/heads/{}", name),
+        format!("refs/tags/{}", name),
+        format!("refs/remotes/{}", name),
+        format!("refs/remotes/{}/HEAD", name),
+    ]
+}
+
+/// "." 과 ".." 을 정리한 경로 (파일 시스템 접근 없음)
+fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                result.pop();
+            }
+            other => result.push(other.as_os_str()),
+        }
+    }
+    result
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Commit, Tag, Tree};
+    use crate::refs::Expected;
+
+    fn setup() -> (tempfile::TempDir, Repository) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        (dir, repo)
+    }
+
+    fn commit(repo: &Repository, parent: Option<String>, message: &str) -> String {
+        let tree = repo.objects().write(&Object::from(Tree::new())).unwrap();
+        let commit = Commit::new(
+            tree,
+            parent,
+            message.into(),
+            Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap(),
+            Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap(),
+        );
+        repo.objects().write(&Object::from(commit)).unwrap()
+    }
+
+    #[test]
+    fn test_discover_from_subdirectory() {
+        let (dir, repo) = setup();
+        let sub = dir.path().join("a/b");
+        fs::create_dir_all(&sub).unwrap();
+
+        let found = Repository::discover(&sub).unwrap();
+        assert_eq!(
+            fs::canonicalize(found.cts_dir()).unwrap(),
+            fs::canonicalize(repo.cts_dir()).unwrap()
+        );
+        assert!(found.work_dir().is_some());
+    }
+
+    #[test]
+    fn test_discover_outside_repository() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(Repository::discover(dir.path()), Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_unborn_head() {
+        let (_dir, repo) = setup();
+        assert_eq!(repo.head().unwrap(), None);
+        assert!(repo.resolve_rev("HEAD").is_err());
+    }
+
+    #[test]
+    fn test_resolve_rev() {
+        let (_dir, repo) = setup();
+        let c0 = commit(&repo, None, "first");
+        let c1 = commit(&repo, Some(c0.clone()), "second");
+        let c2 = commit(&repo, Some(c1.clone()), "third");
+        fs::write(repo.cts_dir().join("refs/heads/main"), format!("{}\n", c2)).unwrap();
+
+        assert_eq!(repo.resolve_rev("HEAD").unwrap(), c2);
+        assert_eq!(repo.resolve_rev("main").unwrap(), c2);
+        assert_eq!(repo.resolve_rev("refs/heads/main").unwrap(), c2);
+        assert_eq!(repo.resolve_rev("HEAD^").unwrap(), c1);
+        assert_eq!(repo.resolve_rev("HEAD~2").unwrap(), c0);
+        assert_eq!(repo.resolve_rev("main~1^").unwrap(), c0);
+        assert_eq!(repo.resolve_rev(&c1[..10]).unwrap(), c1);
+        assert!(repo.resolve_rev("HEAD~3").is_err());
+        assert!(repo.resolve_rev("../../etc/passwd").is_err());
+
+        // 주석 태그는 커밋으로 해석
+        let tag = Tag::new(c1.clone(), "v1".into(), "v1\n".into(), Actor::now("T".into(), "t@example.com".into()));
+        let tag = repo.objects().write(&Object::from(tag)).unwrap();
+        fs::create_dir_all(repo.cts_dir().join("refs/tags")).unwrap();
+        fs::write(repo.cts_dir().join("refs/tags/v1"), &tag).unwrap();
+        assert_eq!(repo.resolve_rev("v1").unwrap(), c1);
+        assert_eq!(repo.resolve_rev("v1^").unwrap(), c0);
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let (_dir, repo) = setup();
+        let c0 = commit(&repo, None, "first");
+        let c1 = commit(&repo, Some(c0.clone()), "second");
+        fs::write(repo.cts_dir().join("refs/heads/main"), &c1).unwrap();
+
+        assert_eq!(repo.resolve_range("HEAD^..HEAD").unwrap(), (vec![c0.clone()], c1.clone()));
+        assert_eq!(repo.resolve_range("HEAD^..").unwrap(), (vec![c0], c1.clone()));
+        assert_eq!(repo.resolve_range("main").unwrap(), (vec![], c1));
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let (dir, repo) = setup();
+        let sub = dir.path().join("src");
+        fs::create_dir_all(&sub).unwrap();
+
+        assert_eq!(repo.relative_path(&sub, Path::new("main.rs")).unwrap(), "src/main.rs");
+        assert_eq!(repo.relative_path(&sub, Path::new("../README.md")).unwrap(), "README.md");
+        assert!(repo.relative_path(&sub, Path::new("../../outside")).is_err());
+    }
+
+    #[test]
+    fn test_init_twice_fails() {
+        let (dir, _repo) = setup();
+        assert!(matches!(Repository::init(dir.path()), Err(AppError::AlreadyExists(_))));
+    }
+
+    #[test]
+    fn test_init_bare_with_branch() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = InitOptions {
+            bare: true,
+            initial_branch: Some("trunk".into()),
+        };
+        let repo = Repository::init_with(dir.path().join("server.cts"), &options).unwrap();
+        assert_eq!(repo.cts_dir(), dir.path().join("server.cts"));
+        assert!(repo.work_dir().is_none());
+        assert_eq!(repo.full_ref_name("HEAD").unwrap().as_deref(), Some("refs/heads/trunk"));
+
+        let config = repo.config().unwrap();
+        assert_eq!(config.get("core.repositoryformatversion"), Some("0"));
+        assert_eq!(config.get("core.objectformat"), Some(HASH_ALGORITHM));
+        assert_eq!(config.get_bool("core.bare").unwrap(), Some(true));
+
+        // 저장소 디렉토리나 그 안에서 찾아도 bare (이름과 무관하게 core.bare 를 따름)
+        let objects = dir.path().join("server.cts/objects");
+        for start in [dir.path().join("server.cts"), objects] {
+            let found = Repository::discover(&start).unwrap();
+            assert_eq!(fs::canonicalize(found.cts_dir()).unwrap(), fs::canonicalize(repo.cts_dir()).unwrap());
+            assert!(found.work_dir().is_none());
+        }
+        let named = InitOptions { bare: true, initial_branch: None };
+        let named = Repository::init_with(dir.path().join("work").join(CTS_DIR_NAME), &named).unwrap();
+        assert!(Repository::open(named.cts_dir()).unwrap().work_dir().is_none());
+
+        let invalid = InitOptions {
+            bare: true,
+            initial_branch: Some("bad..name".into()),
+        };
+        assert!(Repository::init_with(dir.path().join("other"), &invalid).is_err());
+    }
+
+    #[test]
+    fn test_open_rejects_unknown_format() {
+        let (_dir, repo) = setup();
+        let mut config = repo.config().unwrap();
+        config.set("core.objectformat", "sha1").unwrap();
+        config.save(&repo.config_path()).unwrap();
+        assert!(matches!(Repository::open(repo.cts_dir()), Err(AppError::InvalidInput(_))));
+
+        config.set("core.objectformat", HASH_ALGORITHM).unwrap();
+        config.set("core.repositoryformatversion", "1").unwrap();
+        config.save(&repo.config_path()).unwrap();
+        assert!(matches!(Repository::open(repo.cts_dir()), Err(AppError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_write_and_list_refs() {
+        let (_dir, repo) = setup();
+        let c0 = commit(&repo, None, "first");
+        let id = Identity::new("Tester", "tester@example.com");
+        let mut transaction = repo.refs().transaction();
+        transaction
+            .update("refs/heads/main", &c0, Expected::Missing)
+            .update("refs/heads/feature/x", &c0, Expected::Missing)
+            .update("refs/tags/v1", &c0, Expected::Missing)
+            .update("refs/remotes/origin/main", &c0, Expected::Missing);
+        transaction.commit(&id, "test").unwrap();
+
+        let heads = repo.list_refs("refs/heads/").unwrap();
+        assert_eq!(
+            heads,
+            vec![
+                ("refs/heads/feature/x".to_string(), c0.clone()),
+                ("refs/heads/main".to_string(), c0.clone()),
+            ]
+        );
+        assert_eq!(repo.full_ref_name("HEAD").unwrap(), Some("refs/heads/main".to_string()));
+        assert_eq!(repo.full_ref_name("v1").unwrap(), Some("refs/tags/v1".to_string()));
+        assert_eq!(repo.full_ref_name("nope").unwrap(), None);
+        assert_eq!(repo.resolve_rev("origin/main").unwrap(), c0);
+        assert!(repo.refs().update("../outside", &c0, Expected::Any, &id, "x").is_err());
+    }
+
+    #[test]
+    fn test_resolve_reflog_entry() {
+        let (_dir, repo) = setup();
+        let id = Identity::new("Tester", "tester@example.com");
+        let c0 = commit(&repo, None, "first");
+        let c1 = commit(&repo, Some(c0.clone()), "second");
+        repo.refs().update("HEAD", &c0, Expected::Missing, &id, "commit").unwrap();
+        repo.refs().update("HEAD", &c1, Expected::Value(c0.clone()), &id, "commit").unwrap();
+
+        assert_eq!(repo.resolve_rev("main@{0}").unwrap(), c1);
+        assert_eq!(repo.resolve_rev("main@{1}").unwrap(), c0);
+        assert_eq!(repo.resolve_rev("HEAD@{1}").unwrap(), c0);
+        assert!(repo.resolve_rev("main@{2}").is_err());
+    }
+}
@@ -0,0 +1,297 @@
+// =============================================================================
+// 커밋 그래프 탐색 (revwalk.rs)
+// =============================================================================
+//
+// 커밋의 부모 링크를 따라가며 히스토리 탐색
+//
+// - ancestors: 시작 커밋들에서 도달 가능한 모든 커밋
+// - walk: "A..B" 범위 (B에서 도달 가능하지만 A에서는 도달 불가능한 커밋)
+// - reachable_objects: 범위의 커밋 + 그 커밋들이 참조하는 트리/Blob
+// - commit_objects: 지정한 커밋들 + 트리/Blob (받는 쪽이 가진 커밋의 내용은 제외)
+// - merge_base: 두 커밋의 가장 가까운 공통 조상
+//
+// shallow 경계 커밋에서는 부모를 따라가지 않음 (ObjectStore::parent_of)
+//
+// 파일 위치: crates/core/src/revwalk.rs
+// =============================================================================
+
+use std::collections::{HashSet, VecDeque};
+
+use shared::error::AppError;
+
+use crate::object::Object;
+use crate::store::ObjectStore;
+
+/// 시작 커밋들과 그 모든 조상 커밋의 해시 집합
+pub fn ancestors(store: &ObjectStore, starts: &[String]) -> Result<HashSet<String>, AppError> {
+    let mut seen = HashSet::new();
+    let mut queue: VecDeque<String> = starts.iter().cloned().collect();
+
+    while let Some(hash) = queue.pop_front() {
+        if !seen.insert(hash.clone()) {
+            continue;
+        }
+        let commit = store.read_commit(&hash)?;
+        if let Some(parent) = store.parent_of(&hash, &commit) {
+            queue.push_back(parent);
+        }
+    }
+    Ok(seen)
+}
+
+/// 두 커밋의 가장 가까운 공통 조상 (없으면 None)
+///
+/// 부모가 하나뿐이므로 b 에서 부모를 따라가며 a 의 조상을 처음 만나는 커밋
+pub fn merge_base(store: &ObjectStore, a: &str, b: &str) -> Result<Option<String>, AppError> {
+    let reachable = ancestors(store, &[a.to_string()])?;
+    let mut next = Some(b.to_string());
+    while let Some(hash) = next {
+        if reachable.contains(&hash) {
+            return Ok(Some(hash));
+        }
+        next = store.parent_of(&hash, &store.read_commit(&hash)?);
+    }
+    Ok(None)
+}
+
+/// 범위 탐색: include 에서 도달 가능하고 exclude 에서는 도달 불가능한 커밋
+///
+/// # Returns
+/// 자식 → 부모 순서 (최신 커밋이 먼저)
+/// 각 커밋은 항상 자신의 부모보다 앞에 옴
+pub fn walk(
+    store: &ObjectStore,
+    include: &[String],
+    exclude: &[String],
+) -> Result<Vec<String>, AppError> {
+    let excluded = ancestors(store, exclude)?;
+    let mut seen = HashSet::new();
+    let mut result = Vec::new();
+    let mut queue: VecDeque<String> = include.iter().cloned().collect();
+
+    while let Some(hash) = queue.pop_front() {
+        if excluded.contains(&hash) || !seen.insert(hash.clone()) {
+            continue;
+        }
+        let commit = store.read_commit(&hash)?;
+        if let Some(parent) = store.parent_of(&hash, &commit) {
+            queue.push_back(parent);
+        }
+        result.push(hash);
+    }
+    Ok(result)
+}
+
+/// 범위 탐색 결과 전송에 필요한 모든 객체
+///
+/// walk(include, exclude) 의 커밋들과 그 트리/Blob 을 모으되,
+/// 경계 커밋(범위 밖의 부모)의 트리에 이미 있는 객체는 제외
+/// → 받는 쪽은 경계 커밋을 가지고 있다고 가정
+///
+/// include 에 주석 태그가 있으면 태그 객체도 포함하고 가리키는 커밋부터 탐색
+///
+/// # Returns
+/// (객체 해시 목록, 경계 커밋 목록)
+pub fn reachable_objects(
+    store: &ObjectStore,
+    include: &[String],
+    exclude: &[String],
+) -> Result<(Vec<String>, Vec<String>), AppError> {
+    let mut objects = Vec::new();
+    let mut tips = Vec::new();
+    for hash in include {
+        let mut current = hash.clone();
+        while let Object::Tag(tag) = store.read(&current)? {
+            if !objects.contains(&current) {
+                objects.push(current);
+            }
+            current = tag.object_hash;
+        }
+        tips.push(current);
+    }
+
+    let commits = walk(store, &tips, exclude)?;
+    let in_range: HashSet<&String> = commits.iter().collect();
+
+    // 경계 커밋: 범위 안 커밋의 부모 중 범위 밖인 것
+    let mut boundary = Vec::new();
+    for hash in &commits {
+        if let Some(parent) = store.parent_of(hash, &store.read_commit(hash)?) {
+            if !in_range.contains(&parent) && !boundary.contains(&parent) {
+                boundary.push(parent);
+            }
+        }
+    }
+
+    objects.extend(commit_objects(store, &commits, &boundary)?);
+    Ok((objects, boundary))
+}
+
+/// 커밋들과 그 트리/Blob (known 커밋의 트리에 이미 있는 객체는 제외)
+///
+/// # Arguments
+/// * `commits` - 보낼 커밋
+/// * `known` - 받는 쪽이 가진 커밋 (트리 내용도 가지고 있다고 가정)
+pub fn commit_objects(
+    store: &ObjectStore,
+    commits: &[String],
+    known: &[String],
+) -> Result<Vec<String>, AppError> {
+    let mut seen = HashSet::new();
+    for hash in known {
+        let tree = store.read_commit(hash)?.tree_hash;
+        collect_tree(store, &tree, &mut seen, &mut Vec::new())?;
+    }
+
+    let mut objects = Vec::new();
+    for hash in commits {
+        objects.push(hash.clone());
+        let tree = store.read_commit(hash)?.tree_hash;
+        collect_tree(store, &tree, &mut seen, &mut objects)?;
+    }
+    Ok(objects)
+}
+
+/// 트리와 하위 객체를 재귀적으로 수집 (known 에 있는 객체는 건너뜀)
+///
+/// 서브모듈 커밋은 다른 저장소의 객체라서 수집하지 않음
+fn collect_tree(
+    store: &ObjectStore,
+    tree_hash: &str,
+    known: &mut HashSet<String>,
+    out: &mut Vec<String>,
+) -> Result<(), AppError> {
+    if !known.insert(tree_hash.to_string()) {
+        return Ok(());
+    }
+    out.push(tree_hash.to_string());
+    for entry in store.read_tree(tree_hash)?.entries() {
+        if entry.is_directory() {
+            collect_tree(store, &entry.hash, known, out)?;
+        } else if entry.is_submodule() {
+            continue;
+        } else if known.insert(entry.hash.clone()) {
+            out.push(entry.hash.clone());
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Blob, Commit, Tag, Tree, TreeEntry};
+
+    /// 선형 히스토리 생성: c0 ← c1 ← ... ← c{n-1}
+    /// 커밋 i 의 트리에는 "file{0..=i}" 파일들이 있음
+    fn linear_history(store: &ObjectStore, n: usize) -> Vec<String> {
+        let mut hashes: Vec<String> = Vec::new();
+        let mut entries = Vec::new();
+        for i in 0..n {
+            let blob = store.write(&Object::from(Blob::new(format!("{}", i).into_bytes()))).unwrap();
+            entries.push(TreeEntry::file(format!("file{}", i), blob));
+            let tree = store.write(&Object::from(Tree::with_entries(entries.clone()))).unwrap();
+            let stamp = Actor::parse(&format!("Tester <tester@example.com> {} +0000", 1705314600 + i * 60)).unwrap();
+            let commit = Commit::new(
+                tree,
+                hashes.last().cloned(),
+                format!("commit {}", i),
+                stamp.clone(),
+                stamp,
+            );
+            hashes.push(store.write(&Object::from(commit)).unwrap());
+        }
+        hashes
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commits = linear_history(&store, 4);
+
+        let set = ancestors(&store, &[commits[2].clone()]).unwrap();
+        assert_eq!(set.len(), 3);
+        assert!(!set.contains(&commits[3]));
+    }
+
+    #[test]
+    fn test_merge_base() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commits = linear_history(&store, 3);
+
+        // commits[1] 에서 갈라진 커밋
+        let stamp = Actor::parse("Tester <tester@example.com> 1705318200 +0000").unwrap();
+        let tree = store.read_commit(&commits[1]).unwrap().tree_hash;
+        let side = Commit::new(tree.clone(), Some(commits[1].clone()), "side".into(), stamp.clone(), stamp);
+        let side = store.write(&Object::from(side)).unwrap();
+
+        assert_eq!(merge_base(&store, &commits[2], &side).unwrap(), Some(commits[1].clone()));
+        assert_eq!(merge_base(&store, &commits[2], &commits[0]).unwrap(), Some(commits[0].clone()));
+        assert_eq!(merge_base(&store, &commits[0], &commits[2]).unwrap(), Some(commits[0].clone()));
+
+        // 부모가 없는 별도 히스토리
+        let stamp = Actor::parse("Other <other@example.com> 1705318200 +0000").unwrap();
+        let unrelated = Commit::initial(tree, "root".into(), stamp.clone(), stamp);
+        let unrelated = store.write(&Object::from(unrelated)).unwrap();
+        assert_eq!(merge_base(&store, &commits[2], &unrelated).unwrap(), None);
+    }
+
+    #[test]
+    fn test_walk_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commits = linear_history(&store, 5);
+
+        // c1..c4 → c4, c3, c2
+        let range = walk(&store, &[commits[4].clone()], &[commits[1].clone()]).unwrap();
+        assert_eq!(range, vec![commits[4].clone(), commits[3].clone(), commits[2].clone()]);
+
+        // 제외 없음 → 전체
+        let all = walk(&store, &[commits[4].clone()], &[]).unwrap();
+        assert_eq!(all.len(), 5);
+    }
+
+    #[test]
+    fn test_reachable_objects() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commits = linear_history(&store, 3);
+
+        // 전체: 커밋 3 + 트리 3 + Blob 3
+        let (all, boundary) = reachable_objects(&store, &[commits[2].clone()], &[]).unwrap();
+        assert_eq!(all.len(), 9);
+        assert!(boundary.is_empty());
+
+        // c1..c2: 커밋 1 + 트리 1 + 새 Blob 1 (file0, file1 은 경계 커밋에 있음)
+        let (range, boundary) =
+            reachable_objects(&store, &[commits[2].clone()], &[commits[1].clone()]).unwrap();
+        assert_eq!(range.len(), 3);
+        assert_eq!(boundary, vec![commits[1].clone()]);
+    }
+
+    #[test]
+    fn test_reachable_objects_through_tag() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let commits = linear_history(&store, 2);
+        let tag = Tag::new(
+            commits[1].clone(),
+            "v1".into(),
+            "v1\n".into(),
+            Actor::parse("Tester <tester@example.com> 1705316400 +0000").unwrap(),
+        );
+        let tag = store.write(&Object::from(tag)).unwrap();
+
+        // 태그 객체 + c1 (트리 1 + 새 Blob 1)
+        let (range, _) = reachable_objects(&store, std::slice::from_ref(&tag), &[commits[0].clone()]).unwrap();
+        assert_eq!(range.len(), 4);
+        assert_eq!(range[0], tag);
+        assert_eq!(store.peel_to_commit(&tag).unwrap(), commits[1]);
+    }
+}
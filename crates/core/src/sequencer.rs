@@ -0,0 +1,890 @@
+// =============================================================================
+// 시퀀서 (sequencer.rs)
+// =============================================================================
+//
+// 커밋을 하나씩 다시 적용하는 작업: cherry-pick, revert, rebase
+//
+// 동작:
+// - 할 일 목록(todo)의 항목마다 merge_trees 로 3-way 병합한 결과를 새 커밋으로
+//   cherry-pick/pick: 기준 = 원본 커밋의 부모, 상대 = 원본 커밋
+//   revert:          기준 = 원본 커밋, 상대 = 원본 커밋의 부모
+// - rebase 는 HEAD 를 onto 로 옮긴 뒤 pick/reword/squash/fixup/drop 목록 실행
+//   (부모가 이미 현재 HEAD 인 pick 은 새 커밋 없이 그대로 따라감)
+// - 결과가 현재 HEAD 와 같은(빈) 커밋은 건너뜀
+// - 충돌이 나면 작업 디렉토리에 충돌 마커를 남기고 멈춤
+//   → continue (해결한 파일을 스테이징하고 커밋), skip, abort (시작 전 HEAD 로)
+//
+// 진행 상태 (.cts/sequencer/):
+//   operation      cherry-pick | revert | rebase
+//   orig-head      시작 전 HEAD 커밋 (abort 용)
+//   todo / done    남은 항목 / 끝난 항목 ("pick {해시} {제목}" 한 줄씩)
+//   stopped        충돌로 멈춘 항목
+//   stopped-tree   멈출 때 작업 디렉토리에 풀어놓은 트리
+//   conflicts      충돌 경로 목록
+//
+// 파일 위치: crates/core/src/sequencer.rs
+// =============================================================================
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use shared::error::AppError;
+
+use crate::checkout::{force_worktree, update_worktree};
+use crate::index::Index;
+use crate::merge::merge_trees;
+use crate::object::{Actor, Commit, Object};
+use crate::refs::{Expected, Identity};
+use crate::repo::Repository;
+use crate::revwalk::walk;
+
+/// .cts 안의 진행 상태 디렉토리
+pub const SEQUENCER_DIR: &str = "sequencer";
+
+/// 할 일 목록/충돌 라벨에 쓰는 짧은 해시 길이
+const SHORT_HASH_LENGTH: usize = 8;
+
+/// 커밋 메시지 편집기 (편집 전 메시지 → 편집 후 메시지)
+pub type MessageEditor<'e> = &'e mut dyn FnMut(&str) -> Result<String, AppError>;
+
+// =============================================================================
+// 작업 종류 / 할 일 항목
+// =============================================================================
+
+/// 진행 중인 작업 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    CherryPick,
+    Revert,
+    Rebase,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operation::CherryPick => "cherry-pick",
+            Operation::Revert => "revert",
+            Operation::Rebase => "rebase",
+        })
+    }
+}
+
+impl FromStr for Operation {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "cherry-pick" => Ok(Operation::CherryPick),
+            "revert" => Ok(Operation::Revert),
+            "rebase" => Ok(Operation::Rebase),
+            other => Err(AppError::InvalidInput(format!("Unknown sequencer operation: {}", other))),
+        }
+    }
+}
+
+/// 할 일 항목의 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoAction {
+    /// 커밋 그대로 적용
+    Pick,
+    /// 적용 후 메시지 편집
+    Reword,
+    /// 직전 커밋에 합치고 메시지도 합쳐서 편집
+    Squash,
+    /// 직전 커밋에 합치고 메시지는 버림
+    Fixup,
+    /// 적용하지 않음
+    Drop,
+    /// 변경을 되돌리는 커밋 생성
+    Revert,
+}
+
+impl fmt::Display for TodoAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TodoAction::Pick => "pick",
+            TodoAction::Reword => "reword",
+            TodoAction::Squash => "squash",
+            TodoAction::Fixup => "fixup",
+            TodoAction::Drop => "drop",
+            TodoAction::Revert => "revert",
+        })
+    }
+}
+
+impl FromStr for TodoAction {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pick" | "p" => Ok(TodoAction::Pick),
+            "reword" | "r" => Ok(TodoAction::Reword),
+            "squash" | "s" => Ok(TodoAction::Squash),
+            "fixup" | "f" => Ok(TodoAction::Fixup),
+            "drop" | "d" => Ok(TodoAction::Drop),
+            "revert" => Ok(TodoAction::Revert),
+            other => Err(AppError::InvalidInput(format!("Unknown todo command: {}", other))),
+        }
+    }
+}
+
+/// 할 일 목록의 한 줄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoItem {
+    pub action: TodoAction,
+    /// 대상 커밋 (전체 해시)
+    pub commit: String,
+    /// 커밋 메시지 첫 줄 (표시용)
+    pub subject: String,
+}
+
+impl TodoItem {
+    /// 커밋 정보로 항목 생성
+    pub fn new(repo: &Repository, action: TodoAction, commit: &str) -> Result<Self, AppError> {
+        let message = repo.objects().read_commit(commit)?.message;
+        Ok(Self {
+            action,
+            commit: commit.to_string(),
+            subject: message.lines().next().unwrap_or("").to_string(),
+        })
+    }
+
+    /// "{짧은 해시} {제목}"
+    pub fn summary(&self) -> String {
+        format!("{} {}", short(&self.commit), self.subject)
+    }
+}
+
+/// 할 일 목록 텍스트 ("pick {해시} {제목}" 한 줄씩)
+///
+/// * `abbreviate` - 해시를 짧게 (사용자가 편집할 목록)
+pub fn format_todo(items: &[TodoItem], abbreviate: bool) -> String {
+    items
+        .iter()
+        .map(|item| {
+            let hash = if abbreviate { short(&item.commit) } else { &item.commit };
+            format!("{} {} {}\n", item.action, hash, item.subject)
+        })
+        .collect()
+}
+
+/// 할 일 목록 파싱 (빈 줄과 '#' 주석 무시, 해시 접두사는 커밋으로 해석)
+pub fn parse_todo(repo: &Repository, text: &str) -> Result<Vec<TodoItem>, AppError> {
+    let mut items = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.splitn(3, ' ');
+        let action: TodoAction = parts.next().unwrap_or("").parse()?;
+        let rev = parts
+            .next()
+            .ok_or_else(|| AppError::InvalidInput(format!("Missing commit in todo line: {}", line)))?;
+        let commit = repo.resolve_rev(rev)?;
+        items.push(TodoItem::new(repo, action, &commit)?);
+    }
+    if items.first().is_some_and(|item| matches!(item.action, TodoAction::Squash | TodoAction::Fixup)) {
+        return Err(AppError::InvalidInput(
+            "Cannot squash or fixup without a previous commit".to_string(),
+        ));
+    }
+    Ok(items)
+}
+
+/// 편집기에서 돌아온 메시지 정리 ('#' 주석 줄 제거, 끝 공백 정리)
+///
+/// # Errors
+/// * `AppError::InvalidInput` - 남은 내용이 없음
+pub fn cleanup_message(text: &str) -> Result<String, AppError> {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(str::trim_end)
+        .collect();
+    let message = lines.join("\n");
+    let message = message.trim_matches('\n');
+    if message.is_empty() {
+        return Err(AppError::InvalidInput(
+            "Aborting commit due to empty commit message".to_string(),
+        ));
+    }
+    Ok(format!("{}\n", message))
+}
+
+// =============================================================================
+// 결과 / 진행 상태
+// =============================================================================
+
+/// 시퀀서 실행 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequencerOutcome {
+    /// 모든 항목 완료
+    Done {
+        /// 최종 HEAD
+        head: String,
+        /// 결과가 비어 건너뛴 항목
+        empty: Vec<TodoItem>,
+    },
+    /// 충돌로 멈춤 (continue / skip / abort 대기)
+    Stopped { item: TodoItem, conflicts: Vec<String> },
+}
+
+/// 충돌로 멈춘 항목
+#[derive(Debug, Clone)]
+struct Stopped {
+    item: TodoItem,
+    tree: String,
+    conflicts: Vec<String>,
+}
+
+/// .cts/sequencer 의 내용
+#[derive(Debug, Clone)]
+struct State {
+    operation: Operation,
+    orig_head: String,
+    todo: Vec<TodoItem>,
+    done: Vec<TodoItem>,
+    stopped: Option<Stopped>,
+}
+
+// =============================================================================
+// Sequencer
+// =============================================================================
+
+/// cherry-pick / revert / rebase 실행기
+#[derive(Debug)]
+pub struct Sequencer<'a> {
+    repo: &'a Repository,
+    identity: Identity,
+}
+
+impl<'a> Sequencer<'a> {
+    pub fn new(repo: &'a Repository, identity: Identity) -> Self {
+        Self { repo, identity }
+    }
+
+    /// 진행 중인 작업 (없으면 None)
+    pub fn operation(&self) -> Result<Option<Operation>, AppError> {
+        Ok(self.load()?.map(|state| state.operation))
+    }
+
+    /// 커밋들을 순서대로 현재 HEAD 위에 다시 적용
+    pub fn cherry_pick(&self, commits: &[String], edit: MessageEditor<'_>) -> Result<SequencerOutcome, AppError> {
+        let todo = commits
+            .iter()
+            .map(|commit| TodoItem::new(self.repo, TodoAction::Pick, commit))
+            .collect::<Result<_, _>>()?;
+        self.start(Operation::CherryPick, todo, None, edit)
+    }
+
+    /// 커밋들의 변경을 되돌리는 커밋을 순서대로 생성
+    pub fn revert(&self, commits: &[String], edit: MessageEditor<'_>) -> Result<SequencerOutcome, AppError> {
+        let todo = commits
+            .iter()
+            .map(|commit| TodoItem::new(self.repo, TodoAction::Revert, commit))
+            .collect::<Result<_, _>>()?;
+        self.start(Operation::Revert, todo, None, edit)
+    }
+
+    /// upstream..HEAD 커밋의 기본 할 일 목록 (오래된 것부터 pick)
+    pub fn rebase_todo(&self, upstream: &str) -> Result<Vec<TodoItem>, AppError> {
+        let head = self.head()?;
+        let mut commits = walk(
+            self.repo.objects(),
+            std::slice::from_ref(&head),
+            &[upstream.to_string()],
+        )?;
+        commits.reverse();
+        commits
+            .iter()
+            .map(|commit| TodoItem::new(self.repo, TodoAction::Pick, commit))
+            .collect()
+    }
+
+    /// HEAD 를 onto 로 옮기고 할 일 목록 실행
+    ///
+    /// HEAD 가 브랜치를 가리키면 그 브랜치가 함께 옮겨짐
+    pub fn rebase(
+        &self,
+        onto: &str,
+        todo: Vec<TodoItem>,
+        edit: MessageEditor<'_>,
+    ) -> Result<SequencerOutcome, AppError> {
+        self.start(Operation::Rebase, todo, Some(onto), edit)
+    }
+
+    /// 충돌을 해결한 뒤 이어서 진행 (--continue)
+    ///
+    /// 충돌 파일에 마커가 남아 있으면 거부, 없으면 작업 디렉토리 내용을 스테이징해서 커밋
+    pub fn resume(&self, edit: MessageEditor<'_>) -> Result<SequencerOutcome, AppError> {
+        let mut state = self.require_state()?;
+        let work_dir = self.work_dir()?;
+        if let Some(stopped) = state.stopped.take() {
+            let mut index = self.repo.read_index()?;
+            for path in &stopped.conflicts {
+                let full = work_dir.join(path);
+                if !full.exists() {
+                    index.remove(path);
+                    continue;
+                }
+                if has_conflict_markers(&fs::read(&full)?) {
+                    return Err(AppError::InvalidInput(format!(
+                        "{} still has conflict markers; resolve it and run --continue again",
+                        path
+                    )));
+                }
+                index.stage_file(self.repo.objects(), work_dir, path)?;
+            }
+            self.repo.write_index(&index)?;
+            let tree = index.write_tree(self.repo.objects())?;
+            self.commit_item(state.operation, &stopped.item, &tree, edit)?;
+            state.done.push(stopped.item);
+            self.save(&state)?;
+        }
+        self.run(state, edit)
+    }
+
+    /// 멈춘 항목을 버리고 다음 항목부터 진행 (--skip)
+    pub fn skip(&self, edit: MessageEditor<'_>) -> Result<SequencerOutcome, AppError> {
+        let mut state = self.require_state()?;
+        if let Some(stopped) = state.stopped.take() {
+            let head_tree = self.head_tree()?;
+            force_worktree(self.repo.objects(), Some(&stopped.tree), &head_tree, self.work_dir()?)?;
+            self.repo.write_index(&Index::from_tree(self.repo.objects(), &head_tree)?)?;
+            state.done.push(stopped.item);
+            self.save(&state)?;
+        }
+        self.run(state, edit)
+    }
+
+    /// 작업을 취소하고 시작 전 HEAD 로 되돌림 (--abort)
+    pub fn abort(&self) -> Result<String, AppError> {
+        let state = self.require_state()?;
+        let store = self.repo.objects();
+        let current = match &state.stopped {
+            Some(stopped) => stopped.tree.clone(),
+            None => self.head_tree()?,
+        };
+        let orig_tree = store.read_commit(&state.orig_head)?.tree_hash;
+        force_worktree(store, Some(&current), &orig_tree, self.work_dir()?)?;
+        self.repo.write_index(&Index::from_tree(store, &orig_tree)?)?;
+        self.repo.refs().update(
+            "HEAD",
+            &state.orig_head,
+            Expected::Any,
+            &self.identity,
+            &format!("{}: abort", state.operation),
+        )?;
+        self.clear()?;
+        Ok(state.orig_head)
+    }
+
+    // -------------------------------------------------------------------------
+    // 실행
+    // -------------------------------------------------------------------------
+
+    fn start(
+        &self,
+        operation: Operation,
+        todo: Vec<TodoItem>,
+        onto: Option<&str>,
+        edit: MessageEditor<'_>,
+    ) -> Result<SequencerOutcome, AppError> {
+        if let Some(current) = self.operation()? {
+            return Err(AppError::Conflict(format!(
+                "A {} is already in progress; use --continue, --skip or --abort",
+                current
+            )));
+        }
+        let work_dir = self.work_dir()?;
+        let store = self.repo.objects();
+        let orig_head = self.head()?;
+        let head_tree = store.read_commit(&orig_head)?.tree_hash;
+
+        // 작업 중 충돌 처리를 위해 깨끗한 상태에서만 시작
+        let index = self.repo.read_index()?;
+        if index.write_tree(store)? != head_tree || !index.worktree_changes(work_dir)?.is_empty() {
+            return Err(AppError::InvalidInput(format!(
+                "Cannot {}: you have uncommitted changes; commit or stash them first",
+                operation
+            )));
+        }
+
+        if let Some(onto) = onto.filter(|onto| *onto != orig_head) {
+            let onto_tree = store.read_commit(onto)?.tree_hash;
+            update_worktree(store, Some(&head_tree), &onto_tree, work_dir)?;
+            self.repo.write_index(&Index::from_tree(store, &onto_tree)?)?;
+            self.repo.refs().update(
+                "HEAD",
+                onto,
+                Expected::Value(orig_head.clone()),
+                &self.identity,
+                &format!("rebase: checkout {}", short(onto)),
+            )?;
+        }
+
+        let state = State {
+            operation,
+            orig_head,
+            todo,
+            done: Vec::new(),
+            stopped: None,
+        };
+        self.save(&state)?;
+        self.run(state, edit)
+    }
+
+    /// 남은 항목을 차례로 적용 (충돌이면 상태를 저장하고 멈춤)
+    fn run(&self, mut state: State, edit: MessageEditor<'_>) -> Result<SequencerOutcome, AppError> {
+        let store = self.repo.objects();
+        let work_dir = self.work_dir()?;
+        let mut empty = Vec::new();
+
+        while !state.todo.is_empty() {
+            let item = state.todo.remove(0);
+            if item.action == TodoAction::Drop {
+                state.done.push(item);
+                continue;
+            }
+            let head = self.head()?;
+            let head_tree = store.read_commit(&head)?.tree_hash;
+            let commit = store.read_commit(&item.commit)?;
+            let parent_tree = match &commit.parent_hash {
+                Some(parent) => Some(store.read_commit(parent)?.tree_hash),
+                None => None,
+            };
+
+            // 이미 현재 HEAD 위에 있는 커밋은 그대로 따라감
+            if state.operation == Operation::Rebase
+                && item.action == TodoAction::Pick
+                && commit.parent_hash.as_deref() == Some(head.as_str())
+            {
+                update_worktree(store, Some(&head_tree), &commit.tree_hash, work_dir)?;
+                self.repo.write_index(&Index::from_tree(store, &commit.tree_hash)?)?;
+                self.repo.refs().update(
+                    "HEAD",
+                    &item.commit,
+                    Expected::Value(head),
+                    &self.identity,
+                    &format!("rebase: fast-forward {}", item.summary()),
+                )?;
+                state.done.push(item);
+                continue;
+            }
+
+            let (base, theirs) = match item.action {
+                TodoAction::Revert => (Some(commit.tree_hash.clone()), parent_tree.ok_or_else(|| {
+                    AppError::InvalidInput(format!("Cannot revert the root commit {}", short(&item.commit)))
+                })?),
+                _ => (parent_tree, commit.tree_hash.clone()),
+            };
+            let label = format!("{} ({})", short(&item.commit), item.subject);
+            let merge = merge_trees(store, base.as_deref(), &head_tree, &theirs, ("HEAD", &label))?;
+            update_worktree(store, Some(&head_tree), &merge.tree, work_dir)?;
+
+            if !merge.is_clean() {
+                // 충돌 경로는 인덱스에 HEAD 쪽을 남김 (해결 후 continue 에서 스테이징)
+                let mut index = Index::from_tree(store, &merge.tree)?;
+                let ours = Index::from_tree(store, &head_tree)?;
+                for path in &merge.conflicts {
+                    match ours.get(path) {
+                        Some(entry) => index.insert(path.clone(), entry.clone()),
+                        None => {
+                            index.remove(path);
+                        }
+                    }
+                }
+                self.repo.write_index(&index)?;
+                state.stopped = Some(Stopped {
+                    item: item.clone(),
+                    tree: merge.tree,
+                    conflicts: merge.conflicts.clone(),
+                });
+                self.save(&state)?;
+                return Ok(SequencerOutcome::Stopped {
+                    item,
+                    conflicts: merge.conflicts,
+                });
+            }
+
+            self.repo.write_index(&Index::from_tree(store, &merge.tree)?)?;
+            if !self.commit_item(state.operation, &item, &merge.tree, edit)? {
+                empty.push(item.clone());
+            }
+            state.done.push(item);
+            self.save(&state)?;
+        }
+
+        self.clear()?;
+        Ok(SequencerOutcome::Done {
+            head: self.head()?,
+            empty,
+        })
+    }
+
+    /// 적용 결과 트리로 커밋 생성 후 HEAD 이동
+    ///
+    /// # Returns
+    /// 커밋을 만들었는지 (결과가 비어 건너뛰면 false)
+    fn commit_item(
+        &self,
+        operation: Operation,
+        item: &TodoItem,
+        tree: &str,
+        edit: MessageEditor<'_>,
+    ) -> Result<bool, AppError> {
+        let store = self.repo.objects();
+        let head = self.head()?;
+        let head_commit = store.read_commit(&head)?;
+        let original = store.read_commit(&item.commit)?;
+        let committer = Actor::now(self.identity.name.clone(), self.identity.email.clone());
+
+        let (parent, author, message) = match item.action {
+            // 직전 커밋을 고쳐 씀 (부모/작성자 유지)
+            TodoAction::Squash | TodoAction::Fixup => {
+                let message = if item.action == TodoAction::Squash {
+                    cleanup_message(&edit(&format!(
+                        "{}\n\n{}",
+                        head_commit.message.trim_end_matches('\n'),
+                        original.message
+                    ))?)?
+                } else {
+                    head_commit.message.clone()
+                };
+                (head_commit.parent_hash.clone(), head_commit.author.clone(), message)
+            }
+            _ => {
+                if tree == head_commit.tree_hash {
+                    return Ok(false);
+                }
+                let message = match item.action {
+                    TodoAction::Reword => cleanup_message(&edit(&original.message)?)?,
+                    TodoAction::Revert => format!(
+                        "Revert \"{}\"\n\nThis reverts commit {}.\n",
+                        item.subject, item.commit
+                    ),
+                    _ => original.message.clone(),
+                };
+                let author = if item.action == TodoAction::Revert {
+                    committer.clone()
+                } else {
+                    original.author.clone()
+                };
+                (Some(head.clone()), author, message)
+            }
+        };
+
+        let commit = Commit::new(tree.to_string(), parent, message, author, committer);
+        let hash = store.write(&Object::from(commit))?;
+        let reason = match operation {
+            Operation::Rebase => format!("rebase ({}): {}", item.action, item.subject),
+            _ => format!("{}: {}", operation, item.subject),
+        };
+        self.repo
+            .refs()
+            .update("HEAD", &hash, Expected::Value(head), &self.identity, &reason)?;
+        Ok(true)
+    }
+
+    // -------------------------------------------------------------------------
+    // 진행 상태 파일
+    // -------------------------------------------------------------------------
+
+    fn dir(&self) -> PathBuf {
+        self.repo.cts_dir().join(SEQUENCER_DIR)
+    }
+
+    fn load(&self) -> Result<Option<State>, AppError> {
+        let dir = self.dir();
+        let operation = match fs::read_to_string(dir.join("operation")) {
+            Ok(content) => content.parse()?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let read = |name: &str| -> Result<String, AppError> {
+            match fs::read_to_string(dir.join(name)) {
+                Ok(content) => Ok(content),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+                Err(err) => Err(err.into()),
+            }
+        };
+        let stopped = match parse_todo(self.repo, &read("stopped")?)?.pop() {
+            Some(item) => Some(Stopped {
+                item,
+                tree: read("stopped-tree")?.trim().to_string(),
+                conflicts: read("conflicts")?.lines().map(str::to_string).collect(),
+            }),
+            None => None,
+        };
+        Ok(Some(State {
+            operation,
+            orig_head: read("orig-head")?.trim().to_string(),
+            todo: parse_todo(self.repo, &read("todo")?)?,
+            done: parse_todo(self.repo, &read("done")?)?,
+            stopped,
+        }))
+    }
+
+    fn save(&self, state: &State) -> Result<(), AppError> {
+        let dir = self.dir();
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("operation"), format!("{}\n", state.operation))?;
+        fs::write(dir.join("orig-head"), format!("{}\n", state.orig_head))?;
+        fs::write(dir.join("todo"), format_todo(&state.todo, false))?;
+        fs::write(dir.join("done"), format_todo(&state.done, false))?;
+        let (stopped, tree, conflicts) = match &state.stopped {
+            Some(stopped) => (
+                format_todo(std::slice::from_ref(&stopped.item), false),
+                format!("{}\n", stopped.tree),
+                stopped.conflicts.iter().map(|path| format!("{}\n", path)).collect(),
+            ),
+            None => (String::new(), String::new(), String::new()),
+        };
+        fs::write(dir.join("stopped"), stopped)?;
+        fs::write(dir.join("stopped-tree"), tree)?;
+        fs::write(dir.join("conflicts"), conflicts)?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), AppError> {
+        match fs::remove_dir_all(self.dir()) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn require_state(&self) -> Result<State, AppError> {
+        self.load()?
+            .ok_or_else(|| AppError::InvalidInput("No cherry-pick, revert or rebase in progress".to_string()))
+    }
+
+    // -------------------------------------------------------------------------
+    // 내부
+    // -------------------------------------------------------------------------
+
+    fn work_dir(&self) -> Result<&'a Path, AppError> {
+        self.repo
+            .work_dir()
+            .ok_or_else(|| AppError::InvalidInput("This operation requires a work tree".to_string()))
+    }
+
+    fn head(&self) -> Result<String, AppError> {
+        self.repo
+            .head()?
+            .ok_or_else(|| AppError::InvalidInput("You do not have the initial commit yet".to_string()))
+    }
+
+    fn head_tree(&self) -> Result<String, AppError> {
+        Ok(self.repo.objects().read_commit(&self.head()?)?.tree_hash)
+    }
+}
+
+fn short(hash: &str) -> &str {
+    &hash[..SHORT_HASH_LENGTH.min(hash.len())]
+}
+
+/// 충돌 마커 줄이 남아 있는지
+fn has_conflict_markers(content: &[u8]) -> bool {
+    content
+        .split(|&b| b == b'\n')
+        .any(|line| line.starts_with(b"<<<<<<< ") || line.starts_with(b">>>>>>> "))
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkout::checkout_tree;
+    use crate::object::{Blob, TreeEntry};
+    use std::collections::BTreeMap;
+
+    fn identity() -> Identity {
+        Identity::new("Tester", "tester@example.com")
+    }
+
+    fn no_edit() -> impl FnMut(&str) -> Result<String, AppError> {
+        |message: &str| Ok(message.to_string())
+    }
+
+    /// parent 위에 파일 내용을 바꾼 커밋 생성 (None 이면 삭제)
+    fn commit(repo: &Repository, parent: Option<&str>, files: &[(&str, Option<&str>)], message: &str) -> String {
+        let store = repo.objects();
+        let mut flat: BTreeMap<String, TreeEntry> = match parent {
+            Some(parent) => store.flatten_tree(&store.read_commit(parent).unwrap().tree_hash).unwrap(),
+            None => BTreeMap::new(),
+        };
+        for (path, content) in files {
+            match content {
+                Some(content) => {
+                    let hash = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+                    flat.insert(path.to_string(), TreeEntry::file(path.to_string(), hash));
+                }
+                None => {
+                    flat.remove(*path);
+                }
+            }
+        }
+        let tree = store.write_flat_tree(&flat).unwrap();
+        let actor = Actor::parse("Author <author@example.com> 1705314600 +0000").unwrap();
+        let commit = Commit::new(tree, parent.map(str::to_string), format!("{}\n", message), actor.clone(), actor);
+        store.write(&Object::from(commit)).unwrap()
+    }
+
+    /// main 을 head 로 두고 작업 디렉토리/인덱스를 맞춤
+    fn checkout(repo: &Repository, head: &str) {
+        let tree = repo.objects().read_commit(head).unwrap().tree_hash;
+        checkout_tree(repo.objects(), &tree, repo.work_dir().unwrap()).unwrap();
+        repo.write_index(&Index::from_tree(repo.objects(), &tree).unwrap()).unwrap();
+        repo.refs().update("refs/heads/main", head, Expected::Any, &identity(), "test").unwrap();
+    }
+
+    fn read(repo: &Repository, path: &str) -> String {
+        fs::read_to_string(repo.work_dir().unwrap().join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_cherry_pick_and_revert() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let base = commit(&repo, None, &[("a.txt", Some("1\n2\n3\n"))], "base");
+        let main = commit(&repo, Some(&base), &[("a.txt", Some("one\n2\n3\n"))], "main change");
+        let topic = commit(&repo, Some(&base), &[("a.txt", Some("1\n2\nthree\n")), ("b.txt", Some("b\n"))], "topic change");
+        checkout(&repo, &main);
+
+        let sequencer = Sequencer::new(&repo, identity());
+        let SequencerOutcome::Done { head, empty } = sequencer.cherry_pick(std::slice::from_ref(&topic), &mut no_edit()).unwrap() else {
+            panic!("expected clean cherry-pick");
+        };
+        assert!(empty.is_empty());
+        let picked = repo.objects().read_commit(&head).unwrap();
+        assert_eq!(picked.parent_hash.as_deref(), Some(main.as_str()));
+        assert_eq!(picked.message, "topic change\n");
+        assert_eq!(picked.author.name, "Author");
+        assert_eq!(picked.committer.name, "Tester");
+        assert_eq!(read(&repo, "a.txt"), "one\n2\nthree\n");
+        assert_eq!(read(&repo, "b.txt"), "b\n");
+
+        // 같은 커밋을 다시 고르면 비어서 건너뜀
+        let SequencerOutcome::Done { empty, .. } = sequencer.cherry_pick(std::slice::from_ref(&topic), &mut no_edit()).unwrap() else {
+            panic!("expected done");
+        };
+        assert_eq!(empty.len(), 1);
+
+        sequencer.revert(std::slice::from_ref(&head), &mut no_edit()).unwrap();
+        let reverted = repo.objects().read_commit(&repo.head().unwrap().unwrap()).unwrap();
+        assert!(reverted.message.starts_with("Revert \"topic change\""));
+        assert_eq!(read(&repo, "a.txt"), "one\n2\n3\n");
+        assert!(!dir.path().join("b.txt").exists());
+        assert!(sequencer.operation().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_conflict_continue_skip_abort() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let base = commit(&repo, None, &[("a.txt", Some("a\n"))], "base");
+        let main = commit(&repo, Some(&base), &[("a.txt", Some("main\n"))], "main");
+        let first = commit(&repo, Some(&base), &[("a.txt", Some("topic\n"))], "conflicting");
+        let second = commit(&repo, Some(&first), &[("c.txt", Some("c\n"))], "independent");
+        checkout(&repo, &main);
+        let sequencer = Sequencer::new(&repo, identity());
+
+        // 충돌 → abort 로 원래대로
+        let outcome = sequencer.cherry_pick(&[first.clone(), second.clone()], &mut no_edit()).unwrap();
+        assert!(matches!(&outcome, SequencerOutcome::Stopped { conflicts, .. } if conflicts == &vec!["a.txt".to_string()]));
+        assert_eq!(sequencer.operation().unwrap(), Some(Operation::CherryPick));
+        assert!(read(&repo, "a.txt").contains("<<<<<<< HEAD"));
+        assert!(matches!(
+            sequencer.cherry_pick(std::slice::from_ref(&second), &mut no_edit()),
+            Err(AppError::Conflict(_))
+        ));
+        sequencer.abort().unwrap();
+        assert_eq!(repo.head().unwrap().unwrap(), main);
+        assert_eq!(read(&repo, "a.txt"), "main\n");
+        assert!(sequencer.operation().unwrap().is_none());
+
+        // 충돌 → 마커가 남아 있으면 continue 거부 → 해결 후 continue
+        sequencer.cherry_pick(&[first.clone(), second.clone()], &mut no_edit()).unwrap();
+        assert!(matches!(sequencer.resume(&mut no_edit()), Err(AppError::InvalidInput(_))));
+        fs::write(dir.path().join("a.txt"), "resolved\n").unwrap();
+        let SequencerOutcome::Done { head, .. } = sequencer.resume(&mut no_edit()).unwrap() else {
+            panic!("expected done");
+        };
+        let tip = repo.objects().read_commit(&head).unwrap();
+        assert_eq!(tip.message, "independent\n");
+        let resolved = repo.objects().read_commit(tip.parent_hash.as_deref().unwrap()).unwrap();
+        assert_eq!(resolved.message, "conflicting\n");
+        assert_eq!(read(&repo, "a.txt"), "resolved\n");
+        assert_eq!(read(&repo, "c.txt"), "c\n");
+
+        // 충돌 → skip 하면 다음 항목만 적용
+        checkout(&repo, &main);
+        fs::remove_file(dir.path().join("c.txt")).unwrap();
+        sequencer.cherry_pick(&[first, second], &mut no_edit()).unwrap();
+        let SequencerOutcome::Done { head, .. } = sequencer.skip(&mut no_edit()).unwrap() else {
+            panic!("expected done");
+        };
+        assert_eq!(repo.objects().read_commit(&head).unwrap().parent_hash.as_deref(), Some(main.as_str()));
+        assert_eq!(read(&repo, "a.txt"), "main\n");
+    }
+
+    #[test]
+    fn test_rebase_with_todo_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let base = commit(&repo, None, &[("a.txt", Some("a\n"))], "base");
+        let upstream = commit(&repo, Some(&base), &[("up.txt", Some("up\n"))], "upstream");
+        let c1 = commit(&repo, Some(&base), &[("one.txt", Some("1\n"))], "one");
+        let c2 = commit(&repo, Some(&c1), &[("two.txt", Some("2\n"))], "two");
+        let c3 = commit(&repo, Some(&c2), &[("three.txt", Some("3\n"))], "three");
+        let c4 = commit(&repo, Some(&c3), &[("four.txt", Some("4\n"))], "four");
+        checkout(&repo, &c4);
+        let sequencer = Sequencer::new(&repo, identity());
+
+        let todo = sequencer.rebase_todo(&upstream).unwrap();
+        let text = format_todo(&todo, true);
+        assert_eq!(text.lines().count(), 4);
+        assert!(text.starts_with(&format!("pick {} one\n", &c1[..8])));
+
+        // reword one, two 를 squash, three 는 fixup 대신 drop, four 는 fixup
+        let script = format!(
+            "# edited plan\nreword {} one\nsquash {} two\ndrop {} three\nfixup {} four\n",
+            &c1[..8], &c2[..8], &c3[..8], &c4[..8]
+        );
+        let todo = parse_todo(&repo, &script).unwrap();
+        let mut edits = Vec::new();
+        let mut editor = |message: &str| {
+            edits.push(message.to_string());
+            Ok(format!("# comment\n{}", message.replace("one", "ONE")))
+        };
+        let SequencerOutcome::Done { head, .. } = sequencer.rebase(&upstream, todo, &mut editor).unwrap() else {
+            panic!("expected done");
+        };
+        assert_eq!(edits.len(), 2);
+
+        let tip = repo.objects().read_commit(&head).unwrap();
+        assert_eq!(tip.parent_hash.as_deref(), Some(upstream.as_str()));
+        assert_eq!(tip.message, "ONE\n\ntwo\n");
+        assert_eq!(tip.author.name, "Author");
+        for file in ["up.txt", "one.txt", "two.txt", "four.txt"] {
+            assert!(dir.path().join(file).exists(), "{}", file);
+        }
+        assert!(!dir.path().join("three.txt").exists());
+        assert_eq!(repo.read_ref("refs/heads/main").unwrap().unwrap(), head);
+
+        assert!(parse_todo(&repo, &format!("fixup {}\n", &c1[..8])).is_err());
+        assert!(parse_todo(&repo, &format!("edit {}\n", &c1[..8])).is_err());
+    }
+
+    #[test]
+    fn test_cleanup_message() {
+        assert_eq!(cleanup_message("subject  \n\nbody\n# comment\n\n").unwrap(), "subject\n\nbody\n");
+        assert!(cleanup_message("# only comments\n\n").is_err());
+    }
+}
@@ -0,0 +1,309 @@
+// =============================================================================
+// Shallow clone (shallow.rs)
+// =============================================================================
+//
+// 최근 히스토리만 받아온 저장소
+//
+// .cts/shallow: 부모를 받지 않은 경계 커밋 목록 (한 줄에 하나)
+// - ObjectStore::parent_of 가 경계 커밋의 부모를 None 으로 돌려줘서
+//   log, merge-base, blame 등의 탐색이 거기서 멈춤
+//
+// 깊이 제한 (fetch 요청에 실어 보냄):
+// - depth N: 받을 커밋(want)부터 N 개
+// - deepen-since T: 커밋 시각이 T 이후인 커밋까지
+// - deepen N: 이미 있는 경계 커밋에서 N 개 더
+// - unshallow: 경계 커밋 너머의 히스토리 전부
+//
+// 보내는 쪽은 받는 쪽의 경계 커밋 목록("shallow {hash}")을 받아서
+// 새 경계(shallow) 와 더 이상 경계가 아닌 커밋(unshallow) 을 알려줌
+//
+// 파일 위치: crates/core/src/shallow.rs
+// =============================================================================
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use shared::error::AppError;
+
+use crate::refs::LockFile;
+use crate::store::ObjectStore;
+
+/// 경계 커밋 파일 이름 (.cts 기준)
+pub const SHALLOW_FILE: &str = "shallow";
+
+// =============================================================================
+// .cts/shallow
+// =============================================================================
+
+/// 경계 커밋 목록 읽기 (파일이 없으면 빈 목록 = 전체 히스토리)
+pub fn load(cts_dir: &Path) -> Result<HashSet<String>, AppError> {
+    match fs::read_to_string(cts_dir.join(SHALLOW_FILE)) {
+        Ok(text) => Ok(text.lines().filter(|line| !line.is_empty()).map(str::to_string).collect()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashSet::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// 경계 커밋 목록 갱신 (비면 파일 삭제)
+///
+/// # Returns
+/// 갱신된 경계 커밋 목록
+pub fn update(cts_dir: &Path, added: &[String], removed: &[String]) -> Result<HashSet<String>, AppError> {
+    let mut commits = load(cts_dir)?;
+    commits.extend(added.iter().cloned());
+    for hash in removed {
+        commits.remove(hash);
+    }
+
+    let path = cts_dir.join(SHALLOW_FILE);
+    if commits.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        return Ok(commits);
+    }
+    let mut sorted: Vec<&String> = commits.iter().collect();
+    sorted.sort();
+    let content: String = sorted.iter().map(|hash| format!("{}\n", hash)).collect();
+    let lock = LockFile::acquire(&path)?;
+    lock.write(content.as_bytes())?;
+    lock.commit()?;
+    Ok(commits)
+}
+
+// =============================================================================
+// 깊이 제한
+// =============================================================================
+
+/// fetch 에서 받을 히스토리 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthLimit {
+    /// 받을 커밋부터 N 개 (--depth)
+    Depth(usize),
+    /// 이 시각(epoch 초) 이후의 커밋 (--shallow-since)
+    Since(i64),
+    /// 기존 경계에서 N 개 더 (--deepen)
+    Deepen(usize),
+    /// 경계 너머 전부 (--unshallow)
+    Unshallow,
+}
+
+impl fmt::Display for DepthLimit {
+    /// fetch 요청의 한 줄
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Depth(depth) => write!(f, "depth {}", depth),
+            Self::Since(time) => write!(f, "deepen-since {}", time),
+            Self::Deepen(depth) => write!(f, "deepen {}", depth),
+            Self::Unshallow => write!(f, "unshallow"),
+        }
+    }
+}
+
+impl FromStr for DepthLimit {
+    type Err = AppError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let invalid = || AppError::InvalidInput(format!("Invalid depth limit: {}", line));
+        if line == "unshallow" {
+            return Ok(Self::Unshallow);
+        }
+        let (kind, value) = line.split_once(' ').ok_or_else(invalid)?;
+        match kind {
+            "depth" => match value.parse().map_err(|_| invalid())? {
+                0 => Err(invalid()),
+                depth => Ok(Self::Depth(depth)),
+            },
+            "deepen-since" => Ok(Self::Since(value.parse().map_err(|_| invalid())?)),
+            "deepen" => match value.parse().map_err(|_| invalid())? {
+                0 => Err(invalid()),
+                depth => Ok(Self::Deepen(depth)),
+            },
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// =============================================================================
+// 보낼 커밋 선택
+// =============================================================================
+
+/// select_commits 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShallowSelection {
+    /// 보낼 커밋
+    pub commits: Vec<String>,
+    /// 받는 쪽이 이미 가진 커밋 중 보낼 커밋과 맞닿은 것 (트리 내용 생략 기준)
+    pub known: Vec<String>,
+    /// 받는 쪽의 새 경계 커밋
+    pub shallow: Vec<String>,
+    /// 받는 쪽에서 더 이상 경계가 아닌 커밋
+    pub unshallow: Vec<String>,
+}
+
+/// fetch 요청에 맞춰 보낼 커밋 고르기
+///
+/// # Arguments
+/// * `wants` - 받는 쪽이 원하는 커밋
+/// * `haves` - 받는 쪽이 가진 커밋 (모르는 해시는 무시)
+/// * `client_shallow` - 받는 쪽의 경계 커밋 (이 너머는 받는 쪽에 없음)
+/// * `limit` - 깊이 제한 (None 이면 받는 쪽이 가진 커밋까지 전부)
+pub fn select_commits(
+    store: &ObjectStore,
+    wants: &[String],
+    haves: &[String],
+    client_shallow: &[String],
+    limit: Option<DepthLimit>,
+) -> Result<ShallowSelection, AppError> {
+    let client_shallow: HashSet<&String> = client_shallow.iter().filter(|hash| store.contains(hash)).collect();
+
+    // 받는 쪽이 가진 커밋: haves 의 조상 (받는 쪽의 경계 커밋에서 멈춤)
+    let mut client_has: HashSet<String> = client_shallow.iter().map(|hash| hash.to_string()).collect();
+    let mut queue: VecDeque<String> = haves.iter().filter_map(|hash| store.peel_to_commit(hash).ok()).collect();
+    while let Some(hash) = queue.pop_front() {
+        if client_shallow.contains(&hash) || !client_has.insert(hash.clone()) {
+            continue;
+        }
+        if let Some(parent) = store.parent_of(&hash, &store.read_commit(&hash)?) {
+            queue.push_back(parent);
+        }
+    }
+
+    // (커밋, 남은 개수) - 경계를 늘릴 때는 받는 쪽의 경계 커밋부터 먼저 탐색
+    let mut queue: VecDeque<(String, Option<usize>)> = VecDeque::new();
+    let mut starts: Vec<&String> = client_shallow.iter().copied().collect();
+    starts.sort();
+    match limit {
+        Some(DepthLimit::Deepen(depth)) => queue.extend(starts.into_iter().map(|hash| (hash.clone(), Some(depth + 1)))),
+        Some(DepthLimit::Unshallow) => queue.extend(starts.into_iter().map(|hash| (hash.clone(), None))),
+        _ => {}
+    }
+    let budget = match limit {
+        Some(DepthLimit::Depth(depth)) => Some(depth),
+        _ => None,
+    };
+    queue.extend(wants.iter().map(|hash| (hash.clone(), budget)));
+
+    let mut selection = ShallowSelection::default();
+    let mut seen = HashSet::new();
+    while let Some((hash, budget)) = queue.pop_front() {
+        if !seen.insert(hash.clone()) {
+            continue;
+        }
+        let has = client_has.contains(&hash);
+        let was_shallow = client_shallow.contains(&hash);
+        if has {
+            selection.known.push(hash.clone());
+            // 온전히 가진 커밋이거나 경계를 늘리지 않는 요청이면 여기서 멈춤
+            if !was_shallow || limit.is_none() {
+                continue;
+            }
+        }
+
+        let commit = store.read_commit(&hash)?;
+        let parent = store.parent_of(&hash, &commit);
+        let cut = match (budget, limit, &parent) {
+            (Some(remaining), _, _) if remaining <= 1 => true,
+            (_, Some(DepthLimit::Since(since)), Some(parent)) => {
+                store.read_commit(parent)?.committer.time.timestamp() < since
+            }
+            _ => false,
+        };
+        let follow = parent.filter(|_| !cut);
+
+        if !has {
+            selection.commits.push(hash.clone());
+            // 부모가 있지만 보내지 않음 (보내는 쪽이 shallow 여도 마찬가지)
+            if follow.is_none() && commit.parent_hash.is_some() {
+                selection.shallow.push(hash.clone());
+            }
+        } else if follow.is_some() {
+            selection.unshallow.push(hash.clone());
+        }
+        if let Some(parent) = follow {
+            queue.push_back((parent, budget.map(|remaining| remaining - 1)));
+        }
+    }
+    Ok(selection)
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Commit, Object, Tree};
+
+    /// c0 ← c1 ← ... ← c{n-1}, 커밋 i 의 시각은 1000 * i
+    fn history(store: &ObjectStore, n: usize) -> Vec<String> {
+        let tree = store.write(&Object::from(Tree::new())).unwrap();
+        let mut hashes: Vec<String> = Vec::new();
+        for i in 0..n {
+            let actor = Actor::parse(&format!("T <t@example.com> {} +0000", 1000 * i)).unwrap();
+            let commit = Commit::new(tree.clone(), hashes.last().cloned(), format!("c{}", i), actor.clone(), actor);
+            hashes.push(store.write(&Object::from(commit)).unwrap());
+        }
+        hashes
+    }
+
+    #[test]
+    fn test_depth_and_since() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let c = history(&store, 5);
+        let tip = std::slice::from_ref(&c[4]);
+
+        let selection = select_commits(&store, tip, &[], &[], Some(DepthLimit::Depth(2))).unwrap();
+        assert_eq!(selection.commits, vec![c[4].clone(), c[3].clone()]);
+        assert_eq!(selection.shallow, vec![c[3].clone()]);
+
+        let selection = select_commits(&store, tip, &[], &[], Some(DepthLimit::Since(2000))).unwrap();
+        assert_eq!(selection.commits, vec![c[4].clone(), c[3].clone(), c[2].clone()]);
+        assert_eq!(selection.shallow, vec![c[2].clone()]);
+
+        // 전체: 경계 없음, 가진 커밋에서 멈춤
+        let selection = select_commits(&store, tip, std::slice::from_ref(&c[1]), &[], None).unwrap();
+        assert_eq!(selection.commits, vec![c[4].clone(), c[3].clone(), c[2].clone()]);
+        assert_eq!(selection.known, vec![c[1].clone()]);
+        assert!(selection.shallow.is_empty());
+    }
+
+    #[test]
+    fn test_deepen_and_unshallow() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path());
+        let c = history(&store, 5);
+        let tip = std::slice::from_ref(&c[4]);
+        let boundary = std::slice::from_ref(&c[3]);
+
+        // 받는 쪽: c4, c3 (c3 이 경계)
+        let selection = select_commits(&store, tip, tip, boundary, None).unwrap();
+        assert!(selection.commits.is_empty());
+
+        let selection = select_commits(&store, tip, tip, boundary, Some(DepthLimit::Deepen(1))).unwrap();
+        assert_eq!(selection.commits, vec![c[2].clone()]);
+        assert_eq!(selection.shallow, vec![c[2].clone()]);
+        assert_eq!(selection.unshallow, vec![c[3].clone()]);
+
+        let selection = select_commits(&store, tip, tip, boundary, Some(DepthLimit::Unshallow)).unwrap();
+        assert_eq!(selection.commits, vec![c[2].clone(), c[1].clone(), c[0].clone()]);
+        assert!(selection.shallow.is_empty());
+
+        // 경계 파일 갱신과 탐색 중단
+        let cts = tempfile::tempdir().unwrap();
+        let commits = update(cts.path(), boundary, &[]).unwrap();
+        let shallow_store = store.clone().with_shallow(commits);
+        assert_eq!(crate::revwalk::walk(&shallow_store, tip, &[]).unwrap().len(), 2);
+        assert!(update(cts.path(), &[], boundary).unwrap().is_empty());
+        assert!(!cts.path().join(SHALLOW_FILE).exists());
+
+        assert_eq!("deepen 3".parse::<DepthLimit>().unwrap(), DepthLimit::Deepen(3));
+        assert_eq!(DepthLimit::Since(5).to_string().parse::<DepthLimit>().unwrap(), DepthLimit::Since(5));
+        assert!("depth 0".parse::<DepthLimit>().is_err());
+    }
+}
@@ -0,0 +1,392 @@
+// =============================================================================
+// 서명 모듈 (signing.rs)
+// =============================================================================
+//
+// Ed25519 키로 커밋/태그에 서명하고 검증
+// 외부 에이전트(gpg 등) 없이 로컬 키 파일만 사용
+//
+// 서명 과정:
+// - 서명 대상: signature 헤더를 뺀 객체 본문 (signed_payload)
+// - 서명 결과: "signature ed25519 {공개키 hex} {서명 hex}" 헤더로 본문에 추가
+// - 객체 해시는 서명 헤더까지 포함해서 계산
+//
+// 검증은 서명에 들어있는 공개키로 수행
+// → "누가" 서명했는지는 호출자가 공개키를 신뢰 목록과 비교해서 판단
+//
+// 키 파일:
+// - 비밀키: ~/.cts/signing_key (hex 한 줄, 권한 0600)
+// - 공개키: ~/.cts/signing_key.pub ("ed25519 {hex} {설명}")
+// - CTS_SIGNING_KEY 환경 변수로 비밀키 경로 변경 가능
+//
+// 파일 위치: crates/core/src/signing.rs
+// =============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ed25519_dalek::{Signer, SECRET_KEY_LENGTH, SIGNATURE_LENGTH};
+use shared::error::AppError;
+
+use crate::hash::Hasher;
+use crate::object::{Commit, Signature, Tag};
+
+/// 서명 알고리즘 이름 (signature 헤더의 첫 필드)
+pub const ALGORITHM: &str = "ed25519";
+
+/// 비밀키 경로를 바꾸는 환경 변수
+pub const KEY_PATH_ENV: &str = "CTS_SIGNING_KEY";
+
+/// 공개키 파일 확장자 (비밀키 경로 + ".pub")
+pub const PUBLIC_KEY_SUFFIX: &str = ".pub";
+
+/// 기본 비밀키 경로
+///
+/// CTS_SIGNING_KEY → ~/.cts/signing_key 순서
+/// 홈 디렉토리를 알 수 없으면 None
+pub fn default_key_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(KEY_PATH_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".cts").join("signing_key"))
+}
+
+// =============================================================================
+// 비밀키
+// =============================================================================
+
+/// 서명용 비밀키
+///
+/// # Example
+/// ```
+/// use core::object::{Actor, Commit};
+/// use core::signing::{verify_commit, SigningKey, Verification};
+///
+/// let key = SigningKey::generate().unwrap();
+/// let me = Actor::now("A".into(), "a@example.com".into());
+/// let mut commit = Commit::initial("0".repeat(64), "Signed".into(), me.clone(), me);
+/// key.sign_commit(&mut commit);
+///
+/// assert_eq!(verify_commit(&commit), Verification::Good(key.public_key()));
+/// ```
+pub struct SigningKey {
+    inner: ed25519_dalek::SigningKey,
+}
+
+impl SigningKey {
+    /// OS 난수로 새 키 생성
+    pub fn generate() -> Result<Self, AppError> {
+        let mut seed = [0u8; SECRET_KEY_LENGTH];
+        getrandom::getrandom(&mut seed)
+            .map_err(|err| AppError::Internal(format!("Cannot generate signing key: {}", err)))?;
+        Ok(Self {
+            inner: ed25519_dalek::SigningKey::from_bytes(&seed),
+        })
+    }
+
+    /// hex 비밀키 파싱
+    pub fn from_hex(secret: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput("Invalid signing key".to_string());
+        let bytes: [u8; SECRET_KEY_LENGTH] = hex::decode(secret.trim())
+            .map_err(|_| invalid())?
+            .try_into()
+            .map_err(|_| invalid())?;
+        Ok(Self {
+            inner: ed25519_dalek::SigningKey::from_bytes(&bytes),
+        })
+    }
+
+    /// 비밀키 hex
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.inner.to_bytes())
+    }
+
+    /// 대응하는 공개키
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            inner: self.inner.verifying_key(),
+        }
+    }
+
+    /// 키 파일 읽기
+    ///
+    /// # Errors
+    /// * `AppError::NotFound` - 키 파일이 없음
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AppError> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(AppError::NotFound(format!("Signing key {}", path.display())));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        Self::from_hex(&text)
+    }
+
+    /// 키 파일 저장 (비밀키 + ".pub" 공개키)
+    ///
+    /// 비밀키 파일은 소유자만 읽을 수 있게 생성
+    ///
+    /// # Errors
+    /// * `AppError::AlreadyExists` - 이미 키 파일이 있음
+    pub fn save<P: AsRef<Path>>(&self, path: P, comment: &str) -> Result<(), AppError> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(AppError::AlreadyExists(format!("Signing key {}", path.display())));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(path)?;
+        std::io::Write::write_all(&mut file, format!("{}\n", self.to_hex()).as_bytes())?;
+
+        let mut public_path = path.as_os_str().to_owned();
+        public_path.push(PUBLIC_KEY_SUFFIX);
+        let line = match comment {
+            "" => format!("{}\n", self.public_key()),
+            _ => format!("{} {}\n", self.public_key(), comment),
+        };
+        fs::write(public_path, line)?;
+        Ok(())
+    }
+
+    /// 임의 바이트 서명
+    pub fn sign(&self, payload: &[u8]) -> Signature {
+        Signature {
+            algorithm: ALGORITHM.to_string(),
+            public_key: self.public_key().to_hex(),
+            value: hex::encode(self.inner.sign(payload).to_bytes()),
+        }
+    }
+
+    /// 커밋 서명 (기존 서명은 교체)
+    pub fn sign_commit(&self, commit: &mut Commit) {
+        let signature = self.sign(commit.signed_payload().as_bytes());
+        commit.set_signature(Some(signature));
+    }
+
+    /// 태그 서명 (기존 서명은 교체)
+    pub fn sign_tag(&self, tag: &mut Tag) {
+        tag.signature = Some(self.sign(tag.signed_payload().as_bytes()));
+    }
+}
+
+// =============================================================================
+// 공개키
+// =============================================================================
+
+/// 검증용 공개키
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    inner: ed25519_dalek::VerifyingKey,
+}
+
+impl PublicKey {
+    /// 공개키 파싱
+    ///
+    /// "ed25519 {hex} [설명]" (공개키 파일 형식) 또는 hex 만
+    pub fn parse(text: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput(format!("Invalid public key: {}", text.trim()));
+        let mut parts = text.split_whitespace();
+        let first = parts.next().ok_or_else(invalid)?;
+        let key = if first == ALGORITHM { parts.next().ok_or_else(invalid)? } else { first };
+        Self::from_hex(key).map_err(|_| invalid())
+    }
+
+    /// hex 공개키 파싱
+    pub fn from_hex(key: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput(format!("Invalid public key: {}", key));
+        let bytes: [u8; 32] = hex::decode(key)
+            .map_err(|_| invalid())?
+            .try_into()
+            .map_err(|_| invalid())?;
+        Ok(Self {
+            inner: ed25519_dalek::VerifyingKey::from_bytes(&bytes).map_err(|_| invalid())?,
+        })
+    }
+
+    /// 공개키 hex (signature 헤더와 같은 표현)
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.inner.as_bytes())
+    }
+
+    /// 사람이 비교하기 쉬운 짧은 지문 ("SHA256:" + 키 해시 앞 16자)
+    pub fn fingerprint(&self) -> String {
+        let hash = Hasher::new().hash_bytes(self.inner.as_bytes());
+        format!("SHA256:{}", &hash[..16])
+    }
+
+    /// 서명 검증
+    pub fn verify(&self, payload: &[u8], signature: &Signature) -> bool {
+        if signature.algorithm != ALGORITHM || signature.public_key != self.to_hex() {
+            return false;
+        }
+        let Ok(bytes) = hex::decode(&signature.value) else {
+            return false;
+        };
+        let Ok(bytes) = <[u8; SIGNATURE_LENGTH]>::try_from(bytes) else {
+            return false;
+        };
+        self.inner
+            .verify_strict(payload, &ed25519_dalek::Signature::from_bytes(&bytes))
+            .is_ok()
+    }
+}
+
+impl std::fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", ALGORITHM, self.to_hex())
+    }
+}
+
+// =============================================================================
+// 검증
+// =============================================================================
+
+/// 서명 검증 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    /// 서명 없음
+    Unsigned,
+    /// 서명이 본문과 일치 (서명한 공개키)
+    Good(PublicKey),
+    /// 서명이 있지만 유효하지 않음 (사유)
+    Bad(String),
+}
+
+impl Verification {
+    /// 유효한 서명인지
+    pub fn is_good(&self) -> bool {
+        matches!(self, Verification::Good(_))
+    }
+
+    /// 서명한 공개키 (유효한 서명일 때만)
+    pub fn public_key(&self) -> Option<&PublicKey> {
+        match self {
+            Verification::Good(key) => Some(key),
+            _ => None,
+        }
+    }
+}
+
+/// 커밋 서명 검증
+pub fn verify_commit(commit: &Commit) -> Verification {
+    verify_payload(commit.signature.as_ref(), commit.signed_payload().as_bytes())
+}
+
+/// 태그 서명 검증
+pub fn verify_tag(tag: &Tag) -> Verification {
+    verify_payload(tag.signature.as_ref(), tag.signed_payload().as_bytes())
+}
+
+fn verify_payload(signature: Option<&Signature>, payload: &[u8]) -> Verification {
+    let Some(signature) = signature else {
+        return Verification::Unsigned;
+    };
+    if signature.algorithm != ALGORITHM {
+        return Verification::Bad(format!("unsupported algorithm {}", signature.algorithm));
+    }
+    let key = match PublicKey::from_hex(&signature.public_key) {
+        Ok(key) => key,
+        Err(_) => return Verification::Bad("malformed public key".to_string()),
+    };
+    if key.verify(payload, signature) {
+        Verification::Good(key)
+    } else {
+        Verification::Bad("signature does not match".to_string())
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Actor;
+    use tempfile::TempDir;
+
+    fn actor() -> Actor {
+        Actor::parse("A <a@example.com> 1705314600 +0900").unwrap()
+    }
+
+    fn commit() -> Commit {
+        Commit::initial("1".repeat(64), "Release\n".into(), actor(), actor())
+    }
+
+    #[test]
+    fn test_sign_and_verify_commit() {
+        let key = SigningKey::generate().unwrap();
+        let mut signed = commit();
+        assert_eq!(verify_commit(&signed), Verification::Unsigned);
+
+        key.sign_commit(&mut signed);
+        assert!(verify_commit(&signed).is_good());
+        assert_eq!(verify_commit(&signed).public_key(), Some(&key.public_key()));
+
+        // 본문을 바꾸면 서명이 깨짐
+        let mut tampered = signed.clone();
+        tampered.message = "Release (edited)\n".into();
+        assert!(matches!(verify_commit(&tampered), Verification::Bad(_)));
+
+        // 다른 키로 서명을 바꿔치기해도 공개키가 서명과 함께 바뀌어야 함
+        let other = SigningKey::generate().unwrap();
+        let mut forged = signed.clone();
+        let mut signature = forged.signature.clone().unwrap();
+        signature.public_key = other.public_key().to_hex();
+        forged.set_signature(Some(signature));
+        assert!(matches!(verify_commit(&forged), Verification::Bad(_)));
+    }
+
+    #[test]
+    fn test_sign_and_verify_tag() {
+        let key = SigningKey::generate().unwrap();
+        let mut tag = Tag::new(
+            "2".repeat(64),
+            "v1.0".into(),
+            "Version 1.0\n".into(),
+            actor(),
+        );
+        key.sign_tag(&mut tag);
+        assert!(verify_tag(&tag).is_good());
+
+        tag.name = "v2.0".into();
+        assert!(!verify_tag(&tag).is_good());
+    }
+
+    #[test]
+    fn test_key_files_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("keys").join("signing_key");
+
+        let key = SigningKey::generate().unwrap();
+        key.save(&path, "a@example.com").unwrap();
+        assert!(matches!(key.save(&path, ""), Err(AppError::AlreadyExists(_))));
+
+        let loaded = SigningKey::load(&path).unwrap();
+        assert_eq!(loaded.to_hex(), key.to_hex());
+
+        let public = fs::read_to_string(dir.path().join("keys").join("signing_key.pub")).unwrap();
+        assert_eq!(PublicKey::parse(&public).unwrap(), key.public_key());
+        assert!(public.ends_with(" a@example.com\n"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        assert!(matches!(SigningKey::load(dir.path().join("missing")), Err(AppError::NotFound(_))));
+        assert!(PublicKey::parse("ed25519 nothex").is_err());
+    }
+}
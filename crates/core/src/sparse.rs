@@ -0,0 +1,295 @@
+// =============================================================================
+// Sparse checkout (sparse.rs)
+// =============================================================================
+//
+// 큰 저장소에서 필요한 디렉토리만 작업 디렉토리에 풀기
+//
+// cone 패턴 (디렉토리 목록):
+// - 최상위 디렉토리의 파일은 항상 포함
+// - 지정한 디렉토리 아래의 파일은 전부 포함
+// - 지정한 디렉토리의 상위 디렉토리들에 바로 있는 파일도 포함
+//   예) "src/app" → README, src/Cargo.toml, src/app/** 포함 / src/lib/**, docs/** 제외
+//
+// 범위 밖 파일:
+// - 체크아웃 엔진(checkout.rs)이 작업 디렉토리에 쓰지 않음
+// - 인덱스 엔트리에 skip-worktree 표시 (Repository::write_index 에서 갱신)
+// - 작업 디렉토리에 없어도 삭제가 아니라 변경 없음으로 취급 → 커밋에는 HEAD 내용 그대로
+//
+// 패턴 파일: .cts/info/sparse-checkout (작업 디렉토리별, 없으면 sparse 아님)
+//   # cts sparse-checkout (cone)
+//   src/app
+//   tools
+//
+// 파일 위치: crates/core/src/sparse.rs
+// =============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use shared::error::AppError;
+
+use crate::checkout::{is_safe_path, remove_file_and_empty_parents, set_executable, MODE_EXECUTABLE};
+use crate::index::worktree_hash;
+use crate::object::MODE_SUBMODULE;
+use crate::refs::LockFile;
+use crate::repo::{Repository, CTS_DIR_NAME};
+use crate::worktree::read_link;
+
+/// 패턴 파일 경로 (.cts 기준)
+pub const SPARSE_FILE: &str = "info/sparse-checkout";
+
+/// 패턴 파일 첫 줄
+const SPARSE_HEADER: &str = "# cts sparse-checkout (cone)";
+
+// =============================================================================
+// 패턴
+// =============================================================================
+
+/// cone 모드 디렉토리 목록
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparsePatterns {
+    /// 포함할 디렉토리 ('/' 구분, 앞뒤 '/' 없음, 정렬, 하위 디렉토리 중복 제거)
+    dirs: Vec<String>,
+}
+
+impl SparsePatterns {
+    /// 디렉토리 목록으로 생성
+    ///
+    /// # Errors
+    /// * `AppError::InvalidInput` - ".." 등 잘못된 경로
+    pub fn new<S: AsRef<str>>(dirs: &[S]) -> Result<Self, AppError> {
+        let mut patterns = Self::default();
+        patterns.add(dirs)?;
+        Ok(patterns)
+    }
+
+    /// 디렉토리 추가
+    pub fn add<S: AsRef<str>>(&mut self, dirs: &[S]) -> Result<(), AppError> {
+        for dir in dirs {
+            let dir = dir.as_ref().trim_matches('/');
+            if dir.is_empty() || dir == "." {
+                continue;
+            }
+            if !is_safe_path(dir) {
+                return Err(AppError::InvalidInput(format!("Invalid sparse directory: {}", dir)));
+            }
+            self.dirs.push(dir.to_string());
+        }
+        self.dirs.sort();
+        self.dirs.dedup();
+        // 상위 디렉토리가 이미 있으면 하위 디렉토리는 필요 없음
+        let dirs = std::mem::take(&mut self.dirs);
+        for dir in dirs {
+            if !self.dirs.iter().any(|parent| is_under(&dir, parent)) {
+                self.dirs.push(dir);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn dirs(&self) -> &[String] {
+        &self.dirs
+    }
+
+    /// 파일 경로가 작업 디렉토리에 풀릴 대상인지
+    pub fn includes(&self, path: &str) -> bool {
+        let Some((parent, _)) = path.rsplit_once('/') else {
+            return true;
+        };
+        self.dirs
+            .iter()
+            .any(|dir| is_under(path, dir) || dir == parent || is_under(dir, parent))
+    }
+
+    /// 패턴 파일 읽기 (없으면 None)
+    pub fn load(cts_dir: &Path) -> Result<Option<Self>, AppError> {
+        let content = match fs::read_to_string(cts_dir.join(SPARSE_FILE)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let dirs: Vec<&str> = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        Self::new(&dirs).map(Some)
+    }
+
+    /// 패턴 파일 기록
+    pub fn save(&self, cts_dir: &Path) -> Result<(), AppError> {
+        let path = cts_dir.join(SPARSE_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut content = format!("{}\n", SPARSE_HEADER);
+        for dir in &self.dirs {
+            content.push_str(&format!("{}\n", dir));
+        }
+        let lock = LockFile::acquire(&path)?;
+        lock.write(content.as_bytes())?;
+        lock.commit()
+    }
+
+    /// 작업 디렉토리에 적용된 패턴 (.cts 가 연결된 작업 디렉토리 파일이어도 따라감)
+    pub fn for_work_dir(work_dir: &Path) -> Result<Option<Self>, AppError> {
+        let link = work_dir.join(CTS_DIR_NAME);
+        let cts_dir: PathBuf = if link.is_file() { read_link(&link)? } else { link };
+        Self::load(&cts_dir)
+    }
+}
+
+/// path 가 dir 아래에 있는지
+fn is_under(path: &str, dir: &str) -> bool {
+    path.strip_prefix(dir).is_some_and(|rest| rest.starts_with('/'))
+}
+
+// =============================================================================
+// 작업 디렉토리 반영
+// =============================================================================
+
+/// 패턴 변경 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseUpdate {
+    /// 새로 풀어놓은 파일
+    pub materialized: Vec<String>,
+    /// 작업 디렉토리에서 지운 파일
+    pub removed: Vec<String>,
+}
+
+/// 패턴을 바꾸고 작업 디렉토리/인덱스에 반영
+///
+/// # Arguments
+/// * `patterns` - 새 패턴 (None 이면 sparse checkout 해제 → 모든 파일을 풀어놓음)
+///
+/// # Errors
+/// * `AppError::Conflict` - 범위에서 빠질 파일에 커밋하지 않은 수정이 있음 (아무것도 바꾸지 않음)
+pub fn set_patterns(repo: &Repository, patterns: Option<&SparsePatterns>) -> Result<SparseUpdate, AppError> {
+    let work_dir = repo
+        .work_dir()
+        .ok_or_else(|| AppError::InvalidInput("Sparse checkout requires a work tree".to_string()))?;
+    let index = repo.read_index()?;
+
+    let mut update = SparseUpdate::default();
+    let mut dirty = Vec::new();
+    for (path, entry) in index.entries() {
+        let included = patterns.is_none_or(|patterns| patterns.includes(path));
+        if included && entry.skip_worktree {
+            update.materialized.push(path.clone());
+        } else if !included && !entry.skip_worktree {
+            match worktree_hash(work_dir, path, Some(entry))? {
+                Some(current) if current.hash != entry.hash => dirty.push(path.clone()),
+                Some(_) => update.removed.push(path.clone()),
+                None => {}
+            }
+        }
+    }
+    if !dirty.is_empty() {
+        return Err(AppError::Conflict(format!(
+            "These files have local changes and would leave the sparse checkout: {}",
+            dirty.join(", ")
+        )));
+    }
+
+    let sparse_file = repo.cts_dir().join(SPARSE_FILE);
+    match patterns {
+        Some(patterns) => patterns.save(repo.cts_dir())?,
+        None if sparse_file.exists() => fs::remove_file(&sparse_file)?,
+        None => {}
+    }
+
+    for path in &update.removed {
+        remove_file_and_empty_parents(work_dir, path)?;
+    }
+    for path in &update.materialized {
+        let entry = &index.entries()[path];
+        let target = work_dir.join(path);
+        if entry.mode == MODE_SUBMODULE {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, repo.objects().read_blob(&entry.hash)?.content())?;
+        set_executable(&target, entry.mode == MODE_EXECUTABLE)?;
+    }
+    // skip-worktree 표시는 write_index 가 패턴에 맞춰 갱신
+    repo.write_index(&index)?;
+    Ok(update)
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkout::{checkout_tree, update_worktree};
+    use crate::index::Index;
+    use crate::object::{Blob, Object, TreeEntry};
+    use std::collections::BTreeMap;
+
+    fn tree(repo: &Repository, files: &[(&str, &str)]) -> String {
+        let store = repo.objects();
+        let mut map = BTreeMap::new();
+        for (path, content) in files {
+            let blob = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+            map.insert(path.to_string(), TreeEntry::file(path.to_string(), blob));
+        }
+        store.write_flat_tree(&map).unwrap()
+    }
+
+    #[test]
+    fn test_cone_includes() {
+        let patterns = SparsePatterns::new(&["src/app/", "src/app/ui", "tools"]).unwrap();
+        assert_eq!(patterns.dirs(), ["src/app", "tools"]);
+        assert!(patterns.includes("README"));
+        assert!(patterns.includes("src/Cargo.toml"));
+        assert!(patterns.includes("src/app/ui/main.rs"));
+        assert!(patterns.includes("tools/x/y.sh"));
+        assert!(!patterns.includes("src/lib/mod.rs"));
+        assert!(!patterns.includes("src/application/a.rs"));
+        assert!(!patterns.includes("docs/guide.md"));
+        assert!(SparsePatterns::new(&["../etc"]).is_err());
+    }
+
+    #[test]
+    fn test_sparse_checkout_and_switch() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let work = dir.path();
+        let first = tree(&repo, &[("README", "r\n"), ("src/a.rs", "a\n"), ("docs/d.md", "d\n")]);
+        checkout_tree(repo.objects(), &first, work).unwrap();
+        repo.write_index(&Index::from_tree(repo.objects(), &first).unwrap()).unwrap();
+
+        let update = set_patterns(&repo, Some(&SparsePatterns::new(&["src"]).unwrap())).unwrap();
+        assert_eq!(update.removed, vec!["docs/d.md"]);
+        assert!(!work.join("docs").exists());
+        let index = repo.read_index().unwrap();
+        assert!(index.get("docs/d.md").unwrap().skip_worktree);
+        assert!(!index.get("src/a.rs").unwrap().skip_worktree);
+        // 풀지 않은 파일은 변경 없음 → 인덱스 트리는 그대로
+        assert!(index.worktree_changes(work).unwrap().is_empty());
+        assert_eq!(index.write_tree(repo.objects()).unwrap(), first);
+
+        // 범위 밖 파일은 전환할 때도 풀지 않음
+        let second = tree(&repo, &[("README", "r\n"), ("src/a.rs", "a2\n"), ("docs/d.md", "d2\n")]);
+        update_worktree(repo.objects(), Some(&first), &second, work).unwrap();
+        assert_eq!(fs::read_to_string(work.join("src/a.rs")).unwrap(), "a2\n");
+        assert!(!work.join("docs").exists());
+
+        // 수정한 파일은 범위에서 빼지 않음
+        fs::write(work.join("src/a.rs"), "local\n").unwrap();
+        let narrower = SparsePatterns::new(&["tools"]).unwrap();
+        assert!(matches!(set_patterns(&repo, Some(&narrower)), Err(AppError::Conflict(_))));
+        fs::write(work.join("src/a.rs"), "a\n").unwrap();
+
+        let update = set_patterns(&repo, None).unwrap();
+        assert_eq!(update.materialized, vec!["docs/d.md"]);
+        assert_eq!(fs::read_to_string(work.join("docs/d.md")).unwrap(), "d\n");
+        assert!(!repo.read_index().unwrap().get("docs/d.md").unwrap().skip_worktree);
+        assert!(SparsePatterns::load(repo.cts_dir()).unwrap().is_none());
+    }
+}
@@ -0,0 +1,486 @@
+// =============================================================================
+// 스테이징 (staging.rs)
+// =============================================================================
+//
+// 작업 디렉토리의 변경을 인덱스에 반영 (cts add / rm / mv)
+//
+// pathspec: 작업 디렉토리 기준 경로 ('/' 구분)
+// - 파일 경로 또는 디렉토리 (그 아래 모든 파일), "" 은 전체
+// - '*', '?' 가 있으면 glob (grep 의 pathspec 과 같은 규칙)
+// - 아무 파일에도 맞지 않는 pathspec 이 있으면 아무것도 바꾸지 않고 오류
+//
+// add:
+// - 맞는 경로의 새 파일과 수정한 파일은 Blob 으로 기록, 작업 디렉토리에서 지운 파일은 인덱스에서 제거
+// - all: pathspec 이 없으면 작업 디렉토리 전체
+// - update: 이미 추적하는 파일만 (새 파일은 추가하지 않음)
+// - 중첩된 저장소는 새로 추가하지 않음 (submodule add 사용)
+// rm:
+// - 인덱스와 작업 디렉토리에서 제거 (cached 면 인덱스에서만)
+// - 커밋하지 않은 내용을 잃게 되면 거부 (force 로 무시)
+// - 디렉토리는 recursive 일 때만
+// mv:
+// - 작업 디렉토리에서 옮기고 인덱스 엔트리도 새 경로로 (스테이징한 내용 그대로)
+// - 대상이 디렉토리면 그 안으로
+//
+// sparse checkout 범위 밖(skip-worktree) 엔트리는 add / rm 대상에서 제외
+//
+// 파일 위치: crates/core/src/staging.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use shared::error::AppError;
+
+use crate::checkout::{is_safe_path, remove_file_and_empty_parents};
+use crate::grep::matches_pathspec;
+use crate::index::{worktree_files, worktree_hash, Index, IndexEntry};
+use crate::object::{TreeEntry, MODE_SUBMODULE};
+use crate::repo::Repository;
+
+// =============================================================================
+// 옵션과 결과
+// =============================================================================
+
+/// add 옵션
+#[derive(Debug, Clone, Default)]
+pub struct AddOptions {
+    /// pathspec 이 없으면 작업 디렉토리 전체
+    pub all: bool,
+    /// 이미 추적하는 파일만
+    pub update: bool,
+    /// 인덱스와 객체 저장소는 그대로 두고 결과만 계산
+    pub dry_run: bool,
+}
+
+/// rm 옵션
+#[derive(Debug, Clone, Default)]
+pub struct RemoveOptions {
+    /// 인덱스에서만 제거 (작업 디렉토리 파일은 남김)
+    pub cached: bool,
+    /// 커밋하지 않은 내용이 있어도 제거
+    pub force: bool,
+    /// 디렉토리 아래 파일 모두 제거
+    pub recursive: bool,
+    /// 아무것도 바꾸지 않고 결과만 계산
+    pub dry_run: bool,
+}
+
+/// mv 옵션
+#[derive(Debug, Clone, Default)]
+pub struct MoveOptions {
+    /// 대상 파일이 이미 있어도 덮어쓰기
+    pub force: bool,
+    /// 아무것도 바꾸지 않고 결과만 계산
+    pub dry_run: bool,
+}
+
+/// add 가 인덱스에 반영한 경로
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StagedPath {
+    /// 새로 추가했거나 내용이 바뀐 파일
+    Added(String),
+    /// 작업 디렉토리에서 지워서 인덱스에서도 뺀 파일
+    Removed(String),
+}
+
+// =============================================================================
+// add / rm / mv
+// =============================================================================
+
+/// pathspec 에 맞는 작업 디렉토리 변경을 인덱스에 반영
+///
+/// # Returns
+/// 반영한 경로 (경로순)
+///
+/// # Errors
+/// * `AppError::InvalidInput` - pathspec 이 없음 (all / update 제외) 또는 맞는 파일이 없음
+pub fn add(repo: &Repository, pathspecs: &[String], options: &AddOptions) -> Result<Vec<StagedPath>, AppError> {
+    let work_dir = work_dir(repo)?;
+    let pathspecs = match pathspecs {
+        [] if options.all || options.update => vec![String::new()],
+        [] => return Err(AppError::InvalidInput("Nothing specified, nothing added".to_string())),
+        pathspecs => pathspecs.to_vec(),
+    };
+    let mut index = repo.read_index()?;
+
+    // 추적하는 파일 (+ update 가 아니면 작업 디렉토리의 모든 파일)
+    let mut candidates = tracked_paths(&index);
+    if !options.update {
+        candidates.extend(worktree_files(work_dir)?);
+    }
+    check_matches(&pathspecs, &candidates)?;
+
+    let mut staged = Vec::new();
+    for path in candidates.iter().filter(|path| matches_any(&pathspecs, path)) {
+        let cached = index.get(path).cloned();
+        match (worktree_hash(work_dir, path, cached.as_ref())?, cached) {
+            (None, Some(_)) => {
+                index.remove(path);
+                staged.push(StagedPath::Removed(path.clone()));
+            }
+            (None, None) => {}
+            // 내용이 같으면 상태 정보만 갱신 (다음 비교에서 다시 해시하지 않도록)
+            (Some(current), Some(entry)) if same_content(&current, &entry) => {
+                index.insert(path.clone(), current);
+            }
+            (Some(current), _) => {
+                if !options.dry_run {
+                    if current.mode == MODE_SUBMODULE {
+                        index.insert(path.clone(), current);
+                    } else {
+                        index.stage_file(repo.objects(), work_dir, path)?;
+                    }
+                }
+                staged.push(StagedPath::Added(path.clone()));
+            }
+        }
+    }
+    if !options.dry_run {
+        repo.write_index(&index)?;
+    }
+    Ok(staged)
+}
+
+/// pathspec 에 맞는 파일을 인덱스(와 작업 디렉토리)에서 제거
+///
+/// # Returns
+/// 제거한 경로 (경로순)
+///
+/// # Errors
+/// * `AppError::InvalidInput` - 맞는 파일이 없음, recursive 없이 디렉토리,
+///   커밋하지 않은 내용을 잃게 됨 (force 없이) → 아무것도 지우지 않음
+pub fn remove(repo: &Repository, pathspecs: &[String], options: &RemoveOptions) -> Result<Vec<String>, AppError> {
+    let work_dir = work_dir(repo)?;
+    if pathspecs.is_empty() {
+        return Err(AppError::InvalidInput("No pathspec given; nothing removed".to_string()));
+    }
+    let mut index = repo.read_index()?;
+    let tracked = tracked_paths(&index);
+    check_matches(pathspecs, &tracked)?;
+    if !options.recursive {
+        for spec in pathspecs {
+            let spec = spec.trim_end_matches('/');
+            if !is_glob(spec) && !tracked.contains(spec) {
+                return Err(AppError::InvalidInput(format!(
+                    "Not removing '{}' recursively without -r",
+                    spec
+                )));
+            }
+        }
+    }
+
+    let head = head_files(repo)?;
+    let removed: Vec<String> = tracked.into_iter().filter(|path| matches_any(pathspecs, path)).collect();
+    if !options.force {
+        for path in &removed {
+            check_removable(work_dir, path, &index, &head, options.cached)?;
+        }
+    }
+    if options.dry_run {
+        return Ok(removed);
+    }
+
+    for path in &removed {
+        index.remove(path);
+        if !options.cached {
+            remove_file_and_empty_parents(work_dir, path)?;
+        }
+    }
+    repo.write_index(&index)?;
+    Ok(removed)
+}
+
+/// 파일이나 디렉토리를 옮기고 인덱스 엔트리도 새 경로로
+///
+/// # Arguments
+/// * `sources` - 옮길 경로 (추적하는 파일 또는 그런 파일이 있는 디렉토리)
+/// * `destination` - 새 경로, 이미 있는 디렉토리면 그 안으로 (sources 가 여럿이면 디렉토리여야 함)
+///
+/// # Returns
+/// 인덱스에서 옮긴 (이전 경로, 새 경로) 목록
+///
+/// # Errors
+/// * `AppError::InvalidInput` - 추적하지 않는 경로, 대상이 이미 있음 (force 없이), 자기 안으로 옮김
+pub fn rename(
+    repo: &Repository,
+    sources: &[String],
+    destination: &str,
+    options: &MoveOptions,
+) -> Result<Vec<(String, String)>, AppError> {
+    let work_dir = work_dir(repo)?;
+    let destination = destination.trim_end_matches('/');
+    let into_dir = destination.is_empty() || work_dir.join(destination).is_dir();
+    if sources.len() > 1 && !into_dir {
+        return Err(AppError::InvalidInput(format!(
+            "Destination '{}' is not a directory",
+            destination
+        )));
+    }
+    let mut index = repo.read_index()?;
+
+    // (이전 경로, 새 경로): 작업 디렉토리에서 옮길 것과 인덱스에서 옮길 것
+    let mut moves = Vec::new();
+    let mut renames = Vec::new();
+    for source in sources {
+        let source = source.trim_end_matches('/');
+        let name = source.rsplit('/').next().unwrap_or(source);
+        let target = match (into_dir, destination) {
+            (true, "") => name.to_string(),
+            (true, dir) => format!("{}/{}", dir, name),
+            (false, path) => path.to_string(),
+        };
+        let entries: Vec<String> = index.entries().keys().filter(|path| is_under(path, source)).cloned().collect();
+        if source.is_empty() || entries.is_empty() {
+            return Err(AppError::InvalidInput(format!("Not under version control: '{}'", source)));
+        }
+        if !is_safe_path(&target) {
+            return Err(AppError::InvalidInput(format!("Invalid destination: '{}'", target)));
+        }
+        if is_under(&target, source) {
+            return Err(AppError::InvalidInput(format!(
+                "Cannot move '{}' into itself ('{}')",
+                source, target
+            )));
+        }
+        let full_target = work_dir.join(&target);
+        let occupied = full_target.symlink_metadata().is_ok() || index.contains(&target);
+        if occupied && (!options.force || full_target.is_dir() || entries.len() > 1) {
+            return Err(AppError::InvalidInput(format!("Destination exists: '{}'", target)));
+        }
+        // sparse checkout 범위 밖의 엔트리만 있으면 작업 디렉토리에 없어도 됨
+        let in_worktree = entries.iter().any(|path| index.get(path).is_some_and(|entry| !entry.skip_worktree));
+        if in_worktree && work_dir.join(source).symlink_metadata().is_err() {
+            return Err(AppError::InvalidInput(format!(
+                "Bad source: '{}' is missing from the working tree",
+                source
+            )));
+        }
+        for path in entries {
+            let moved = format!("{}{}", target, &path[source.len()..]);
+            renames.push((path, moved));
+        }
+        moves.push((source.to_string(), target));
+    }
+    if options.dry_run {
+        return Ok(renames);
+    }
+
+    for (source, target) in &moves {
+        let from = work_dir.join(source);
+        if from.symlink_metadata().is_err() {
+            continue;
+        }
+        let to = work_dir.join(target);
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(&from, &to)?;
+        remove_file_and_empty_parents(work_dir, source)?;
+    }
+    for (from, to) in &renames {
+        if let Some(entry) = index.remove(from) {
+            index.insert(to.clone(), entry);
+        }
+    }
+    repo.write_index(&index)?;
+    Ok(renames)
+}
+
+// =============================================================================
+// 헬퍼 함수
+// =============================================================================
+
+fn work_dir(repo: &Repository) -> Result<&Path, AppError> {
+    repo.work_dir()
+        .ok_or_else(|| AppError::InvalidInput("Bare repository has no working tree".to_string()))
+}
+
+/// 작업 디렉토리에 있어야 하는 추적 파일 (skip-worktree 제외)
+fn tracked_paths(index: &Index) -> BTreeSet<String> {
+    index
+        .entries()
+        .iter()
+        .filter(|(_, entry)| !entry.skip_worktree)
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+/// HEAD 커밋의 파일 (커밋이 없으면 비어 있음)
+fn head_files(repo: &Repository) -> Result<BTreeMap<String, TreeEntry>, AppError> {
+    match repo.head()? {
+        Some(head) => {
+            let store = repo.objects();
+            store.flatten_tree(&store.read_commit(&head)?.tree_hash)
+        }
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+/// 지워도 커밋하지 않은 내용을 잃지 않는지 확인
+///
+/// - 인덱스가 HEAD 와도 작업 디렉토리와도 다르면 항상 거부
+/// - cached 가 아니면 작업 디렉토리 수정이나 스테이징한 변경이 있어도 거부
+fn check_removable(
+    work_dir: &Path,
+    path: &str,
+    index: &Index,
+    head: &BTreeMap<String, TreeEntry>,
+    cached: bool,
+) -> Result<(), AppError> {
+    let Some(entry) = index.get(path) else {
+        return Ok(());
+    };
+    let staged = !head.get(path).is_some_and(|head| head.hash == entry.hash && head.mode == entry.mode);
+    let modified = worktree_hash(work_dir, path, Some(entry))?.is_some_and(|current| !same_content(&current, entry));
+    let problem = match (staged, modified) {
+        (true, true) => "has staged content different from both the file and HEAD",
+        (false, true) if !cached => "has local modifications",
+        (true, false) if !cached => "has changes staged in the index",
+        _ => return Ok(()),
+    };
+    Err(AppError::InvalidInput(format!(
+        "'{}' {} (use --cached to keep the file, or --force to remove it)",
+        path, problem
+    )))
+}
+
+/// 아무 경로에도 맞지 않는 pathspec 이 있으면 오류 ("" 은 빈 작업 디렉토리에도 맞음)
+fn check_matches(pathspecs: &[String], paths: &BTreeSet<String>) -> Result<(), AppError> {
+    for spec in pathspecs {
+        if !spec.is_empty() && !paths.iter().any(|path| matches_pathspec(spec, path)) {
+            return Err(AppError::InvalidInput(format!(
+                "Pathspec '{}' did not match any files",
+                spec
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn matches_any(pathspecs: &[String], path: &str) -> bool {
+    pathspecs.iter().any(|spec| matches_pathspec(spec, path))
+}
+
+fn is_glob(spec: &str) -> bool {
+    spec.contains(['*', '?'])
+}
+
+/// path 가 dir 자신이거나 그 아래에 있는지
+fn is_under(path: &str, dir: &str) -> bool {
+    path == dir || path.strip_prefix(dir).is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn same_content(a: &IndexEntry, b: &IndexEntry) -> bool {
+    a.hash == b.hash && a.mode == b.mode
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Commit, Object};
+    use crate::refs::{Expected, Identity};
+
+    fn setup() -> (tempfile::TempDir, Repository) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        fs::create_dir_all(dir.path().join("src/nested")).unwrap();
+        fs::write(dir.path().join("README"), "readme\n").unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "pub fn lib() {}\n").unwrap();
+        fs::write(dir.path().join("src/nested/mod.rs"), "mod nested;\n").unwrap();
+        (dir, repo)
+    }
+
+    fn paths(repo: &Repository) -> Vec<String> {
+        repo.read_index().unwrap().entries().keys().cloned().collect()
+    }
+
+    fn commit_index(repo: &Repository) {
+        let tree = repo.read_index().unwrap().write_tree(repo.objects()).unwrap();
+        let actor = Actor::now("Test".into(), "test@example.com".into());
+        let commit = Commit::new(tree, None, "initial\n".into(), actor.clone(), actor);
+        let hash = repo.objects().write(&Object::from(commit)).unwrap();
+        repo.refs()
+            .update("refs/heads/main", &hash, Expected::Missing, &Identity::from_env(), "commit")
+            .unwrap();
+    }
+
+    fn specs(specs: &[&str]) -> Vec<String> {
+        specs.iter().map(|spec| spec.to_string()).collect()
+    }
+
+    #[test]
+    fn test_add_pathspecs_and_update() {
+        let (dir, repo) = setup();
+        assert!(add(&repo, &[], &AddOptions::default()).is_err());
+        assert!(add(&repo, &specs(&["missing"]), &AddOptions::default()).is_err());
+
+        let dry_run = AddOptions { dry_run: true, ..Default::default() };
+        assert_eq!(add(&repo, &specs(&["src"]), &dry_run).unwrap().len(), 2);
+        assert!(paths(&repo).is_empty());
+
+        add(&repo, &specs(&["src"]), &AddOptions::default()).unwrap();
+        assert_eq!(paths(&repo), vec!["src/lib.rs", "src/nested/mod.rs"]);
+
+        // update: 추적하는 파일의 수정과 삭제만
+        fs::write(dir.path().join("src/lib.rs"), "pub fn changed() {}\n").unwrap();
+        fs::remove_file(dir.path().join("src/nested/mod.rs")).unwrap();
+        let update = AddOptions { update: true, ..Default::default() };
+        let staged = add(&repo, &[], &update).unwrap();
+        assert_eq!(
+            staged,
+            vec![StagedPath::Added("src/lib.rs".into()), StagedPath::Removed("src/nested/mod.rs".into())]
+        );
+        assert_eq!(paths(&repo), vec!["src/lib.rs"]);
+
+        let all = AddOptions { all: true, ..Default::default() };
+        assert_eq!(add(&repo, &[], &all).unwrap(), vec![StagedPath::Added("README".into())]);
+        assert!(add(&repo, &[], &all).unwrap().is_empty());
+        assert_eq!(add(&repo, &specs(&["*.rs"]), &AddOptions::default()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_remove_checks_and_cached() {
+        let (dir, repo) = setup();
+        add(&repo, &[], &AddOptions { all: true, ..Default::default() }).unwrap();
+        commit_index(&repo);
+
+        assert!(remove(&repo, &specs(&["src"]), &RemoveOptions::default()).is_err());
+        fs::write(dir.path().join("README"), "edited\n").unwrap();
+        assert!(remove(&repo, &specs(&["README"]), &RemoveOptions::default()).is_err());
+
+        // cached: 작업 디렉토리 파일은 그대로
+        let cached = RemoveOptions { cached: true, ..Default::default() };
+        assert_eq!(remove(&repo, &specs(&["README"]), &cached).unwrap(), vec!["README"]);
+        assert!(dir.path().join("README").exists());
+
+        let recursive = RemoveOptions { recursive: true, ..Default::default() };
+        assert_eq!(remove(&repo, &specs(&["src"]), &recursive).unwrap().len(), 2);
+        assert!(paths(&repo).is_empty());
+        assert!(!dir.path().join("src").exists());
+    }
+
+    #[test]
+    fn test_rename_file_and_directory() {
+        let (dir, repo) = setup();
+        add(&repo, &[], &AddOptions { all: true, ..Default::default() }).unwrap();
+
+        rename(&repo, &specs(&["README"]), "README.md", &MoveOptions::default()).unwrap();
+        assert!(dir.path().join("README.md").exists());
+        assert!(rename(&repo, &specs(&["src/lib.rs"]), "README.md", &MoveOptions::default()).is_err());
+        assert!(rename(&repo, &specs(&["src"]), "src/nested", &MoveOptions::default()).is_err());
+        assert!(rename(&repo, &specs(&["untracked"]), "x", &MoveOptions::default()).is_err());
+
+        fs::create_dir(dir.path().join("lib")).unwrap();
+        let moved = rename(&repo, &specs(&["src/nested"]), "lib", &MoveOptions::default()).unwrap();
+        assert_eq!(moved, vec![("src/nested/mod.rs".to_string(), "lib/nested/mod.rs".to_string())]);
+        assert_eq!(paths(&repo), vec!["README.md", "lib/nested/mod.rs", "src/lib.rs"]);
+        assert!(dir.path().join("lib/nested/mod.rs").exists());
+        assert!(!dir.path().join("src/nested").exists());
+    }
+}
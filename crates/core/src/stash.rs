@@ -0,0 +1,609 @@
+// =============================================================================
+// 스태시 (stash.rs)
+// =============================================================================
+//
+// 커밋하지 않은 인덱스/작업 디렉토리 변경을 잠시 치워 두었다가 다시 적용
+//
+// 스태시 하나 = 커밋 두 개:
+//   HEAD ← I (인덱스 트리, "index on {브랜치}: ...")
+//        ← W (작업 디렉토리 트리, "WIP on {브랜치}: ..." 또는 "On {브랜치}: {메시지}")
+//
+// - refs/stash 는 가장 최근 W 를 가리키고, 스택은 refs/stash 의 reflog (stash@{0} 이 최신)
+// - 추적하지 않는 파일(-u)은 W 트리에만 들어감 (W 에는 있고 I 에는 없는 경로)
+// - 적용할 때는 경로별 3-way: 기준 = HEAD(스태시 당시), 우리 = 현재 HEAD, 상대 = W
+//   양쪽이 모두 바꾼 텍스트 파일은 merge3 로 병합 (충돌 마커 포함)
+//
+// 파일 위치: crates/core/src/stash.rs
+// =============================================================================
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use shared::error::AppError;
+
+use crate::checkout::{is_safe_path, set_executable, update_worktree};
+use crate::index::{worktree_files, worktree_hash, Index, IndexEntry};
+use crate::merge::merge3;
+use crate::object::{Actor, Blob, Commit, Object, TreeEntry, MODE_SUBMODULE};
+use crate::patch::{diff_trees, FilePatch};
+use crate::refs::{Expected, Identity, RefValue};
+use crate::repo::Repository;
+
+/// 스태시 참조
+pub const STASH_REF: &str = "refs/stash";
+
+/// 충돌 마커 라벨 (우리, 상대)
+const MERGE_LABELS: (&str, &str) = ("Updated upstream", "Stashed changes");
+
+/// 작업 디렉토리에 기록할 (내용, 모드), None 이면 삭제
+type FileContent = Option<(Vec<u8>, String)>;
+
+// =============================================================================
+// 타입
+// =============================================================================
+
+/// 스택의 스태시 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashEntry {
+    /// 스택 위치 (0 이 최신)
+    pub index: usize,
+    /// 작업 디렉토리 커밋 (W)
+    pub commit: String,
+    /// W 커밋 메시지 첫 줄
+    pub message: String,
+}
+
+impl StashEntry {
+    /// "stash@{n}"
+    pub fn name(&self) -> String {
+        format!("stash@{{{}}}", self.index)
+    }
+}
+
+/// 스태시 적용 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StashApplyOutcome {
+    /// 충돌이 난 경로 (파일에 충돌 마커가 남거나 한쪽만 삭제)
+    pub conflicts: Vec<String>,
+}
+
+impl StashApplyOutcome {
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+// =============================================================================
+// Stash
+// =============================================================================
+
+/// 저장소의 스태시 스택
+#[derive(Debug)]
+pub struct Stash<'a> {
+    repo: &'a Repository,
+}
+
+impl<'a> Stash<'a> {
+    pub fn open(repo: &'a Repository) -> Self {
+        Self { repo }
+    }
+
+    /// 스태시 목록 (최신순)
+    pub fn list(&self) -> Result<Vec<StashEntry>, AppError> {
+        let entries = self
+            .repo
+            .refs()
+            .reflog(STASH_REF)?
+            .into_iter()
+            .rev()
+            .filter_map(|entry| entry.new.map(|commit| (commit, entry.reason)))
+            .enumerate()
+            .map(|(index, (commit, message))| StashEntry { index, commit, message })
+            .collect();
+        Ok(entries)
+    }
+
+    /// n 번째 스태시
+    ///
+    /// # Errors
+    /// * `AppError::NotFound` - 스태시가 없음
+    pub fn get(&self, index: usize) -> Result<StashEntry, AppError> {
+        self.list()?
+            .into_iter()
+            .nth(index)
+            .ok_or_else(|| AppError::NotFound(format!("stash@{{{}}} is not a valid stash", index)))
+    }
+
+    /// 인덱스와 작업 디렉토리 변경을 스태시로 저장하고 HEAD 상태로 되돌림
+    ///
+    /// # Arguments
+    /// * `message` - 스태시 설명 (없으면 "WIP on {브랜치}: {HEAD 요약}")
+    /// * `include_untracked` - 추적하지 않는 파일도 저장하고 삭제
+    ///
+    /// # Returns
+    /// 새 스태시 커밋 (저장할 변경이 없으면 None)
+    pub fn push(
+        &self,
+        message: Option<&str>,
+        include_untracked: bool,
+        identity: &Identity,
+    ) -> Result<Option<String>, AppError> {
+        let work_dir = self.work_dir()?;
+        let store = self.repo.objects();
+        let head = self
+            .repo
+            .head()?
+            .ok_or_else(|| AppError::InvalidInput("You do not have the initial commit yet".to_string()))?;
+        let head_commit = store.read_commit(&head)?;
+        let head_tree = head_commit.tree_hash.clone();
+
+        // 인덱스 트리
+        let index = self.repo.read_index()?;
+        let index_tree = index.write_tree(store)?;
+
+        // 작업 디렉토리 트리: 인덱스 경로의 현재 내용 (+ 추적하지 않는 파일)
+        let mut worktree = index.clone();
+        for (path, entry) in index.entries() {
+            // sparse checkout 범위 밖의 파일은 작업 디렉토리에 없어도 변경 없음
+            // 서브모듈 안의 변경은 서브모듈 저장소에서 따로 보관
+            if entry.skip_worktree || entry.mode == MODE_SUBMODULE {
+                continue;
+            }
+            match worktree_hash(work_dir, path, Some(entry))? {
+                None => {
+                    worktree.remove(path);
+                }
+                Some(current) if current.hash != entry.hash || current.mode != entry.mode => {
+                    worktree.stage_file(store, work_dir, path)?;
+                }
+                Some(_) => {}
+            }
+        }
+        let untracked: Vec<String> = if include_untracked {
+            worktree_files(work_dir)?
+                .into_iter()
+                .filter(|path| !index.contains(path))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        for path in &untracked {
+            worktree.stage_file(store, work_dir, path)?;
+        }
+        let worktree_tree = worktree.write_tree(store)?;
+
+        if index_tree == head_tree && worktree_tree == index_tree {
+            return Ok(None);
+        }
+
+        let branch = self.branch_name()?;
+        let summary = format!("{} {}", &head[..8], head_commit.message.lines().next().unwrap_or(""));
+        let actor = Actor::now(identity.name.clone(), identity.email.clone());
+        let index_commit = Commit::new(
+            index_tree,
+            Some(head.clone()),
+            format!("index on {}: {}\n", branch, summary),
+            actor.clone(),
+            actor.clone(),
+        );
+        let index_commit = store.write(&Object::from(index_commit))?;
+        let stash_message = match message {
+            Some(message) => format!("On {}: {}", branch, message.trim()),
+            None => format!("WIP on {}: {}", branch, summary),
+        };
+        let stash_commit = Commit::new(
+            worktree_tree.clone(),
+            Some(index_commit),
+            format!("{}\n", stash_message),
+            actor.clone(),
+            actor,
+        );
+        let stash_commit = store.write(&Object::from(stash_commit))?;
+
+        self.repo
+            .refs()
+            .update(STASH_REF, &stash_commit, Expected::Any, identity, &stash_message)?;
+
+        // 작업 디렉토리와 인덱스를 HEAD 로 (추적하지 않는 파일은 -u 일 때만 지워짐)
+        update_worktree(store, Some(&worktree_tree), &head_tree, work_dir)?;
+        self.repo.write_index(&Index::from_tree(store, &head_tree)?)?;
+        Ok(Some(stash_commit))
+    }
+
+    /// 스태시를 현재 작업 디렉토리에 적용 (스택은 그대로)
+    ///
+    /// # Arguments
+    /// * `restore_index` - 스태시 당시 스테이징한 변경을 인덱스에도 복원
+    ///
+    /// # Errors
+    /// * `AppError::InvalidInput` - 바뀔 파일에 로컬 수정이 있음 (아무것도 바꾸지 않음)
+    /// * `AppError::Conflict` - restore_index 인데 인덱스 변경을 그대로 복원할 수 없음
+    pub fn apply(&self, index: usize, restore_index: bool) -> Result<StashApplyOutcome, AppError> {
+        let work_dir = self.work_dir()?;
+        let store = self.repo.objects();
+        let stash = self.get(index)?;
+        let (base_tree, index_tree, stash_tree) = self.trees(&stash.commit)?;
+        let base = store.flatten_tree(&base_tree)?;
+        let staged = store.flatten_tree(&index_tree)?;
+        let theirs = store.flatten_tree(&stash_tree)?;
+        let ours = match self.repo.head()? {
+            Some(head) => store.flatten_tree(&store.read_commit(&head)?.tree_hash)?,
+            None => BTreeMap::new(),
+        };
+        let mut current_index = self.repo.read_index()?;
+
+        // 경로별 결과 계산 (아직 쓰지 않음)
+        let paths: BTreeSet<&String> = base.keys().chain(theirs.keys()).collect();
+        let mut writes: Vec<(String, FileContent)> = Vec::new();
+        let mut conflicts = Vec::new();
+        for path in paths {
+            let (b, o, t) = (base.get(path), ours.get(path), theirs.get(path));
+            if t == b {
+                continue;
+            }
+            if !is_safe_path(path) {
+                return Err(AppError::InvalidInput(format!("Unsafe path in stash: {}", path)));
+            }
+            if o == b || o == t {
+                writes.push((path.clone(), self.entry_content(t)?));
+                continue;
+            }
+            match (b, o, t) {
+                (Some(b), Some(o), Some(t)) => {
+                    let result = merge3(
+                        store.read_blob(&b.hash)?.content(),
+                        store.read_blob(&o.hash)?.content(),
+                        store.read_blob(&t.hash)?.content(),
+                        MERGE_LABELS,
+                    );
+                    if !result.is_clean() {
+                        conflicts.push(path.clone());
+                    }
+                    writes.push((path.clone(), Some((result.content, t.mode.clone()))));
+                }
+                // 한쪽은 삭제, 다른 쪽은 수정 → 남아 있는 쪽 내용을 작업 디렉토리에 두고 충돌로 보고
+                (_, Some(_), None) => conflicts.push(path.clone()),
+                (_, _, t) => {
+                    conflicts.push(path.clone());
+                    writes.push((path.clone(), self.entry_content(t)?));
+                }
+            }
+        }
+
+        // 로컬 수정 검사: 바뀔 경로의 작업 디렉토리/인덱스가 현재 HEAD 와 같아야 함
+        for (path, _) in &writes {
+            let expected = ours.get(path).map(|entry| entry.hash.as_str());
+            let on_disk = worktree_hash(work_dir, path, current_index.get(path))?;
+            if on_disk.as_ref().map(|entry| entry.hash.as_str()) != expected {
+                let reason = if expected.is_none() { "already exists" } else { "has local changes" };
+                return Err(AppError::InvalidInput(format!(
+                    "{} {}; commit or stash them before applying",
+                    path, reason
+                )));
+            }
+            if current_index.get(path).map(|entry| entry.hash.as_str()) != expected {
+                return Err(AppError::InvalidInput(format!(
+                    "{} has staged changes; commit or stash them before applying",
+                    path
+                )));
+            }
+        }
+
+        // 인덱스 변경 계산: 기본은 스태시 당시 새로 추가했던 파일만, restore_index 면 전부
+        let mut index_updates: Vec<(String, Option<TreeEntry>)> = Vec::new();
+        let staged_paths: BTreeSet<&String> = base.keys().chain(staged.keys()).collect();
+        for path in staged_paths {
+            let (b, o, s) = (base.get(path), ours.get(path), staged.get(path));
+            if s == b {
+                continue;
+            }
+            if restore_index {
+                if o != b && o != s {
+                    return Err(AppError::Conflict(format!(
+                        "Conflicts in index for {}; try without --index",
+                        path
+                    )));
+                }
+                index_updates.push((path.clone(), s.cloned()));
+            } else if b.is_none() && !conflicts.contains(path) {
+                index_updates.push((path.clone(), s.cloned()));
+            }
+        }
+
+        for (path, content) in &writes {
+            let target = work_dir.join(path);
+            match content {
+                Some((data, mode)) => {
+                    if let Some(parent) = target.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&target, data)?;
+                    set_executable(&target, mode == crate::checkout::MODE_EXECUTABLE)?;
+                }
+                None => {
+                    if target.exists() {
+                        fs::remove_file(&target)?;
+                    }
+                }
+            }
+        }
+        for (path, entry) in index_updates {
+            match entry {
+                Some(entry) => current_index.insert(path, IndexEntry::new(entry.mode, entry.hash)),
+                None => {
+                    current_index.remove(&path);
+                }
+            }
+        }
+        self.repo.write_index(&current_index)?;
+
+        Ok(StashApplyOutcome { conflicts })
+    }
+
+    /// 적용 후 충돌이 없으면 스택에서 제거
+    ///
+    /// # Returns
+    /// (적용 결과, 제거한 스태시 커밋 - 충돌이 있으면 None)
+    pub fn pop(
+        &self,
+        index: usize,
+        restore_index: bool,
+        identity: &Identity,
+    ) -> Result<(StashApplyOutcome, Option<String>), AppError> {
+        let outcome = self.apply(index, restore_index)?;
+        if !outcome.is_clean() {
+            return Ok((outcome, None));
+        }
+        let dropped = self.drop(index, identity)?;
+        Ok((outcome, Some(dropped)))
+    }
+
+    /// 스택에서 스태시 제거
+    ///
+    /// # Returns
+    /// 제거한 스태시 커밋
+    pub fn drop(&self, index: usize, identity: &Identity) -> Result<String, AppError> {
+        let refs = self.repo.refs();
+        let mut log = refs.reflog(STASH_REF)?;
+        let position = log
+            .len()
+            .checked_sub(index + 1)
+            .ok_or_else(|| AppError::NotFound(format!("stash@{{{}}} is not a valid stash", index)))?;
+        let removed = log.remove(position);
+        let dropped = removed
+            .new
+            .clone()
+            .ok_or_else(|| AppError::InvalidInput("Corrupt stash reflog".to_string()))?;
+
+        let Some(top) = log.last().and_then(|entry| entry.new.clone()) else {
+            refs.delete(STASH_REF, Expected::Any)?;
+            return Ok(dropped);
+        };
+        // 다음 항목의 이전 값을 이어 붙임
+        if let Some(next) = log.get_mut(position) {
+            next.old = removed.old;
+        }
+        if index == 0 {
+            refs.update(STASH_REF, &top, Expected::Value(dropped.clone()), identity, "stash: drop")?;
+        }
+        // update 가 덧붙인 항목까지 남은 스택으로 교체
+        refs.rewrite_reflog(STASH_REF, &log)?;
+        Ok(dropped)
+    }
+
+    /// 스태시가 기준 커밋 대비 바꾼 내용
+    pub fn show(&self, index: usize) -> Result<Vec<FilePatch>, AppError> {
+        let stash = self.get(index)?;
+        let (base_tree, _, stash_tree) = self.trees(&stash.commit)?;
+        diff_trees(self.repo.objects(), Some(&base_tree), &stash_tree)
+    }
+
+    // -------------------------------------------------------------------------
+    // 내부
+    // -------------------------------------------------------------------------
+
+    fn work_dir(&self) -> Result<&'a Path, AppError> {
+        self.repo
+            .work_dir()
+            .ok_or_else(|| AppError::InvalidInput("Stash requires a work tree".to_string()))
+    }
+
+    /// 현재 브랜치 짧은 이름 (분리된 HEAD 면 "(no branch)")
+    fn branch_name(&self) -> Result<String, AppError> {
+        Ok(match self.repo.refs().read("HEAD")? {
+            Some(RefValue::Symbolic(target)) => target
+                .strip_prefix("refs/heads/")
+                .unwrap_or(&target)
+                .to_string(),
+            _ => "(no branch)".to_string(),
+        })
+    }
+
+    /// 스태시 커밋의 (기준 HEAD 트리, 인덱스 트리, 작업 디렉토리 트리)
+    fn trees(&self, stash: &str) -> Result<(String, String, String), AppError> {
+        let store = self.repo.objects();
+        let corrupt = || AppError::InvalidInput(format!("{} is not a stash commit", stash));
+        let worktree = store.read_commit(stash)?;
+        let index = store.read_commit(worktree.parent_hash.as_deref().ok_or_else(corrupt)?)?;
+        let base = store.read_commit(index.parent_hash.as_deref().ok_or_else(corrupt)?)?;
+        Ok((base.tree_hash, index.tree_hash, worktree.tree_hash))
+    }
+
+    fn entry_content(&self, entry: Option<&TreeEntry>) -> Result<FileContent, AppError> {
+        match entry {
+            Some(entry) => {
+                let blob: Blob = self.repo.objects().read_blob(&entry.hash)?;
+                Ok(Some((blob.content().to_vec(), entry.mode.clone())))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// "stash@{n}" 또는 "n" → 스택 위치
+pub fn parse_stash_index(spec: &str) -> Result<usize, AppError> {
+    let digits = spec
+        .strip_prefix("stash@{")
+        .and_then(|rest| rest.strip_suffix('}'))
+        .unwrap_or(spec);
+    digits
+        .parse()
+        .map_err(|_| AppError::InvalidInput(format!("{} is not a valid stash reference", spec)))
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkout::checkout_tree;
+
+    fn identity() -> Identity {
+        Identity::new("Tester", "tester@example.com")
+    }
+
+    /// 파일들로 커밋을 만들어 HEAD(main) 로 두고 작업 디렉토리/인덱스도 맞춤
+    fn commit_files(repo: &Repository, files: &[(&str, &str)]) -> String {
+        let store = repo.objects();
+        let work = repo.work_dir().unwrap();
+        let flat: BTreeMap<String, TreeEntry> = files
+            .iter()
+            .map(|(path, content)| {
+                let blob = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+                let name = path.rsplit('/').next().unwrap().to_string();
+                (path.to_string(), TreeEntry::file(name, blob))
+            })
+            .collect();
+        let tree = store.write_flat_tree(&flat).unwrap();
+        let parent = repo.head().unwrap();
+        let old_tree = parent.as_ref().map(|p| store.read_commit(p).unwrap().tree_hash);
+        let actor = Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap();
+        let commit = Commit::new(tree.clone(), parent, "commit\n".to_string(), actor.clone(), actor);
+        let hash = store.write(&Object::from(commit)).unwrap();
+        match old_tree {
+            Some(old) => update_worktree(store, Some(&old), &tree, work).unwrap(),
+            None => {
+                checkout_tree(store, &tree, work).unwrap();
+            }
+        }
+        repo.refs().update("refs/heads/main", &hash, Expected::Any, &identity(), "commit").unwrap();
+        repo.write_index(&Index::from_tree(store, &tree).unwrap()).unwrap();
+        hash
+    }
+
+    fn read(repo: &Repository, path: &str) -> String {
+        fs::read_to_string(repo.work_dir().unwrap().join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_push_and_pop_restores_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        commit_files(&repo, &[("a.txt", "a\n"), ("b.txt", "b\n")]);
+        let work = dir.path();
+        let stash = Stash::open(&repo);
+
+        assert!(stash.push(None, false, &identity()).unwrap().is_none());
+
+        fs::write(work.join("a.txt"), "a changed\n").unwrap();
+        fs::write(work.join("new.txt"), "staged\n").unwrap();
+        fs::write(work.join("scratch.txt"), "untracked\n").unwrap();
+        let mut index = repo.read_index().unwrap();
+        index.stage_file(repo.objects(), work, "new.txt").unwrap();
+        repo.write_index(&index).unwrap();
+
+        stash.push(Some("work in progress"), false, &identity()).unwrap().unwrap();
+        assert_eq!(read(&repo, "a.txt"), "a\n");
+        assert!(!work.join("new.txt").exists());
+        // -u 없이는 추적하지 않는 파일을 건드리지 않음
+        assert!(work.join("scratch.txt").exists());
+        assert!(!repo.read_index().unwrap().contains("new.txt"));
+
+        let list = stash.list().unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].message, "On main: work in progress");
+        let changed: Vec<String> = stash.show(0).unwrap().iter().map(|f| f.path().to_string()).collect();
+        assert_eq!(changed, vec!["a.txt", "new.txt"]);
+
+        let (outcome, dropped) = stash.pop(0, false, &identity()).unwrap();
+        assert!(outcome.is_clean());
+        assert!(dropped.is_some());
+        assert_eq!(read(&repo, "a.txt"), "a changed\n");
+        assert_eq!(read(&repo, "new.txt"), "staged\n");
+        assert!(repo.read_index().unwrap().contains("new.txt"));
+        assert!(stash.list().unwrap().is_empty());
+        assert!(repo.read_ref(STASH_REF).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_include_untracked_and_drop_middle() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        commit_files(&repo, &[("a.txt", "a\n")]);
+        let work = dir.path();
+        let stash = Stash::open(&repo);
+
+        let mut pushed = Vec::new();
+        for i in 0..3 {
+            fs::write(work.join("a.txt"), format!("a{}\n", i)).unwrap();
+            fs::write(work.join("untracked.txt"), format!("u{}\n", i)).unwrap();
+            pushed.push(stash.push(Some(&format!("s{}", i)), true, &identity()).unwrap().unwrap());
+            assert!(!work.join("untracked.txt").exists());
+        }
+
+        assert_eq!(stash.drop(1, &identity()).unwrap(), pushed[1]);
+        let messages: Vec<String> = stash.list().unwrap().into_iter().map(|e| e.message).collect();
+        assert_eq!(messages, vec!["On main: s2", "On main: s0"]);
+        assert_eq!(stash.drop(0, &identity()).unwrap(), pushed[2]);
+        assert_eq!(repo.read_ref(STASH_REF).unwrap().as_deref(), Some(pushed[0].as_str()));
+        assert!(stash.get(1).is_err());
+
+        stash.apply(0, false).unwrap();
+        assert_eq!(read(&repo, "a.txt"), "a0\n");
+        assert_eq!(read(&repo, "untracked.txt"), "u0\n");
+        assert!(!repo.read_index().unwrap().contains("untracked.txt"));
+        // 적용만 하면 스택은 그대로
+        assert_eq!(stash.list().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_apply_merges_when_base_moved() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        commit_files(&repo, &[("f.txt", "1\n2\n3\n4\n5\n")]);
+        let work = dir.path();
+        let stash = Stash::open(&repo);
+
+        fs::write(work.join("f.txt"), "1\n2\n3\n4\nfive\n").unwrap();
+        stash.push(None, false, &identity()).unwrap().unwrap();
+        assert!(stash.list().unwrap()[0].message.starts_with("WIP on main: "));
+
+        // 스태시 이후 HEAD 가 같은 파일의 다른 부분을 바꿈 → 깨끗하게 병합
+        commit_files(&repo, &[("f.txt", "one\n2\n3\n4\n5\n")]);
+        let outcome = stash.apply(0, false).unwrap();
+        assert!(outcome.is_clean());
+        assert_eq!(read(&repo, "f.txt"), "one\n2\n3\n4\nfive\n");
+
+        // 같은 라인을 바꾸면 충돌 마커, pop 은 스택을 남김
+        fs::write(work.join("f.txt"), "one\n2\n3\n4\n5\n").unwrap();
+        commit_files(&repo, &[("f.txt", "one\n2\n3\n4\nFIVE\n")]);
+        let (outcome, dropped) = stash.pop(0, false, &identity()).unwrap();
+        assert_eq!(outcome.conflicts, vec!["f.txt"]);
+        assert!(dropped.is_none());
+        assert!(read(&repo, "f.txt").contains("<<<<<<< Updated upstream"));
+        assert_eq!(stash.list().unwrap().len(), 1);
+
+        // 로컬 수정이 있으면 거부
+        assert!(matches!(stash.apply(0, false), Err(AppError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_parse_stash_index() {
+        assert_eq!(parse_stash_index("stash@{2}").unwrap(), 2);
+        assert_eq!(parse_stash_index("0").unwrap(), 0);
+        assert!(parse_stash_index("stash@{x}").is_err());
+    }
+}
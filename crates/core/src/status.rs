@@ -0,0 +1,221 @@
+// =============================================================================
+// 작업 상태 (status.rs)
+// =============================================================================
+//
+// HEAD / 인덱스 / 작업 디렉토리 비교 (cts status)
+//
+// - staged:    HEAD 트리 → 인덱스 (다음 커밋에 들어갈 변경)
+// - unstaged:  인덱스 → 작업 디렉토리 (추적하는 파일의 수정/삭제)
+// - untracked: 작업 디렉토리에만 있는 파일
+//
+// sparse checkout 범위 밖(skip-worktree) 파일은 작업 디렉토리에 없어도 변경 없음
+// (Index::worktree_changes 가 건너뜀)
+//
+// 파일 위치: crates/core/src/status.rs
+// =============================================================================
+
+use std::collections::BTreeMap;
+
+use shared::error::AppError;
+
+use crate::index::{worktree_files, Index};
+use crate::object::TreeEntry;
+use crate::repo::Repository;
+
+// =============================================================================
+// 타입
+// =============================================================================
+
+/// 변경 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl ChangeKind {
+    /// 짧은 형식의 상태 문자 (A / M / D)
+    pub fn code(&self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Modified => 'M',
+            Self::Deleted => 'D',
+        }
+    }
+}
+
+/// 경로 하나의 변경
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEntry {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+/// 작업 상태 (각 목록은 경로순)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Status {
+    /// HEAD 와 인덱스의 차이
+    pub staged: Vec<StatusEntry>,
+    /// 인덱스와 작업 디렉토리의 차이
+    pub unstaged: Vec<StatusEntry>,
+    /// 추적하지 않는 파일
+    pub untracked: Vec<String>,
+}
+
+impl Status {
+    /// 커밋할 변경도 작업 디렉토리 변경도 없는지 (추적하지 않는 파일은 무관)
+    pub fn is_clean(&self) -> bool {
+        self.staged.is_empty() && self.unstaged.is_empty()
+    }
+}
+
+// =============================================================================
+// 상태 계산
+// =============================================================================
+
+/// 저장소의 작업 상태
+///
+/// # Errors
+/// * `AppError::InvalidInput` - bare 저장소
+pub fn status(repo: &Repository) -> Result<Status, AppError> {
+    let work_dir = repo
+        .work_dir()
+        .ok_or_else(|| AppError::InvalidInput("Bare repository has no working tree".to_string()))?;
+    let index = repo.read_index()?;
+
+    let staged = staged_changes(&head_files(repo)?, &index);
+    let unstaged = index
+        .worktree_changes(work_dir)?
+        .into_iter()
+        .map(|path| {
+            let kind = if work_dir.join(&path).symlink_metadata().is_ok() {
+                ChangeKind::Modified
+            } else {
+                ChangeKind::Deleted
+            };
+            StatusEntry { path, kind }
+        })
+        .collect();
+    let untracked = worktree_files(work_dir)?
+        .into_iter()
+        .filter(|path| !index.contains(path))
+        .collect();
+    Ok(Status {
+        staged,
+        unstaged,
+        untracked,
+    })
+}
+
+/// HEAD 커밋의 파일 (커밋이 없으면 비어 있음)
+fn head_files(repo: &Repository) -> Result<BTreeMap<String, TreeEntry>, AppError> {
+    match repo.head()? {
+        Some(head) => {
+            let store = repo.objects();
+            store.flatten_tree(&store.read_commit(&head)?.tree_hash)
+        }
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+/// HEAD 트리 → 인덱스 변경
+fn staged_changes(head: &BTreeMap<String, TreeEntry>, index: &Index) -> Vec<StatusEntry> {
+    let mut changes = Vec::new();
+    for (path, entry) in index.entries() {
+        let kind = match head.get(path) {
+            None => ChangeKind::Added,
+            Some(old) if old.hash != entry.hash || old.mode != entry.mode => ChangeKind::Modified,
+            Some(_) => continue,
+        };
+        changes.push(StatusEntry { path: path.clone(), kind });
+    }
+    for path in head.keys().filter(|path| !index.contains(path)) {
+        changes.push(StatusEntry {
+            path: path.clone(),
+            kind: ChangeKind::Deleted,
+        });
+    }
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::checkout::checkout_tree;
+    use crate::object::{Actor, Blob, Commit, Object};
+    use crate::refs::{Expected, Identity};
+    use crate::sparse::{set_patterns, SparsePatterns};
+
+    fn entry(path: &str, kind: ChangeKind) -> StatusEntry {
+        StatusEntry { path: path.into(), kind }
+    }
+
+    /// 파일들로 커밋을 만들고 체크아웃
+    fn setup(files: &[(&str, &str)]) -> (tempfile::TempDir, Repository) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let store = repo.objects();
+        let mut flat = BTreeMap::new();
+        for (path, content) in files {
+            let hash = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+            let name = path.rsplit('/').next().unwrap();
+            flat.insert(path.to_string(), TreeEntry::file(name.to_string(), hash));
+        }
+        let tree = store.write_flat_tree(&flat).unwrap();
+        let actor = Actor::now("Test".into(), "test@example.com".into());
+        let commit = Commit::new(tree.clone(), None, "c\n".into(), actor.clone(), actor);
+        let commit = store.write(&Object::from(commit)).unwrap();
+        repo.refs()
+            .update("refs/heads/main", &commit, Expected::Missing, &Identity::from_env(), "commit")
+            .unwrap();
+        checkout_tree(store, &tree, dir.path()).unwrap();
+        repo.write_index(&Index::from_tree(store, &tree).unwrap()).unwrap();
+        (dir, repo)
+    }
+
+    #[test]
+    fn test_status_staged_unstaged_untracked() {
+        let (dir, repo) = setup(&[("a.txt", "a\n"), ("b.txt", "b\n"), ("c.txt", "c\n")]);
+        assert!(status(&repo).unwrap().is_clean());
+
+        let mut index = repo.read_index().unwrap();
+        fs::write(dir.path().join("a.txt"), "a2\n").unwrap();
+        index.stage_file(repo.objects(), dir.path(), "a.txt").unwrap();
+        index.remove("b.txt");
+        repo.write_index(&index).unwrap();
+        fs::write(dir.path().join("c.txt"), "c2\n").unwrap();
+        fs::write(dir.path().join("new.txt"), "n\n").unwrap();
+
+        let current = status(&repo).unwrap();
+        assert_eq!(
+            current.staged,
+            vec![entry("a.txt", ChangeKind::Modified), entry("b.txt", ChangeKind::Deleted)]
+        );
+        assert_eq!(current.unstaged, vec![entry("c.txt", ChangeKind::Modified)]);
+        assert_eq!(current.untracked, vec!["b.txt", "new.txt"]);
+    }
+
+    #[test]
+    fn test_status_ignores_sparse_excluded_paths() {
+        let (dir, repo) = setup(&[("README", "r\n"), ("src/a.rs", "a\n"), ("docs/d.md", "d\n")]);
+        set_patterns(&repo, Some(&SparsePatterns::new(&["src"]).unwrap())).unwrap();
+        assert!(!dir.path().join("docs/d.md").exists());
+
+        // 풀지 않은 파일은 삭제가 아니라 변경 없음
+        let current = status(&repo).unwrap();
+        assert!(current.is_clean());
+        assert!(current.untracked.is_empty());
+
+        // 범위 안의 파일을 지우면 삭제
+        fs::remove_file(dir.path().join("src/a.rs")).unwrap();
+        assert_eq!(status(&repo).unwrap().unstaged, vec![entry("src/a.rs", ChangeKind::Deleted)]);
+    }
+}
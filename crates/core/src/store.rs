@@ -0,0 +1,547 @@
+// =============================================================================
+// 객체 저장소 (store.rs)
+// =============================================================================
+//
+// 로컬 객체 저장소 (.cts/objects/)
+//
+// Git의 loose object 방식과 동일한 구조:
+// - 경로: objects/{해시 앞 2자}/{나머지 62자}
+// - 내용: zlib 압축된 "{type} {size}\0{body}"
+// - 저장된 바이트의 SHA-256 = 객체 해시
+//
+// partial clone: promisor 원격이 있으면 없는 객체를 처음 읽을 때 받아옴
+// (여러 개가 필요한 곳은 prefetch 로 한 번에)
+//
+// shallow clone: 경계 커밋(.cts/shallow)의 부모는 없으므로 parent_of 가 None
+//
+// 파일 위치: crates/core/src/store.rs
+//
+// 사용 예시:
+//   use core::store::ObjectStore;
+//   use core::object::{Blob, Object};
+//
+//   let store = ObjectStore::new(".cts/objects");
+//   let hash = store.write(&Object::from(Blob::new(b"hello".to_vec())))?;
+//   let blob = store.read_blob(&hash)?;
+// =============================================================================
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use shared::error::AppError;
+
+use flate2::Compression;
+
+use crate::compression::{compress, compress_with_level, decompress};
+use crate::hash::{Hasher, HASH_HEX_LENGTH};
+use crate::object::{Blob, Commit, Object, Tag, Tree, TreeEntry};
+use crate::pack::unpack;
+use crate::transport::{FetchRequest, Transport, PREFETCH_BATCH_SIZE};
+
+/// entry_content() 가 서브모듈 엔트리를 표현하는 줄의 접두사
+pub const SUBPROJECT_PREFIX: &str = "Subproject commit ";
+
+// =============================================================================
+// ObjectStore 구조체
+// =============================================================================
+
+/// 파일 시스템 기반 객체 저장소
+///
+/// 같은 내용은 같은 해시 → 같은 파일이므로 쓰기는 멱등(idempotent)
+#[derive(Debug, Clone)]
+pub struct ObjectStore {
+    /// objects 디렉토리 경로
+    root: PathBuf,
+    /// 없는 객체를 받아올 원격 (partial clone)
+    promisor: Option<Arc<dyn Transport>>,
+    /// 부모를 따라가지 않는 경계 커밋 (shallow clone)
+    shallow: Arc<HashSet<String>>,
+    /// 객체 파일 압축 레벨 (None = 기본값)
+    compression: Option<Compression>,
+}
+
+impl ObjectStore {
+    /// objects 디렉토리를 가리키는 저장소 생성
+    ///
+    /// 디렉토리가 없으면 첫 쓰기 시 생성됨
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self {
+            root: root.into(),
+            promisor: None,
+            shallow: Arc::default(),
+            compression: None,
+        }
+    }
+
+    /// 경계 커밋을 지정한 저장소 (shallow clone)
+    pub fn with_shallow(mut self, commits: HashSet<String>) -> Self {
+        self.shallow = Arc::new(commits);
+        self
+    }
+
+    /// shallow 경계 커밋인지
+    pub fn is_shallow(&self, hash: &str) -> bool {
+        self.shallow.contains(hash)
+    }
+
+    /// 히스토리 탐색에서 따라갈 부모 (첫 커밋이나 shallow 경계 커밋이면 None)
+    pub fn parent_of(&self, hash: &str, commit: &Commit) -> Option<String> {
+        if self.is_shallow(hash) {
+            return None;
+        }
+        commit.parent_hash.clone()
+    }
+
+    /// 새로 쓰는 객체의 압축 레벨 지정 (설정 core.compression, 읽기에는 영향 없음)
+    pub fn with_compression(mut self, level: Compression) -> Self {
+        self.compression = Some(level);
+        self
+    }
+
+    /// 없는 객체를 원격에서 받아오는 저장소 (partial clone)
+    pub fn with_promisor(mut self, remote: Arc<dyn Transport>) -> Self {
+        self.promisor = Some(remote);
+        self
+    }
+
+    /// partial clone 저장소인지
+    pub fn has_promisor(&self) -> bool {
+        self.promisor.is_some()
+    }
+
+    /// objects 디렉토리 경로
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 해시에 해당하는 객체 파일 경로
+    fn object_path(&self, hash: &str) -> PathBuf {
+        let (dir, file) = hash.split_at(2);
+        self.root.join(dir).join(file)
+    }
+
+    // -------------------------------------------------------------------------
+    // 쓰기
+    // -------------------------------------------------------------------------
+
+    /// 객체 저장
+    ///
+    /// # Returns
+    /// 저장된 객체의 해시
+    pub fn write(&self, object: &Object) -> Result<String, AppError> {
+        self.write_raw(&object.encode())
+    }
+
+    /// 직렬화된 객체 바이트 저장 ("{type} {size}\0{body}")
+    ///
+    /// 이미 존재하면 다시 쓰지 않음
+    /// 임시 파일에 쓴 후 rename 하므로 중간에 실패해도 깨진 객체가 남지 않음
+    pub fn write_raw(&self, data: &[u8]) -> Result<String, AppError> {
+        let hash = Hasher::new().hash_bytes(data);
+        let path = self.object_path(&hash);
+        if path.exists() {
+            return Ok(hash);
+        }
+
+        let dir = path.parent().expect("object path always has a parent");
+        fs::create_dir_all(dir)?;
+        let tmp = dir.join(format!(".tmp-{}-{}", std::process::id(), &hash[2..]));
+        let compressed = match self.compression {
+            Some(level) => compress_with_level(data, level)?,
+            None => compress(data)?,
+        };
+        fs::write(&tmp, compressed)?;
+        fs::rename(&tmp, &path)?;
+        Ok(hash)
+    }
+
+    // -------------------------------------------------------------------------
+    // 읽기
+    // -------------------------------------------------------------------------
+
+    /// 로컬에 없는 객체를 promisor 원격에서 한 번에 받아오기
+    ///
+    /// PREFETCH_BATCH_SIZE 개씩 나눠서 요청, promisor 가 없으면 아무것도 하지 않음
+    ///
+    /// # Returns
+    /// 받아온 객체 수
+    pub fn prefetch(&self, hashes: &[String]) -> Result<usize, AppError> {
+        let Some(remote) = &self.promisor else {
+            return Ok(0);
+        };
+        let mut missing: Vec<String> = hashes
+            .iter()
+            .filter(|hash| is_valid_hash(hash) && !self.contains(hash))
+            .cloned()
+            .collect();
+        missing.sort();
+        missing.dedup();
+        for batch in missing.chunks(PREFETCH_BATCH_SIZE) {
+            let request = FetchRequest {
+                objects: batch.to_vec(),
+                ..Default::default()
+            };
+            unpack(self, &remote.fetch(&request)?.pack)?;
+        }
+        Ok(missing.len())
+    }
+
+    /// 객체 존재 여부 (로컬에 있는지만 확인)
+    pub fn contains(&self, hash: &str) -> bool {
+        is_valid_hash(hash) && self.object_path(hash).exists()
+    }
+
+    /// 직렬화된 객체 바이트 읽기 (압축 해제 + 해시 검증)
+    pub fn read_raw(&self, hash: &str) -> Result<Vec<u8>, AppError> {
+        if !is_valid_hash(hash) {
+            return Err(AppError::InvalidInput(format!("Invalid object hash: {}", hash)));
+        }
+        let path = self.object_path(hash);
+        if self.promisor.is_some() && !path.exists() {
+            self.prefetch(&[hash.to_string()])?;
+        }
+        let compressed = fs::read(&path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => AppError::NotFound(format!("Object {}", hash)),
+            _ => AppError::from(err),
+        })?;
+        let data = decompress(&compressed)?;
+
+        let actual = Hasher::new().hash_bytes(&data);
+        if actual != hash {
+            return Err(AppError::HashMismatch {
+                expected: hash.to_string(),
+                actual,
+            });
+        }
+        Ok(data)
+    }
+
+    /// 객체 읽기
+    pub fn read(&self, hash: &str) -> Result<Object, AppError> {
+        Object::decode(&self.read_raw(hash)?)
+    }
+
+    /// Blob 읽기 (타입이 다르면 에러)
+    pub fn read_blob(&self, hash: &str) -> Result<Blob, AppError> {
+        match self.read(hash)? {
+            Object::Blob(blob) => Ok(Blob::with_hash(blob.content().to_vec(), hash.to_string())),
+            other => Err(type_mismatch(hash, "blob", &other)),
+        }
+    }
+
+    /// Tree 읽기 (타입이 다르면 에러)
+    pub fn read_tree(&self, hash: &str) -> Result<Tree, AppError> {
+        match self.read(hash)? {
+            Object::Tree(tree) => Ok(tree),
+            other => Err(type_mismatch(hash, "tree", &other)),
+        }
+    }
+
+    /// Commit 읽기 (타입이 다르면 에러)
+    pub fn read_commit(&self, hash: &str) -> Result<Commit, AppError> {
+        match self.read(hash)? {
+            Object::Commit(commit) => Ok(commit),
+            other => Err(type_mismatch(hash, "commit", &other)),
+        }
+    }
+
+    /// Tag 읽기 (타입이 다르면 에러)
+    pub fn read_tag(&self, hash: &str) -> Result<Tag, AppError> {
+        match self.read(hash)? {
+            Object::Tag(tag) => Ok(tag),
+            other => Err(type_mismatch(hash, "tag", &other)),
+        }
+    }
+
+    /// 주석 태그를 따라가 커밋 해시 반환 (커밋이면 그대로)
+    ///
+    /// 태그가 태그를 가리키는 경우도 끝까지 따라감
+    pub fn peel_to_commit(&self, hash: &str) -> Result<String, AppError> {
+        let mut current = hash.to_string();
+        loop {
+            match self.read(&current)? {
+                Object::Commit(_) => return Ok(current),
+                Object::Tag(tag) => current = tag.object_hash,
+                other => return Err(type_mismatch(&current, "commit", &other)),
+            }
+        }
+    }
+
+    /// 저장된 모든 객체 해시 (정렬됨)
+    pub fn list(&self) -> Result<Vec<String>, AppError> {
+        let mut hashes = Vec::new();
+        if !self.root.exists() {
+            return Ok(hashes);
+        }
+        for dir in fs::read_dir(&self.root)? {
+            let dir = dir?;
+            let prefix = dir.file_name().to_string_lossy().to_string();
+            if prefix.len() != 2 || !dir.file_type()?.is_dir() {
+                continue;
+            }
+            for file in fs::read_dir(dir.path())? {
+                let name = file?.file_name().to_string_lossy().to_string();
+                let hash = format!("{}{}", prefix, name);
+                if is_valid_hash(&hash) {
+                    hashes.push(hash);
+                }
+            }
+        }
+        hashes.sort();
+        Ok(hashes)
+    }
+
+    /// 해시 접두사로 객체 찾기
+    ///
+    /// # Returns
+    /// * `Ok(Some(hash))` - 유일하게 일치하는 객체
+    /// * `Ok(None)` - 일치하는 객체 없음
+    /// * `Err(InvalidInput)` - 여러 객체가 일치 (모호함)
+    pub fn find_by_prefix(&self, prefix: &str) -> Result<Option<String>, AppError> {
+        let prefix = prefix.to_lowercase();
+        if prefix.len() < 4 || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Ok(None);
+        }
+        let dir = self.root.join(&prefix[..2]);
+        if !dir.exists() {
+            return Ok(None);
+        }
+
+        let mut found = None;
+        for file in fs::read_dir(dir)? {
+            let hash = format!("{}{}", &prefix[..2], file?.file_name().to_string_lossy());
+            if is_valid_hash(&hash) && hash.starts_with(&prefix) {
+                if found.is_some() {
+                    return Err(AppError::InvalidInput(format!("Ambiguous object prefix: {}", prefix)));
+                }
+                found = Some(hash);
+            }
+        }
+        Ok(found)
+    }
+
+    // -------------------------------------------------------------------------
+    // 트리 탐색
+    // -------------------------------------------------------------------------
+
+    /// 트리를 재귀적으로 펼쳐 "경로 → 파일 엔트리" 맵 생성
+    ///
+    /// 디렉토리 엔트리는 포함하지 않음 (서브모듈은 파일처럼 포함), 경로 구분자는 '/'
+    pub fn flatten_tree(&self, tree_hash: &str) -> Result<BTreeMap<String, TreeEntry>, AppError> {
+        let mut files = BTreeMap::new();
+        self.flatten_into(tree_hash, "", &mut files)?;
+        Ok(files)
+    }
+
+    fn flatten_into(
+        &self,
+        tree_hash: &str,
+        prefix: &str,
+        files: &mut BTreeMap<String, TreeEntry>,
+    ) -> Result<(), AppError> {
+        for entry in self.read_tree(tree_hash)?.entries() {
+            let path = format!("{}{}", prefix, entry.name);
+            if entry.is_directory() {
+                self.flatten_into(&entry.hash, &format!("{}/", path), files)?;
+            } else {
+                files.insert(path, entry.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// 파일 엔트리의 내용
+    ///
+    /// 서브모듈은 가리키는 커밋이 이 저장소에 없으므로 "Subproject commit {해시}" 한 줄로 표현
+    /// (diff / 패치 적용에서 일반 파일처럼 다루기 위함)
+    pub fn entry_content(&self, entry: &TreeEntry) -> Result<Vec<u8>, AppError> {
+        if entry.is_submodule() {
+            return Ok(format!("{}{}\n", SUBPROJECT_PREFIX, entry.hash).into_bytes());
+        }
+        Ok(self.read_blob(&entry.hash)?.content().to_vec())
+    }
+
+    /// flatten_tree() 의 역변환: "경로 → 파일 엔트리" 맵으로 트리 객체들을 기록
+    ///
+    /// 엔트리의 name 은 무시하고 경로의 마지막 요소를 사용
+    ///
+    /// # Returns
+    /// 루트 트리 해시 (맵이 비어 있으면 빈 트리)
+    pub fn write_flat_tree(&self, files: &BTreeMap<String, TreeEntry>) -> Result<String, AppError> {
+        let entries: Vec<(&str, &TreeEntry)> =
+            files.iter().map(|(path, entry)| (path.as_str(), entry)).collect();
+        self.write_subtree(&entries)
+    }
+
+    fn write_subtree(&self, files: &[(&str, &TreeEntry)]) -> Result<String, AppError> {
+        let mut tree = Tree::new();
+        let mut i = 0;
+        while i < files.len() {
+            let (path, entry) = files[i];
+            match path.split_once('/') {
+                None => {
+                    let mut entry = entry.clone();
+                    entry.name = path.to_string();
+                    tree.add_entry(entry);
+                    i += 1;
+                }
+                Some((dir, _)) => {
+                    // 같은 디렉토리 아래 경로들은 정렬되어 연속으로 나옴
+                    let prefix = format!("{}/", dir);
+                    let children: Vec<(&str, &TreeEntry)> = files[i..]
+                        .iter()
+                        .take_while(|(p, _)| p.starts_with(&prefix))
+                        .map(|(p, e)| (&p[prefix.len()..], *e))
+                        .collect();
+                    i += children.len();
+                    let hash = self.write_subtree(&children)?;
+                    tree.add_entry(TreeEntry::directory(dir.to_string(), hash));
+                }
+            }
+        }
+        self.write(&Object::from(tree))
+    }
+
+    /// 트리에서 경로("a/b/c.txt")에 해당하는 엔트리 찾기
+    pub fn lookup_path(&self, tree_hash: &str, path: &str) -> Result<Option<TreeEntry>, AppError> {
+        let mut current = tree_hash.to_string();
+        let mut components = path.split('/').filter(|c| !c.is_empty()).peekable();
+
+        while let Some(name) = components.next() {
+            let tree = self.read_tree(&current)?;
+            let entry = match tree.find(name) {
+                Some(entry) => entry.clone(),
+                None => return Ok(None),
+            };
+            if components.peek().is_none() {
+                return Ok(Some(entry));
+            }
+            if !entry.is_directory() {
+                return Ok(None);
+            }
+            current = entry.hash;
+        }
+        Ok(None)
+    }
+}
+
+// =============================================================================
+// 헬퍼 함수
+// =============================================================================
+
+/// 완전한 객체 해시 형식인지 확인 (64자 소문자 hex)
+pub fn is_valid_hash(hash: &str) -> bool {
+    hash.len() == HASH_HEX_LENGTH && hash.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+}
+
+fn type_mismatch(hash: &str, expected: &str, actual: &Object) -> AppError {
+    AppError::InvalidInput(format!(
+        "Object {} is a {}, not a {}",
+        hash,
+        actual.object_type(),
+        expected
+    ))
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store() -> (tempfile::TempDir, ObjectStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ObjectStore::new(dir.path().join("objects"));
+        (dir, store)
+    }
+
+    #[test]
+    fn test_write_and_read_blob() {
+        let (_dir, store) = temp_store();
+        let mut blob = Blob::new(b"hello world".to_vec());
+
+        let hash = store.write(&Object::from(blob.clone())).unwrap();
+        assert_eq!(hash, blob.hash());
+        assert!(store.contains(&hash));
+
+        let loaded = store.read_blob(&hash).unwrap();
+        assert_eq!(loaded.content(), b"hello world");
+    }
+
+    #[test]
+    fn test_write_is_idempotent() {
+        let (_dir, store) = temp_store();
+        let object = Object::from(Blob::new(b"same".to_vec()));
+
+        let first = store.write(&object).unwrap();
+        let second = store.write(&object).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(store.list().unwrap(), vec![first]);
+    }
+
+    #[test]
+    fn test_read_missing_object() {
+        let (_dir, store) = temp_store();
+        let result = store.read(&"0".repeat(HASH_HEX_LENGTH));
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_read_wrong_type() {
+        let (_dir, store) = temp_store();
+        let hash = store.write(&Object::from(Blob::new(b"x".to_vec()))).unwrap();
+        assert!(store.read_tree(&hash).is_err());
+    }
+
+    #[test]
+    fn test_corrupted_object_detected() {
+        let (_dir, store) = temp_store();
+        let hash = store.write(&Object::from(Blob::new(b"original".to_vec()))).unwrap();
+
+        // 다른 내용으로 덮어쓰기
+        let path = store.object_path(&hash);
+        fs::write(&path, compress(b"blob 8\0modified").unwrap()).unwrap();
+
+        assert!(matches!(store.read(&hash), Err(AppError::HashMismatch { .. })));
+    }
+
+    #[test]
+    fn test_find_by_prefix() {
+        let (_dir, store) = temp_store();
+        let hash = store.write(&Object::from(Blob::new(b"prefix".to_vec()))).unwrap();
+
+        assert_eq!(store.find_by_prefix(&hash[..8]).unwrap(), Some(hash.clone()));
+        assert_eq!(store.find_by_prefix("abc").unwrap(), None); // 너무 짧음
+    }
+
+    #[test]
+    fn test_flatten_and_lookup() {
+        let (_dir, store) = temp_store();
+        let file = store.write(&Object::from(Blob::new(b"fn main() {}".to_vec()))).unwrap();
+        let readme = store.write(&Object::from(Blob::new(b"# readme".to_vec()))).unwrap();
+
+        let src = store
+            .write(&Object::from(Tree::with_entries(vec![TreeEntry::file("main.rs".into(), file.clone())])))
+            .unwrap();
+        let root = store
+            .write(&Object::from(Tree::with_entries(vec![
+                TreeEntry::file("README.md".into(), readme.clone()),
+                TreeEntry::directory("src".into(), src),
+            ])))
+            .unwrap();
+
+        let files = store.flatten_tree(&root).unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["README.md", "src/main.rs"]);
+
+        assert_eq!(store.lookup_path(&root, "src/main.rs").unwrap().unwrap().hash, file);
+        assert!(store.lookup_path(&root, "src/missing.rs").unwrap().is_none());
+        assert!(store.lookup_path(&root, "README.md/x").unwrap().is_none());
+
+        // 펼친 맵으로 다시 기록하면 같은 트리
+        assert_eq!(store.write_flat_tree(&files).unwrap(), root);
+    }
+}
@@ -0,0 +1,574 @@
+// =============================================================================
+// 서브모듈 (submodule.rs)
+// =============================================================================
+//
+// 다른 저장소의 특정 커밋을 작업 디렉토리의 하위 디렉토리로 포함
+//
+// - 트리 엔트리: 모드 160000, 해시는 서브모듈 저장소의 커밋 (object.rs)
+// - .ctsmodules (작업 디렉토리 최상위, 커밋에 포함): 경로 → 원격 주소
+//     [submodule "libs/shared"]
+//     	path = libs/shared
+//     	url = http://server:3000/api/repositories/{id}
+// - init: .ctsmodules 의 주소를 .cts/config 의 submodule.{이름}.url 로 복사
+//   ("./", "../" 로 시작하는 주소는 origin 주소(없으면 작업 디렉토리) 기준)
+// - update: init 된 서브모듈을 받아서 (없으면 clone) 기록된 커밋을 HEAD 분리로 체크아웃
+// - 서브모듈 저장소는 {작업 디렉토리}/{경로}/.cts 에 그대로 둠
+//
+// 파일 위치: crates/core/src/submodule.rs
+// =============================================================================
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use shared::error::AppError;
+
+use crate::checkout::{is_safe_path, update_worktree};
+use crate::config::Config;
+use crate::index::{Index, IndexEntry};
+use crate::object::MODE_SUBMODULE;
+use crate::refs::{Expected, Identity, HEADS_PREFIX, REMOTES_PREFIX, TAGS_PREFIX};
+use crate::repo::{Repository, CTS_DIR_NAME};
+use crate::transport::{self, FetchRequest};
+use crate::worktree::read_link;
+
+/// 서브모듈 목록 파일 이름 (작업 디렉토리 최상위)
+pub const MODULES_FILE: &str = ".ctsmodules";
+
+/// 서브모듈 저장소에 붙이는 원격 이름
+const SUBMODULE_REMOTE: &str = "origin";
+
+// =============================================================================
+// .ctsmodules
+// =============================================================================
+
+/// .ctsmodules 의 서브모듈 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submodule {
+    /// 이름 (설정 키에 사용, 보통 경로와 같음)
+    pub name: String,
+    /// 작업 디렉토리 기준 경로
+    pub path: String,
+    /// 원격 주소
+    pub url: String,
+}
+
+/// .ctsmodules 내용 파싱
+///
+/// # Errors
+/// * `AppError::InvalidInput` - path/url 이 빠졌거나 경로가 작업 디렉토리 밖
+pub fn parse_modules(text: &str) -> Result<Vec<Submodule>, AppError> {
+    let config = Config::parse(text)?;
+    let mut modules = Vec::new();
+    for name in config.subsections("submodule") {
+        let field = |key: &str| {
+            config
+                .get(&format!("submodule.{}.{}", name, key))
+                .map(str::to_string)
+                .ok_or_else(|| AppError::InvalidInput(format!("Submodule '{}' has no {}", name, key)))
+        };
+        let path = field("path")?.trim_end_matches('/').to_string();
+        if !is_safe_path(&path) {
+            return Err(AppError::InvalidInput(format!("Unsafe submodule path: {}", path)));
+        }
+        modules.push(Submodule { url: field("url")?, path, name });
+    }
+    Ok(modules)
+}
+
+/// .ctsmodules 텍스트로 변환
+pub fn format_modules(modules: &[Submodule]) -> Result<String, AppError> {
+    let mut config = Config::new();
+    for module in modules {
+        config.set(&format!("submodule.{}.path", module.name), &module.path)?;
+        config.set(&format!("submodule.{}.url", module.name), &module.url)?;
+    }
+    Ok(config.format())
+}
+
+/// 작업 디렉토리의 .ctsmodules 읽기 (없으면 빈 목록)
+pub fn load_modules(work_dir: &Path) -> Result<Vec<Submodule>, AppError> {
+    match fs::read_to_string(work_dir.join(MODULES_FILE)) {
+        Ok(text) => parse_modules(&text),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+// =============================================================================
+// 서브모듈 저장소
+// =============================================================================
+
+/// 서브모듈 디렉토리의 저장소 열기 (아직 받지 않았으면 None)
+///
+/// 상위 디렉토리로 올라가며 찾지 않음 (상위 프로젝트를 서브모듈로 착각하지 않도록)
+pub fn open_submodule(dir: &Path) -> Result<Option<Repository>, AppError> {
+    let candidate = dir.join(CTS_DIR_NAME);
+    if candidate.is_file() {
+        Repository::open(read_link(&candidate)?).map(Some)
+    } else if candidate.is_dir() {
+        Repository::open(candidate).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// 서브모듈 디렉토리에 체크아웃된 커밋 (받지 않았거나 커밋이 없으면 None)
+pub fn checked_out_commit(dir: &Path) -> Result<Option<String>, AppError> {
+    match open_submodule(dir)? {
+        Some(repo) => repo.head(),
+        None => Ok(None),
+    }
+}
+
+/// 서브모듈 상태
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmoduleState {
+    /// 아직 받지 않음
+    Uninitialized,
+    /// 기록된 커밋이 체크아웃되어 있음
+    UpToDate,
+    /// 다른 커밋이 체크아웃되어 있음 (체크아웃된 커밋)
+    Modified(String),
+}
+
+/// 서브모듈 하나의 상태
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmoduleStatus {
+    pub path: String,
+    /// 상위 프로젝트 인덱스에 기록된 커밋
+    pub recorded: String,
+    pub state: SubmoduleState,
+    /// 서브모듈 작업 디렉토리에 커밋하지 않은 변경이 있는지
+    pub dirty: bool,
+}
+
+/// 인덱스의 모든 서브모듈 상태 (경로순)
+pub fn status(repo: &Repository) -> Result<Vec<SubmoduleStatus>, AppError> {
+    let work_dir = require_work_dir(repo)?;
+    let mut result = Vec::new();
+    for (path, entry) in submodule_entries(&repo.read_index()?) {
+        let (state, dirty) = match open_submodule(&work_dir.join(&path))? {
+            None => (SubmoduleState::Uninitialized, false),
+            Some(sub) => {
+                let state = match sub.head()? {
+                    Some(head) if head == entry.hash => SubmoduleState::UpToDate,
+                    Some(head) => SubmoduleState::Modified(head),
+                    None => SubmoduleState::Uninitialized,
+                };
+                let dirty = match sub.work_dir() {
+                    Some(dir) => !sub.read_index()?.worktree_changes(dir)?.is_empty(),
+                    None => false,
+                };
+                (state, dirty)
+            }
+        };
+        result.push(SubmoduleStatus { path, recorded: entry.hash, state, dirty });
+    }
+    Ok(result)
+}
+
+// =============================================================================
+// add / init / update
+// =============================================================================
+
+/// 새 서브모듈 추가
+///
+/// 원격 저장소를 `path` 에 clone 해서 기본 브랜치를 체크아웃하고,
+/// .ctsmodules 와 서브모듈 커밋을 상위 프로젝트 인덱스에 스테이징
+///
+/// # Errors
+/// * `AppError::AlreadyExists` - 이미 인덱스에 있는 경로이거나 비어 있지 않은 디렉토리
+/// * `AppError::NotFound` - 원격에 브랜치가 없음
+/// * `AppError::InvalidInput` - reflog 에 기록할 사용자 (user.name / user.email) 가 설정되지 않음
+pub fn add(repo: &Repository, url: &str, path: &str) -> Result<Submodule, AppError> {
+    let work_dir = require_work_dir(repo)?;
+    let path = path.trim_end_matches('/').to_string();
+    if !is_safe_path(&path) {
+        return Err(AppError::InvalidInput(format!("Unsafe submodule path: {}", path)));
+    }
+    let mut index = repo.read_index()?;
+    let dir = work_dir.join(&path);
+    if index.contains(&path) || index.entries().keys().any(|p| p.starts_with(&format!("{}/", path))) {
+        return Err(AppError::AlreadyExists(format!("'{}' already exists in the index", path)));
+    }
+    if dir.exists() && dir.read_dir()?.next().is_some() {
+        return Err(AppError::AlreadyExists(format!("'{}' already exists and is not empty", path)));
+    }
+
+    let identity = repo.committer_identity()?;
+    let module = Submodule { name: path.clone(), path: path.clone(), url: url.to_string() };
+    let resolved = resolve_url(repo, url)?;
+    let sub = clone_into(&resolved, &dir, &identity)?;
+
+    // 원격 HEAD 와 같은 커밋의 브랜치 → 첫 브랜치
+    let remote_refs = sub.list_refs(&format!("{}{}/", REMOTES_PREFIX, SUBMODULE_REMOTE))?;
+    let head_hash = transport::connect(&resolved)?
+        .list_refs()?
+        .into_iter()
+        .find(|(name, _)| name == "HEAD")
+        .map(|(_, hash)| hash);
+    let (branch_ref, commit) = remote_refs
+        .iter()
+        .find(|(_, hash)| Some(hash) == head_hash.as_ref())
+        .or_else(|| remote_refs.first())
+        .cloned()
+        .ok_or_else(|| AppError::NotFound(format!("Remote {} has no branches", url)))?;
+    let branch = branch_ref.rsplit('/').next().unwrap_or(&branch_ref).to_string();
+    let reason = format!("submodule add: {}", url);
+    sub.refs().update(&format!("{}{}", HEADS_PREFIX, branch), &commit, Expected::Missing, &identity, &reason)?;
+    sub.refs().set_symbolic("HEAD", &format!("{}{}", HEADS_PREFIX, branch), &identity, &reason)?;
+    checkout_commit(&sub, None, &commit)?;
+
+    let mut modules = load_modules(work_dir)?;
+    modules.push(module.clone());
+    fs::write(work_dir.join(MODULES_FILE), format_modules(&modules)?)?;
+    index.stage_file(repo.objects(), work_dir, MODULES_FILE)?;
+    index.insert(path.clone(), IndexEntry::new(MODE_SUBMODULE, commit));
+    repo.write_index(&index)?;
+
+    let mut config = repo.config()?;
+    config.set(&format!("submodule.{}.url", module.name), &resolved)?;
+    config.save(&repo.config_path())?;
+    Ok(module)
+}
+
+/// .ctsmodules 의 주소를 저장소 설정으로 복사 (이미 설정된 서브모듈은 그대로)
+///
+/// # Arguments
+/// * `paths` - 대상 서브모듈 경로 (비어 있으면 전부)
+///
+/// # Returns
+/// 새로 init 한 서브모듈 (url 은 설정에 기록한 주소)
+pub fn init(repo: &Repository, paths: &[String]) -> Result<Vec<Submodule>, AppError> {
+    let work_dir = require_work_dir(repo)?;
+    let modules = select(load_modules(work_dir)?, paths)?;
+    let mut config = repo.config()?;
+    let mut initialized = Vec::new();
+    for mut module in modules {
+        let key = format!("submodule.{}.url", module.name);
+        if config.get(&key).is_some() {
+            continue;
+        }
+        module.url = resolve_url(repo, &module.url)?;
+        config.set(&key, &module.url)?;
+        initialized.push(module);
+    }
+    if !initialized.is_empty() {
+        config.save(&repo.config_path())?;
+    }
+    Ok(initialized)
+}
+
+/// init 된 서브모듈을 받아서 인덱스에 기록된 커밋을 체크아웃
+///
+/// 아직 받지 않은 서브모듈은 clone, 기록된 커밋이 없으면 원격에서 fetch
+/// 서브모듈 HEAD 는 기록된 커밋으로 분리됨
+///
+/// # Arguments
+/// * `paths` - 대상 서브모듈 경로 (비어 있으면 전부)
+/// * `recursive` - 서브모듈 안의 서브모듈도 init + update
+///
+/// # Returns
+/// 체크아웃을 바꾼 (경로, 커밋) 목록 (recursive 면 하위 서브모듈 경로 포함)
+///
+/// # Errors
+/// * `AppError::InvalidInput` - 서브모듈에 기록된 커밋이 덮어쓸 로컬 수정이 있음,
+///   reflog 에 기록할 사용자 (user.name / user.email) 가 설정되지 않음
+pub fn update(repo: &Repository, paths: &[String], recursive: bool) -> Result<Vec<(String, String)>, AppError> {
+    update_as(repo, paths, recursive, &repo.committer_identity()?)
+}
+
+/// update 본체 (하위 서브모듈도 상위 프로젝트의 사용자로 reflog 기록)
+fn update_as(
+    repo: &Repository,
+    paths: &[String],
+    recursive: bool,
+    identity: &Identity,
+) -> Result<Vec<(String, String)>, AppError> {
+    let work_dir = require_work_dir(repo)?;
+    let config = repo.layered_config()?;
+    let modules = select(load_modules(work_dir)?, paths)?;
+    let wanted: HashSet<&str> = modules.iter().map(|module| module.path.as_str()).collect();
+
+    let mut updated = Vec::new();
+    for (path, entry) in submodule_entries(&repo.read_index()?) {
+        if !wanted.contains(path.as_str()) {
+            continue;
+        }
+        let module = modules.iter().find(|module| module.path == path).expect("selected module");
+        // init 하지 않은 서브모듈은 건너뜀
+        let Some(url) = config.get(&format!("submodule.{}.url", module.name)) else {
+            continue;
+        };
+        let dir = work_dir.join(&path);
+        let sub = match open_submodule(&dir)? {
+            Some(sub) => sub,
+            None => clone_into(url, &dir, identity)?,
+        };
+        if !sub.objects().contains(&entry.hash) {
+            fetch_into(&sub, url, Some(&entry.hash), identity)?;
+        }
+
+        let head = sub.head()?;
+        if head.as_deref() != Some(entry.hash.as_str()) {
+            checkout_commit(&sub, head.as_deref(), &entry.hash)?;
+            sub.refs().set_detached(
+                "HEAD",
+                &entry.hash,
+                identity,
+                &format!("submodule update: {}", entry.hash),
+            )?;
+            updated.push((path.clone(), entry.hash.clone()));
+        }
+        if recursive {
+            init(&sub, &[])?;
+            for (nested, hash) in update_as(&sub, &[], true, identity)? {
+                updated.push((format!("{}/{}", path, nested), hash));
+            }
+        }
+    }
+    Ok(updated)
+}
+
+// =============================================================================
+// 헬퍼
+// =============================================================================
+
+fn require_work_dir(repo: &Repository) -> Result<&Path, AppError> {
+    repo.work_dir()
+        .ok_or_else(|| AppError::InvalidInput("Submodules need a working directory".to_string()))
+}
+
+/// 인덱스의 서브모듈 엔트리 (경로순)
+fn submodule_entries(index: &Index) -> Vec<(String, IndexEntry)> {
+    index
+        .entries()
+        .iter()
+        .filter(|(_, entry)| entry.mode == MODE_SUBMODULE)
+        .map(|(path, entry)| (path.clone(), entry.clone()))
+        .collect()
+}
+
+/// 경로로 서브모듈 고르기 (비어 있으면 전부)
+fn select(modules: Vec<Submodule>, paths: &[String]) -> Result<Vec<Submodule>, AppError> {
+    if paths.is_empty() {
+        return Ok(modules);
+    }
+    let mut selected = Vec::new();
+    for path in paths {
+        let path = path.trim_end_matches('/');
+        let module = modules
+            .iter()
+            .find(|module| module.path == path)
+            .ok_or_else(|| AppError::NotFound(format!("No submodule at '{}' in {}", path, MODULES_FILE)))?;
+        selected.push(module.clone());
+    }
+    Ok(selected)
+}
+
+/// 상대 주소("./", "../")를 origin 주소(없으면 작업 디렉토리) 기준으로 풀기
+fn resolve_url(repo: &Repository, url: &str) -> Result<String, AppError> {
+    if !url.starts_with("./") && !url.starts_with("../") {
+        return Ok(transport::normalize_url(url));
+    }
+    let base = match repo.layered_config()?.get(&format!("remote.{}.url", SUBMODULE_REMOTE)) {
+        Some(base) => base.to_string(),
+        None => require_work_dir(repo)?.display().to_string(),
+    };
+    Ok(transport::normalize_url(&join_url(&base, url)))
+}
+
+/// "http://h/repos/app" + "../lib" → "http://h/repos/lib"
+fn join_url(base: &str, relative: &str) -> String {
+    let mut url = base.trim_end_matches('/').to_string();
+    for part in relative.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if let Some((parent, _)) = url.rsplit_once('/') {
+                    url.truncate(parent.len());
+                }
+            }
+            name => {
+                url.push('/');
+                url.push_str(name);
+            }
+        }
+    }
+    url
+}
+
+/// 원격 저장소를 dir 에 새로 받기 (체크아웃은 하지 않음)
+fn clone_into(url: &str, dir: &Path, identity: &Identity) -> Result<Repository, AppError> {
+    let sub = Repository::init(dir)?;
+    let mut config = sub.config()?;
+    config.set(&format!("remote.{}.url", SUBMODULE_REMOTE), url)?;
+    config.save(&sub.config_path())?;
+    fetch_into(&sub, url, None, identity)?;
+    Ok(sub)
+}
+
+/// 원격의 모든 브랜치/태그 (와 extra 커밋) 를 받아서 원격 추적 참조 갱신
+fn fetch_into(sub: &Repository, url: &str, extra: Option<&str>, identity: &Identity) -> Result<(), AppError> {
+    let remote = transport::connect(url)?;
+    let refs = remote.list_refs()?;
+    let mut wants: Vec<String> = refs
+        .iter()
+        .map(|(_, hash)| hash.clone())
+        .chain(extra.map(str::to_string))
+        .filter(|hash| !sub.objects().contains(hash))
+        .collect();
+    wants.sort();
+    wants.dedup();
+    if !wants.is_empty() {
+        let request = FetchRequest {
+            wants,
+            haves: sub.list_refs("refs/")?.into_iter().map(|(_, hash)| hash).collect(),
+            ..Default::default()
+        };
+        transport::receive_pack(sub, &remote.fetch(&request)?)?;
+    }
+
+    let mut transaction = sub.refs().transaction();
+    for (name, hash) in &refs {
+        if let Some(branch) = name.strip_prefix(HEADS_PREFIX) {
+            transaction.update(&format!("{}{}/{}", REMOTES_PREFIX, SUBMODULE_REMOTE, branch), hash, Expected::Any);
+        } else if name.starts_with(TAGS_PREFIX) {
+            transaction.update(name, hash, Expected::Any);
+        }
+    }
+    transaction.commit(identity, &format!("fetch: {}", url))
+}
+
+/// 서브모듈 작업 디렉토리를 from 커밋에서 to 커밋으로 전환하고 인덱스 갱신
+fn checkout_commit(sub: &Repository, from: Option<&str>, to: &str) -> Result<(), AppError> {
+    let work_dir = require_work_dir(sub)?;
+    let store = sub.objects();
+    let from_tree = match from {
+        Some(commit) => Some(store.read_commit(commit)?.tree_hash),
+        None => None,
+    };
+    let to_tree = store.read_commit(to)?.tree_hash;
+    update_worktree(store, from_tree.as_deref(), &to_tree, work_dir)?;
+    sub.write_index(&Index::from_tree(store, &to_tree)?)
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkout::checkout_tree;
+    use crate::object::{Actor, Blob, Commit, Object, TreeEntry};
+    use std::collections::BTreeMap;
+
+    /// 파일들로 커밋을 만들어 main 브랜치에 기록
+    fn commit(repo: &Repository, parent: Option<&str>, files: &[(&str, &str)]) -> String {
+        let store = repo.objects();
+        let mut map = BTreeMap::new();
+        for (path, content) in files {
+            let blob = store.write(&Object::from(Blob::new(content.as_bytes().to_vec()))).unwrap();
+            map.insert(path.to_string(), TreeEntry::file(path.to_string(), blob));
+        }
+        let tree = store.write_flat_tree(&map).unwrap();
+        let actor = Actor::parse("T <t@example.com> 1700000000 +0000").unwrap();
+        let commit = Commit::new(tree, parent.map(str::to_string), "c".into(), actor.clone(), actor);
+        let hash = store.write(&Object::from(commit)).unwrap();
+        repo.refs()
+            .update("refs/heads/main", &hash, Expected::Any, &Identity::from_env(), "test")
+            .unwrap();
+        hash
+    }
+
+    /// 저장소 설정에 사용자 기록 (reflog 에 남길 사용자)
+    fn configure_identity(repo: &Repository) {
+        let mut config = repo.config().unwrap();
+        config.set("user.name", "T").unwrap();
+        config.set("user.email", "t@example.com").unwrap();
+        config.save(&repo.config_path()).unwrap();
+    }
+
+    #[test]
+    fn test_modules_file_and_urls() {
+        let text = "[submodule \"libs/a\"]\n\tpath = libs/a/\n\turl = ../a\n";
+        let modules = parse_modules(text).unwrap();
+        assert_eq!(modules, vec![Submodule { name: "libs/a".into(), path: "libs/a".into(), url: "../a".into() }]);
+        assert_eq!(parse_modules(&format_modules(&modules).unwrap()).unwrap()[0].path, "libs/a");
+        assert!(parse_modules("[submodule \"x\"]\n\tpath = ../x\n\turl = u\n").is_err());
+        assert!(parse_modules("[submodule \"x\"]\n\tpath = x\n").is_err());
+
+        assert_eq!(join_url("http://h/api/repositories/1/", "../2"), "http://h/api/repositories/2");
+        assert_eq!(join_url("/srv/app", "./libs/../lib"), "/srv/app/lib");
+    }
+
+    #[test]
+    fn test_add_update_and_status() {
+        let dir = tempfile::tempdir().unwrap();
+        let lib = Repository::init(dir.path().join("lib")).unwrap();
+        let first = commit(&lib, None, &[("lib.rs", "v1\n")]);
+
+        let app_dir = dir.path().join("app");
+        let app = Repository::init(&app_dir).unwrap();
+        configure_identity(&app);
+        let module = add(&app, "../lib", "vendor/lib").unwrap();
+        assert_eq!(module.url, "../lib");
+        assert_eq!(fs::read_to_string(app_dir.join("vendor/lib/lib.rs")).unwrap(), "v1\n");
+        let index = app.read_index().unwrap();
+        assert_eq!(index.get("vendor/lib").unwrap().hash, first);
+        assert!(index.contains(MODULES_FILE));
+        assert!(matches!(add(&app, "../lib", "vendor/lib"), Err(AppError::AlreadyExists(_))));
+
+        // 서브모듈 엔트리는 트리에 커밋 모드로 기록됨
+        let tree = index.write_tree(app.objects()).unwrap();
+        let entry = app.objects().lookup_path(&tree, "vendor/lib").unwrap().unwrap();
+        assert!(entry.is_submodule());
+        // 서브모듈 커밋은 상위 프로젝트의 전송 대상이 아님
+        let actor = Actor::parse("T <t@example.com> 1700000000 +0000").unwrap();
+        let top = app
+            .objects()
+            .write(&Object::from(Commit::initial(tree.clone(), "add lib".into(), actor.clone(), actor)))
+            .unwrap();
+        let objects = crate::revwalk::commit_objects(app.objects(), &[top], &[]).unwrap();
+        assert!(objects.contains(&tree) && !objects.contains(&first));
+
+        // 서브모듈에서 다른 커밋을 체크아웃하면 Modified
+        let second = commit(&lib, Some(&first), &[("lib.rs", "v2\n")]);
+        let sub = open_submodule(&app_dir.join("vendor/lib")).unwrap().unwrap();
+        let url = sub.config().unwrap().get("remote.origin.url").unwrap().to_string();
+        fetch_into(&sub, &url, None, &app.committer_identity().unwrap()).unwrap();
+        checkout_commit(&sub, Some(&first), &second).unwrap();
+        sub.refs().set_detached("HEAD", &second, &Identity::from_env(), "test").unwrap();
+        let states = status(&app).unwrap();
+        assert_eq!(states[0].state, SubmoduleState::Modified(second.clone()));
+        assert!(!states[0].dirty);
+        assert_eq!(app.read_index().unwrap().worktree_changes(&app_dir).unwrap(), vec!["vendor/lib"]);
+
+        // update 는 기록된 커밋으로 되돌림 (reflog 에는 상위 프로젝트에 설정한 사용자)
+        assert_eq!(update(&app, &[], false).unwrap(), vec![("vendor/lib".to_string(), first.clone())]);
+        let entry = sub.refs().reflog("HEAD").unwrap().pop().unwrap();
+        assert_eq!((entry.identity.name.as_str(), entry.identity.email.as_str()), ("T", "t@example.com"));
+        assert_eq!(fs::read_to_string(app_dir.join("vendor/lib/lib.rs")).unwrap(), "v1\n");
+        fs::write(app_dir.join("vendor/lib/lib.rs"), "local\n").unwrap();
+        let states = status(&app).unwrap();
+        assert_eq!(states[0].state, SubmoduleState::UpToDate);
+        assert!(states[0].dirty);
+
+        // 다른 곳에 체크아웃한 상위 프로젝트: init 전에는 Uninitialized, init + update 후 받아옴
+        let copy_dir = dir.path().join("copy");
+        let copy = Repository::init(&copy_dir).unwrap();
+        configure_identity(&copy);
+        checkout_tree(app.objects(), &tree, &copy_dir).unwrap();
+        copy.write_index(&Index::from_tree(app.objects(), &tree).unwrap()).unwrap();
+        assert!(copy_dir.join("vendor/lib").is_dir());
+        assert_eq!(status(&copy).unwrap()[0].state, SubmoduleState::Uninitialized);
+        assert!(update(&copy, &[], false).unwrap().is_empty());
+        let initialized = init(&copy, &[]).unwrap();
+        assert_eq!(initialized[0].url, dir.path().join("lib").canonicalize().unwrap().display().to_string());
+        assert_eq!(update(&copy, &[], true).unwrap().len(), 1);
+        assert_eq!(fs::read_to_string(copy_dir.join("vendor/lib/lib.rs")).unwrap(), "v1\n");
+        assert_eq!(status(&copy).unwrap()[0].state, SubmoduleState::UpToDate);
+    }
+}
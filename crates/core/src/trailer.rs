@@ -0,0 +1,261 @@
+// =============================================================================
+// 커밋 트레일러 (trailer.rs)
+// =============================================================================
+//
+// 커밋 메시지 마지막 문단의 "Key: value" 메타데이터
+//
+//   Fix crash when the index is empty
+//
+//   The loop assumed at least one entry.
+//
+//   Fixes: #42
+//   Reviewed-by: Jane Doe <jane@example.com>
+//   Signed-off-by: John Smith <john@example.com>
+//
+// 규칙 (Git 과 동일한 부분만):
+// - 트레일러 블록은 마지막 문단, 제목 문단은 제외
+// - 블록의 모든 줄이 "Key: value" 이거나 공백으로 시작하는 이어지는 줄이어야 함
+// - 키는 영문자/숫자/'-', 비교는 대소문자 무시
+//
+// 파일 위치: crates/core/src/trailer.rs
+// =============================================================================
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use shared::error::AppError;
+
+/// 서명자 트레일러 키
+pub const SIGNED_OFF_BY: &str = "Signed-off-by";
+
+// =============================================================================
+// 트레일러
+// =============================================================================
+
+/// 트레일러 한 줄
+///
+/// # Example
+/// ```
+/// use core::trailer::Trailer;
+///
+/// let trailer: Trailer = "fixes=#42".parse().unwrap();
+/// assert!(trailer.has_key("Fixes"));
+/// assert_eq!(trailer.to_string(), "fixes: #42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trailer {
+    /// 키 (메시지에 적힌 그대로)
+    pub key: String,
+    /// 값 (이어지는 줄은 공백 하나로 합침)
+    pub value: String,
+}
+
+impl Trailer {
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// "Signed-off-by: 이름 <이메일>"
+    pub fn signed_off_by(name: &str, email: &str) -> Self {
+        Self::new(SIGNED_OFF_BY, format!("{} <{}>", name, email))
+    }
+
+    /// 키 비교 (대소문자 무시)
+    pub fn has_key(&self, key: &str) -> bool {
+        self.key.eq_ignore_ascii_case(key)
+    }
+
+    /// 메시지의 한 줄 파싱 ("Key: value")
+    fn parse_line(line: &str) -> Option<Self> {
+        let (key, value) = line.split_once(':')?;
+        let value = value.trim();
+        if !is_valid_key(key) || value.is_empty() {
+            return None;
+        }
+        Some(Self::new(key, value))
+    }
+}
+
+/// 명령행 인자 파싱 ("Key: value" 또는 "Key=value")
+impl FromStr for Trailer {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AppError::InvalidInput(format!("Invalid trailer '{}': expected 'Key: value'", s));
+        let split = match (s.find(':'), s.find('=')) {
+            (Some(colon), Some(equals)) => colon.min(equals),
+            (Some(at), None) | (None, Some(at)) => at,
+            (None, None) => return Err(invalid()),
+        };
+        let key = s[..split].trim();
+        let value = s[split + 1..].trim();
+        if !is_valid_key(key) || value.is_empty() || value.contains('\n') {
+            return Err(invalid());
+        }
+        Ok(Self::new(key, value))
+    }
+}
+
+impl fmt::Display for Trailer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.value)
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+// =============================================================================
+// 메시지 파싱/포맷
+// =============================================================================
+
+/// 메시지의 트레일러 블록 위치 (줄 인덱스 범위)
+fn trailer_block(lines: &[&str]) -> Option<(usize, usize)> {
+    let end = lines.iter().rposition(|line| !line.trim().is_empty())? + 1;
+    let start = lines[..end]
+        .iter()
+        .rposition(|line| line.trim().is_empty())
+        .map_or(0, |blank| blank + 1);
+
+    // 제목 문단은 트레일러가 아님
+    let first = lines.iter().position(|line| !line.trim().is_empty())?;
+    if start <= first {
+        return None;
+    }
+    Some((start, end))
+}
+
+/// 메시지에서 트레일러 목록 추출
+///
+/// 마지막 문단이 트레일러 형식이 아니면 빈 목록
+pub fn parse_trailers(message: &str) -> Vec<Trailer> {
+    let lines: Vec<&str> = message.lines().collect();
+    let Some((start, end)) = trailer_block(&lines) else {
+        return Vec::new();
+    };
+
+    let mut trailers: Vec<Trailer> = Vec::new();
+    for line in &lines[start..end] {
+        if line.starts_with([' ', '\t']) {
+            // 이어지는 줄
+            match trailers.last_mut() {
+                Some(last) => {
+                    last.value.push(' ');
+                    last.value.push_str(line.trim());
+                }
+                None => return Vec::new(),
+            }
+            continue;
+        }
+        match Trailer::parse_line(line) {
+            Some(trailer) => trailers.push(trailer),
+            None => return Vec::new(),
+        }
+    }
+    trailers
+}
+
+/// 메시지에 트레일러 추가
+///
+/// - 트레일러 블록이 있으면 그 뒤에, 없으면 빈 줄 하나 띄우고 새 블록으로
+/// - 키와 값이 같은 트레일러가 이미 있으면 건너뜀
+/// - 결과 메시지는 개행으로 끝남
+pub fn add_trailers(message: &str, trailers: &[Trailer]) -> String {
+    let existing = parse_trailers(message);
+    let mut added: Vec<&Trailer> = Vec::new();
+    for trailer in trailers {
+        let duplicate = existing
+            .iter()
+            .chain(added.iter().copied())
+            .any(|t| t.has_key(&trailer.key) && t.value == trailer.value);
+        if !duplicate {
+            added.push(trailer);
+        }
+    }
+    if added.is_empty() {
+        return message.to_string();
+    }
+
+    let body = message.trim_end();
+    let mut text = body.to_string();
+    if !existing.is_empty() {
+        text.push('\n');
+    } else if !body.is_empty() {
+        text.push_str("\n\n");
+    }
+    for trailer in added {
+        text.push_str(&trailer.to_string());
+        text.push('\n');
+    }
+    text
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = "Fix crash on empty index\n\
+        \n\
+        The loop assumed at least one entry.\n\
+        \n\
+        Fixes: #42\n\
+        Reviewed-by: Jane Doe\n  <jane@example.com>\n\
+        Signed-off-by: John Smith <john@example.com>\n";
+
+    #[test]
+    fn test_parse_trailers() {
+        let trailers = parse_trailers(MESSAGE);
+        assert_eq!(
+            trailers,
+            vec![
+                Trailer::new("Fixes", "#42"),
+                Trailer::new("Reviewed-by", "Jane Doe <jane@example.com>"),
+                Trailer::new(SIGNED_OFF_BY, "John Smith <john@example.com>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_trailer_paragraphs() {
+        // 제목만 있는 메시지
+        assert!(parse_trailers("Fixes: #42\n").is_empty());
+        // 마지막 문단에 일반 문장이 섞임
+        assert!(parse_trailers("Subject\n\nFixes: #42\nand some prose\n").is_empty());
+        // 키에 공백
+        assert!(parse_trailers("Subject\n\nSee also: docs\n").is_empty());
+        assert!(parse_trailers("").is_empty());
+    }
+
+    #[test]
+    fn test_add_trailers() {
+        // 새 블록
+        let message = add_trailers("Subject\n\nBody text.\n", &[Trailer::new("Fixes", "#1")]);
+        assert_eq!(message, "Subject\n\nBody text.\n\nFixes: #1\n");
+
+        // 기존 블록 뒤에 이어 붙이고 중복은 건너뜀
+        let signoff = Trailer::signed_off_by("John Smith", "john@example.com");
+        let message = add_trailers(MESSAGE, &[signoff.clone(), Trailer::new("Fixes", "#43")]);
+        assert!(message.ends_with("Signed-off-by: John Smith <john@example.com>\nFixes: #43\n"));
+        assert_eq!(parse_trailers(&message).len(), 4);
+
+        assert_eq!(add_trailers(&message, &[signoff]), message);
+    }
+
+    #[test]
+    fn test_trailer_from_str() {
+        assert_eq!("Fixes: #42".parse::<Trailer>().unwrap(), Trailer::new("Fixes", "#42"));
+        assert_eq!("Acked-by=A <a@b>".parse::<Trailer>().unwrap(), Trailer::new("Acked-by", "A <a@b>"));
+        assert!("no separator".parse::<Trailer>().is_err());
+        assert!("Bad key: x".parse::<Trailer>().is_err());
+        assert!("Fixes:".parse::<Trailer>().is_err());
+    }
+}
@@ -0,0 +1,810 @@
+// =============================================================================
+// 원격 저장소 전송 (transport.rs)
+// =============================================================================
+//
+// clone / fetch 가 원격 저장소에서 참조 목록과 객체를 받아오고, push 로 보내는 방법
+//
+// 원격 주소:
+// - http://{호스트}[:{포트}]/api/repositories/{id}   서버 (GET /refs, POST /fetch, POST /push)
+// - file://{경로} 또는 경로                           로컬 디렉토리의 저장소
+//
+// 참조 목록 (GET {url}/refs, text/plain):
+//   {hash} HEAD\n
+//   {hash} refs/heads/main\n
+//
+// fetch 요청 (POST {url}/fetch, text/plain)
+//   want {hash}\n          이 커밋(태그)에서 도달 가능한 객체 전부
+//   have {hash}\n          받는 쪽이 이미 가진 커밋 (그 조상 객체는 제외)
+//   filter blob:none\n     partial clone: Blob 생략 (blob:limit={크기} 는 큰 Blob 만)
+//   object {hash}\n        이 객체만 그대로 (partial clone 의 빠진 Blob 요청)
+//   shallow {hash}\n       받는 쪽의 shallow 경계 커밋
+//   depth {N}\n            깊이 제한 (deepen-since {epoch}, deepen {N}, unshallow)
+//
+// fetch 응답 (application/octet-stream)
+//   shallow {hash}\n       받는 쪽이 새로 기록할 경계 커밋
+//   unshallow {hash}\n     더 이상 경계가 아닌 커밋
+//   \n
+//   {pack}                 (pack.rs 포맷)
+//
+// push 요청 (POST {url}/push, application/octet-stream)
+//   update {이전 해시 또는 -} {새 해시} {참조}\n   이전 값이 다르면 전체 거부 (compare-and-swap)
+//   \n
+//   {pack}                 새 커밋에서 도달 가능하고 받는 쪽에 없는 객체
+// 받는 쪽은 객체를 저장하고 연결성을 확인한 뒤 참조를 한 트랜잭션으로 갱신
+//
+// 인증: CTS_TOKEN 환경 변수가 있으면 "Authorization: Bearer {토큰}" 헤더를 붙임
+// (서버는 push 에 토큰을 요구, 없거나 틀리면 401)
+//
+// 파일 위치: crates/core/src/transport.rs
+// =============================================================================
+
+use std::fmt;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use shared::error::AppError;
+
+use crate::object::Object;
+use crate::pack::{build_pack, unpack};
+use crate::refs::{Expected, Identity};
+use crate::repo::{Repository, CTS_DIR_NAME};
+use crate::revwalk::commit_objects;
+use crate::shallow::{self, select_commits, DepthLimit};
+use crate::store::{is_valid_hash, ObjectStore};
+use crate::worktree::read_link;
+
+/// partial clone 에서 빠진 객체를 한 번에 요청하는 최대 개수
+pub const PREFETCH_BATCH_SIZE: usize = 512;
+
+/// HTTP 요청에 붙일 접근 토큰 환경 변수
+pub const TOKEN_ENV: &str = "CTS_TOKEN";
+
+/// 광고하는 참조 접두사 (원격 추적 브랜치, stash 등은 제외)
+const ADVERTISED_PREFIXES: [&str; 3] = ["refs/heads/", "refs/tags/", "refs/notes/"];
+
+// =============================================================================
+// 객체 필터 (partial clone)
+// =============================================================================
+
+/// fetch 에서 생략할 객체
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectFilter {
+    /// 모든 Blob 생략 (커밋과 트리만)
+    BlobNone,
+    /// 이 크기(바이트) 이상인 Blob 생략
+    BlobLimit(u64),
+}
+
+impl ObjectFilter {
+    /// 직렬화된 객체 ("{type} {size}\0{body}") 를 생략해야 하는지
+    pub fn omits(&self, data: &[u8]) -> bool {
+        let header_end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+        let header = String::from_utf8_lossy(&data[..header_end]);
+        let Some(("blob", size)) = header.split_once(' ') else {
+            return false;
+        };
+        match self {
+            Self::BlobNone => true,
+            Self::BlobLimit(limit) => size.parse::<u64>().is_ok_and(|size| size >= *limit),
+        }
+    }
+}
+
+impl FromStr for ObjectFilter {
+    type Err = AppError;
+
+    /// "blob:none", "blob:limit=1m" (k, m, g 단위 허용)
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let invalid = || AppError::InvalidInput(format!("Invalid filter: {}", spec));
+        if spec == "blob:none" {
+            return Ok(Self::BlobNone);
+        }
+        let limit = spec.strip_prefix("blob:limit=").ok_or_else(invalid)?.to_ascii_lowercase();
+        let (number, unit) = match limit.char_indices().last() {
+            Some((i, 'k')) => (&limit[..i], 1024),
+            Some((i, 'm')) => (&limit[..i], 1024 * 1024),
+            Some((i, 'g')) => (&limit[..i], 1024 * 1024 * 1024),
+            _ => (limit.as_str(), 1),
+        };
+        let number: u64 = number.parse().map_err(|_| invalid())?;
+        Ok(Self::BlobLimit(number.checked_mul(unit).ok_or_else(invalid)?))
+    }
+}
+
+impl fmt::Display for ObjectFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlobNone => write!(f, "blob:none"),
+            Self::BlobLimit(limit) => write!(f, "blob:limit={}", limit),
+        }
+    }
+}
+
+// =============================================================================
+// fetch 요청
+// =============================================================================
+
+/// 원격에 보내는 fetch 요청
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchRequest {
+    /// 받을 커밋(태그)
+    pub wants: Vec<String>,
+    /// 이미 가진 커밋
+    pub haves: Vec<String>,
+    /// 그대로 받을 객체 (partial clone 의 빠진 객체)
+    pub objects: Vec<String>,
+    /// 생략할 객체
+    pub filter: Option<ObjectFilter>,
+    /// 받는 쪽의 shallow 경계 커밋
+    pub shallow: Vec<String>,
+    /// 깊이 제한 (shallow clone)
+    pub limit: Option<DepthLimit>,
+}
+
+impl FetchRequest {
+    pub fn encode(&self) -> String {
+        let mut out = String::new();
+        for hash in &self.wants {
+            out.push_str(&format!("want {}\n", hash));
+        }
+        for hash in &self.haves {
+            out.push_str(&format!("have {}\n", hash));
+        }
+        for hash in &self.objects {
+            out.push_str(&format!("object {}\n", hash));
+        }
+        for hash in &self.shallow {
+            out.push_str(&format!("shallow {}\n", hash));
+        }
+        if let Some(filter) = &self.filter {
+            out.push_str(&format!("filter {}\n", filter));
+        }
+        if let Some(limit) = &self.limit {
+            out.push_str(&format!("{}\n", limit));
+        }
+        out
+    }
+
+    pub fn decode(text: &str) -> Result<Self, AppError> {
+        let mut request = Self::default();
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let invalid = || AppError::InvalidInput(format!("Invalid fetch request line: {}", line));
+            let (kind, value) = line.split_once(' ').unwrap_or((line, ""));
+            match kind {
+                "filter" => {
+                    request.filter = Some(value.parse()?);
+                    continue;
+                }
+                "depth" | "deepen-since" | "deepen" | "unshallow" => {
+                    request.limit = Some(line.parse()?);
+                    continue;
+                }
+                _ => {}
+            }
+            if !is_valid_hash(value) {
+                return Err(invalid());
+            }
+            match kind {
+                "want" => request.wants.push(value.to_string()),
+                "have" => request.haves.push(value.to_string()),
+                "object" => request.objects.push(value.to_string()),
+                "shallow" => request.shallow.push(value.to_string()),
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(request)
+    }
+}
+
+/// fetch 응답
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchResponse {
+    /// 받는 쪽이 새로 기록할 shallow 경계 커밋
+    pub shallow: Vec<String>,
+    /// 더 이상 경계가 아닌 커밋
+    pub unshallow: Vec<String>,
+    /// 객체 팩
+    pub pack: Vec<u8>,
+}
+
+impl FetchResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = String::new();
+        for hash in &self.shallow {
+            out.push_str(&format!("shallow {}\n", hash));
+        }
+        for hash in &self.unshallow {
+            out.push_str(&format!("unshallow {}\n", hash));
+        }
+        out.push('\n');
+        let mut data = out.into_bytes();
+        data.extend_from_slice(&self.pack);
+        data
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput("Invalid fetch response".to_string());
+        let mut response = Self::default();
+        let mut rest = data;
+        loop {
+            let end = rest.iter().position(|&b| b == b'\n').ok_or_else(invalid)?;
+            let line = std::str::from_utf8(&rest[..end]).map_err(|_| invalid())?;
+            rest = &rest[end + 1..];
+            match line.split_once(' ') {
+                None if line.is_empty() => break,
+                Some(("shallow", hash)) if is_valid_hash(hash) => response.shallow.push(hash.to_string()),
+                Some(("unshallow", hash)) if is_valid_hash(hash) => response.unshallow.push(hash.to_string()),
+                _ => return Err(invalid()),
+            }
+        }
+        response.pack = rest.to_vec();
+        Ok(response)
+    }
+}
+
+// =============================================================================
+// push 요청
+// =============================================================================
+
+/// push 로 갱신할 참조 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushUpdate {
+    /// 전체 참조 이름 (refs/heads/main)
+    pub name: String,
+    /// 보내는 쪽이 알고 있는 원격의 현재 값 (None = 아직 없음)
+    pub old: Option<String>,
+    /// 새 값 (커밋 또는 주석 태그)
+    pub new: String,
+}
+
+/// 원격에 보내는 push 요청
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PushRequest {
+    pub updates: Vec<PushUpdate>,
+    /// 객체 팩
+    pub pack: Vec<u8>,
+}
+
+impl PushRequest {
+    /// 갱신할 참조와 팩 준비
+    ///
+    /// # Arguments
+    /// * `haves` - 원격이 가진 커밋 (보통 원격 참조 값, 이 저장소에 없는 해시는 무시)
+    pub fn new(store: &ObjectStore, updates: Vec<PushUpdate>, haves: &[String]) -> Result<Self, AppError> {
+        let request = FetchRequest {
+            wants: updates.iter().map(|update| update.new.clone()).collect(),
+            haves: haves.iter().filter(|hash| store.contains(hash)).cloned().collect(),
+            ..Default::default()
+        };
+        let pack = upload_pack(store, &request)?.pack;
+        Ok(Self { updates, pack })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = String::new();
+        for update in &self.updates {
+            let old = update.old.as_deref().unwrap_or("-");
+            out.push_str(&format!("update {} {} {}\n", old, update.new, update.name));
+        }
+        out.push('\n');
+        let mut data = out.into_bytes();
+        data.extend_from_slice(&self.pack);
+        data
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, AppError> {
+        let invalid = || AppError::InvalidInput("Invalid push request".to_string());
+        let mut request = Self::default();
+        let mut rest = data;
+        loop {
+            let end = rest.iter().position(|&b| b == b'\n').ok_or_else(invalid)?;
+            let line = std::str::from_utf8(&rest[..end]).map_err(|_| invalid())?;
+            rest = &rest[end + 1..];
+            if line.is_empty() {
+                break;
+            }
+            let mut parts = line.splitn(4, ' ');
+            let (Some("update"), Some(old), Some(new), Some(name)) = (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                return Err(invalid());
+            };
+            let old = match old {
+                "-" => None,
+                hash if is_valid_hash(hash) => Some(hash.to_string()),
+                _ => return Err(invalid()),
+            };
+            if !is_valid_hash(new) {
+                return Err(invalid());
+            }
+            request.updates.push(PushUpdate { name: name.to_string(), old, new: new.to_string() });
+        }
+        request.pack = rest.to_vec();
+        Ok(request)
+    }
+}
+
+// =============================================================================
+// 보내는 쪽
+// =============================================================================
+
+/// 원격에 광고할 참조 목록 (HEAD + 브랜치/태그/노트)
+pub fn advertised_refs(repo: &Repository) -> Result<Vec<(String, String)>, AppError> {
+    let mut refs = Vec::new();
+    if let Some(head) = repo.head()? {
+        refs.push(("HEAD".to_string(), head));
+    }
+    for prefix in ADVERTISED_PREFIXES {
+        refs.extend(repo.list_refs(prefix)?);
+    }
+    Ok(refs)
+}
+
+/// fetch 요청에 맞는 응답 생성
+///
+/// # Errors
+/// * `AppError::NotFound` - 요청한 커밋이나 객체가 없음
+pub fn upload_pack(store: &ObjectStore, request: &FetchRequest) -> Result<FetchResponse, AppError> {
+    for hash in request.wants.iter().chain(&request.objects) {
+        if !store.contains(hash) {
+            return Err(AppError::NotFound(format!("Object {}", hash)));
+        }
+    }
+    let mut response = FetchResponse::default();
+    let mut objects = request.objects.clone();
+    if !request.wants.is_empty() {
+        // 주석 태그는 태그 객체도 보내고 가리키는 커밋부터 탐색
+        let mut tips = Vec::new();
+        for hash in &request.wants {
+            let mut current = hash.clone();
+            while let Object::Tag(tag) = store.read(&current)? {
+                if !objects.contains(&current) {
+                    objects.push(current);
+                }
+                current = tag.object_hash;
+            }
+            tips.push(current);
+        }
+        let selection = select_commits(store, &tips, &request.haves, &request.shallow, request.limit)?;
+        for hash in commit_objects(store, &selection.commits, &selection.known)? {
+            if let Some(filter) = &request.filter {
+                if filter.omits(&store.read_raw(&hash)?) {
+                    continue;
+                }
+            }
+            objects.push(hash);
+        }
+        response.shallow = selection.shallow;
+        response.unshallow = selection.unshallow;
+    }
+    response.pack = build_pack(store, &objects)?;
+    Ok(response)
+}
+
+/// fetch 응답을 저장소에 반영 (객체 저장 + .cts/shallow 갱신)
+///
+/// 경계 커밋이 바뀌었으면 탐색에 반영되도록 저장소를 다시 열어서 사용할 것
+pub fn receive_pack(repo: &Repository, response: &FetchResponse) -> Result<Vec<String>, AppError> {
+    let objects = unpack(repo.objects(), &response.pack)?;
+    if !response.shallow.is_empty() || !response.unshallow.is_empty() {
+        shallow::update(repo.common_dir(), &response.shallow, &response.unshallow)?;
+    }
+    Ok(objects)
+}
+
+/// push 요청을 저장소에 반영
+///
+/// 객체 저장 → 새 값에서 현재 참조들까지 빠진 객체가 없는지 확인 → 참조를 한 트랜잭션으로 갱신
+///
+/// # Errors
+/// * `InvalidInput` - 팩에 객체가 빠져 있음
+/// * `Conflict` - 참조의 현재 값이 요청의 이전 값과 다름 (그 사이 다른 push)
+pub fn apply_push(repo: &Repository, request: &PushRequest, identity: &Identity) -> Result<(), AppError> {
+    if request.updates.is_empty() {
+        return Err(AppError::InvalidInput("Push request updates nothing".to_string()));
+    }
+    let store = repo.objects();
+    unpack(store, &request.pack)?;
+
+    let existing: Vec<String> = advertised_refs(repo)?.into_iter().map(|(_, hash)| hash).collect();
+    let mut tips = Vec::new();
+    for update in &request.updates {
+        tips.push(
+            store
+                .peel_to_commit(&update.new)
+                .map_err(|_| AppError::InvalidInput(format!("Push for {} is missing {}", update.name, update.new)))?,
+        );
+    }
+    select_commits(store, &tips, &existing, &[], None)
+        .and_then(|selection| commit_objects(store, &selection.commits, &selection.known))
+        .map_err(|err| AppError::InvalidInput(format!("Push is missing objects: {}", err)))?;
+
+    let mut transaction = repo.refs().transaction();
+    for update in &request.updates {
+        let expected = match &update.old {
+            Some(old) => Expected::Value(old.clone()),
+            None => Expected::Missing,
+        };
+        transaction.update(&update.name, &update.new, expected);
+    }
+    transaction.commit(identity, "push")
+}
+
+// =============================================================================
+// 받는 쪽
+// =============================================================================
+
+/// 원격 저장소 연결
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// 참조 목록 (이름, 해시)
+    fn list_refs(&self) -> Result<Vec<(String, String)>, AppError>;
+
+    /// 요청한 객체를 팩으로 받기
+    fn fetch(&self, request: &FetchRequest) -> Result<FetchResponse, AppError>;
+
+    /// 객체를 보내고 참조 갱신
+    fn push(&self, request: &PushRequest) -> Result<(), AppError>;
+}
+
+/// 원격 주소에 맞는 Transport
+///
+/// # Errors
+/// * `AppError::InvalidInput` - 지원하지 않는 주소 (https 등)
+pub fn connect(url: &str) -> Result<Arc<dyn Transport>, AppError> {
+    if url.starts_with("http://") {
+        return Ok(Arc::new(HttpTransport::new(url)?));
+    }
+    if url.contains("://") && !url.starts_with("file://") {
+        return Err(AppError::InvalidInput(format!("Unsupported remote URL: {}", url)));
+    }
+    let path = url.strip_prefix("file://").unwrap_or(url);
+    Ok(Arc::new(LocalTransport::new(path)))
+}
+
+// -----------------------------------------------------------------------------
+// 로컬 디렉토리
+// -----------------------------------------------------------------------------
+
+/// 같은 파일 시스템의 저장소 (작업 디렉토리 또는 bare 저장소 경로)
+#[derive(Debug, Clone)]
+pub struct LocalTransport {
+    path: PathBuf,
+}
+
+impl LocalTransport {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn open(&self) -> Result<Repository, AppError> {
+        let link = self.path.join(CTS_DIR_NAME);
+        if link.is_file() {
+            return Repository::open(read_link(&link)?);
+        }
+        if link.is_dir() {
+            return Repository::open(link);
+        }
+        Repository::open(&self.path)
+    }
+}
+
+impl Transport for LocalTransport {
+    fn list_refs(&self) -> Result<Vec<(String, String)>, AppError> {
+        advertised_refs(&self.open()?)
+    }
+
+    fn fetch(&self, request: &FetchRequest) -> Result<FetchResponse, AppError> {
+        upload_pack(self.open()?.objects(), request)
+    }
+
+    fn push(&self, request: &PushRequest) -> Result<(), AppError> {
+        apply_push(&self.open()?, request, &Identity::from_env())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// HTTP
+// -----------------------------------------------------------------------------
+
+/// CTS 서버 (HTTP/1.1, 요청마다 연결)
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    /// "{호스트}:{포트}"
+    address: String,
+    /// 호스트 헤더
+    host: String,
+    /// 저장소 경로 (끝의 '/' 없음)
+    path: String,
+}
+
+impl HttpTransport {
+    /// "http://{호스트}[:{포트}]{경로}" 파싱 (포트 기본값 80)
+    pub fn new(url: &str) -> Result<Self, AppError> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| AppError::InvalidInput(format!("Not an HTTP URL: {}", url)))?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, ""),
+        };
+        if host.is_empty() {
+            return Err(AppError::InvalidInput(format!("Missing host in URL: {}", url)));
+        }
+        let address = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+        Ok(Self {
+            address,
+            host: host.to_string(),
+            path: path.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// 요청을 보내고 응답 본문 받기 (2xx 가 아니면 에러)
+    fn request(&self, method: &str, endpoint: &str, body: &[u8]) -> Result<Vec<u8>, AppError> {
+        let unreachable = |err: std::io::Error| {
+            AppError::Storage(format!("Cannot reach {}: {}", self.address, err))
+        };
+        let mut stream = TcpStream::connect(&self.address).map_err(unreachable)?;
+        let authorization = match std::env::var(TOKEN_ENV) {
+            Ok(token) if !token.is_empty() => format!("Authorization: Bearer {}\r\n", token),
+            _ => String::new(),
+        };
+        let head = format!(
+            "{} {}{} HTTP/1.1\r\nHost: {}\r\n{}Content-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            method,
+            self.path,
+            endpoint,
+            self.host,
+            authorization,
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).map_err(unreachable)?;
+        stream.write_all(body).map_err(unreachable)?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).map_err(unreachable)?;
+
+        let (status, body) = parse_response(&response)?;
+        match status {
+            200..=299 => Ok(body),
+            401 => Err(AppError::Unauthorized),
+            404 => Err(AppError::NotFound(String::from_utf8_lossy(&body).into_owned())),
+            409 => Err(AppError::Conflict(String::from_utf8_lossy(&body).into_owned())),
+            _ => Err(AppError::Storage(format!(
+                "Server returned {}: {}",
+                status,
+                String::from_utf8_lossy(&body)
+            ))),
+        }
+    }
+}
+
+impl Transport for HttpTransport {
+    fn list_refs(&self) -> Result<Vec<(String, String)>, AppError> {
+        let body = self.request("GET", "/refs", &[])?;
+        parse_ref_lines(&String::from_utf8_lossy(&body))
+    }
+
+    fn fetch(&self, request: &FetchRequest) -> Result<FetchResponse, AppError> {
+        FetchResponse::decode(&self.request("POST", "/fetch", request.encode().as_bytes())?)
+    }
+
+    fn push(&self, request: &PushRequest) -> Result<(), AppError> {
+        self.request("POST", "/push", &request.encode())?;
+        Ok(())
+    }
+}
+
+/// 참조 목록 응답 생성 ("{hash} {name}\n")
+pub fn format_ref_lines(refs: &[(String, String)]) -> String {
+    refs.iter().map(|(name, hash)| format!("{} {}\n", hash, name)).collect()
+}
+
+/// 참조 목록 응답 파싱
+pub fn parse_ref_lines(text: &str) -> Result<Vec<(String, String)>, AppError> {
+    text.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once(' ') {
+            Some((hash, name)) if is_valid_hash(hash) => Ok((name.to_string(), hash.to_string())),
+            _ => Err(AppError::InvalidInput(format!("Invalid ref line: {}", line))),
+        })
+        .collect()
+}
+
+/// HTTP 응답을 (상태 코드, 본문) 으로 (Content-Length / chunked / 연결 종료까지)
+fn parse_response(response: &[u8]) -> Result<(u16, Vec<u8>), AppError> {
+    let invalid = || AppError::Storage("Invalid HTTP response".to_string());
+    let split = response.windows(4).position(|w| w == b"\r\n\r\n").ok_or_else(invalid)?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let rest = &response[split + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status: u16 = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(invalid)?;
+    let mut length = None;
+    let mut chunked = false;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else { continue };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            length = value.parse::<usize>().ok();
+        } else if name.eq_ignore_ascii_case("transfer-encoding") && value.eq_ignore_ascii_case("chunked") {
+            chunked = true;
+        }
+    }
+
+    if chunked {
+        return Ok((status, decode_chunked(rest).ok_or_else(invalid)?));
+    }
+    match length {
+        Some(length) => Ok((status, rest.get(..length).ok_or_else(invalid)?.to_vec())),
+        None => Ok((status, rest.to_vec())),
+    }
+}
+
+/// chunked 본문 디코딩
+fn decode_chunked(mut data: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = data.windows(2).position(|w| w == b"\r\n")?;
+        let size_text = std::str::from_utf8(&data[..line_end]).ok()?;
+        let size = usize::from_str_radix(size_text.split(';').next()?.trim(), 16).ok()?;
+        data = &data[line_end + 2..];
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(data.get(..size)?);
+        data = data.get(size + 2..)?;
+    }
+}
+
+/// 로컬 경로 원격 주소를 절대 경로로 (다른 디렉토리에서도 같은 저장소를 가리키도록)
+pub fn normalize_url(url: &str) -> String {
+    if url.contains("://") {
+        return url.to_string();
+    }
+    std::fs::canonicalize(Path::new(url))
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| url.to_string())
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Blob, Commit, Object, Tree, TreeEntry};
+
+    #[test]
+    fn test_filter_and_request_encoding() {
+        assert_eq!("blob:none".parse::<ObjectFilter>().unwrap(), ObjectFilter::BlobNone);
+        assert_eq!("blob:limit=2k".parse::<ObjectFilter>().unwrap(), ObjectFilter::BlobLimit(2048));
+        assert!("tree:0".parse::<ObjectFilter>().is_err());
+
+        let big = Object::from(Blob::new(vec![b'x'; 4096])).encode();
+        let small = Object::from(Blob::new(b"x".to_vec())).encode();
+        assert!(ObjectFilter::BlobLimit(2048).omits(&big));
+        assert!(!ObjectFilter::BlobLimit(2048).omits(&small));
+        assert!(!ObjectFilter::BlobNone.omits(&Object::from(Tree::new()).encode()));
+
+        let request = FetchRequest {
+            wants: vec!["a".repeat(64)],
+            haves: vec!["b".repeat(64)],
+            objects: vec!["c".repeat(64)],
+            filter: Some(ObjectFilter::BlobLimit(10)),
+            shallow: vec!["d".repeat(64)],
+            limit: Some(DepthLimit::Unshallow),
+        };
+        assert_eq!(FetchRequest::decode(&request.encode()).unwrap(), request);
+        let response = FetchResponse {
+            shallow: vec!["e".repeat(64)],
+            unshallow: vec![],
+            pack: b"CPCK\n\nbinary".to_vec(),
+        };
+        assert_eq!(FetchResponse::decode(&response.encode()).unwrap(), response);
+        assert!(FetchRequest::decode("want xyz\n").is_err());
+    }
+
+    #[test]
+    fn test_chunked_response() {
+        let response = b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n";
+        assert_eq!(parse_response(response).unwrap(), (200, b"abcde".to_vec()));
+        let response = b"HTTP/1.1 404 Not Found\r\ncontent-length: 4\r\n\r\nnope";
+        assert_eq!(parse_response(response).unwrap(), (404, b"nope".to_vec()));
+    }
+
+    #[test]
+    fn test_partial_clone_fetches_missing_blobs() {
+        let origin_dir = tempfile::tempdir().unwrap();
+        let origin = Repository::init(origin_dir.path()).unwrap();
+        let store = origin.objects();
+        let blob = store.write(&Object::from(Blob::new(b"large content\n".to_vec()))).unwrap();
+        let tree = store
+            .write(&Object::from(Tree::with_entries(vec![TreeEntry::file("big.bin".to_string(), blob.clone())])))
+            .unwrap();
+        let actor = Actor::parse("Tester <t@example.com> 0 +0000").unwrap();
+        let commit = store
+            .write(&Object::from(Commit::new(tree.clone(), None, "init".to_string(), actor.clone(), actor)))
+            .unwrap();
+
+        let remote = connect(&origin_dir.path().display().to_string()).unwrap();
+        let head = remote.list_refs().unwrap();
+        assert!(head.is_empty());
+
+        let clone_dir = tempfile::tempdir().unwrap();
+        let clone = Repository::init(clone_dir.path()).unwrap();
+        let request = FetchRequest {
+            wants: vec![commit.clone()],
+            filter: Some(ObjectFilter::BlobNone),
+            ..Default::default()
+        };
+        receive_pack(&clone, &remote.fetch(&request).unwrap()).unwrap();
+        assert!(clone.objects().contains(&tree));
+        assert!(!clone.objects().contains(&blob));
+        assert!(clone.objects().read_blob(&blob).is_err());
+
+        // promisor 가 있으면 처음 읽을 때 받아옴
+        let lazy = clone.objects().clone().with_promisor(remote);
+        assert_eq!(lazy.read_blob(&blob).unwrap().content(), b"large content\n");
+        assert!(clone.objects().contains(&blob));
+        assert_eq!(lazy.prefetch(&[blob]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_push_updates_refs_with_compare_and_swap() {
+        let local_dir = tempfile::tempdir().unwrap();
+        let local = Repository::init(local_dir.path()).unwrap();
+        let store = local.objects();
+        let actor = Actor::parse("Tester <t@example.com> 0 +0000").unwrap();
+        let mut commits: Vec<String> = Vec::new();
+        for i in 0..2 {
+            let blob = store.write(&Object::from(Blob::new(format!("v{}\n", i).into_bytes()))).unwrap();
+            let tree = store
+                .write(&Object::from(Tree::with_entries(vec![TreeEntry::file("a.txt".to_string(), blob)])))
+                .unwrap();
+            let commit = Commit::new(tree, commits.last().cloned(), format!("c{}", i), actor.clone(), actor.clone());
+            commits.push(store.write(&Object::from(commit)).unwrap());
+        }
+
+        let remote_dir = tempfile::tempdir().unwrap();
+        let remote_repo = Repository::init(remote_dir.path()).unwrap();
+        let remote = connect(&remote_dir.path().display().to_string()).unwrap();
+        let update = |old: Option<&String>, new: &String| PushUpdate {
+            name: "refs/heads/main".to_string(),
+            old: old.cloned(),
+            new: new.clone(),
+        };
+
+        let first = PushRequest::new(store, vec![update(None, &commits[0])], &[]).unwrap();
+        assert_eq!(PushRequest::decode(&first.encode()).unwrap(), first);
+        remote.push(&first).unwrap();
+        assert_eq!(remote_repo.read_ref("refs/heads/main").unwrap(), Some(commits[0].clone()));
+
+        // 원격이 가진 커밋의 객체는 보내지 않아도 됨
+        let second = PushRequest::new(store, vec![update(Some(&commits[0]), &commits[1])], &[commits[0].clone()]).unwrap();
+        assert!(second.pack.len() < first.pack.len() * 2);
+        remote.push(&second).unwrap();
+        assert_eq!(remote_repo.read_ref("refs/heads/main").unwrap(), Some(commits[1].clone()));
+
+        // 오래된 이전 값 → 거부
+        let stale = PushRequest::new(store, vec![update(Some(&commits[0]), &commits[0])], &[]).unwrap();
+        assert!(matches!(remote.push(&stale), Err(AppError::Conflict(_))));
+
+        // 빠진 객체 → 거부, 참조는 그대로
+        let tree = store.write(&Object::from(Tree::new())).unwrap();
+        let orphan = store
+            .write(&Object::from(Commit::new(tree, Some(commits[1].clone()), "c2".to_string(), actor.clone(), actor)))
+            .unwrap();
+        let incomplete = PushRequest {
+            updates: vec![update(Some(&commits[1]), &orphan)],
+            pack: build_pack(store, std::slice::from_ref(&orphan)).unwrap(),
+        };
+        assert!(matches!(remote.push(&incomplete), Err(AppError::InvalidInput(_))));
+        assert_eq!(remote_repo.read_ref("refs/heads/main").unwrap(), Some(commits[1].clone()));
+    }
+}
@@ -0,0 +1,436 @@
+// =============================================================================
+// 연결된 작업 디렉토리 (worktree.rs)
+// =============================================================================
+//
+// 하나의 저장소에 작업 디렉토리를 여러 개 연결
+// - 객체 저장소와 브랜치/태그는 공유 → 다시 clone 할 필요 없음
+// - HEAD, index, 진행 중인 작업(rebase, bisect 등)은 작업 디렉토리마다 따로
+// - 같은 브랜치는 두 작업 디렉토리에서 동시에 체크아웃할 수 없음
+//   (한쪽에서 커밋하면 다른 쪽 작업 디렉토리가 브랜치와 어긋나므로)
+//
+// 구조:
+//   {기본 작업 디렉토리}/.cts/worktrees/{이름}/
+//   ├── HEAD          # 이 작업 디렉토리의 HEAD
+//   ├── index
+//   ├── commondir     # 공유 .cts 디렉토리 절대 경로
+//   └── workdir       # 작업 디렉토리 절대 경로
+//   {연결된 작업 디렉토리}/.cts   # 파일: "ctsdir: {관리 디렉토리 절대 경로}"
+//
+// 작업 디렉토리를 그냥 지웠다면 prune 으로 남은 관리 디렉토리 정리
+//
+// 파일 위치: crates/core/src/worktree.rs
+// =============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use shared::error::AppError;
+
+use crate::checkout::checkout_tree;
+use crate::index::{worktree_files, Index};
+use crate::refs::{Expected, Identity, RefStore, RefValue, HEADS_PREFIX};
+use crate::repo::{Repository, CTS_DIR_NAME};
+
+/// 관리 디렉토리들이 있는 곳 (공유 .cts 기준)
+pub const WORKTREES_DIR: &str = "worktrees";
+
+/// 공유 .cts 디렉토리 경로를 담은 파일 (관리 디렉토리 기준)
+pub const COMMONDIR_FILE: &str = "commondir";
+
+/// 작업 디렉토리 경로를 담은 파일 (관리 디렉토리 기준)
+pub const WORKDIR_FILE: &str = "workdir";
+
+/// 연결된 작업 디렉토리의 .cts 파일 접두사
+const LINK_PREFIX: &str = "ctsdir: ";
+
+// =============================================================================
+// 타입
+// =============================================================================
+
+/// 작업 디렉토리 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worktree {
+    /// 관리 디렉토리 이름 (기본 작업 디렉토리면 None)
+    pub name: Option<String>,
+    /// 작업 디렉토리 경로 (bare 저장소의 기본 항목이면 None)
+    pub path: Option<PathBuf>,
+    /// HEAD 가 가리키는 커밋
+    pub head: Option<String>,
+    /// 체크아웃한 브랜치 (refs/heads/..., detached 면 None)
+    pub branch: Option<String>,
+    /// 작업 디렉토리가 사라져서 prune 대상인지
+    pub prunable: bool,
+}
+
+impl Worktree {
+    pub fn is_main(&self) -> bool {
+        self.name.is_none()
+    }
+}
+
+/// 새 작업 디렉토리에 체크아웃할 대상
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorktreeCheckout {
+    /// 기존 브랜치 (전체 참조 이름)
+    Branch(String),
+    /// start 커밋에서 새 브랜치 생성 (짧은 이름)
+    NewBranch { name: String, start: String },
+    /// 커밋을 HEAD 분리 상태로
+    Detached(String),
+}
+
+// =============================================================================
+// 작업
+// =============================================================================
+
+/// 모든 작업 디렉토리 (기본 작업 디렉토리 먼저, 나머지는 이름순)
+pub fn list(repo: &Repository) -> Result<Vec<Worktree>, AppError> {
+    let common = repo.common_dir();
+    let main_path = match common.file_name() {
+        Some(name) if name == CTS_DIR_NAME => common.parent().map(Path::to_path_buf),
+        _ => None,
+    };
+    let mut worktrees = vec![describe(&RefStore::new(common), None, main_path, false)?];
+
+    let dir = common.join(WORKTREES_DIR);
+    if !dir.is_dir() {
+        return Ok(worktrees);
+    }
+    let mut names: Vec<String> = fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    for name in names {
+        let admin = dir.join(&name);
+        let path = read_link(&admin.join(WORKDIR_FILE)).ok();
+        // 작업 디렉토리가 없거나 다른 저장소를 가리키면 정리 대상
+        let prunable = match &path {
+            Some(path) => match read_link(&path.join(CTS_DIR_NAME)) {
+                Ok(link) => fs::canonicalize(link).ok() != fs::canonicalize(&admin).ok(),
+                Err(_) => true,
+            },
+            None => true,
+        };
+        let refs = RefStore::with_worktree(common, &admin);
+        worktrees.push(describe(&refs, Some(name), path, prunable)?);
+    }
+    Ok(worktrees)
+}
+
+/// 브랜치를 체크아웃하고 있는 작업 디렉토리
+pub fn checked_out_in(repo: &Repository, branch: &str) -> Result<Option<Worktree>, AppError> {
+    Ok(list(repo)?
+        .into_iter()
+        .find(|worktree| !worktree.prunable && worktree.branch.as_deref() == Some(branch)))
+}
+
+/// 작업 디렉토리 추가
+///
+/// # Arguments
+/// * `path` - 새 작업 디렉토리 (없거나 빈 디렉토리)
+/// * `force` - 다른 작업 디렉토리에서 체크아웃 중인 브랜치도 허용
+///
+/// # Errors
+/// * `AppError::AlreadyExists` - 경로에 파일이 있음, 새 브랜치가 이미 있음
+/// * `AppError::Conflict` - 브랜치가 다른 작업 디렉토리에서 체크아웃 중
+pub fn add(
+    repo: &Repository,
+    path: &Path,
+    checkout: WorktreeCheckout,
+    force: bool,
+    identity: &Identity,
+) -> Result<Worktree, AppError> {
+    if path.exists() && (!path.is_dir() || fs::read_dir(path)?.next().is_some()) {
+        return Err(AppError::AlreadyExists(format!("'{}' already exists", path.display())));
+    }
+
+    let commit = match &checkout {
+        WorktreeCheckout::Branch(branch) => {
+            if !force {
+                if let Some(other) = checked_out_in(repo, branch)? {
+                    return Err(AppError::Conflict(format!(
+                        "'{}' is already checked out at '{}'",
+                        branch.trim_start_matches(HEADS_PREFIX),
+                        other.path.as_deref().unwrap_or(repo.common_dir()).display()
+                    )));
+                }
+            }
+            repo.read_ref(branch)?
+                .ok_or_else(|| AppError::NotFound(format!("Branch {} has no commits", branch)))?
+        }
+        WorktreeCheckout::NewBranch { name, start } => {
+            let full = format!("{}{}", HEADS_PREFIX, name);
+            if repo.refs().read(&full)?.is_some() {
+                return Err(AppError::AlreadyExists(format!("Branch '{}'", name)));
+            }
+            start.clone()
+        }
+        WorktreeCheckout::Detached(commit) => commit.clone(),
+    };
+    let tree = repo.objects().read_commit(&commit)?.tree_hash;
+
+    // 관리 디렉토리 (이름이 겹치면 번호를 붙임)
+    let base = admin_name(path);
+    let worktrees_dir = repo.common_dir().join(WORKTREES_DIR);
+    let mut name = base.clone();
+    let mut n = 1;
+    while worktrees_dir.join(&name).exists() {
+        name = format!("{}{}", base, n);
+        n += 1;
+    }
+
+    if let WorktreeCheckout::NewBranch { name: branch, start } = &checkout {
+        repo.refs().update(
+            &format!("{}{}", HEADS_PREFIX, branch),
+            start,
+            Expected::Missing,
+            identity,
+            &format!("branch: Created from {}", start),
+        )?;
+    }
+
+    fs::create_dir_all(path)?;
+    let path = fs::canonicalize(path)?;
+    let common = fs::canonicalize(repo.common_dir())?;
+    let admin = common.join(WORKTREES_DIR).join(&name);
+    fs::create_dir_all(&admin)?;
+    fs::write(admin.join(COMMONDIR_FILE), format!("{}\n", common.display()))?;
+    fs::write(admin.join(WORKDIR_FILE), format!("{}\n", path.display()))?;
+
+    let refs = RefStore::with_worktree(&common, &admin);
+    let reason = format!("worktree: add {}", path.display());
+    match &checkout {
+        WorktreeCheckout::Branch(branch) => refs.set_symbolic("HEAD", branch, identity, &reason)?,
+        WorktreeCheckout::NewBranch { name: branch, .. } => {
+            refs.set_symbolic("HEAD", &format!("{}{}", HEADS_PREFIX, branch), identity, &reason)?
+        }
+        WorktreeCheckout::Detached(commit) => refs.set_detached("HEAD", commit, identity, &reason)?,
+    }
+    fs::write(path.join(CTS_DIR_NAME), format!("{}{}\n", LINK_PREFIX, admin.display()))?;
+
+    let linked = Repository::open(&admin)?;
+    checkout_tree(linked.objects(), &tree, &path)?;
+    linked.write_index(&Index::from_tree(linked.objects(), &tree)?)?;
+    describe(&refs, Some(name), Some(path), false)
+}
+
+/// 연결된 작업 디렉토리 삭제 (작업 디렉토리와 관리 디렉토리 모두)
+///
+/// # Arguments
+/// * `target` - 이름 또는 작업 디렉토리 경로
+/// * `force` - 커밋하지 않은 변경이나 추적하지 않는 파일이 있어도 삭제
+pub fn remove(repo: &Repository, target: &str, force: bool) -> Result<Worktree, AppError> {
+    let worktree = find(repo, target)?;
+    let name = worktree.name.clone().unwrap_or_default();
+    let admin = repo.common_dir().join(WORKTREES_DIR).join(&name);
+    if fs::canonicalize(&admin).ok() == fs::canonicalize(repo.cts_dir()).ok() {
+        return Err(AppError::InvalidInput(
+            "Cannot remove the worktree you are in; run the command from another worktree".to_string(),
+        ));
+    }
+
+    if let Some(path) = worktree.path.as_ref().filter(|_| !worktree.prunable) {
+        if !force {
+            let linked = Repository::open(&admin)?;
+            let index = linked.read_index()?;
+            let head_tree = match &worktree.head {
+                Some(head) => Some(linked.objects().read_commit(head)?.tree_hash),
+                None => None,
+            };
+            let untracked = worktree_files(path)?.into_iter().any(|file| !index.contains(&file));
+            if untracked
+                || Some(index.write_tree(linked.objects())?) != head_tree
+                || !index.worktree_changes(path)?.is_empty()
+            {
+                return Err(AppError::Conflict(format!(
+                    "'{}' contains modified or untracked files, use --force to delete it",
+                    path.display()
+                )));
+            }
+        }
+        fs::remove_dir_all(path)?;
+    }
+    fs::remove_dir_all(&admin)?;
+    Ok(worktree)
+}
+
+/// 작업 디렉토리가 사라진 관리 디렉토리 정리
+///
+/// # Returns
+/// 정리한 작업 디렉토리
+pub fn prune(repo: &Repository) -> Result<Vec<Worktree>, AppError> {
+    let mut pruned = Vec::new();
+    for worktree in list(repo)? {
+        if let (true, Some(name)) = (worktree.prunable, &worktree.name) {
+            fs::remove_dir_all(repo.common_dir().join(WORKTREES_DIR).join(name))?;
+            pruned.push(worktree);
+        }
+    }
+    Ok(pruned)
+}
+
+// =============================================================================
+// 도우미
+// =============================================================================
+
+/// 경로를 담은 파일 읽기 (.cts 링크 파일의 "ctsdir: " 접두사 허용)
+pub(crate) fn read_link(file: &Path) -> Result<PathBuf, AppError> {
+    let content = fs::read_to_string(file)?;
+    let content = content.trim();
+    Ok(PathBuf::from(content.strip_prefix(LINK_PREFIX).unwrap_or(content)))
+}
+
+/// 이름 또는 경로로 연결된 작업 디렉토리 찾기
+fn find(repo: &Repository, target: &str) -> Result<Worktree, AppError> {
+    let target_path = fs::canonicalize(target).ok();
+    list(repo)?
+        .into_iter()
+        .find(|worktree| {
+            worktree.name.as_deref() == Some(target)
+                || (target_path.is_some() && worktree.path.as_ref().and_then(|p| fs::canonicalize(p).ok()) == target_path)
+        })
+        .filter(|worktree| !worktree.is_main())
+        .ok_or_else(|| AppError::NotFound(format!("'{}' is not a linked worktree", target)))
+}
+
+fn describe(
+    refs: &RefStore,
+    name: Option<String>,
+    path: Option<PathBuf>,
+    prunable: bool,
+) -> Result<Worktree, AppError> {
+    let branch = match refs.read("HEAD")? {
+        Some(RefValue::Symbolic(target)) => Some(target),
+        _ => None,
+    };
+    Ok(Worktree {
+        name,
+        path,
+        head: refs.resolve("HEAD")?,
+        branch,
+        prunable,
+    })
+}
+
+/// 작업 디렉토리 경로의 마지막 요소로 관리 디렉토리 이름 만들기
+fn admin_name(path: &Path) -> String {
+    let base: String = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '-' })
+        .collect();
+    match base.trim_start_matches('.') {
+        "" => "worktree".to_string(),
+        base => base.to_string(),
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Actor, Blob, Commit, Object, TreeEntry};
+    use std::collections::BTreeMap;
+
+    fn identity() -> Identity {
+        Identity::new("Tester", "tester@example.com")
+    }
+
+    fn setup() -> (tempfile::TempDir, Repository, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path().join("main")).unwrap();
+        let store = repo.objects();
+        let blob = store.write(&Object::from(Blob::new(b"hello\n".to_vec()))).unwrap();
+        let files = BTreeMap::from([("a.txt".to_string(), TreeEntry::file("a.txt".into(), blob))]);
+        let tree = store.write_flat_tree(&files).unwrap();
+        let actor = Actor::parse("Tester <tester@example.com> 1705314600 +0000").unwrap();
+        let commit = store
+            .write(&Object::from(Commit::new(tree.clone(), None, "init\n".into(), actor.clone(), actor)))
+            .unwrap();
+        checkout_tree(store, &tree, repo.work_dir().unwrap()).unwrap();
+        repo.refs().update("refs/heads/main", &commit, Expected::Any, &identity(), "test").unwrap();
+        (dir, repo, commit)
+    }
+
+    #[test]
+    fn test_add_shares_refs_but_not_head() {
+        let (dir, repo, commit) = setup();
+        let path = dir.path().join("feature");
+        let checkout = WorktreeCheckout::NewBranch {
+            name: "feature".into(),
+            start: commit.clone(),
+        };
+        let added = add(&repo, &path, checkout, false, &identity()).unwrap();
+        assert_eq!(added.name.as_deref(), Some("feature"));
+        assert_eq!(fs::read_to_string(path.join("a.txt")).unwrap(), "hello\n");
+
+        // 하위 디렉토리에서도 연결된 저장소를 찾음
+        fs::create_dir(path.join("sub")).unwrap();
+        let linked = Repository::discover(path.join("sub")).unwrap();
+        assert!(linked.is_linked_worktree());
+        assert_eq!(linked.work_dir(), Some(fs::canonicalize(&path).unwrap().as_path()));
+        assert_eq!(linked.full_ref_name("HEAD").unwrap().as_deref(), Some("refs/heads/feature"));
+        assert_eq!(repo.full_ref_name("HEAD").unwrap().as_deref(), Some("refs/heads/main"));
+        assert!(linked.index_path().is_file());
+        assert!(!repo.index_path().exists());
+
+        // 연결된 쪽에서 HEAD 를 통해 옮긴 브랜치가 기본 저장소에도 보임
+        let actor = Actor::parse("Tester <tester@example.com> 1705314700 +0000").unwrap();
+        let tree = linked.objects().read_commit(&commit).unwrap().tree_hash;
+        let next = linked
+            .objects()
+            .write(&Object::from(Commit::new(tree, Some(commit.clone()), "next\n".into(), actor.clone(), actor)))
+            .unwrap();
+        linked.refs().update("HEAD", &next, Expected::Value(commit.clone()), &identity(), "commit").unwrap();
+        assert_eq!(repo.read_ref("refs/heads/feature").unwrap(), Some(next.clone()));
+        assert_eq!(repo.head().unwrap(), Some(commit.clone()));
+
+        let worktrees = list(&repo).unwrap();
+        assert_eq!(worktrees.len(), 2);
+        assert!(worktrees[0].is_main());
+        assert_eq!(worktrees[1].branch.as_deref(), Some("refs/heads/feature"));
+        assert_eq!(worktrees[1].head, Some(next));
+    }
+
+    #[test]
+    fn test_same_branch_is_refused() {
+        let (dir, repo, commit) = setup();
+        let main = WorktreeCheckout::Branch("refs/heads/main".into());
+        let result = add(&repo, &dir.path().join("other"), main.clone(), false, &identity());
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert!(!dir.path().join("other").exists());
+
+        add(&repo, &dir.path().join("other"), WorktreeCheckout::Detached(commit), false, &identity()).unwrap();
+        let other = Repository::discover(dir.path().join("other")).unwrap();
+        assert_eq!(other.full_ref_name("HEAD").unwrap(), None);
+        assert!(add(&repo, &dir.path().join("forced"), main, true, &identity()).is_ok());
+    }
+
+    #[test]
+    fn test_remove_and_prune() {
+        let (dir, repo, commit) = setup();
+        let one = dir.path().join("one");
+        let two = dir.path().join("two");
+        add(&repo, &one, WorktreeCheckout::Detached(commit.clone()), false, &identity()).unwrap();
+        add(&repo, &two, WorktreeCheckout::Detached(commit), false, &identity()).unwrap();
+
+        fs::write(one.join("a.txt"), "changed\n").unwrap();
+        assert!(matches!(remove(&repo, "one", false), Err(AppError::Conflict(_))));
+        remove(&repo, one.to_str().unwrap(), true).unwrap();
+        assert!(!one.exists());
+        assert!(matches!(remove(&repo, "main", false), Err(AppError::NotFound(_))));
+
+        fs::remove_dir_all(&two).unwrap();
+        let pruned = prune(&repo).unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].name.as_deref(), Some("two"));
+        assert_eq!(list(&repo).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,39 @@
+// =============================================================================
+// API 에러 응답
+// =============================================================================
+//
+// AppError → HTTP 상태 코드 + JSON 본문 {"error": "..."}
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use shared::error::AppError;
+
+/// 핸들러 반환용 에러 래퍼
+#[derive(Debug)]
+pub struct ApiError(pub AppError);
+
+impl From<AppError> for ApiError {
+    fn from(err: AppError) -> Self {
+        Self(err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::AlreadyExists(_) | AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::InvalidInput(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AppError::Internal(_) | AppError::Storage(_) | AppError::HashMismatch { .. } => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+        if status.is_server_error() {
+            tracing::error!("{}", self.0);
+        }
+        let body = Json(serde_json::json!({ "error": self.0.to_string() }));
+        (status, body).into_response()
+    }
+}
@@ -10,3 +10,18 @@
 pub mod repository;
 pub mod user;
 pub mod build;
+
+pub mod error;
+pub mod state;
+
+use axum::Router;
+
+use crate::state::AppState;
+
+/// 전체 API 라우터
+pub fn app(state: AppState) -> Router {
+    Router::new()
+        .merge(repository::api::routes::routes())
+        .merge(user::api::routes::routes())
+        .with_state(state)
+}
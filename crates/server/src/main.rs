@@ -1,7 +1,41 @@
 // =============================================================================
 // CTS Server 진입점
 // =============================================================================
+//
+// 환경 변수 (.env.example 참고):
+// - HOST, PORT: 바인딩 주소
+// - STORAGE_PATH: 저장소 파일 루트 (repositories/{id} 에 bare 저장소,
+//   commits/{id}.json 에 커밋 메타데이터, signing_keys.json 에 사용자 서명 공개키,
+//   access_tokens.json 에 API 접근 토큰)
 
-fn main() {
-    println!("CTS Server - Coming soon!");
+use std::sync::Arc;
+
+use server::repository::infrastructure::adapters::{FsCommitRepository, FsRepositoryStorage};
+use server::user::infrastructure::adapters::{FsAccessTokenRepository, FsSigningKeyRepository};
+use server::state::AppState;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::dotenv().ok();
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+    let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
+    let storage_path = std::env::var("STORAGE_PATH").unwrap_or_else(|_| "./storage".to_string());
+
+    let storage_path = std::path::Path::new(&storage_path);
+    let state = AppState {
+        repository_storage: Arc::new(FsRepositoryStorage::new(storage_path.join("repositories"))),
+        commits: Arc::new(FsCommitRepository::new(storage_path.join("commits"))),
+        signing_keys: Arc::new(FsSigningKeyRepository::new(storage_path.join("signing_keys.json"))),
+        access_tokens: Arc::new(FsAccessTokenRepository::new(storage_path.join("access_tokens.json"))),
+    };
+
+    let addr = format!("{}:{}", host, port);
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    tracing::info!("CTS Server listening on {}", addr);
+    axum::serve(listener, server::app(state)).await?;
+    Ok(())
 }
@@ -0,0 +1,84 @@
+// =============================================================================
+// Archive 핸들러
+// =============================================================================
+
+use std::io::Write;
+
+use axum::body::Body;
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::IntoResponse;
+use shared::error::AppError;
+use shared::types::Id;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::error::ApiError;
+use crate::repository::application::dto::ArchiveRequest;
+use crate::repository::application::use_cases::ExportArchive;
+use crate::state::AppState;
+
+/// 한 번에 보내는 본문 조각 크기
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// 생성 스레드가 앞서 나갈 수 있는 조각 수 (느린 클라이언트에 맞춰 생성도 멈춤)
+const CHANNEL_CAPACITY: usize = 8;
+
+/// GET /api/repositories/:id/archive?rev=v1.0&format=zip&prefix=app-1.0/&paths=src,docs
+///
+/// 응답 본문은 아카이브를 만들면서 바로 스트리밍 (전체를 메모리에 올리지 않음)
+pub async fn download_archive(
+    State(state): State<AppState>,
+    Path(repository_id): Path<Id>,
+    Query(request): Query<ArchiveRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let use_case = ExportArchive::new(state.repository_storage.clone());
+    let archive = tokio::task::spawn_blocking(move || use_case.prepare(repository_id, request))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    let content_type = archive.format.content_type();
+    let disposition = format!("attachment; filename=\"{}\"", archive.file_name);
+    let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        let mut writer = ChannelWriter { sender, buffer: Vec::with_capacity(CHUNK_SIZE) };
+        let result = archive.write_to(&mut writer).and_then(|()| writer.flush().map_err(AppError::from));
+        if let Err(err) = result {
+            // 이미 보낸 본문은 되돌릴 수 없으므로 스트림을 에러로 끊음
+            tracing::error!("archive failed: {}", err);
+            let _ = writer.sender.blocking_send(Err(std::io::Error::other(err.to_string())));
+        }
+    });
+
+    Ok((
+        [(header::CONTENT_TYPE, content_type.to_string()), (header::CONTENT_DISPOSITION, disposition)],
+        Body::from_stream(ReceiverStream::new(receiver)),
+    ))
+}
+
+/// 쓴 내용을 CHUNK_SIZE 단위로 채널에 보내는 Write (블로킹 스레드 전용)
+struct ChannelWriter {
+    sender: mpsc::Sender<Result<Vec<u8>, std::io::Error>>,
+    buffer: Vec<u8>,
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(data);
+        if self.buffer.len() >= CHUNK_SIZE {
+            self.flush()?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE));
+        // 클라이언트가 연결을 끊으면 수신기가 사라짐 → 생성 중단
+        self.sender
+            .blocking_send(Ok(chunk))
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::BrokenPipe, "client disconnected"))
+    }
+}
@@ -0,0 +1,29 @@
+// =============================================================================
+// Blame 핸들러
+// =============================================================================
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::error::ApiError;
+use crate::repository::application::dto::{BlameFileRequest, BlameFileResponse};
+use crate::repository::application::use_cases::BlameFile;
+use crate::state::AppState;
+
+/// GET /api/repositories/:id/blame?path=src/main.rs&rev=HEAD&ignore_revs=abc,def
+pub async fn blame_file(
+    State(state): State<AppState>,
+    Path(repository_id): Path<Id>,
+    Query(request): Query<BlameFileRequest>,
+) -> Result<Json<BlameFileResponse>, ApiError> {
+    let use_case = BlameFile::new(state.repository_storage.clone());
+
+    // 히스토리 탐색은 블로킹 I/O → 별도 스레드에서 실행
+    let response = tokio::task::spawn_blocking(move || use_case.execute(repository_id, request))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,29 @@
+// =============================================================================
+// Commit 핸들러
+// =============================================================================
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::error::ApiError;
+use crate::repository::application::dto::{CommitResponse, ListCommitsByTrailerRequest};
+use crate::repository::application::use_cases::ListCommitsByTrailer;
+use crate::state::AppState;
+
+/// GET /api/repositories/:id/commits?trailer=Fixes&value=%2342
+pub async fn list_commits_by_trailer(
+    State(state): State<AppState>,
+    Path(repository_id): Path<Id>,
+    Query(request): Query<ListCommitsByTrailerRequest>,
+) -> Result<Json<Vec<CommitResponse>>, ApiError> {
+    let use_case = ListCommitsByTrailer::new(state.repository_storage.clone(), state.commits.clone());
+
+    // 히스토리 탐색과 커밋 기록은 블로킹 I/O → 별도 스레드에서 실행
+    let response = tokio::task::spawn_blocking(move || use_case.execute(repository_id, request))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(Json(response))
+}
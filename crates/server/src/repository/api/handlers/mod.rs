@@ -2,4 +2,8 @@
 // Repository API 핸들러
 // =============================================================================
 
-// TODO: 구현 예정
+pub mod archive;
+pub mod blame;
+pub mod commit;
+pub mod transfer;
+pub mod verification;
@@ -0,0 +1,70 @@
+// =============================================================================
+// 전송 핸들러 (clone / fetch / push)
+// =============================================================================
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::error::ApiError;
+use crate::repository::application::use_cases::{ReceivePack, UploadPack};
+use crate::state::AppState;
+use crate::user::api::auth::AuthUser;
+
+/// push 요청 본문 최대 크기 (팩 포함)
+pub const MAX_PUSH_SIZE: usize = 512 * 1024 * 1024;
+
+/// GET /api/repositories/:id/refs
+pub async fn list_refs(
+    State(state): State<AppState>,
+    Path(repository_id): Path<Id>,
+) -> Result<impl IntoResponse, ApiError> {
+    let use_case = UploadPack::new(state.repository_storage.clone());
+    let refs = tokio::task::spawn_blocking(move || use_case.list_refs(repository_id))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(([(header::CONTENT_TYPE, "text/plain")], refs))
+}
+
+/// POST /api/repositories/:id/fetch
+///
+/// 본문: "want {hash}\n", "have {hash}\n", "object {hash}\n", "filter blob:none\n", "depth 1\n" 등
+/// 응답: "shallow {hash}\n" 들 + 빈 줄 + 팩 (application/octet-stream)
+pub async fn fetch_pack(
+    State(state): State<AppState>,
+    Path(repository_id): Path<Id>,
+    body: String,
+) -> Result<impl IntoResponse, ApiError> {
+    let use_case = UploadPack::new(state.repository_storage.clone());
+
+    // 객체 탐색과 압축은 블로킹 작업 → 별도 스레드에서 실행
+    let pack = tokio::task::spawn_blocking(move || use_case.execute(repository_id, &body))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(([(header::CONTENT_TYPE, "application/octet-stream")], pack))
+}
+
+/// POST /api/repositories/:id/push
+///
+/// 본문: "update {이전 해시 또는 -} {새 해시} {참조}\n" 들 + 빈 줄 + 팩
+/// 참조의 현재 값이 이전 해시와 다르면 409 (아무 참조도 바뀌지 않음)
+/// 접근 토큰이 필요 (reflog 에는 토큰의 사용자를 기록)
+pub async fn push_pack(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(repository_id): Path<Id>,
+    body: Bytes,
+) -> Result<impl IntoResponse, ApiError> {
+    let use_case = ReceivePack::new(state.repository_storage.clone());
+    let identity = user.identity();
+    tokio::task::spawn_blocking(move || use_case.execute(repository_id, &body, &identity))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,41 @@
+// =============================================================================
+// 서명 검증 핸들러
+// =============================================================================
+
+use axum::extract::{Path, State};
+use axum::Json;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::error::ApiError;
+use crate::repository::application::dto::SignatureVerificationResponse;
+use crate::repository::application::use_cases::VerifySignature;
+use crate::state::AppState;
+
+/// GET /api/repositories/:id/commits/:rev/verification
+pub async fn verify_commit(
+    State(state): State<AppState>,
+    Path((repository_id, rev)): Path<(Id, String)>,
+) -> Result<Json<SignatureVerificationResponse>, ApiError> {
+    let use_case = VerifySignature::new(state.repository_storage.clone(), state.signing_keys.clone());
+
+    let response = tokio::task::spawn_blocking(move || use_case.execute_commit(repository_id, &rev))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(Json(response))
+}
+
+/// GET /api/repositories/:id/tags/:name/verification
+pub async fn verify_tag(
+    State(state): State<AppState>,
+    Path((repository_id, name)): Path<(Id, String)>,
+) -> Result<Json<SignatureVerificationResponse>, ApiError> {
+    let use_case = VerifySignature::new(state.repository_storage.clone(), state.signing_keys.clone());
+
+    let response = tokio::task::spawn_blocking(move || use_case.execute_tag(repository_id, &name))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(Json(response))
+}
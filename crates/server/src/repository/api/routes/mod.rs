@@ -9,3 +9,34 @@
 // POST   /api/repositories/:id/commits
 // GET    /api/repositories/:id/commits
 // etc.
+
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
+use axum::Router;
+
+use crate::repository::api::handlers;
+use crate::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/repositories/:id/archive", get(handlers::archive::download_archive))
+        .route("/api/repositories/:id/blame", get(handlers::blame::blame_file))
+        .route(
+            "/api/repositories/:id/commits",
+            get(handlers::commit::list_commits_by_trailer),
+        )
+        .route(
+            "/api/repositories/:id/commits/:rev/verification",
+            get(handlers::verification::verify_commit),
+        )
+        .route(
+            "/api/repositories/:id/tags/:name/verification",
+            get(handlers::verification::verify_tag),
+        )
+        .route("/api/repositories/:id/refs", get(handlers::transfer::list_refs))
+        .route("/api/repositories/:id/fetch", post(handlers::transfer::fetch_pack))
+        .route(
+            "/api/repositories/:id/push",
+            post(handlers::transfer::push_pack).layer(DefaultBodyLimit::max(handlers::transfer::MAX_PUSH_SIZE)),
+        )
+}
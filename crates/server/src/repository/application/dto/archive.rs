@@ -0,0 +1,18 @@
+// =============================================================================
+// Archive DTO
+// =============================================================================
+
+use serde::Deserialize;
+
+/// GET /api/repositories/:id/archive 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchiveRequest {
+    /// 브랜치, 태그 또는 커밋 (기본값: HEAD)
+    pub rev: Option<String>,
+    /// tar, tar.gz, zip (기본값: tar.gz)
+    pub format: Option<String>,
+    /// 모든 경로 앞에 붙일 접두사 (기본값: 없음)
+    pub prefix: Option<String>,
+    /// 포함할 파일/디렉토리 (쉼표 구분, 기본값: 전부)
+    pub paths: Option<String>,
+}
@@ -0,0 +1,28 @@
+// =============================================================================
+// Blame DTO
+// =============================================================================
+
+use core::blame::BlameLine;
+use serde::{Deserialize, Serialize};
+
+/// GET /api/repositories/:id/blame 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize)]
+pub struct BlameFileRequest {
+    /// 저장소 루트 기준 파일 경로
+    pub path: String,
+    /// 리비전 또는 범위 (기본값: HEAD)
+    pub rev: Option<String>,
+    /// 무시할 커밋들 (쉼표 구분)
+    pub ignore_revs: Option<String>,
+}
+
+/// Blame 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct BlameFileResponse {
+    /// 파일 경로
+    pub path: String,
+    /// blame 을 시작한 커밋 해시
+    pub commit: String,
+    /// 라인별 결과
+    pub lines: Vec<BlameLine>,
+}
@@ -0,0 +1,63 @@
+// =============================================================================
+// Commit DTO
+// =============================================================================
+
+use core::trailer::Trailer;
+use serde::{Deserialize, Serialize};
+use shared::types::Timestamp;
+
+use crate::repository::domain::entities::Commit;
+
+/// GET /api/repositories/:id/commits?trailer=Fixes&value=%2342 쿼리 파라미터
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListCommitsByTrailerRequest {
+    /// 트레일러 키 (대소문자 무시)
+    pub trailer: String,
+    /// 트레일러 값 (없으면 키만 일치하는 모든 커밋)
+    pub value: Option<String>,
+}
+
+/// 사람 (작성자/커미터)
+#[derive(Debug, Clone, Serialize)]
+pub struct ActorResponse {
+    pub name: String,
+    pub email: String,
+    /// UTC 시각
+    pub time: Timestamp,
+    /// 작성 당시 UTC 오프셋 (분)
+    pub tz_offset: i32,
+}
+
+/// 커밋 요약
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitResponse {
+    pub hash: String,
+    pub parent_hash: Option<String>,
+    pub message: String,
+    pub author: ActorResponse,
+    pub committer: ActorResponse,
+    pub trailers: Vec<Trailer>,
+}
+
+impl From<Commit> for CommitResponse {
+    fn from(commit: Commit) -> Self {
+        Self {
+            hash: commit.hash,
+            parent_hash: commit.parent_hash,
+            message: commit.message,
+            author: ActorResponse {
+                name: commit.author_name,
+                email: commit.author_email,
+                time: commit.authored_at,
+                tz_offset: commit.author_tz_offset,
+            },
+            committer: ActorResponse {
+                name: commit.committer_name,
+                email: commit.committer_email,
+                time: commit.committed_at,
+                tz_offset: commit.committer_tz_offset,
+            },
+            trailers: commit.trailers,
+        }
+    }
+}
@@ -2,4 +2,12 @@
 // Repository DTO
 // =============================================================================
 
-// TODO: 구현 예정
+pub mod archive;
+pub mod blame;
+pub mod commit;
+pub mod verification;
+
+pub use archive::ArchiveRequest;
+pub use blame::{BlameFileRequest, BlameFileResponse};
+pub use commit::{ActorResponse, CommitResponse, ListCommitsByTrailerRequest};
+pub use verification::{SignatureVerificationResponse, VerificationReason};
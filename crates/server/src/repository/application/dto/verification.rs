@@ -0,0 +1,38 @@
+// =============================================================================
+// 서명 검증 DTO
+// =============================================================================
+
+use serde::Serialize;
+use shared::types::Id;
+
+/// 검증 결과 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationReason {
+    /// 서명이 유효하고 등록된 사용자 키로 서명됨
+    Valid,
+    /// 서명 없음
+    Unsigned,
+    /// 서명이 본문과 맞지 않음 (변조 또는 손상)
+    BadSignature,
+    /// 서명은 유효하지만 등록된 키가 아님
+    UnknownKey,
+}
+
+/// GET .../verification 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct SignatureVerificationResponse {
+    /// 검증한 객체 해시
+    pub object: String,
+    /// "commit" 또는 "tag"
+    pub object_type: String,
+    /// 서명이 있는지
+    pub signed: bool,
+    /// 유효한 서명이고 등록된 키인지 (reason == valid)
+    pub verified: bool,
+    pub reason: VerificationReason,
+    /// 서명 공개키 지문 (서명이 있을 때)
+    pub key_fingerprint: Option<String>,
+    /// 키를 등록한 사용자 (등록된 키일 때)
+    pub signer_user_id: Option<Id>,
+}
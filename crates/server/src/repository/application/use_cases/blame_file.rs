@@ -0,0 +1,53 @@
+// =============================================================================
+// BlameFile 유스케이스
+// =============================================================================
+//
+// 파일의 각 라인을 마지막으로 변경한 커밋 조회 (웹 UI 용)
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use core::blame::{blame, BlameOptions};
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::application::dto::{BlameFileRequest, BlameFileResponse};
+use crate::repository::domain::ports::RepositoryStorage;
+
+pub struct BlameFile {
+    storage: Arc<dyn RepositoryStorage>,
+}
+
+impl BlameFile {
+    pub fn new(storage: Arc<dyn RepositoryStorage>) -> Self {
+        Self { storage }
+    }
+
+    pub fn execute(
+        &self,
+        repository_id: Id,
+        request: BlameFileRequest,
+    ) -> Result<BlameFileResponse, AppError> {
+        let repo = self.storage.open(repository_id)?;
+        let (exclude, commit) = repo.resolve_range(request.rev.as_deref().unwrap_or("HEAD"))?;
+
+        let ignore_revs = request
+            .ignore_revs
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|rev| !rev.is_empty())
+            .map(|rev| repo.resolve_rev(rev))
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        let options = BlameOptions { exclude, ignore_revs };
+        let lines = blame(repo.objects(), &commit, &request.path, &options)?;
+
+        Ok(BlameFileResponse {
+            path: request.path,
+            commit,
+            lines,
+        })
+    }
+}
@@ -0,0 +1,81 @@
+// =============================================================================
+// ExportArchive 유스케이스
+// =============================================================================
+//
+// 브랜치/태그/커밋의 트리를 tar / tar.gz / zip 으로 내려받기 (core::archive)
+// 리비전 확인은 먼저 끝내서 잘못된 요청은 본문을 보내기 전에 에러로 응답하고,
+// 아카이브 본문은 만들면서 바로 내보냄
+
+use std::io::Write;
+use std::sync::Arc;
+
+use core::archive::{write_archive, ArchiveFormat, ArchiveOptions};
+use core::repo::Repository;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::application::dto::ArchiveRequest;
+use crate::repository::domain::ports::RepositoryStorage;
+
+/// 파일 이름에 쓰는 커밋 해시 길이 (rev 가 HEAD 일 때)
+const SHORT_HASH_LENGTH: usize = 12;
+
+pub struct ExportArchive {
+    storage: Arc<dyn RepositoryStorage>,
+}
+
+/// 리비전과 옵션을 확인한 아카이브 요청 (본문은 write_to 에서 생성)
+pub struct PreparedArchive {
+    repo: Repository,
+    commit: String,
+    options: ArchiveOptions,
+    pub format: ArchiveFormat,
+    /// 다운로드 파일 이름 (예: "v1.0.tar.gz", "feature-login.zip")
+    pub file_name: String,
+}
+
+impl ExportArchive {
+    pub fn new(storage: Arc<dyn RepositoryStorage>) -> Self {
+        Self { storage }
+    }
+
+    pub fn prepare(&self, repository_id: Id, request: ArchiveRequest) -> Result<PreparedArchive, AppError> {
+        let repo = self.storage.open(repository_id)?;
+        let rev = request.rev.as_deref().unwrap_or("HEAD");
+        let commit = repo.resolve_rev(rev)?;
+        let format: ArchiveFormat = request.format.as_deref().unwrap_or("tar.gz").parse()?;
+        let options = ArchiveOptions {
+            prefix: request.prefix.unwrap_or_default(),
+            paths: request
+                .paths
+                .as_deref()
+                .unwrap_or("")
+                .split(',')
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+                .map(str::to_string)
+                .collect(),
+        };
+
+        let name = if rev == "HEAD" {
+            commit[..SHORT_HASH_LENGTH].to_string()
+        } else {
+            rev.replace(['/', '\\', '"'], "-")
+        };
+        Ok(PreparedArchive {
+            file_name: format!("{}.{}", name, format.extension()),
+            repo,
+            commit,
+            options,
+            format,
+        })
+    }
+}
+
+impl PreparedArchive {
+    /// 아카이브 본문 기록
+    pub fn write_to<W: Write>(&self, out: W) -> Result<(), AppError> {
+        write_archive(self.repo.objects(), &self.commit, self.format, &self.options, out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// =============================================================================
+// ListCommitsByTrailer 유스케이스
+// =============================================================================
+//
+// 트레일러 키/값으로 커밋 조회 (예: 특정 이슈를 고친 모든 커밋)
+//
+// 조회 전에 참조에서 도달 가능한 커밋 중 아직 기록되지 않은 것을
+// CommitRepository 에 기록 (이미 기록된 커밋을 만나면 그 아래는 탐색하지 않음)
+
+use std::sync::Arc;
+
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::application::dto::{CommitResponse, ListCommitsByTrailerRequest};
+use crate::repository::domain::entities::Commit;
+use crate::repository::domain::ports::{CommitRepository, RepositoryStorage};
+
+pub struct ListCommitsByTrailer {
+    storage: Arc<dyn RepositoryStorage>,
+    commits: Arc<dyn CommitRepository>,
+}
+
+impl ListCommitsByTrailer {
+    pub fn new(storage: Arc<dyn RepositoryStorage>, commits: Arc<dyn CommitRepository>) -> Self {
+        Self { storage, commits }
+    }
+
+    pub fn execute(
+        &self,
+        repository_id: Id,
+        request: ListCommitsByTrailerRequest,
+    ) -> Result<Vec<CommitResponse>, AppError> {
+        let key = request.trailer.trim();
+        if key.is_empty() {
+            return Err(AppError::InvalidInput("Trailer key is required".to_string()));
+        }
+
+        self.index(repository_id)?;
+        let commits = self
+            .commits
+            .find_by_trailer(repository_id, key, request.value.as_deref())?;
+        Ok(commits.into_iter().map(CommitResponse::from).collect())
+    }
+
+    /// 아직 기록되지 않은 커밋 기록
+    fn index(&self, repository_id: Id) -> Result<(), AppError> {
+        let repo = self.storage.open(repository_id)?;
+        let mut known = self.commits.known_hashes(repository_id)?;
+
+        let mut new = Vec::new();
+        for (_, tip) in repo.list_refs("refs/")? {
+            let mut next = Some(repo.objects().peel_to_commit(&tip)?);
+            while let Some(hash) = next {
+                if !known.insert(hash.clone()) {
+                    break;
+                }
+                let commit = repo.objects().read_commit(&hash)?;
+                next = commit.parent_hash.clone();
+                new.push(Commit::from_object(commit));
+            }
+        }
+        self.commits.save_all(repository_id, new)
+    }
+}
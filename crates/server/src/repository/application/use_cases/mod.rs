@@ -10,3 +10,17 @@
 // - GetCommitHistory
 // - CreateBranch
 // - etc.
+
+pub mod blame_file;
+pub mod export_archive;
+pub mod list_commits_by_trailer;
+pub mod receive_pack;
+pub mod upload_pack;
+pub mod verify_signature;
+
+pub use blame_file::BlameFile;
+pub use export_archive::{ExportArchive, PreparedArchive};
+pub use list_commits_by_trailer::ListCommitsByTrailer;
+pub use receive_pack::ReceivePack;
+pub use upload_pack::UploadPack;
+pub use verify_signature::VerifySignature;
@@ -0,0 +1,35 @@
+// =============================================================================
+// ReceivePack 유스케이스
+// =============================================================================
+//
+// push 요청 반영 (core::transport 프로토콜)
+// 객체 저장 → 연결성 확인 → 참조를 compare-and-swap 트랜잭션으로 갱신
+
+use std::sync::Arc;
+
+use core::refs::Identity;
+use core::transport::{apply_push, PushRequest};
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::domain::ports::RepositoryStorage;
+
+pub struct ReceivePack {
+    storage: Arc<dyn RepositoryStorage>,
+}
+
+impl ReceivePack {
+    pub fn new(storage: Arc<dyn RepositoryStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// push 요청 본문 반영 (참조의 현재 값이 요청과 다르면 Conflict)
+    ///
+    /// # Arguments
+    /// * `identity` - reflog 에 기록할 사용자 (push 한 사람)
+    pub fn execute(&self, repository_id: Id, request: &[u8], identity: &Identity) -> Result<(), AppError> {
+        let request = PushRequest::decode(request)?;
+        let repo = self.storage.open(repository_id)?;
+        apply_push(&repo, &request, identity)
+    }
+}
@@ -0,0 +1,40 @@
+// =============================================================================
+// UploadPack 유스케이스
+// =============================================================================
+//
+// cts clone / fetch 에 참조 목록과 팩 제공 (core::transport 프로토콜)
+// partial clone 의 필터와 빠진 객체 요청, shallow clone 의 깊이 제한도 여기서 처리
+
+use std::sync::Arc;
+
+use core::transport::{advertised_refs, format_ref_lines, upload_pack, FetchRequest};
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::domain::ports::RepositoryStorage;
+
+pub struct UploadPack {
+    storage: Arc<dyn RepositoryStorage>,
+}
+
+impl UploadPack {
+    pub fn new(storage: Arc<dyn RepositoryStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// 참조 목록 ("{hash} {name}\n")
+    pub fn list_refs(&self, repository_id: Id) -> Result<String, AppError> {
+        let repo = self.storage.open(repository_id)?;
+        Ok(format_ref_lines(&advertised_refs(&repo)?))
+    }
+
+    /// fetch 요청 본문에 맞는 응답 (경계 커밋 목록 + 팩)
+    pub fn execute(&self, repository_id: Id, request: &str) -> Result<Vec<u8>, AppError> {
+        let request = FetchRequest::decode(request)?;
+        if request.wants.is_empty() && request.objects.is_empty() {
+            return Err(AppError::InvalidInput("Fetch request wants nothing".to_string()));
+        }
+        let repo = self.storage.open(repository_id)?;
+        Ok(upload_pack(repo.objects(), &request)?.encode())
+    }
+}
@@ -0,0 +1,88 @@
+// =============================================================================
+// VerifySignature 유스케이스
+// =============================================================================
+//
+// 커밋/주석 태그의 서명을 검증하고 등록된 사용자 키와 대조
+// (서명 브랜치 정책 등 서버 측 검사에서 사용)
+
+use std::sync::Arc;
+
+use core::object::{Object, Signature};
+use core::refs::TAGS_PREFIX;
+use core::signing::{verify_commit, verify_tag, PublicKey, Verification};
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::application::dto::{SignatureVerificationResponse, VerificationReason};
+use crate::repository::domain::ports::RepositoryStorage;
+use crate::user::domain::ports::SigningKeyRepository;
+
+pub struct VerifySignature {
+    storage: Arc<dyn RepositoryStorage>,
+    keys: Arc<dyn SigningKeyRepository>,
+}
+
+impl VerifySignature {
+    pub fn new(storage: Arc<dyn RepositoryStorage>, keys: Arc<dyn SigningKeyRepository>) -> Self {
+        Self { storage, keys }
+    }
+
+    /// 리비전이 가리키는 커밋 검증
+    pub fn execute_commit(
+        &self,
+        repository_id: Id,
+        rev: &str,
+    ) -> Result<SignatureVerificationResponse, AppError> {
+        let repo = self.storage.open(repository_id)?;
+        let hash = repo.resolve_rev(rev)?;
+        let commit = repo.objects().read_commit(&hash)?;
+        self.respond(hash, "commit", commit.signature.as_ref(), verify_commit(&commit))
+    }
+
+    /// 주석 태그 검증 (가벼운 태그는 서명할 수 없으므로 에러)
+    pub fn execute_tag(
+        &self,
+        repository_id: Id,
+        name: &str,
+    ) -> Result<SignatureVerificationResponse, AppError> {
+        let repo = self.storage.open(repository_id)?;
+        let hash = repo
+            .read_ref(&format!("{}{}", TAGS_PREFIX, name))?
+            .ok_or_else(|| AppError::NotFound(format!("Tag {}", name)))?;
+        let Object::Tag(tag) = repo.objects().read(&hash)? else {
+            return Err(AppError::InvalidInput(format!("{} is not an annotated tag", name)));
+        };
+        self.respond(hash, "tag", tag.signature.as_ref(), verify_tag(&tag))
+    }
+
+    fn respond(
+        &self,
+        object: String,
+        object_type: &str,
+        signature: Option<&Signature>,
+        verification: Verification,
+    ) -> Result<SignatureVerificationResponse, AppError> {
+        let key_fingerprint = signature
+            .and_then(|signature| PublicKey::from_hex(&signature.public_key).ok())
+            .map(|key| key.fingerprint());
+
+        let (reason, signer_user_id) = match verification {
+            Verification::Unsigned => (VerificationReason::Unsigned, None),
+            Verification::Bad(_) => (VerificationReason::BadSignature, None),
+            Verification::Good(key) => match self.keys.find_by_public_key(&key.to_hex())? {
+                Some(registered) => (VerificationReason::Valid, Some(registered.user_id)),
+                None => (VerificationReason::UnknownKey, None),
+            },
+        };
+
+        Ok(SignatureVerificationResponse {
+            object,
+            object_type: object_type.to_string(),
+            signed: signature.is_some(),
+            verified: reason == VerificationReason::Valid,
+            reason,
+            key_fingerprint,
+            signer_user_id,
+        })
+    }
+}
@@ -1,6 +1,68 @@
 // =============================================================================
 // Commit 엔티티
 // =============================================================================
+//
+// commits 테이블 한 행에 대응
+// 작성자(author)와 커미터(committer)를 따로 보관하고,
+// 각 시각은 UTC 로, 작성 당시 시간대는 오프셋(분)으로 저장
+// committed_at 은 커미터 시각 (패치 적용/리베이스 시점)
+// 메시지의 트레일러는 파싱해서 따로 보관 (commit_trailers 테이블, 조회용)
 
-// TODO: 구현 예정
-pub struct Commit;
+use core::trailer::Trailer;
+use serde::{Deserialize, Serialize};
+use shared::types::Timestamp;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commit {
+    pub hash: String,
+    pub tree_hash: String,
+    /// 첫 커밋은 None
+    pub parent_hash: Option<String>,
+    pub message: String,
+    pub author_name: String,
+    pub author_email: String,
+    /// 작성 시각 (UTC)
+    pub authored_at: Timestamp,
+    /// 작성 시각의 UTC 오프셋 (분)
+    pub author_tz_offset: i32,
+    pub committer_name: String,
+    pub committer_email: String,
+    /// 커밋 시각 (UTC)
+    pub committed_at: Timestamp,
+    /// 커밋 시각의 UTC 오프셋 (분)
+    pub committer_tz_offset: i32,
+    /// 메시지 마지막 문단의 트레일러 (메시지 순서)
+    pub trailers: Vec<Trailer>,
+}
+
+impl Commit {
+    /// 트레일러 조건 (키는 대소문자 무시, 값이 None 이면 키만 비교)
+    pub fn has_trailer(&self, key: &str, value: Option<&str>) -> bool {
+        self.trailers
+            .iter()
+            .any(|trailer| trailer.has_key(key) && value.is_none_or(|value| trailer.value == value))
+    }
+
+    /// 저장소의 커밋 객체를 행으로 변환
+    pub fn from_object(mut commit: core::object::Commit) -> Self {
+        let hash = commit.hash().to_string();
+        let trailers = commit.trailers();
+        let author = commit.author;
+        let committer = commit.committer;
+        Self {
+            hash,
+            tree_hash: commit.tree_hash,
+            parent_hash: commit.parent_hash,
+            message: commit.message,
+            author_name: author.name,
+            author_email: author.email,
+            authored_at: author.time,
+            author_tz_offset: author.offset,
+            committer_name: committer.name,
+            committer_email: committer.email,
+            committed_at: committer.time,
+            committer_tz_offset: committer.offset,
+            trailers,
+        }
+    }
+}
@@ -1,6 +1,35 @@
 // =============================================================================
 // Commit Repository 포트
 // =============================================================================
+//
+// 저장소별 커밋 메타데이터 (작성자/커미터/트레일러) 보관
+// 객체 저장소를 매번 탐색하지 않고 트레일러 등으로 커밋을 조회할 때 사용
 
-// TODO: 구현 예정
-pub trait CommitRepository {}
+use std::collections::HashSet;
+
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::domain::entities::Commit;
+
+pub trait CommitRepository: Send + Sync {
+    /// 이미 기록된 커밋 해시들
+    fn known_hashes(&self, repository_id: Id) -> Result<HashSet<String>, AppError>;
+
+    /// 커밋 기록 (이미 있는 해시는 건너뜀)
+    fn save_all(&self, repository_id: Id, commits: Vec<Commit>) -> Result<(), AppError>;
+
+    /// 트레일러로 커밋 조회
+    ///
+    /// * `key` - 트레일러 키 (대소문자 무시)
+    /// * `value` - 값 (None 이면 키만 일치하면 됨)
+    ///
+    /// # Returns
+    /// 커밋 시각 최신순
+    fn find_by_trailer(
+        &self,
+        repository_id: Id,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<Vec<Commit>, AppError>;
+}
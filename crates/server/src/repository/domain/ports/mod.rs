@@ -5,7 +5,9 @@
 pub mod repository_repository;
 pub mod commit_repository;
 pub mod blob_storage;
+pub mod repository_storage;
 
 pub use repository_repository::RepositoryRepository;
 pub use commit_repository::CommitRepository;
 pub use blob_storage::BlobStorage;
+pub use repository_storage::RepositoryStorage;
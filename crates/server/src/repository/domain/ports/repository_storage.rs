@@ -0,0 +1,20 @@
+// =============================================================================
+// Repository Storage 포트
+// =============================================================================
+//
+// 저장소별 CTS 객체 저장소(bare 저장소) 접근
+// - 커밋/트리/Blob 을 core 객체 모델 그대로 읽기
+// - blame, 트리 조회 등 히스토리 기반 기능에서 사용
+
+use core::repo::Repository;
+use shared::error::AppError;
+use shared::types::Id;
+
+/// 저장소 ID → 로컬 bare 저장소
+pub trait RepositoryStorage: Send + Sync {
+    /// 저장소 열기
+    ///
+    /// # Errors
+    /// * `AppError::NotFound` - 해당 ID 의 저장소가 없음
+    fn open(&self, repository_id: Id) -> Result<Repository, AppError>;
+}
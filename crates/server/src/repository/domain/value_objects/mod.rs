@@ -7,6 +7,7 @@ pub mod hash;
 pub mod repository_name;
 pub mod branch_name;
 
+#[allow(unused_imports)] // ids 모듈 구현 전까지 비어 있음
 pub use ids::*;
 pub use hash::Hash;
 pub use repository_name::RepositoryName;
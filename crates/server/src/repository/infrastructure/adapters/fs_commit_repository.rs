@@ -0,0 +1,93 @@
+// =============================================================================
+// 파일 시스템 Commit Repository 어댑터
+// =============================================================================
+//
+// {STORAGE_PATH}/commits/{repository_id}.json 에 저장소별 커밋 목록을 JSON 배열로 보관
+// 쓰기는 임시 파일 + rename 으로 교체 (중간에 실패해도 기존 파일 유지)
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::domain::entities::Commit;
+use crate::repository::domain::ports::CommitRepository;
+
+/// JSON 파일 기반 CommitRepository 구현
+#[derive(Debug)]
+pub struct FsCommitRepository {
+    root: PathBuf,
+    /// 읽기-수정-쓰기 직렬화
+    lock: Mutex<()>,
+}
+
+impl FsCommitRepository {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self {
+            root: root.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn path(&self, repository_id: Id) -> PathBuf {
+        self.root.join(format!("{}.json", repository_id))
+    }
+
+    fn load(&self, repository_id: Id) -> Result<Vec<Commit>, AppError> {
+        let path = self.path(repository_id);
+        match fs::read(&path) {
+            Ok(data) => serde_json::from_slice(&data)
+                .map_err(|err| AppError::Storage(format!("{}: {}", path.display(), err))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn store(&self, repository_id: Id, commits: &[Commit]) -> Result<(), AppError> {
+        fs::create_dir_all(&self.root)?;
+        let path = self.path(repository_id);
+        let data = serde_json::to_vec_pretty(commits).map_err(|err| AppError::Internal(err.to_string()))?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
+
+impl CommitRepository for FsCommitRepository {
+    fn known_hashes(&self, repository_id: Id) -> Result<HashSet<String>, AppError> {
+        Ok(self.load(repository_id)?.into_iter().map(|commit| commit.hash).collect())
+    }
+
+    fn save_all(&self, repository_id: Id, commits: Vec<Commit>) -> Result<(), AppError> {
+        if commits.is_empty() {
+            return Ok(());
+        }
+        let _guard = self.lock.lock().map_err(|_| AppError::Internal("Commit store lock poisoned".to_string()))?;
+        let mut stored = self.load(repository_id)?;
+        for commit in commits {
+            if !stored.iter().any(|existing| existing.hash == commit.hash) {
+                stored.push(commit);
+            }
+        }
+        self.store(repository_id, &stored)
+    }
+
+    fn find_by_trailer(
+        &self,
+        repository_id: Id,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<Vec<Commit>, AppError> {
+        let mut commits: Vec<Commit> = self
+            .load(repository_id)?
+            .into_iter()
+            .filter(|commit| commit.has_trailer(key, value))
+            .collect();
+        commits.sort_by_key(|commit| std::cmp::Reverse(commit.committed_at));
+        Ok(commits)
+    }
+}
@@ -0,0 +1,38 @@
+// =============================================================================
+// 파일 시스템 Repository Storage 어댑터
+// =============================================================================
+//
+// {STORAGE_PATH}/repositories/{repository_id}/ 에 bare 저장소 보관
+
+use std::path::PathBuf;
+
+use core::repo::Repository;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::repository::domain::ports::RepositoryStorage;
+
+/// 로컬 디렉토리 기반 RepositoryStorage 구현
+#[derive(Debug, Clone)]
+pub struct FsRepositoryStorage {
+    /// 저장소들이 위치한 루트 디렉토리
+    root: PathBuf,
+}
+
+impl FsRepositoryStorage {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// 저장소 디렉토리 경로
+    pub fn repository_path(&self, repository_id: Id) -> PathBuf {
+        self.root.join(repository_id.to_string())
+    }
+}
+
+impl RepositoryStorage for FsRepositoryStorage {
+    fn open(&self, repository_id: Id) -> Result<Repository, AppError> {
+        Repository::open(self.repository_path(repository_id))
+            .map_err(|_| AppError::NotFound(format!("Repository {}", repository_id)))
+    }
+}
@@ -6,3 +6,9 @@
 // - PostgresRepositoryAdapter
 // - PostgresCommitAdapter
 // - FileBlobStorage
+
+pub mod fs_repository_storage;
+pub mod fs_commit_repository;
+
+pub use fs_repository_storage::FsRepositoryStorage;
+pub use fs_commit_repository::FsCommitRepository;
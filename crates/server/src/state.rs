@@ -0,0 +1,23 @@
+// =============================================================================
+// 애플리케이션 상태 (AppState)
+// =============================================================================
+//
+// 모든 핸들러가 공유하는 의존성 (포트 구현체)
+// axum State 로 주입
+
+use std::sync::Arc;
+
+use crate::repository::domain::ports::{CommitRepository, RepositoryStorage};
+use crate::user::domain::ports::{AccessTokenRepository, SigningKeyRepository};
+
+#[derive(Clone)]
+pub struct AppState {
+    /// 저장소별 객체 저장소
+    pub repository_storage: Arc<dyn RepositoryStorage>,
+    /// 커밋 메타데이터 (트레일러 조회용)
+    pub commits: Arc<dyn CommitRepository>,
+    /// 사용자 서명 공개키
+    pub signing_keys: Arc<dyn SigningKeyRepository>,
+    /// API 접근 토큰 (요청 인증)
+    pub access_tokens: Arc<dyn AccessTokenRepository>,
+}
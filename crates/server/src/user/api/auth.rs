@@ -0,0 +1,70 @@
+// =============================================================================
+// 요청 인증 (AuthUser 추출기)
+// =============================================================================
+//
+// 핸들러 인자에 AuthUser 를 두면 "Authorization: Bearer {토큰}" 을 확인
+// 헤더가 없거나 등록되지 않은 토큰이면 401 (본문을 읽기 전에 거부)
+//
+// #[async_trait] 는 ::core 경로로 펼쳐져 이 워크스페이스의 core 크레이트와 겹치므로
+// FromRequestParts 의 박싱된 Future 시그니처를 직접 구현
+
+use std::future::Future;
+use std::pin::Pin;
+
+use axum::extract::FromRequestParts;
+use axum::http::header;
+use axum::http::request::Parts;
+use core::refs::Identity;
+use shared::error::AppError;
+
+use crate::error::ApiError;
+use crate::state::AppState;
+use crate::user::application::use_cases::Authenticate;
+use crate::user::domain::entities::AccessToken;
+
+/// 인증된 사용자
+#[derive(Debug, Clone)]
+pub struct AuthUser(pub AccessToken);
+
+impl AuthUser {
+    /// reflog 등에 기록할 사용자
+    pub fn identity(&self) -> Identity {
+        Identity::new(self.0.name.clone(), self.0.email.clone())
+    }
+}
+
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = ApiError;
+
+    fn from_request_parts<'parts, 'state, 'future>(
+        parts: &'parts mut Parts,
+        state: &'state AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Self, Self::Rejection>> + Send + 'future>>
+    where
+        'parts: 'future,
+        'state: 'future,
+        Self: 'future,
+    {
+        Box::pin(authenticate(parts, state))
+    }
+}
+
+/// Authorization 헤더의 토큰 확인
+async fn authenticate(parts: &Parts, state: &AppState) -> Result<AuthUser, ApiError> {
+    let token = parts
+        .headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or(AppError::Unauthorized)?
+        .to_string();
+
+    // 토큰 파일 읽기는 블로킹 I/O → 별도 스레드에서 실행
+    let use_case = Authenticate::new(state.access_tokens.clone());
+    let token = tokio::task::spawn_blocking(move || use_case.execute(&token))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+    Ok(AuthUser(token))
+}
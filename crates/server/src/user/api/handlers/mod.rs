@@ -2,4 +2,4 @@
 // User API 핸들러
 // =============================================================================
 
-// TODO: 구현 예정
+pub mod signing_key;
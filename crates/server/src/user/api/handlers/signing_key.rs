@@ -0,0 +1,49 @@
+// =============================================================================
+// SigningKey 핸들러
+// =============================================================================
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::error::ApiError;
+use crate::state::AppState;
+use crate::user::api::auth::AuthUser;
+use crate::user::application::dto::{AddSigningKeyRequest, SigningKeyResponse};
+use crate::user::application::use_cases::{AddSigningKey, ListSigningKeys};
+
+/// POST /api/users/:id/signing-keys
+///
+/// 접근 토큰의 사용자와 :id 가 같아야 함
+pub async fn add_signing_key(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(user_id): Path<Id>,
+    Json(request): Json<AddSigningKeyRequest>,
+) -> Result<(StatusCode, Json<SigningKeyResponse>), ApiError> {
+    let use_case = AddSigningKey::new(state.signing_keys.clone());
+
+    // 키 파일 갱신은 블로킹 I/O → 별도 스레드에서 실행
+    let requester = user.0.user_id;
+    let response = tokio::task::spawn_blocking(move || use_case.execute(requester, user_id, request))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// GET /api/users/:id/signing-keys
+pub async fn list_signing_keys(
+    State(state): State<AppState>,
+    Path(user_id): Path<Id>,
+) -> Result<Json<Vec<SigningKeyResponse>>, ApiError> {
+    let use_case = ListSigningKeys::new(state.signing_keys.clone());
+
+    let response = tokio::task::spawn_blocking(move || use_case.execute(user_id))
+        .await
+        .map_err(|err| AppError::Internal(err.to_string()))??;
+
+    Ok(Json(response))
+}
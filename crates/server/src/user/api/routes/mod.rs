@@ -6,3 +6,16 @@
 // POST /api/auth/register
 // POST /api/auth/login
 // GET  /api/users/me
+
+use axum::routing::get;
+use axum::Router;
+
+use crate::state::AppState;
+use crate::user::api::handlers;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route(
+        "/api/users/:id/signing-keys",
+        get(handlers::signing_key::list_signing_keys).post(handlers::signing_key::add_signing_key),
+    )
+}
@@ -2,4 +2,6 @@
 // User DTO
 // =============================================================================
 
-// TODO: 구현 예정
+pub mod signing_key;
+
+pub use signing_key::{AddSigningKeyRequest, SigningKeyResponse};
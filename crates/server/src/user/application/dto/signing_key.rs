@@ -0,0 +1,40 @@
+// =============================================================================
+// SigningKey DTO
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+use shared::types::{Id, Timestamp};
+
+use crate::user::domain::entities::SigningKey;
+
+/// POST /api/users/:id/signing-keys 요청 본문
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddSigningKeyRequest {
+    /// 키 이름
+    pub title: String,
+    /// 공개키 ("ed25519 {hex} [설명]" 또는 hex)
+    pub key: String,
+}
+
+/// 등록된 키
+#[derive(Debug, Clone, Serialize)]
+pub struct SigningKeyResponse {
+    pub id: Id,
+    pub title: String,
+    /// "ed25519 {hex}"
+    pub key: String,
+    pub fingerprint: String,
+    pub created_at: Timestamp,
+}
+
+impl From<SigningKey> for SigningKeyResponse {
+    fn from(key: SigningKey) -> Self {
+        Self {
+            id: key.id,
+            title: key.title,
+            key: format!("{} {}", core::signing::ALGORITHM, key.public_key),
+            fingerprint: key.fingerprint,
+            created_at: key.created_at,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// =============================================================================
+// AddSigningKey 유스케이스
+// =============================================================================
+//
+// 사용자 서명 공개키 등록 (형식 검증 후 저장)
+// 본인만 등록 가능 (다른 사람 이름으로 키를 올리면 그 사람의 서명으로 검증되므로)
+
+use std::sync::Arc;
+
+use core::signing::PublicKey;
+use shared::error::AppError;
+use shared::types::{new_id, now, Id};
+
+use crate::user::application::dto::{AddSigningKeyRequest, SigningKeyResponse};
+use crate::user::domain::entities::SigningKey;
+use crate::user::domain::ports::SigningKeyRepository;
+
+pub struct AddSigningKey {
+    keys: Arc<dyn SigningKeyRepository>,
+}
+
+impl AddSigningKey {
+    pub fn new(keys: Arc<dyn SigningKeyRepository>) -> Self {
+        Self { keys }
+    }
+
+    /// # Arguments
+    /// * `requester` - 인증된 사용자
+    /// * `user_id` - 키 소유자 (requester 와 같아야 함)
+    ///
+    /// # Errors
+    /// * `AppError::Unauthorized` - 다른 사용자의 키 등록
+    pub fn execute(
+        &self,
+        requester: Id,
+        user_id: Id,
+        request: AddSigningKeyRequest,
+    ) -> Result<SigningKeyResponse, AppError> {
+        if requester != user_id {
+            return Err(AppError::Unauthorized);
+        }
+        let title = request.title.trim();
+        if title.is_empty() {
+            return Err(AppError::InvalidInput("Key title must not be empty".to_string()));
+        }
+        let public_key = PublicKey::parse(&request.key)?;
+
+        let key = SigningKey {
+            id: new_id(),
+            user_id,
+            title: title.to_string(),
+            public_key: public_key.to_hex(),
+            fingerprint: public_key.fingerprint(),
+            created_at: now(),
+        };
+        self.keys.add(key.clone())?;
+        Ok(key.into())
+    }
+}
@@ -0,0 +1,29 @@
+// =============================================================================
+// Authenticate 유스케이스
+// =============================================================================
+//
+// 요청의 접근 토큰으로 사용자 확인
+
+use std::sync::Arc;
+
+use shared::error::AppError;
+
+use crate::user::domain::entities::AccessToken;
+use crate::user::domain::ports::AccessTokenRepository;
+
+pub struct Authenticate {
+    tokens: Arc<dyn AccessTokenRepository>,
+}
+
+impl Authenticate {
+    pub fn new(tokens: Arc<dyn AccessTokenRepository>) -> Self {
+        Self { tokens }
+    }
+
+    /// 토큰 원문 → 토큰 정보 (등록되지 않았으면 Unauthorized)
+    pub fn execute(&self, token: &str) -> Result<AccessToken, AppError> {
+        self.tokens
+            .find_by_hash(&AccessToken::hash(token))?
+            .ok_or(AppError::Unauthorized)
+    }
+}
@@ -0,0 +1,25 @@
+// =============================================================================
+// ListSigningKeys 유스케이스
+// =============================================================================
+
+use std::sync::Arc;
+
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::user::application::dto::SigningKeyResponse;
+use crate::user::domain::ports::SigningKeyRepository;
+
+pub struct ListSigningKeys {
+    keys: Arc<dyn SigningKeyRepository>,
+}
+
+impl ListSigningKeys {
+    pub fn new(keys: Arc<dyn SigningKeyRepository>) -> Self {
+        Self { keys }
+    }
+
+    pub fn execute(&self, user_id: Id) -> Result<Vec<SigningKeyResponse>, AppError> {
+        Ok(self.keys.list_by_user(user_id)?.into_iter().map(Into::into).collect())
+    }
+}
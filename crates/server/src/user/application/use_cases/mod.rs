@@ -6,3 +6,11 @@
 // - Register
 // - Login
 // - GetProfile
+
+pub mod add_signing_key;
+pub mod list_signing_keys;
+pub mod authenticate;
+
+pub use add_signing_key::AddSigningKey;
+pub use list_signing_keys::ListSigningKeys;
+pub use authenticate::Authenticate;
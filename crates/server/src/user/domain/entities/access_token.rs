@@ -0,0 +1,32 @@
+// =============================================================================
+// AccessToken 엔티티 (API 접근 토큰)
+// =============================================================================
+//
+// "Authorization: Bearer {토큰}" 으로 사용자를 식별
+// 토큰 원문은 저장하지 않고 SHA-256 만 보관
+//
+// 사용자 가입/로그인이 생기기 전까지는 토큰이 사용자 이름과 이메일도 함께 가짐
+// (push 의 reflog 등 사용자를 기록할 때 사용)
+
+use core::hash::Hasher;
+use serde::{Deserialize, Serialize};
+use shared::types::{Id, Timestamp};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessToken {
+    pub id: Id,
+    /// 토큰 소유자
+    pub user_id: Id,
+    pub name: String,
+    pub email: String,
+    /// 토큰 원문의 SHA-256 (hex)
+    pub token_hash: String,
+    pub created_at: Timestamp,
+}
+
+impl AccessToken {
+    /// 토큰 원문 → 저장하는 해시
+    pub fn hash(token: &str) -> String {
+        Hasher::new().hash_bytes(token.as_bytes())
+    }
+}
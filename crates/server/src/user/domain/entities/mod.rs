@@ -3,5 +3,9 @@
 // =============================================================================
 
 pub mod user;
+pub mod signing_key;
+pub mod access_token;
 
 pub use user::User;
+pub use signing_key::SigningKey;
+pub use access_token::AccessToken;
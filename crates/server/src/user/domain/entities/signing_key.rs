@@ -0,0 +1,23 @@
+// =============================================================================
+// SigningKey 엔티티 (사용자 서명 공개키)
+// =============================================================================
+//
+// 사용자가 등록한 Ed25519 공개키
+// 커밋/태그 서명의 공개키가 등록된 키와 같으면 그 사용자가 서명한 것으로 판단
+
+use serde::{Deserialize, Serialize};
+use shared::types::{Id, Timestamp};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningKey {
+    pub id: Id,
+    /// 키 소유자
+    pub user_id: Id,
+    /// 사용자가 붙인 이름 ("laptop" 등)
+    pub title: String,
+    /// 공개키 (hex, 서명 헤더와 같은 표현)
+    pub public_key: String,
+    /// 짧은 지문 ("SHA256:...")
+    pub fingerprint: String,
+    pub created_at: Timestamp,
+}
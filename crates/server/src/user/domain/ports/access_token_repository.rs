@@ -0,0 +1,14 @@
+// =============================================================================
+// AccessToken Repository 포트
+// =============================================================================
+//
+// API 접근 토큰 저장소 (요청 인증 시 토큰 해시로 조회)
+
+use shared::error::AppError;
+
+use crate::user::domain::entities::AccessToken;
+
+pub trait AccessTokenRepository: Send + Sync {
+    /// 토큰 해시로 찾기
+    fn find_by_hash(&self, token_hash: &str) -> Result<Option<AccessToken>, AppError>;
+}
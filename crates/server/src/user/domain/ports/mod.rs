@@ -3,5 +3,9 @@
 // =============================================================================
 
 pub mod user_repository;
+pub mod signing_key_repository;
+pub mod access_token_repository;
 
 pub use user_repository::UserRepository;
+pub use signing_key_repository::SigningKeyRepository;
+pub use access_token_repository::AccessTokenRepository;
@@ -0,0 +1,26 @@
+// =============================================================================
+// SigningKey Repository 포트
+// =============================================================================
+//
+// 사용자 서명 공개키 저장소
+// - 키 등록/조회
+// - 서명 검증 시 공개키로 소유자 찾기
+
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::user::domain::entities::SigningKey;
+
+pub trait SigningKeyRepository: Send + Sync {
+    /// 키 등록
+    ///
+    /// # Errors
+    /// * `AppError::AlreadyExists` - 같은 공개키가 이미 등록됨 (다른 사용자 포함)
+    fn add(&self, key: SigningKey) -> Result<(), AppError>;
+
+    /// 사용자의 키 목록 (등록 순)
+    fn list_by_user(&self, user_id: Id) -> Result<Vec<SigningKey>, AppError>;
+
+    /// 공개키(hex)로 등록된 키 찾기
+    fn find_by_public_key(&self, public_key: &str) -> Result<Option<SigningKey>, AppError>;
+}
@@ -0,0 +1,43 @@
+// =============================================================================
+// 파일 시스템 AccessToken Repository 어댑터
+// =============================================================================
+//
+// {STORAGE_PATH}/access_tokens.json 의 JSON 배열 (운영자가 관리, 서버는 읽기만)
+//   [{"id": ..., "user_id": ..., "name": ..., "email": ...,
+//     "token_hash": "{토큰의 SHA-256 hex}", "created_at": ...}]
+// 파일이 없으면 토큰이 하나도 없음 (인증이 필요한 요청은 모두 401)
+
+use std::fs;
+use std::path::PathBuf;
+
+use shared::error::AppError;
+
+use crate::user::domain::entities::AccessToken;
+use crate::user::domain::ports::AccessTokenRepository;
+
+/// JSON 파일 기반 AccessTokenRepository 구현
+#[derive(Debug)]
+pub struct FsAccessTokenRepository {
+    path: PathBuf,
+}
+
+impl FsAccessTokenRepository {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn load(&self) -> Result<Vec<AccessToken>, AppError> {
+        match fs::read(&self.path) {
+            Ok(data) => serde_json::from_slice(&data)
+                .map_err(|err| AppError::Storage(format!("{}: {}", self.path.display(), err))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl AccessTokenRepository for FsAccessTokenRepository {
+    fn find_by_hash(&self, token_hash: &str) -> Result<Option<AccessToken>, AppError> {
+        Ok(self.load()?.into_iter().find(|token| token.token_hash == token_hash))
+    }
+}
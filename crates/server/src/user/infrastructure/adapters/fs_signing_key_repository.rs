@@ -0,0 +1,73 @@
+// =============================================================================
+// 파일 시스템 SigningKey Repository 어댑터
+// =============================================================================
+//
+// {STORAGE_PATH}/signing_keys.json 에 전체 키 목록을 JSON 배열로 보관
+// 쓰기는 임시 파일 + rename 으로 교체 (중간에 실패해도 기존 파일 유지)
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use shared::error::AppError;
+use shared::types::Id;
+
+use crate::user::domain::entities::SigningKey;
+use crate::user::domain::ports::SigningKeyRepository;
+
+/// JSON 파일 기반 SigningKeyRepository 구현
+#[derive(Debug)]
+pub struct FsSigningKeyRepository {
+    path: PathBuf,
+    /// 읽기-수정-쓰기 직렬화
+    lock: Mutex<()>,
+}
+
+impl FsSigningKeyRepository {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn load(&self) -> Result<Vec<SigningKey>, AppError> {
+        match fs::read(&self.path) {
+            Ok(data) => serde_json::from_slice(&data)
+                .map_err(|err| AppError::Storage(format!("{}: {}", self.path.display(), err))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn store(&self, keys: &[SigningKey]) -> Result<(), AppError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_vec_pretty(keys).map_err(|err| AppError::Internal(err.to_string()))?;
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+impl SigningKeyRepository for FsSigningKeyRepository {
+    fn add(&self, key: SigningKey) -> Result<(), AppError> {
+        let _guard = self.lock.lock().map_err(|_| AppError::Internal("Key store lock poisoned".to_string()))?;
+        let mut keys = self.load()?;
+        if keys.iter().any(|existing| existing.public_key == key.public_key) {
+            return Err(AppError::AlreadyExists(format!("Signing key {}", key.fingerprint)));
+        }
+        keys.push(key);
+        self.store(&keys)
+    }
+
+    fn list_by_user(&self, user_id: Id) -> Result<Vec<SigningKey>, AppError> {
+        Ok(self.load()?.into_iter().filter(|key| key.user_id == user_id).collect())
+    }
+
+    fn find_by_public_key(&self, public_key: &str) -> Result<Option<SigningKey>, AppError> {
+        Ok(self.load()?.into_iter().find(|key| key.public_key == public_key))
+    }
+}
@@ -4,3 +4,9 @@
 
 // TODO: 구현 예정
 // - PostgresUserAdapter
+
+pub mod fs_signing_key_repository;
+pub mod fs_access_token_repository;
+
+pub use fs_signing_key_repository::FsSigningKeyRepository;
+pub use fs_access_token_repository::FsAccessTokenRepository;
@@ -38,6 +38,7 @@ use thiserror::Error;
 /// # 에러 종류
 /// - NotFound: 리소스를 찾을 수 없음 (404)
 /// - AlreadyExists: 이미 존재함 (409 Conflict)
+/// - Conflict: 동시 수정 충돌 (409 Conflict)
 /// - InvalidInput: 잘못된 입력 (400 Bad Request)
 /// - Unauthorized: 인증 필요 (401)
 /// - Internal: 내부 서버 에러 (500)
@@ -52,7 +53,10 @@ pub enum AppError {
     ///
     /// # Example
     /// ```
+    /// # use shared::error::AppError;
+    /// # let _ =
     /// AppError::NotFound("User abc123".to_string())
+    /// # ;
     /// // Display: "Not found: User abc123"
     /// ```
     #[error("Not found: {0}")]
@@ -67,6 +71,12 @@ pub enum AppError {
     #[error("Already exists: {0}")]
     AlreadyExists(String),
 
+    /// 동시 수정 충돌
+    ///
+    /// 예: 참조를 갱신하려는데 그 사이 다른 프로세스가 값을 바꿨을 때
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     // -------------------------------------------------------------------------
     // 400 Bad Request
     // -------------------------------------------------------------------------
@@ -113,10 +123,13 @@ pub enum AppError {
     ///
     /// # Example
     /// ```
+    /// # use shared::error::AppError;
+    /// # let _ =
     /// AppError::HashMismatch {
     ///     expected: "abc123".to_string(),
     ///     actual: "def456".to_string(),
     /// }
+    /// # ;
     /// // Display: "Hash mismatch: expected abc123, got def456"
     /// ```
     #[error("Hash mismatch: expected {expected}, got {actual}")]
@@ -136,13 +149,13 @@ pub enum AppError {
 //   let file = std::fs::read("file.txt")?;  
 //   // std::io::Error → AppError::Storage 자동 변환
 
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Storage(err.to_string())
+    }
+}
+
 // 주석 처리: 필요할 때 활성화
-// impl From<std::io::Error> for AppError {
-//     fn from(err: std::io::Error) -> Self {
-//         AppError::Storage(err.to_string())
-//     }
-// }
-//
 // impl From<sqlx::Error> for AppError {
 //     fn from(err: sqlx::Error) -> Self {
 //         AppError::Internal(err.to_string())
@@ -32,4 +32,5 @@ pub mod error;
 /// - Timestamp: DateTime<Utc> 타입 별칭
 /// - new_id(): 새 UUID 생성
 /// - now(): 현재 시간 반환
+/// - format_epoch() / parse_epoch(): 커밋 시간 "{epoch} {±HHMM}" 표기
 pub mod types;
\ No newline at end of file
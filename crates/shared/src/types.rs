@@ -12,14 +12,19 @@
 //
 //   let user_id: Id = new_id();
 //   let created_at: Timestamp = now();
+//
+// 커밋/태그 시간은 UTC 시각 + 작성자의 시간대 오프셋(분)으로 다룸
+//   "1705314600 +0900" ↔ (Timestamp, 540)
 // =============================================================================
 
 // -----------------------------------------------------------------------------
 // 외부 크레이트 import
 // -----------------------------------------------------------------------------
-use chrono::{DateTime, Utc};  // 날짜/시간 타입
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, Utc};  // 날짜/시간 타입
 use uuid::Uuid;                // UUID 타입
 
+use crate::error::AppError;
+
 // =============================================================================
 // 타입 별칭 (Type Alias)
 // =============================================================================
@@ -44,6 +49,7 @@ use uuid::Uuid;                // UUID 타입
 ///
 /// # Example
 /// ```
+/// # use shared::types::{Id, new_id};
 /// let id: Id = new_id();
 /// println!("{}", id);  // "550e8400-e29b-41d4-a716-446655440000"
 /// ```
@@ -57,11 +63,17 @@ pub type Id = Uuid;
 ///
 /// # Example
 /// ```
+/// # use shared::types::{Timestamp, now};
 /// let timestamp: Timestamp = now();
 /// println!("{}", timestamp);  // "2024-01-15T10:30:00Z"
 /// ```
 pub type Timestamp = DateTime<Utc>;
 
+/// 시간대 오프셋이 적용된 타임스탬프
+///
+/// 커밋 시간을 작성자의 현지 시각으로 보여줄 때 사용
+pub type LocalTimestamp = DateTime<FixedOffset>;
+
 // =============================================================================
 // 헬퍼 함수
 // =============================================================================
@@ -115,6 +127,105 @@ pub fn now() -> Timestamp {
     Utc::now()
 }
 
+// =============================================================================
+// epoch + 오프셋 표기 (커밋/태그 시간)
+// =============================================================================
+
+/// 허용하는 최대 오프셋 (분): ±23:59
+const MAX_OFFSET_MINUTES: i32 = 24 * 60 - 1;
+
+/// 현재 시스템 시간대의 UTC 오프셋 (분)
+pub fn local_offset_minutes() -> i32 {
+    Local::now().offset().fix().local_minus_utc() / 60
+}
+
+/// 오프셋을 적용한 현지 시각
+///
+/// 오프셋이 범위를 벗어나면 UTC 로 표시
+pub fn with_offset(time: &Timestamp, offset_minutes: i32) -> LocalTimestamp {
+    let offset = FixedOffset::east_opt(offset_minutes * 60).unwrap_or_else(|| Utc.fix());
+    time.with_timezone(&offset)
+}
+
+/// "{epoch 초} {±HHMM}" 형식으로 렌더링
+///
+/// # Example
+/// ```
+/// use shared::types::{format_epoch, Timestamp};
+///
+/// let time = Timestamp::from_timestamp(1705314600, 0).unwrap();
+/// assert_eq!(format_epoch(&time, 9 * 60), "1705314600 +0900");
+/// assert_eq!(format_epoch(&time, -150), "1705314600 -0230");
+/// ```
+pub fn format_epoch(time: &Timestamp, offset_minutes: i32) -> String {
+    let sign = if offset_minutes < 0 { '-' } else { '+' };
+    let abs = offset_minutes.abs();
+    format!("{} {}{:02}{:02}", time.timestamp(), sign, abs / 60, abs % 60)
+}
+
+/// "{epoch 초} {±HHMM}" 파싱 (format_epoch 의 역변환)
+///
+/// # Errors
+/// * `AppError::InvalidInput` - 형식이 다르거나 오프셋이 범위를 벗어남
+pub fn parse_epoch(value: &str) -> Result<(Timestamp, i32), AppError> {
+    let invalid = || AppError::InvalidInput(format!("Invalid timestamp: {}", value));
+    let (seconds, offset) = value.split_once(' ').ok_or_else(invalid)?;
+
+    if seconds.is_empty() || !seconds.trim_start_matches('-').bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let seconds: i64 = seconds.parse().map_err(|_| invalid())?;
+    let time = Timestamp::from_timestamp(seconds, 0).ok_or_else(invalid)?;
+
+    let (sign, digits) = match offset.as_bytes().first() {
+        Some(b'+') => (1, &offset[1..]),
+        Some(b'-') => (-1, &offset[1..]),
+        _ => return Err(invalid()),
+    };
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let hours: i32 = digits[..2].parse().map_err(|_| invalid())?;
+    let minutes: i32 = digits[2..].parse().map_err(|_| invalid())?;
+    let offset = sign * (hours * 60 + minutes);
+    if minutes >= 60 || offset.abs() > MAX_OFFSET_MINUTES {
+        return Err(invalid());
+    }
+    Ok((time, offset))
+}
+
+/// 사용자가 입력한 날짜 파싱 (UTC)
+///
+/// "2024-01-15", "2024-01-15 09:30:00", RFC 3339, epoch 초 를 허용
+///
+/// # Example
+/// ```
+/// use shared::types::parse_date;
+///
+/// assert_eq!(parse_date("2024-01-15").unwrap().timestamp(), 1705276800);
+/// assert_eq!(parse_date("2024-01-15T10:30:00+09:00").unwrap().timestamp(), 1705282200);
+/// assert_eq!(parse_date("1705276800").unwrap().timestamp(), 1705276800);
+/// assert!(parse_date("yesterday").is_err());
+/// ```
+pub fn parse_date(value: &str) -> Result<Timestamp, AppError> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<i64>() {
+        if let Some(time) = Timestamp::from_timestamp(seconds, 0) {
+            return Ok(time);
+        }
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    if let Ok(time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Ok(time.and_utc());
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+    Err(AppError::InvalidInput(format!("Invalid date: {}", value)))
+}
+
 // =============================================================================
 // 추가 유틸리티 (필요 시 구현)
 // =============================================================================
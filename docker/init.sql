@@ -80,11 +80,28 @@ CREATE TABLE IF NOT EXISTS commits (
     message TEXT NOT NULL,
     author_name VARCHAR(100) NOT NULL,
     author_email VARCHAR(255) NOT NULL,
-    committed_at TIMESTAMPTZ NOT NULL,
+    authored_at TIMESTAMPTZ NOT NULL,
+    author_tz_offset SMALLINT NOT NULL DEFAULT 0,       -- UTC 오프셋 (분)
+    committer_name VARCHAR(100) NOT NULL,
+    committer_email VARCHAR(255) NOT NULL,
+    committed_at TIMESTAMPTZ NOT NULL,                  -- 커미터 시각
+    committer_tz_offset SMALLINT NOT NULL DEFAULT 0,    -- UTC 오프셋 (분)
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     CONSTRAINT uk_commits_repo_hash UNIQUE (repository_id, hash)
 );
 
+-- ---------------------------------------------------------------------------
+-- commit_trailers (커밋 메시지 트레일러: Fixes, Reviewed-by, Signed-off-by 등)
+-- ---------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS commit_trailers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    commit_id UUID NOT NULL REFERENCES commits(id) ON DELETE CASCADE,
+    position SMALLINT NOT NULL,      -- 메시지 안에서의 순서
+    key VARCHAR(100) NOT NULL,       -- 적힌 그대로 (조회는 대소문자 무시)
+    value TEXT NOT NULL,
+    CONSTRAINT uk_commit_trailers UNIQUE (commit_id, position)
+);
+
 -- ---------------------------------------------------------------------------
 -- branches (브랜치)
 -- ---------------------------------------------------------------------------
@@ -134,6 +151,7 @@ CREATE INDEX IF NOT EXISTS idx_blobs_repository ON blobs(repository_id);
 CREATE INDEX IF NOT EXISTS idx_trees_repository ON trees(repository_id);
 CREATE INDEX IF NOT EXISTS idx_commits_repository ON commits(repository_id);
 CREATE INDEX IF NOT EXISTS idx_commits_parent ON commits(parent_id);
+CREATE INDEX IF NOT EXISTS idx_commit_trailers_key_value ON commit_trailers(LOWER(key), value);
 CREATE INDEX IF NOT EXISTS idx_branches_repository ON branches(repository_id);
 CREATE INDEX IF NOT EXISTS idx_builds_repository ON builds(repository_id);
 CREATE INDEX IF NOT EXISTS idx_builds_status ON builds(status);